@@ -56,6 +56,56 @@ RIGID_BODY_KIND = {
     KINEMATIC_VELOCITY_BASED = 3.0,
 }
 
+---@enum joint_kind
+JOINT_KIND = {
+    FIXED     = 0.0,
+    REVOLUTE  = 1.0,
+    PRISMATIC = 2.0,
+    SPHERICAL = 3.0,
+    ROPE      = 4.0,
+}
+
+---@enum joint_axis
+JOINT_AXIS = {
+    LINEAR_X  = 0.0,
+    LINEAR_Y  = 1.0,
+    LINEAR_Z  = 2.0,
+    ANGULAR_X = 3.0,
+    ANGULAR_Y = 4.0,
+    ANGULAR_Z = 5.0,
+}
+
+---@enum motor_model
+MOTOR_MODEL = {
+    ACCELERATION_BASED = 0.0,
+    FORCE_BASED        = 1.0,
+}
+
+---@enum shape_kind
+SHAPE_KIND = {
+    BALL     = 0.0,
+    CAPSULE  = 1.0,
+    CUBOID   = 2.0,
+    CYLINDER = 3.0,
+}
+
+---@enum collision_type
+COLLISION_TYPE = {
+    DYNAMIC_DYNAMIC     = 1.0,
+    DYNAMIC_KINEMATIC   = 2.0,
+    DYNAMIC_FIXED       = 4.0,
+    KINEMATIC_KINEMATIC = 8.0,
+    KINEMATIC_FIXED     = 16.0,
+    FIXED_FIXED         = 32.0,
+}
+
+---@enum model_shape_kind
+MODEL_SHAPE_KIND = {
+    TRI_MESH             = 0.0,
+    CONVEX_HULL          = 1.0,
+    CONVEX_DECOMPOSITION = 2.0,
+}
+
 ---@enum trace_log_level
 TRACE_LOG_LEVEL = {
     ALL     = 0,
@@ -441,3 +491,26 @@ TEXTURE_WRAP    = {
     MIRROR_REPEAT = 2, -- Mirrors and repeats the texture in tiled mode
     MIRROR_CLAMP  = 3  -- Mirrors and clamps to border the texture in tiled mode
 }
+
+---@enum socket_state
+SOCKET_STATE = {
+    CONNECTING = 0.0,
+    CONNECT    = 1.0,
+    DISCONNECT = 2.0,
+}
+
+---@enum socket_event
+SOCKET_EVENT = {
+    CONNECT    = 0.0,
+    MESSAGE    = 1.0,
+    DISCONNECT = 2.0,
+    FAILURE    = 3.0,
+    PONG       = 4.0,
+}
+
+---@enum net_channel
+NET_CHANNEL = {
+    RELIABLE_ORDERED   = 0.0,
+    RELIABLE_UNORDERED = 1.0,
+    UNRELIABLE         = 2.0,
+}
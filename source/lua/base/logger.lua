@@ -51,16 +51,44 @@
 local LOGGER_LINE_COLOR_HISTORY = color:new(127.0, 127.0, 127.0, 255.0)
 local LOGGER_LINE_COLOR_MESSAGE = color:new(255.0, 255.0, 255.0, 255.0)
 local LOGGER_LINE_COLOR_FAILURE = color:new(255.0, 0.0, 0.0, 255.0)
+local LOGGER_LINE_COLOR_INPUT   = color:new(160.0, 160.0, 160.0, 255.0)
 local LOGGER_LINE_COUNT         = 48.0
 local LOGGER_LINE_DELAY         = 4.0
 local LOGGER_LINE_LABEL_TIME    = true
 local LOGGER_ACTION_ABOVE       = action:new({ action_button:new(INPUT_DEVICE.BOARD, INPUT_BOARD.UP) })
 local LOGGER_ACTION_BELOW       = action:new({ action_button:new(INPUT_DEVICE.BOARD, INPUT_BOARD.DOWN) })
-local LOGGER_ACTION_TOGGLE      = action:new({ action_button:new(INPUT_DEVICE.BOARD, INPUT_BOARD.F2) })
+local LOGGER_ACTION_COMPLETE    = action:new({ action_button:new(INPUT_DEVICE.BOARD, INPUT_BOARD.TAB) })
 local LOGGER_FONT_SCALE         = 24.0
 local LOGGER_FONT_SPACE         = 2.0
 local LOGGER_LINE_CAP           = 4.0
 
+---Convert a value to a string. Tables are pretty-printed.
+---@param value any # Value to convert.
+local function logger_format(value)
+    if type(value) == "table" then
+        return table.as_string(value)
+    end
+
+    return tostring(value)
+end
+
+---Get every key in a table, as well as every key in the table's __index meta-table.
+---@param value table # Table to get the key list of.
+---@param list  table # Table to insert every key into.
+local function logger_key(value, list)
+    for k, _ in pairs(value) do
+        if type(k) == "string" then
+            list[k] = true
+        end
+    end
+
+    local meta = getmetatable(value)
+
+    if type(meta) == "table" and type(meta.__index) == "table" and meta.__index ~= value then
+        logger_key(meta.__index, list)
+    end
+end
+
 --[[----------------------------------------------------------------]]
 
 ---@class logger
@@ -71,9 +99,11 @@ logger = {
     __meta = {}
 }
 
----Create a new logger.
+---Create a new logger. The logger will capture every print call until closed, and will evaluate Lua code in the running Lua state, from either the logger console or the standard input.
+---@example lua/logger.lua
+---@param  key? input_board # OPTIONAL: Key to toggle the logger console with. Default: INPUT_BOARD.F2.
 ---@return logger value # The logger.
-function logger:new()
+function logger:new(key)
     local i = {}
     setmetatable(i, self.__meta)
     getmetatable(i).__index = self
@@ -81,33 +111,152 @@ function logger:new()
     --[[]]
 
     i.__type  = "logger"
-    i.font    = alicia.font.new_default(LOGGER_FONT_SCALE)
     i.work    = ""
     i.current = {}
     i.history = {}
     i.active  = false
-    i.window  = window:new()
+    i.toggle  = action:new({ action_button:new(INPUT_DEVICE.BOARD, key or INPUT_BOARD.F2) })
 
     --[[]]
 
-    -- replace default print function with our own.
+    -- replace default print function with our own, until the logger is closed.
     local lua_print = print
 
+    i.lua_print     = lua_print
     print           = function(...)
         lua_print(...)
-        i:print(..., color:new(255.0, 255.0, 255.0, 255.0))
+
+        local label = {}
+
+        for x = 1, select("#", ...) do
+            table.insert(label, tostring(select(x, ...)))
+        end
+
+        i:print(table.concat(label, "\t"))
     end
 
     return i
 end
 
+---Close the logger, and restore the print function it replaced.
+function logger:close()
+    if self.lua_print then
+        print          = self.lua_print
+        self.lua_print = nil
+    end
+end
+
+---Update the logger. Will evaluate every line sent through the standard input. Can be used in head-less mode.
+function logger:update()
+    local line = alicia.general.get_standard_input()
+
+    while line do
+        if not (line == "") then
+            -- the standard input has no console to show the result in, so also print it out.
+            self:evaluate(line, true)
+        end
+
+        line = alicia.general.get_standard_input()
+    end
+end
+
+---Evaluate Lua code. The code is first evaluated as an expression, and then as a statement. Every result is printed to the logger.
+---@param  code    string  # Lua code to evaluate.
+---@param  output? boolean # OPTIONAL: If true, will also print every result to the standard output.
+---@return boolean success # True on success, false otherwise.
+function logger:evaluate(code, output)
+    local lua_print = self.lua_print or print
+    local write     = function(label, line_color)
+        if output then
+            lua_print(label)
+        end
+
+        self:print(label, line_color)
+    end
+
+    self:print(code, LOGGER_LINE_COLOR_INPUT)
+
+    table.insert(self.history, code)
+
+    -- evaluate as an expression first, so that the result (if any) can be shown.
+    local call, error = loadstring("return " .. code, "=logger")
+
+    if not call then
+        call, error = loadstring(code, "=logger")
+    end
+
+    if not call then
+        write(error, LOGGER_LINE_COLOR_FAILURE)
+        return false
+    end
+
+    local result = { pcall(call) }
+
+    if not result[1] then
+        write(tostring(result[2]), LOGGER_LINE_COLOR_FAILURE)
+        return false
+    end
+
+    for x = 2, table.maxn(result) do
+        write(logger_format(result[x]))
+    end
+
+    return true
+end
+
+---Get every completion for the last identifier in a string, i.e. "alicia.gen" will complete to "alicia.general".
+---@param  work string # String to complete.
+---@return table list # Every completion, in alphabetical order.
+function logger:complete(work)
+    local path = string.match(work, "[%w_%.:]*$")
+    local head, last = string.match(path, "^(.*[%.:])([%w_]*)$")
+
+    -- no table in the path, complete from the global table.
+    if not head then
+        head = ""
+        last = path
+    end
+
+    local value = _G
+
+    -- walk down every table in the path.
+    for name in string.gmatch(head, "([%w_]+)[%.:]") do
+        value = value[name]
+
+        if not (type(value) == "table") then
+            return {}
+        end
+    end
+
+    local key = {}
+    local list = {}
+
+    logger_key(value, key)
+
+    for k, _ in pairs(key) do
+        if string.sub(k, 1, #last) == last then
+            table.insert(list, string.sub(work, 1, #work - #last) .. k)
+        end
+    end
+
+    table.sort(list)
+
+    return list
+end
+
 ---Draw the logger.
 function logger:draw()
+    -- the font and window are only made on first draw, so that a logger can be made in head-less mode.
+    if not self.font then
+        self.font   = alicia.font.new_default(LOGGER_FONT_SCALE)
+        self.window = window:new()
+    end
+
     -- get screen shape.
     local shape = vector_2:new(alicia.window.get_shape())
 
     -- toggle the active state of the logger console.
-    if LOGGER_ACTION_TOGGLE:press() then
+    if self.toggle:press() then
         self.active = not self.active
     end
 
@@ -153,6 +302,17 @@ function logger:draw()
             end
         end
 
+        -- complete the work string. show every candidate if there is more than one.
+        if LOGGER_ACTION_COMPLETE:press() then
+            local list = self:complete(self.work)
+
+            if #list == 1 then
+                self.work = list[1]
+            elseif #list > 1 then
+                self:print(table.concat(list, " "), LOGGER_LINE_COLOR_INPUT)
+            end
+        end
+
         -- draw semi-opaque background.
         alicia.draw_2d.draw_box_2(box_2:new(vector_2:zero(), shape), vector_2:zero(), 0.0,
             color:new(0.0, 0.0, 0.0, 127.0))
@@ -165,23 +325,9 @@ function logger:draw()
                 self.work)
         end)
 
-        -- if the user has hit RETURN, and the work string is not empty, then execute the Lua code in the work string.
+        -- if the user has hit RETURN, and the work string is not empty, then evaluate the Lua code in the work string.
         if click and not (self.work == "") then
-            self:print(self.work, color:new(160.0, 160.0, 160.0, 255.0))
-
-            local call, error = loadstring(self.work)
-
-            if call then
-                local success, message = pcall(call)
-
-                if not success then
-                    self:print(message, color:new(255.0, 0.0, 0.0, 255.0))
-                end
-            else
-                self:print(error, color:new(255.0, 0.0, 0.0, 255.0))
-            end
-
-            table.insert(self.history, self.work)
+            self:evaluate(self.work)
             self.work = ""
         end
     end
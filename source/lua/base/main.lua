@@ -53,8 +53,10 @@ require("base/extension")
 require("base/allocator")
 require("base/primitive")
 require("base/scheduler")
+require("base/replication")
 require("base/action")
 require("base/logger")
+require("base/profiler")
 require("base/window")
 require("base/system")
 require("base/scene")
@@ -0,0 +1,136 @@
+--[[
+-- Copyright (c) 2025 luxreduxdelux
+--
+-- Redistribution and use in source and binary forms, with or without
+-- modification, are permitted provided that the following conditions are met:
+--
+-- 1. Redistributions of source code must retain the above copyright notice,
+-- this list of conditions and the following disclaimer.
+--
+-- 2. Redistributions in binary form must reproduce the above copyright notice,
+-- this list of conditions and the following disclaimer in the documentation
+-- and/or other materials provided with the distribution.
+--
+-- Subject to the terms and conditions of this license, each copyright holder
+-- and contributor hereby grants to those receiving rights under this license
+-- a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+-- (except for failure to satisfy the conditions of this license) patent license
+-- to make, have made, use, offer to sell, sell, import, and otherwise transfer
+-- this software, where such license applies only to those patent claims, already
+-- acquired or hereafter acquired, licensable by such copyright holder or
+-- contributor that are necessarily infringed by:
+--
+-- (a) their Contribution(s) (the licensed copyrights of copyright holders and
+-- non-copyrightable additions of contributors, in source or binary form) alone;
+-- or
+--
+-- (b) combination of their Contribution(s) with the work of authorship to which
+-- such Contribution(s) was added by such copyright holder or contributor, if,
+-- at the time the Contribution is added, such addition causes such combination
+-- to be necessarily infringed. The patent license shall not apply to any other
+-- combinations which include the Contribution.
+--
+-- Except as expressly stated above, no rights or licenses from any copyright
+-- holder or contributor is granted under this license, whether expressly, by
+-- implication, estoppel or otherwise.
+--
+-- DISCLAIMER
+--
+-- THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+-- AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+-- IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+-- DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+-- FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+-- DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+-- SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+-- CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+-- OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+-- OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+local PROFILER_COLOR_BACK  = color:new(0.0, 0.0, 0.0, 191.0)
+local PROFILER_COLOR_BAR   = color:new(156.0, 39.0, 176.0, 255.0)
+local PROFILER_COLOR_TEXT  = color:new(255.0, 255.0, 255.0, 255.0)
+local PROFILER_ENTRY_COUNT = 16.0
+local PROFILER_FONT_SCALE  = 20.0
+local PROFILER_FONT_SPACE  = 1.0
+local PROFILER_SHAPE       = vector_2:new(480.0, PROFILER_FONT_SCALE + 4.0)
+
+--[[----------------------------------------------------------------]]
+
+---@class profiler
+---@field active boolean
+profiler = {
+    __meta = {}
+}
+
+---Create a new profiler overlay. The overlay will show every scope in the last frame as a bar, relative to the frame time.
+---@example profiler/overlay.lua
+---@param  key?  input_board # OPTIONAL: Key to toggle the overlay with. Default: INPUT_BOARD.F4.
+---@param  hook? boolean     # OPTIONAL: Also record every Lua function call. See alicia.profiler.begin.
+---@return profiler value # The profiler overlay.
+function profiler:new(key, hook)
+    local i = {}
+    setmetatable(i, self.__meta)
+    getmetatable(i).__index = self
+
+    --[[]]
+
+    i.__type = "profiler"
+    i.active = false
+    i.hook   = hook
+    i.toggle = action:new({ action_button:new(INPUT_DEVICE.BOARD, key or INPUT_BOARD.F4) })
+
+    return i
+end
+
+---Draw the profiler overlay. Recording is only done while the overlay is active.
+function profiler:draw()
+    -- the font is only made on first draw, so that an overlay can be made in head-less mode.
+    if not self.font then
+        self.font = alicia.font.new_default(PROFILER_FONT_SCALE)
+    end
+
+    -- toggle the active state of the overlay, and begin or close recording.
+    if self.toggle:press() then
+        self.active = not self.active
+
+        if self.active then
+            alicia.profiler.begin(self.hook)
+        else
+            alicia.profiler.close()
+        end
+    end
+
+    if not self.active then
+        return
+    end
+
+    local frame_time, list = alicia.profiler.get_frame()
+    local count = math.min(#list, PROFILER_ENTRY_COUNT)
+    local point = vector_2:new(8.0, 8.0)
+
+    -- draw semi-opaque background.
+    alicia.draw_2d.draw_box_2(
+        box_2:new(point, vector_2:new(PROFILER_SHAPE.x + 8.0, PROFILER_SHAPE.y * (count + 1.0) + 8.0)),
+        vector_2:zero(), 0.0, PROFILER_COLOR_BACK)
+
+    point = point + vector_2:new(4.0, 4.0)
+
+    self.font:draw(string.format("frame: %.2f ms", frame_time), point, vector_2:zero(), 0.0,
+        PROFILER_FONT_SCALE, PROFILER_FONT_SPACE, PROFILER_COLOR_TEXT)
+
+    -- draw every scope as a bar, from slowest to fastest.
+    for x = 1, count do
+        local entry = list[x]
+        local scale = frame_time > 0.0 and math.min(entry.time / frame_time, 1.0) or 0.0
+
+        point.y = point.y + PROFILER_SHAPE.y
+
+        alicia.draw_2d.draw_box_2(
+            box_2:new(point, vector_2:new(PROFILER_SHAPE.x * scale, PROFILER_SHAPE.y - 4.0)),
+            vector_2:zero(), 0.0, PROFILER_COLOR_BAR)
+
+        self.font:draw(string.format("%s: %.2f ms (%d)", entry.name, entry.time, entry.count), point,
+            vector_2:zero(), 0.0, PROFILER_FONT_SCALE, PROFILER_FONT_SPACE, PROFILER_COLOR_TEXT)
+    end
+end
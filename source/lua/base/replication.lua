@@ -0,0 +1,473 @@
+--[[
+-- Copyright (c) 2025 luxreduxdelux
+--
+-- Redistribution and use in source and binary forms, with or without
+-- modification, are permitted provided that the following conditions are met:
+--
+-- 1. Redistributions of source code must retain the above copyright notice,
+-- this list of conditions and the following disclaimer.
+--
+-- 2. Redistributions in binary form must reproduce the above copyright notice,
+-- this list of conditions and the following disclaimer in the documentation
+-- and/or other materials provided with the distribution.
+--
+-- Subject to the terms and conditions of this license, each copyright holder
+-- and contributor hereby grants to those receiving rights under this license
+-- a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+-- (except for failure to satisfy the conditions of this license) patent license
+-- to make, have made, use, offer to sell, sell, import, and otherwise transfer
+-- this software, where such license applies only to those patent claims, already
+-- acquired or hereafter acquired, licensable by such copyright holder or
+-- contributor that are necessarily infringed by:
+--
+-- (a) their Contribution(s) (the licensed copyrights of copyright holders and
+-- non-copyrightable additions of contributors, in source or binary form) alone;
+-- or
+--
+-- (b) combination of their Contribution(s) with the work of authorship to which
+-- such Contribution(s) was added by such copyright holder or contributor, if,
+-- at the time the Contribution is added, such addition causes such combination
+-- to be necessarily infringed. The patent license shall not apply to any other
+-- combinations which include the Contribution.
+--
+-- Except as expressly stated above, no rights or licenses from any copyright
+-- holder or contributor is granted under this license, whether expressly, by
+-- implication, estoppel or otherwise.
+--
+-- DISCLAIMER
+--
+-- THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+-- AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+-- IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+-- DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+-- FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+-- DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+-- SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+-- CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+-- OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+-- OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+--]]
+
+local REPLICATION_HISTORY = 32.0
+local REPLICATION_DELAY   = 2.0
+
+---Check if two values are equal. Tables are compared by value.
+---@param a any # Value A.
+---@param b any # Value B.
+local function equal(a, b)
+    if type(a) ~= "table" or type(b) ~= "table" then
+        return a == b
+    end
+
+    for k, v in pairs(a) do
+        if not equal(v, b[k]) then
+            return false
+        end
+    end
+
+    for k, _ in pairs(b) do
+        if a[k] == nil then
+            return false
+        end
+    end
+
+    return true
+end
+
+---Interpolate between two values. Numbers (and tables of numbers) are interpolated, anything else is taken from value A.
+---@param a    any    # Value A.
+---@param b    any    # Value B.
+---@param time number # Time, from 0.0 to 1.0.
+local function interpolate(a, b, time)
+    if type(a) == "number" and type(b) == "number" then
+        return math.interpolate(a, b, time)
+    end
+
+    if type(a) == "table" and type(b) == "table" then
+        local i = {}
+
+        for k, v in pairs(a) do
+            i[k] = interpolate(v, b[k], time)
+        end
+
+        return i
+    end
+
+    return a
+end
+
+---Decode a message, as sent by a replication server or client. Any message that can not be decoded is dropped.
+---@param data string | data # The message.
+---@return table? message # The message, or nil if it could not be decoded.
+local function decode(data)
+    -- messages from a net transport come in as a data buffer.
+    if type(data) ~= "string" then
+        local success, value = pcall(alicia.data.from_data, data, 2)
+
+        if not success then
+            return nil
+        end
+
+        data = value
+    end
+
+    local success, message = pcall(alicia.data.deserialize, data)
+
+    if not success or type(message) ~= "table" then
+        return nil
+    end
+
+    return message
+end
+
+--[[----------------------------------------------------------------]]
+
+---@class replication_server
+---@field tick    number
+---@field entity  table
+---@field client  table
+---@field history table
+replication_server = {
+    __meta = {}
+}
+
+---Create a new replication server. The server is authoritative over every entity state, and will send each client a snapshot of every entity, as a delta from the last snapshot that client has acknowledged.
+---@example lua/replication.lua
+---@param  history?          number # OPTIONAL: Snapshot count to keep as a delta base. Must match the client. Default: 32.
+---@return replication_server value # The replication server.
+function replication_server:new(history)
+    local i = {}
+    setmetatable(i, self.__meta)
+    getmetatable(i).__index = self
+
+    --[[]]
+
+    i.__type       = "replication_server"
+    i.tick         = 0.0
+    i.entity       = {}
+    i.client       = {}
+    i.history      = {}
+    i.history_size = history or REPLICATION_HISTORY
+
+    return i
+end
+
+---Set the state of an entity. The state must be a table with string keys, and will be copied on the next update.
+---@param id    number | string # The entity ID.
+---@param state table           # The entity state.
+function replication_server:set_entity(id, state)
+    self.entity[id] = state
+end
+
+---Remove an entity.
+---@param id number | string # The entity ID.
+function replication_server:remove_entity(id)
+    self.entity[id] = nil
+end
+
+---Insert a client. Until the client acknowledges a snapshot, every snapshot sent to it will be a full snapshot.
+---@param client number # The client ID. For a net transport, this is the peer number.
+function replication_server:insert_client(client)
+    self.client[client] = {
+        tick = nil
+    }
+end
+
+---Remove a client.
+---@param client number # The client ID.
+function replication_server:remove_client(client)
+    self.client[client] = nil
+end
+
+---Advance the server tick, and take a snapshot of every entity.
+---@return number tick # The new server tick.
+function replication_server:update()
+    self.tick = self.tick + 1.0
+
+    self.history[self.tick] = table.copy(self.entity)
+    self.history[self.tick - self.history_size] = nil
+
+    return self.tick
+end
+
+---Get the snapshot for the current tick, for a given client, as a delta from the last snapshot the client has acknowledged.
+---@param  client number # The client ID.
+---@return string data   # The snapshot, in serialized form.
+function replication_server:get_snapshot(client)
+    local work = self.client[client]
+
+    if not work then
+        error("replication_server:get_snapshot(): Unknown client.")
+    end
+
+    local state = self.history[self.tick]
+
+    if not state then
+        error("replication_server:get_snapshot(): No snapshot available. Call update first.")
+    end
+
+    -- the base snapshot might have been dropped from the history, in which case we send everything.
+    local base = work.tick and self.history[work.tick]
+
+    local snapshot = {
+        tick   = self.tick,
+        base   = base and work.tick or nil,
+        entity = {},
+        remove = {},
+    }
+
+    -- for every entity in the current snapshot...
+    for id, value in pairs(state) do
+        local old = base and base[id]
+        local field = {}
+        local clear = {}
+        local change = false
+
+        -- insert every field that is new or has changed since the base snapshot.
+        for k, v in pairs(value) do
+            if not old or not equal(v, old[k]) then
+                field[k] = v
+                change = true
+            end
+        end
+
+        -- insert every field that has been removed since the base snapshot.
+        if old then
+            for k, _ in pairs(old) do
+                if value[k] == nil then
+                    table.insert(clear, k)
+                    change = true
+                end
+            end
+        end
+
+        if change or not old then
+            table.insert(snapshot.entity, { id = id, field = field, clear = clear })
+        end
+    end
+
+    -- insert every entity that has been removed since the base snapshot.
+    if base then
+        for id, _ in pairs(base) do
+            if state[id] == nil then
+                table.insert(snapshot.remove, id)
+            end
+        end
+    end
+
+    return alicia.data.serialize(snapshot)
+end
+
+---Handle an acknowledgement from a client, as given by replication_client:get_acknowledge.
+---@param client number        # The client ID.
+---@param data   string | data # The acknowledgement.
+function replication_server:receive(client, data)
+    local work = self.client[client]
+
+    if not work then
+        return
+    end
+
+    local message = decode(data)
+
+    if not message then
+        return
+    end
+
+    -- acknowledgements can come out of order; only keep the latest one.
+    if message.tick and self.history[message.tick] and message.tick > (work.tick or 0.0) then
+        work.tick = message.tick
+    end
+end
+
+---Send the snapshot for the current tick to every client, through a net transport. Every client ID must be a peer number.
+---@param net      net     # The net transport.
+---@param channel? number  # OPTIONAL: The channel number to send on. Default: NET_CHANNEL.UNRELIABLE.
+function replication_server:send(net, channel)
+    for client, _ in pairs(self.client) do
+        net:set(client, channel or NET_CHANNEL.UNRELIABLE, self:get_snapshot(client))
+    end
+end
+
+--[[----------------------------------------------------------------]]
+
+---@class replication_client
+---@field tick     number?
+---@field newest   number?
+---@field snapshot table
+replication_client = {
+    __meta = {}
+}
+
+---Create a new replication client. The client will buffer every snapshot by tick, and interpolate between them with a delay.
+---@example lua/replication.lua
+---@param  rate              number # The server tick rate, in ticks per second.
+---@param  delay?            number # OPTIONAL: Interpolation delay, in ticks. Default: 2.
+---@param  history?          number # OPTIONAL: Snapshot count to keep as a delta base. Must match the server. Default: 32.
+---@return replication_client value # The replication client.
+function replication_client:new(rate, delay, history)
+    local i = {}
+    setmetatable(i, self.__meta)
+    getmetatable(i).__index = self
+
+    --[[]]
+
+    i.__type       = "replication_client"
+    i.rate         = rate
+    i.delay        = delay or REPLICATION_DELAY
+    i.tick         = nil
+    i.newest       = nil
+    i.snapshot     = {}
+    i.history_size = history or REPLICATION_HISTORY
+
+    return i
+end
+
+---Handle a snapshot from the server, as given by replication_server:get_snapshot.
+---@param  data   string | data # The snapshot.
+---@return boolean value        # True if the snapshot was applied, false if it was a duplicate, too old, or its delta base is missing.
+function replication_client:receive(data)
+    local snapshot = decode(data)
+
+    -- a malformed snapshot is dropped.
+    if not snapshot or type(snapshot.tick) ~= "number" then
+        return false
+    end
+
+    if self.snapshot[snapshot.tick] then
+        return false
+    end
+
+    if self.newest and snapshot.tick <= self.newest - self.history_size then
+        return false
+    end
+
+    -- rebuild the full snapshot from the base snapshot.
+    local state = {}
+
+    if snapshot.base then
+        local base = self.snapshot[snapshot.base]
+
+        if not base then
+            return false
+        end
+
+        state = table.copy(base)
+    end
+
+    for _, id in ipairs(snapshot.remove) do
+        state[id] = nil
+    end
+
+    for _, entry in ipairs(snapshot.entity) do
+        local value = state[entry.id] or {}
+
+        for k, v in pairs(entry.field) do
+            value[k] = v
+        end
+
+        for _, k in ipairs(entry.clear) do
+            value[k] = nil
+        end
+
+        state[entry.id] = value
+    end
+
+    self.snapshot[snapshot.tick] = state
+
+    -- drop every snapshot that can no longer be used as a delta base.
+    if not self.newest or snapshot.tick > self.newest then
+        self.newest = snapshot.tick
+
+        for tick, _ in pairs(self.snapshot) do
+            if tick <= self.newest - self.history_size then
+                self.snapshot[tick] = nil
+            end
+        end
+    end
+
+    return true
+end
+
+---Get the acknowledgement for the newest snapshot, to send back to the server.
+---@return string? data # The acknowledgement, or nil if no snapshot has been received yet.
+function replication_client:get_acknowledge()
+    if self.newest then
+        return alicia.data.serialize({ tick = self.newest })
+    end
+end
+
+---Advance the interpolation tick. If the interpolation tick drifts too far from the newest snapshot, it will snap back.
+---@param step number # Time step, in seconds. Typically the frame time.
+function replication_client:update(step)
+    if not self.newest then
+        return
+    end
+
+    local target = self.newest - self.delay
+
+    if not self.tick then
+        self.tick = target
+    end
+
+    self.tick = self.tick + step * self.rate
+
+    if math.abs(self.tick - target) > self.delay * 2.0 then
+        self.tick = target
+    end
+
+    self.tick = math.min(self.tick, self.newest)
+end
+
+---Get the state of every entity, interpolated at the current interpolation tick.
+---@return table entity_list # Every entity state, by entity ID.
+function replication_client:get_entity_list()
+    if not self.tick then
+        return {}
+    end
+
+    local a = nil
+    local b = nil
+
+    -- find the nearest snapshot before, and after, the interpolation tick.
+    for tick, _ in pairs(self.snapshot) do
+        if tick <= self.tick and (not a or tick > a) then
+            a = tick
+        end
+
+        if tick > self.tick and (not b or tick < b) then
+            b = tick
+        end
+    end
+
+    if not a then
+        return table.copy(self.snapshot[b])
+    end
+
+    if not b then
+        return table.copy(self.snapshot[a])
+    end
+
+    local time = (self.tick - a) / (b - a)
+    local list = {}
+
+    -- an entity only in snapshot B has not been created yet, and an entity only in snapshot A is kept until removed.
+    for id, value in pairs(self.snapshot[a]) do
+        local other = self.snapshot[b][id]
+
+        if other then
+            list[id] = interpolate(value, other, time)
+        else
+            list[id] = table.copy(value)
+        end
+    end
+
+    return list
+end
+
+---Get the state of an entity, interpolated at the current interpolation tick.
+---@param  id    number | string # The entity ID.
+---@return table? state          # The entity state, or nil if the entity does not exist.
+function replication_client:get_entity(id)
+    return self:get_entity_list()[id]
+end
@@ -22,8 +22,10 @@ function alicia.info()
         size       = { 1024, 768 },
         -- Window frame-rate.
         rate       = 60,
-        -- Window mode.
+        -- Window mode. If false (or if launched with --headless), there will be no window, GPU or audio context, and only the head-less API will be available. alicia.main(step) will instead be called once per tick at the frame-rate, and must return nil to keep running.
         head       = true,
+        -- Hot-reload mode. If true, any change to a Lua module in the game path will re-execute it in place, keeping the Lua state alive. Modules are re-executed at the end of every frame (alicia.draw.begin), or of every tick in head-less mode. alicia.reload(module) will be called after every re-execution.
+        reload     = false,
         -- Vertical sync.
         sync       = false,
         -- Full-screen.
@@ -140,3 +142,13 @@ function alicia.fail(message)
     end
 end
 ]]
+
+--[[----------------------------------------------------------------]]
+
+-- Uncomment this to use a custom reload hook.
+--[[
+--- Reload entry-point. Alicia will call this in hot-reload mode, with the name of the module that was re-executed. Note that this function is OPTIONAL.
+function alicia.reload(module)
+    print("Reloaded module: " .. module)
+end
+]]
@@ -22,7 +22,7 @@ alicia.sound = {}
 ---An unique handle for sound in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L79)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L88)
 ---@class sound
 sound = {}
 
@@ -34,7 +34,7 @@ sound = {}
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L111)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L120)
 function alicia.sound.new(path,alias) end
 
 ---Create a new sound resource, from memory.
@@ -46,77 +46,77 @@ function alicia.sound.new(path,alias) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L154)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L163)
 function alicia.sound.new_from_memory(data,alias,kind) end
 
 ---Create a sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L209)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L218)
 function sound:create_alias() end
 
 ---Remove a sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L219)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L228)
 function sound:remove_alias() end
 
 ---Clear every sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L233)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L242)
 function sound:remove_alias() end
 
 ---Play the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L246)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L255)
 function sound:play() end
 
 ---Check if sound is currently playing.
 ---@return boolean state # State of the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L270)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L279)
 function sound:get_playing() end
 
 ---Stop the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L287)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L296)
 function sound:stop() end
 
 ---Pause the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L304)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L313)
 function sound:pause() end
 
 ---Resume the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L321)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L330)
 function sound:resume() end
 
 ---Set volume for the sound. (range: 0.0 - 1.0)
 ---@param volume number # Current volume.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L347)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L356)
 function sound:set_volume(volume) end
 
 ---Set pitch for the sound.
 ---@param pitch number # Current pitch.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L376)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L385)
 function sound:set_pitch(pitch) end
 
 ---Set pan for the sound. (range: 0.0 - 1.0; 0.5 is center)
 ---@param pan number # Current pan.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L405)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/sound.rs#L414)
 function sound:set_pan(pan) end
 
 ---The input API.
@@ -131,21 +131,21 @@ alicia.input = {}
 ---The board input API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L77)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L86)
 ---@class alicia.input.board
 alicia.input.board = {}
 
 ---The mouse input API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L95)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L104)
 ---@class alicia.input.mouse
 alicia.input.mouse = {}
 
 ---The pad input API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L121)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L130)
 ---@class alicia.input.pad
 alicia.input.pad = {}
 
@@ -153,7 +153,7 @@ alicia.input.pad = {}
 ---@param key input_board # Key to exit Alicia with.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L162)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L171)
 function alicia.input.set_exit_key(key) end
 
 ---Set the clipboard text.
@@ -166,7 +166,7 @@ function alicia.input.set_exit_key(key) end
 ---@param text string # Clipboard text.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L180)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L189)
 function alicia.input.board.set_clipboard_text(text) end
 
 ---Get the clipboard text.
@@ -179,21 +179,21 @@ function alicia.input.board.set_clipboard_text(text) end
 ---@return string text # Clipboard text.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L200)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L209)
 function alicia.input.board.get_clipboard_text() end
 
 ---Get the last unicode glyph in the queue.
 ---@return number key_code # Key-code. If 0, queue is empty.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L217)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L226)
 function alicia.input.board.get_key_code_queue() end
 
 ---Get the last unicode glyph in the queue.
 ---@return number uni_code # Uni-code. If 0, queue is empty.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L231)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L240)
 function alicia.input.board.get_uni_code_queue() end
 
 ---Get the name of a given key.
@@ -206,70 +206,70 @@ function alicia.input.board.get_uni_code_queue() end
 ---@return string name # The name.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L249)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L258)
 function alicia.input.board.get_name(board) end
 
 ---Get the state of an input (up).
 ---@param board input_board # The board button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L271)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L280)
 function alicia.input.board.get_up(board) end
 
 ---Get the state of an input (down).
 ---@param board input_board # The board button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L285)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L294)
 function alicia.input.board.get_down(board) end
 
 ---Get the state of an input (press).
 ---@param board input_board # The board button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L299)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L308)
 function alicia.input.board.get_press(board) end
 
 ---Get the state of an input (repeat-press).
 ---@param board input_board # The board button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L313)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L322)
 function alicia.input.board.get_press_repeat(board) end
 
 ---Get the state of an input (release).
 ---@param board input_board # The board button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L327)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L336)
 function alicia.input.board.get_release(board) end
 
 ---Set the active state of the mouse.
 ---@param state boolean # Current state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L343)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L352)
 function alicia.input.mouse.set_active(state) end
 
 ---Set the hidden state of the mouse.
 ---@param state boolean # Current state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L365)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L374)
 function alicia.input.mouse.set_hidden(state) end
 
 ---Get the hidden state of the mouse.
 ---@return boolean state # Current state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L387)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L396)
 function alicia.input.mouse.get_hidden() end
 
 ---Check if the mouse is currently over the screen.
 ---@return boolean state # Current state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L401)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L410)
 function alicia.input.mouse.get_screen() end
 
 ---Get the current point of the mouse.
@@ -277,14 +277,14 @@ function alicia.input.mouse.get_screen() end
 ---@return number point_y # The point of the mouse (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L416)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L425)
 function alicia.input.mouse.get_point() end
 
 ---Set the current point of the mouse.
 ---@param point vector_2 # The point of the mouse.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L433)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L442)
 function alicia.input.mouse.set_point(point) end
 
 ---Get the current delta (i.e. mouse movement) of the mouse.
@@ -292,28 +292,28 @@ function alicia.input.mouse.set_point(point) end
 ---@return number delta_y # The delta of the mouse (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L452)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L461)
 function alicia.input.mouse.get_delta() end
 
 ---Set the current shift of the mouse.
 ---@param shift vector_2 # The shift of the mouse.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L469)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L478)
 function alicia.input.mouse.set_shift(shift) end
 
 ---Set the current scale of the mouse.
 ---@param scale vector_2 # The scale of the mouse.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L487)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L496)
 function alicia.input.mouse.set_scale(scale) end
 
 ---Set the current cursor of the mouse.
 ---@param cursor cursor_mouse # The cursor of the mouse.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L505)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L514)
 function alicia.input.mouse.set_cursor(cursor) end
 
 ---Get the current delta (i.e. mouse wheel movement) of the mouse wheel.
@@ -321,42 +321,42 @@ function alicia.input.mouse.set_cursor(cursor) end
 ---@return number delta_y # The delta of the mouse wheel (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L523)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L532)
 function alicia.input.mouse.get_wheel() end
 
 ---Get the last mouse button press.
 ---@return input_mouse input # The last mouse button press.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L540)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L549)
 function alicia.input.mouse.get_mouse_queue() end
 
 ---Get the state of an input (up).
 ---@param mouse input_mouse # The mouse button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L564)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L573)
 function alicia.input.mouse.get_up(mouse) end
 
 ---Get the state of an input (down).
 ---@param mouse input_mouse # The mouse button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L578)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L587)
 function alicia.input.mouse.get_down(mouse) end
 
 ---Get the state of an input (press).
 ---@param mouse input_mouse # The mouse button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L592)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L601)
 function alicia.input.mouse.get_press(mouse) end
 
 ---Get the state of an input (release).
 ---@param mouse input_mouse # The mouse button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L606)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L615)
 function alicia.input.mouse.get_release(mouse) end
 
 ---Get the state of a pad.
@@ -364,7 +364,7 @@ function alicia.input.mouse.get_release(mouse) end
 ---@return boolean state # The state of the pad.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L625)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L634)
 function alicia.input.pad.get_state(index) end
 
 ---Get the name of a pad.
@@ -372,42 +372,42 @@ function alicia.input.pad.get_state(index) end
 ---@return string name # The name of the pad.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L642)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L651)
 function alicia.input.pad.get_name(index) end
 
 ---Get the state of an input (press).
 ---@param pad input_pad # The pad button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L659)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L668)
 function alicia.input.pad.get_press(pad) end
 
 ---Get the state of an input (down).
 ---@param pad input_pad # The pad button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L673)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L682)
 function alicia.input.pad.get_down(pad) end
 
 ---Get the state of an input (release).
 ---@param pad input_pad # The pad button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L687)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L696)
 function alicia.input.pad.get_release(pad) end
 
 ---Get the state of an input (up).
 ---@param pad input_pad # The pad button to check for.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L701)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L710)
 function alicia.input.pad.get_up(pad) end
 
 ---Get the last pad button press.
 ---@return input_pad input # The last pad button press.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L715)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L724)
 function alicia.input.pad.get_queue() end
 
 ---Get the axis count of a pad.
@@ -415,7 +415,7 @@ function alicia.input.pad.get_queue() end
 ---@return number axis_count # The axis count of the pad.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L732)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L741)
 function alicia.input.pad.get_axis_count(index) end
 
 ---Get the axis state of a pad.
@@ -424,7 +424,7 @@ function alicia.input.pad.get_axis_count(index) end
 ---@return number axis_state # The axis state of the pad.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L750)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L759)
 function alicia.input.pad.get_axis_state(index,axis) end
 
 ---Set the rumble of a pad.
@@ -434,20 +434,20 @@ function alicia.input.pad.get_axis_state(index,axis) end
 ---@param duration number # The duration of the rumble.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L767)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/input.rs#L776)
 function alicia.input.pad.set_rumble(index,motor_a,motor_b,duration) end
 
 ---The Rapier API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L72)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L78)
 ---@class alicia.rapier
 alicia.rapier = {}
 
 ---An unique handle for a Rapier simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L86)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L93)
 ---@class rapier
 rapier = {}
 
@@ -455,32 +455,172 @@ rapier = {}
 ---@return rapier rapier # Rapier simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L114)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L127)
 function alicia.rapier.new() end
 
+---Load a Rapier simulation from a data buffer, made with rapier:save.
+---```lua
+----- Create a new simulation.
+---local rapier = alicia.rapier.new()
+---
+----- Create a fixed floor, with no rigid body parent.
+---rapier:solid_body(nil, 0, vector_3:new(8.0, 0.5, 8.0))
+---
+----- Create a dynamic rigid body, and bind a cuboid solid body to it.
+---local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---local solid = rapier:solid_body(rigid, 0, vector_3:new(0.5, 0.5, 0.5))
+---
+---rapier:set_rigid_body_position(rigid, vector_3:new(0.0, 4.0, 0.0), true)
+---
+----- Step the simulation for half a second, then save it.
+---for x = 1, 30 do
+---    rapier:step()
+---end
+---
+---local data = rapier:save()
+---
+----- Load the saved simulation. Handles are kept as-is.
+---local other = alicia.rapier.load(data)
+---
+----- Step both simulations for another second.
+---for x = 1, 60 do
+---    rapier:step()
+---    other:step()
+---end
+---
+----- Both simulations should be in the exact same state.
+---local a_x, a_y, a_z = rapier:get_solid_body_position(solid)
+---local b_x, b_y, b_z = other:get_solid_body_position(solid)
+---
+---assert(a_x == b_x)
+---assert(a_y == b_y)
+---assert(a_z == b_z)
+---
+----- Create a vehicle, with a chassis and four wheels, and drive it forward.
+---local chassis = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---local body = rapier:solid_body(chassis, 0, vector_3:new(1.0, 0.25, 0.5))
+---rapier:set_rigid_body_position(chassis, vector_3:new(0.0, 2.0, 4.0), true)
+---
+---local vehicle = rapier:vehicle(chassis)
+---
+---for _, point in ipairs({
+---    vector_3:new(0.75, 0.0, 0.5),
+---    vector_3:new(0.75, 0.0, -0.5),
+---    vector_3:new(-0.75, 0.0, 0.5),
+---    vector_3:new(-0.75, 0.0, -0.5),
+---}) do
+---    local wheel = rapier:vehicle_add_wheel(vehicle, point, vector_3:new(0.0, -1.0, 0.0),
+---        vector_3:new(0.0, 0.0, 1.0), 0.5, 0.25)
+---
+---    rapier:set_vehicle_wheel_engine_force(vehicle, wheel, 4.0)
+---end
+---
+---for x = 1, 30 do
+---    rapier:step()
+---end
+---
+----- Load the saved simulation. The vehicle handle is kept as-is.
+---other = alicia.rapier.load(rapier:save())
+---
+---for x = 1, 60 do
+---    rapier:step()
+---    other:step()
+---end
+---
+----- Both vehicles should be in the exact same state.
+---local a_x, a_y, a_z = rapier:get_solid_body_position(body)
+---local b_x, b_y, b_z = other:get_solid_body_position(body)
+---
+---assert(a_x == b_x)
+---assert(a_y == b_y)
+---assert(a_z == b_z)
+---assert(rapier:get_vehicle_speed(vehicle) == other:get_vehicle_speed(vehicle))
+---
+---```
+---@param data data # Data buffer.
+---@return rapier rapier # Rapier simulation.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L148)
+function alicia.rapier.load(data) end
+
 ---Cast a ray.
+---```lua
+----- Create a new simulation, with a fixed floor, and a sensor above it.
+---local rapier = alicia.rapier.new()
+---
+---local floor  = rapier:solid_body(nil, 0, vector_3:new(8.0, 0.5, 8.0))
+---local sensor = rapier:solid_body(nil, 0, vector_3:new(1.0, 1.0, 1.0))
+---
+---rapier:set_solid_body_position(floor, vector_3:new(0.0, -4.0, 0.0))
+---rapier:set_solid_body_position(sensor, vector_3:new(0.0, 4.0, 0.0))
+---rapier:set_solid_body_sensor(sensor, true)
+---
+----- Step once, to update the scene query pipeline.
+---rapier:step()
+---
+----- Cast a ray downward, which will hit the sensor first.
+---local ray = { position = vector_3:new(0.0, 10.0, 0.0), direction = vector_3:new(0.0, -1.0, 0.0) }
+---
+---local hit = rapier:cast_ray(ray, 20.0, true)
+---
+---assert(hit.index == sensor.index)
+---
+----- Exclude every sensor, which will hit the floor instead.
+---local hit = rapier:cast_ray(ray, 20.0, true, { exclude_sensor = true })
+---
+---assert(hit.index == floor.index)
+---
+----- A rigid body and a solid body handle to exclude are still accepted in place of a filter.
+---local hit = rapier:cast_ray(ray, 20.0, true, nil, sensor)
+---
+---assert(hit.index == floor.index)
+---
+----- An error in the predicate is returned by the query, rather than silently excluding the solid body.
+---local success, message = pcall(rapier.cast_ray, rapier, ray, 20.0, true, {
+---    predicate = function(solid_body)
+---        error("predicate failure")
+---    end,
+---})
+---
+---assert(not success)
+---assert(tostring(message):find("predicate failure"))
+---
+----- Without a filter, a cuboid intersection test will exclude every sensor, as in previous versions.
+---local point = vector_3:new(0.0, 4.0, 0.0)
+---local angle = vector_3:new(0.0, 0.0, 0.0)
+---local shape = vector_3:new(0.5, 0.5, 0.5)
+---
+---assert(rapier:test_intersect_cuboid(point, angle, shape) == nil)
+---
+----- With a filter, every sensor is included unless excluded by the filter.
+---local hit = rapier:test_intersect_cuboid(point, angle, shape, {})
+---
+---assert(hit.index == sensor.index)
+---
+---```
 ---@param ray ray # Ray to cast.
 ---@param range number # Ray range.
 ---@param solid boolean # TO-DO
----@param exclude_rigid table # TO-DO
----@param exclude_collider table # TO-DO
+---@param filter query_filter? # OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
 ---@return table collider_handle # Solid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L163)
-function rapier:cast_ray(ray,range,solid,exclude_rigid,exclude_collider) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L293)
+function rapier:cast_ray(ray,range,solid,filter,exclude_solid_body) end
 
 ---Cast a ray, and also get the normal information..
 ---@param ray ray # Ray to cast.
 ---@param range number # Ray range.
 ---@param solid boolean # TO-DO
----@param exclude_rigid table # TO-DO
----@param exclude_collider table # TO-DO
+---@param filter query_filter? # OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
 ---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L222)
-function rapier:cast_ray_normal(ray,range,solid,exclude_rigid,exclude_collider) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L355)
+function rapier:cast_ray_normal(ray,range,solid,filter,exclude_solid_body) end
 
 ---Check if a cuboid is intersecting against another cuboid.
 ---@param point_a vector_3 # Point of cuboid (A).
@@ -492,14 +632,87 @@ function rapier:cast_ray_normal(ray,range,solid,exclude_rigid,exclude_collider)
 ---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L288)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L424)
 function rapier:test_intersect_cuboid_cuboid(point_a,angle_a,shape_a,point_b,angle_b,shape_b) end
 
----TO-DO
+---Check if a cuboid is intersecting against any solid body in the simulation.
+---@param point vector_3 # Point of cuboid.
+---@param angle vector_3 # Angle of cuboid.
+---@param shape vector_3 # Shape of cuboid.
+---@param filter query_filter? # OPTIONAL: Scene query filter. If nil, every sensor is excluded. A rigid body handle to exclude is also accepted in its place, as in previous versions, and will also exclude every sensor.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
+---@return table? solid_body # Solid body handle, or nil if there is no intersection.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L478)
+function rapier:test_intersect_cuboid(point,angle,shape,filter,exclude_solid_body) end
+
+---Cast a ray, and get every solid body hit along it, sorted by distance.
+---@param ray ray # Ray to cast.
+---@param range number # Ray range.
+---@param solid boolean # TO-DO
+---@param filter query_filter? # OPTIONAL: Scene query filter.
+---@return table hit_list # Hit list. Each hit has a solid body handle, time of impact and normal.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L547)
+function rapier:cast_ray_list(ray,range,solid,filter) end
+
+---Cast (sweep) a shape along a direction, and get the first solid body hit.
+---@param point vector_3 # Point of shape.
+---@param angle vector_3 # Angle of shape.
+---@param kind shape_kind # Shape kind.
+---@param shape vector_3 # Shape. For a ball, (X) is the radius. For a capsule or cylinder, (X) is the radius and (Y) is the half-height. For a cuboid, the half-shape.
+---@param direction vector_3 # Direction (velocity) of the cast.
+---@param range number # Cast range, as a multiple of the direction vector.
+---@param filter query_filter? # OPTIONAL: Scene query filter.
+---@return table? solid_body # Solid body handle, or nil if there was no hit.
+---@return number time # Time of impact.
+---@return number point_x # Hit point on the solid body (X).
+---@return number point_y # Hit point on the solid body (Y).
+---@return number point_z # Hit point on the solid body (Z).
+---@return number normal_x # Hit normal on the solid body (X).
+---@return number normal_y # Hit normal on the solid body (Y).
+---@return number normal_z # Hit normal on the solid body (Z).
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L617)
+function rapier:cast_shape(point,angle,kind,shape,direction,range,filter) end
+
+---Project a point on the closest solid body.
+---@param point vector_3 # Point to project.
+---@param solid boolean # If true, a point inside a solid body will be projected on itself, rather than on its surface.
+---@param filter query_filter? # OPTIONAL: Scene query filter.
+---@return table? solid_body # Solid body handle, or nil if there is no solid body.
+---@return number point_x # Projected point (X).
+---@return number point_y # Projected point (Y).
+---@return number point_z # Projected point (Z).
+---@return boolean inside # Whether or not the point was inside the solid body.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L692)
+function rapier:project_point(point,solid,filter) end
+
+---Get every solid body containing a point.
+---@param point vector_3 # Point to test.
+---@param filter query_filter? # OPTIONAL: Scene query filter.
+---@return table solid_body_list # Solid body handle list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L332)
-function rapier:test_intersect_cuboid() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L739)
+function rapier:test_intersect_point(point,filter) end
+
+---Get every solid body intersecting a shape.
+---@param point vector_3 # Point of shape.
+---@param angle vector_3 # Angle of shape.
+---@param kind shape_kind # Shape kind.
+---@param shape vector_3 # Shape. For a ball, (X) is the radius. For a capsule or cylinder, (X) is the radius and (Y) is the half-height. For a cuboid, the half-shape.
+---@param filter query_filter? # OPTIONAL: Scene query filter.
+---@return table solid_body_list # Solid body handle list.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L784)
+function rapier:test_intersect_shape(point,angle,kind,shape,filter) end
 
 ---Get the shape of a solid body (cuboid).
 ---@param solid_body table # Solid body handle.
@@ -508,14 +721,14 @@ function rapier:test_intersect_cuboid() end
 ---@return number half_shape_z # Half-shape of the cuboid. (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L404)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L845)
 function rapier:get_solid_body_shape_cuboid(solid_body) end
 
 ---Set the shape of a solid body.
 ---@param solid_body table # Solid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L436)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L877)
 function rapier:set_solid_body_shape(solid_body) end
 
 ---Get the parent of a solid body.
@@ -523,7 +736,7 @@ function rapier:set_solid_body_shape(solid_body) end
 ---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L488)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L929)
 function rapier:get_solid_body_parent(solid_body) end
 
 ---Get the position of a solid body.
@@ -533,7 +746,7 @@ function rapier:get_solid_body_parent(solid_body) end
 ---@return number position_z # Solid body position (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L519)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L960)
 function rapier:get_solid_body_position(solid_body) end
 
 ---Set the position of a solid body.
@@ -541,7 +754,7 @@ function rapier:get_solid_body_position(solid_body) end
 ---@param position vector_3 # Solid body position.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L549)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L990)
 function rapier:set_solid_body_position(solid_body,position) end
 
 ---Set the rotation of a solid body.
@@ -549,7 +762,7 @@ function rapier:set_solid_body_position(solid_body,position) end
 ---@param rotation vector_3 # Solid body rotation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L581)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1022)
 function rapier:set_solid_body_rotation(solid_body,rotation) end
 
 ---Set the sensor state of a solid body.
@@ -557,15 +770,84 @@ function rapier:set_solid_body_rotation(solid_body,rotation) end
 ---@param sensor boolean # Solid body sensor state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L610)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1051)
 function rapier:set_solid_body_sensor(solid_body,sensor) end
 
+---Get the collision group and mask of a solid body.
+---@param solid_body table # Solid body handle.
+---@return number group # Collision group bit-mask.
+---@return number mask # Collision mask bit-mask of the query. A solid body is only considered if its group intersects this mask, and its mask intersects the query group.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1081)
+function rapier:get_solid_body_collision_group(solid_body) end
+
+---Set the collision group and mask of a solid body. Two solid bodies will only be tested for contact or intersection if the group of each one intersects the mask of the other.
+---@param solid_body table # Solid body handle.
+---@param group number? # OPTIONAL: Collision group bit-mask. If nil, every bit is set.
+---@param mask number? # OPTIONAL: Collision mask bit-mask. If nil, every bit is set.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1109)
+function rapier:set_solid_body_collision_group(solid_body,group,mask) end
+
+---Get the solver group and mask of a solid body.
+---@param solid_body table # Solid body handle.
+---@return number group # Solver group bit-mask.
+---@return number mask # Solver mask bit-mask.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1139)
+function rapier:get_solid_body_solver_group(solid_body) end
+
+---Set the solver group and mask of a solid body. Two solid bodies in contact will only have forces applied to them if the group of each one intersects the mask of the other.
+---@param solid_body table # Solid body handle.
+---@param group number? # OPTIONAL: Solver group bit-mask. If nil, every bit is set.
+---@param mask number? # OPTIONAL: Solver mask bit-mask. If nil, every bit is set.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1167)
+function rapier:set_solid_body_solver_group(solid_body,group,mask) end
+
+---Set if a solid body will report a collision event (contact start and stop) in rapier:step. Enabled by default.
+---@param solid_body table # Solid body handle.
+---@param state boolean # Collision event state.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1194)
+function rapier:set_solid_body_collision_event(solid_body,state) end
+
+---Set every pair of rigid body kinds a solid body will be tested for contact with. By default, every pair is tested, including a pair of fixed or kinematic rigid bodies.
+---@param solid_body table # Solid body handle.
+---@param kind number? # OPTIONAL: Collision type bit-mask (see COLLISION_TYPE). If nil, every pair is set.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1224)
+function rapier:set_solid_body_collision_type(solid_body,kind) end
+
+---Set the contact force threshold of a solid body. A contact force event will be reported by rapier:step whenever the total force magnitude applied on the solid body is above this threshold.
+---@param solid_body table # Solid body handle.
+---@param threshold number? # OPTIONAL: Contact force threshold. If nil, contact force events are disabled.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1251)
+function rapier:set_solid_body_contact_force_threshold(solid_body,threshold) end
+
+---Get every contact point between two solid bodies, from the last simulation step. Each contact point has a world-space point on either solid body, a normal pointing from solid body (A) to solid body (B), a penetration depth and the impulse applied.
+---@param solid_body_a table # Solid body handle (A).
+---@param solid_body_b table # Solid body handle (B).
+---@return table? contact_list # Contact point list, or nil if the solid bodies are not in contact.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1291)
+function rapier:get_solid_body_contact(solid_body_a,solid_body_b) end
+
 ---Remove a solid body.
 ---@param solid_body table # Solid body handle.
 ---@param wake_parent boolean # Whether or not to wake up the rigid body parent this solid body is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L639)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1373)
 function rapier:solid_body_remove(solid_body,wake_parent) end
 
 ---Remove a rigid body.
@@ -573,38 +855,38 @@ function rapier:solid_body_remove(solid_body,wake_parent) end
 ---@param remove_solid_body boolean # Whether or not to remove every solid body this rigid body is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L666)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1400)
 function rapier:rigid_body_remove(rigid_body,remove_solid_body) end
 
 ---Create a character controller.
 ---@return table character_controller # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L694)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1428)
 function rapier:character_controller() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L705)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1439)
 function rapier:set_character_controller_up_vector() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L722)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1456)
 function rapier:set_character_controller_slope() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L739)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1473)
 function rapier:set_character_auto_step() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L786)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1520)
 function rapier:set_character_snap_ground() end
 
 ---Move a character controller.
@@ -612,6 +894,7 @@ function rapier:set_character_snap_ground() end
 ---@param character table # TO-DO
 ---@param solid_body table # TO-DO
 ---@param translation vector_3 # TO-DO
+---@param filter query_filter? # OPTIONAL: Scene query filter. If nil, every sensor is excluded.
 ---@return number movement_x # Translation point (X).
 ---@return number movement_y # Translation point (Y).
 ---@return number movement_z # Translation point (Z).
@@ -619,15 +902,99 @@ function rapier:set_character_snap_ground() end
 ---@return boolean slope # Currently on slope.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L822)
-function rapier:character_controller_move(step,character,solid_body,translation) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1557)
+function rapier:character_controller_move(step,character,solid_body,translation,filter) end
+
+---Create a vehicle, using a rigid body as the chassis. Wheels are simulated with ray-casts, and must be added with rapier:vehicle_add_wheel. Every vehicle is updated on every simulation step, applying the suspension, engine and brake forces to the chassis.
+---@param rigid_body table # Rigid body handle for the chassis.
+---@param up_axis number? # OPTIONAL: Up axis of the chassis (0: X, 1: Y, 2: Z). Default: 1.
+---@param forward_axis number? # OPTIONAL: Forward axis of the chassis (0: X, 1: Y, 2: Z). Default: 0.
+---@param filter query_filter? # OPTIONAL: Scene query filter for the wheel ray-casts. The chassis is always excluded, and a predicate is not allowed.
+---@return table vehicle # Vehicle handle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1623)
+function rapier:vehicle(rigid_body,up_axis,forward_axis,filter) end
+
+---Remove a vehicle. The chassis rigid body is left as-is.
+---@param vehicle table # Vehicle handle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1664)
+function rapier:vehicle_remove(vehicle) end
+
+---Add a wheel to a vehicle.
+---@param vehicle table # Vehicle handle.
+---@param point vector_3 # Point of the suspension on the chassis, in chassis-space.
+---@param direction vector_3 # Direction of the suspension, in chassis-space.
+---@param axle vector_3 # Axle of the wheel, in chassis-space.
+---@param rest_length number # Rest length of the suspension.
+---@param radius number # Radius of the wheel.
+---@param tuning wheel_tuning? # OPTIONAL: Suspension and friction settings. Any missing field will use its default.
+---@return number wheel # Wheel index.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1695)
+function rapier:vehicle_add_wheel(vehicle,point,direction,axle,rest_length,radius,tuning) end
+
+---Set the engine force of a vehicle wheel.
+---@param vehicle table # Vehicle handle.
+---@param wheel number # Wheel index.
+---@param engine_force number # Engine force applied on the wheel.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1749)
+function rapier:set_vehicle_wheel_engine_force(vehicle,wheel,engine_force) end
+
+---Set the brake of a vehicle wheel.
+---@param vehicle table # Vehicle handle.
+---@param wheel number # Wheel index.
+---@param brake number # Brake force applied on the wheel.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1779)
+function rapier:set_vehicle_wheel_brake(vehicle,wheel,brake) end
+
+---Set the steering of a vehicle wheel.
+---@param vehicle table # Vehicle handle.
+---@param wheel number # Wheel index.
+---@param steering number # Steering angle of the wheel, in radians.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1809)
+function rapier:set_vehicle_wheel_steering(vehicle,wheel,steering) end
+
+---Get the state of a vehicle wheel, as of the last simulation step.
+---@param vehicle table # Vehicle handle.
+---@param wheel number # Wheel index.
+---@return table state # Wheel state. Has the wheel center, contact state, contact point and normal, the solid body in contact with, the suspension length and force, and the wheel rotation.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1841)
+function rapier:get_vehicle_wheel(vehicle,wheel) end
+
+---Get the current forward speed of a vehicle.
+---@param vehicle table # Vehicle handle.
+---@return number speed # Forward speed.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1895)
+function rapier:get_vehicle_speed(vehicle) end
+
+---Set the scene query filter for the wheel ray-casts of a vehicle.
+---@param vehicle table # Vehicle handle.
+---@param filter query_filter? # OPTIONAL: Scene query filter. The chassis is always excluded, and a predicate is not allowed.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1918)
+function rapier:set_vehicle_filter(vehicle,filter) end
 
 ---Create a rigid body.
 ---@param kind rigid_body_kind # Rigid body kind.
 ---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L867)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1948)
 function rapier:rigid_body(kind) end
 
 ---Get the user data of a rigid_body.
@@ -635,7 +1002,7 @@ function rapier:rigid_body(kind) end
 ---@return number user_data # Rigid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L891)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1972)
 function rapier:get_rigid_body_user_data(rigid_body) end
 
 ---Set the user data of a rigid_body.
@@ -643,7 +1010,7 @@ function rapier:get_rigid_body_user_data(rigid_body) end
 ---@param user_data number # Rigid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L917)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1998)
 function rapier:set_rigid_body_user_data(rigid_body,user_data) end
 
 ---Set the position of a rigid_body.
@@ -651,7 +1018,7 @@ function rapier:set_rigid_body_user_data(rigid_body,user_data) end
 ---@param position vector_3 # rigid_body position.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L944)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2025)
 function rapier:set_rigid_body_position(rigid_body,position) end
 
 ---Set the rotation of a rigid_body.
@@ -659,3235 +1026,7818 @@ function rapier:set_rigid_body_position(rigid_body,position) end
 ---@param rotation vector_3 # rigid_body rotation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L973)
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2054)
 function rapier:set_rigid_body_rotation(rigid_body,rotation) end
 
----Get the user data of a solid body.
----@param solid_body userdata # Solid body handle.
----@return number user_data # Solid body user data.
+---Get the linear velocity of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@return number linear_velocity_x # Rigid body linear velocity (X).
+---@return number linear_velocity_y # Rigid body linear velocity (Y).
+---@return number linear_velocity_z # Rigid body linear velocity (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1008)
-function rapier:get_solid_body_user_data(solid_body) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2089)
+function rapier:get_rigid_body_linear_velocity(rigid_body) end
 
----Set the user data of a solid body.
----@param solid_body userdata # Solid body handle.
----@param user_data number # Solid body user data.
+---Set the linear velocity of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param velocity vector_3 # Rigid body linear velocity.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1034)
-function rapier:set_solid_body_user_data(solid_body,user_data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2117)
+function rapier:set_rigid_body_linear_velocity(rigid_body,velocity,wake_up) end
 
----TO-DO
+---Get the angular velocity of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@return number angular_velocity_x # Rigid body angular velocity (X).
+---@return number angular_velocity_y # Rigid body angular velocity (Y).
+---@return number angular_velocity_z # Rigid body angular velocity (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1059)
-function rapier:solid_body() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2149)
+function rapier:get_rigid_body_angular_velocity(rigid_body) end
 
----Step the Rapier simulation.
+---Set the angular velocity of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param velocity vector_3 # Rigid body angular velocity.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1146)
-function rapier:step() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2177)
+function rapier:set_rigid_body_angular_velocity(rigid_body,velocity,wake_up) end
 
----Render the Rapier simulation.
+---Add a force to a rigid body. The force is applied continuously on every step, until reset with rapier:reset_rigid_body_force.
+---@param rigid_body table # Rigid body handle.
+---@param force vector_3 # Force.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L1184)
-function rapier:debug_render() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2206)
+function rapier:add_rigid_body_force(rigid_body,force,wake_up) end
 
----The Steam API.
+---Add a torque to a rigid body. The torque is applied continuously on every step, until reset with rapier:reset_rigid_body_force.
+---@param rigid_body table # Rigid body handle.
+---@param torque vector_3 # Torque.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----*Available with compile feature: `steam`.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L64)
----@class alicia.steam
-alicia.steam = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2235)
+function rapier:add_rigid_body_torque(rigid_body,torque,wake_up) end
 
----An unique handle to a Steam client.
+---Apply an impulse to the center of mass of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param impulse vector_3 # Impulse.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L78)
----@class steam
-steam = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2264)
+function rapier:apply_rigid_body_impulse(rigid_body,impulse,wake_up) end
 
----Update the Steam state.
+---Apply a torque impulse to a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param impulse vector_3 # Torque impulse.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L136)
-function steam:update() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2293)
+function rapier:apply_rigid_body_torque_impulse(rigid_body,impulse,wake_up) end
 
----Get the current AppID.
----@return number ID # The current AppID.
+---Apply an impulse to a rigid body, at a given world-space point.
+---@param rigid_body table # Rigid body handle.
+---@param impulse vector_3 # Impulse.
+---@param point vector_3 # World-space point.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L155)
-function steam:get_app_ID() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2324)
+function rapier:apply_rigid_body_impulse_at_point(rigid_body,impulse,point,wake_up) end
 
----Get the current country code from the user's IP.
----@return string code # The current country code.
+---Reset every force and torque added to a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L169)
-function steam:get_IP_country() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2364)
+function rapier:reset_rigid_body_force(rigid_body,wake_up) end
 
----Get the current language for the Steam client.
----@return string language # The current language.
+---Get the damping of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@return number linear # Linear damping.
+---@return number angular # Angular damping.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L183)
-function steam:get_UI_language() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2395)
+function rapier:get_rigid_body_damping(rigid_body) end
 
----Get the current Steam server time, as an UNIX time-stamp.
----@return number time # The current server time.
+---Set the damping of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param linear number # Linear damping.
+---@param angular number # Angular damping.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L197)
-function steam:get_server_time() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2422)
+function rapier:set_rigid_body_damping(rigid_body,linear,angular) end
 
----Set the position of the Steam overlay.
----@param position steam_overlay_position # The Steam overlay position.
+---Get the gravity scale of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@return number scale # Gravity scale.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L211)
-function steam:set_overlay_position(position) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2452)
+function rapier:get_rigid_body_gravity_scale(rigid_body) end
 
----TO-DO
+---Set the gravity scale of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param scale number # Gravity scale.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L245)
-function steam:set_message() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2479)
+function rapier:set_rigid_body_gravity_scale(rigid_body,scale,wake_up) end
 
----TO-DO
+---Get the mass of a rigid body, including the mass of every solid body bound to it.
+---@param rigid_body table # Rigid body handle.
+---@return number mass # Rigid body mass.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L269)
-function steam:get_message() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2508)
+function rapier:get_rigid_body_mass(rigid_body) end
 
----Check if a given AppID is currently on the system.
----@param ID number # The AppID.
----@return boolean install # The state of the AppID.
+---Set the additional mass of a rigid body, on top of the mass of every solid body bound to it.
+---@param rigid_body table # Rigid body handle.
+---@param mass number # Additional mass.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L297)
-function steam:get_app_install(ID) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2535)
+function rapier:set_rigid_body_additional_mass(rigid_body,mass,wake_up) end
 
----Check if a given DLC is currently on the system.
----@param ID number # The AppID.
----@return boolean install # The state of the AppID.
+---Set the density of a solid body. The mass of the solid body will be computed from its shape.
+---@param solid_body table # Solid body handle.
+---@param density number # Solid body density.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L314)
-function steam:get_DLC_install(ID) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2562)
+function rapier:set_solid_body_density(solid_body,density) end
 
----Check if the user has a subscription to the given AppID.
----@param ID number # The AppID.
----@return boolean subscription # Subscription state.
+---Set the mass of a solid body. The density of the solid body will be computed from its shape.
+---@param solid_body table # Solid body handle.
+---@param mass number # Solid body mass.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L331)
-function steam:get_app_subscribe(ID) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2589)
+function rapier:set_solid_body_mass(solid_body,mass) end
 
----Check if the user has a VAC ban on record.
----@return boolean ban # The VAC ban state.
+---Lock the translation of a rigid body, on any axis.
+---@param rigid_body table # Rigid body handle.
+---@param lock_x boolean # Lock translation (X).
+---@param lock_y boolean # Lock translation (Y).
+---@param lock_z boolean # Lock translation (Z).
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L345)
-function steam:get_VAC_ban() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2619)
+function rapier:set_rigid_body_lock_translation(rigid_body,lock_x,lock_y,lock_z,wake_up) end
 
----Check if the user has a VAC ban on record.
----@return boolean ban # The VAC ban state.
+---Lock the rotation of a rigid body, on any axis.
+---@param rigid_body table # Rigid body handle.
+---@param lock_x boolean # Lock rotation (X).
+---@param lock_y boolean # Lock rotation (Y).
+---@param lock_z boolean # Lock rotation (Z).
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L359)
-function steam:get_cyber_cafe() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2657)
+function rapier:set_rigid_body_lock_rotation(rigid_body,lock_x,lock_y,lock_z,wake_up) end
 
----Check if the current AppID has support for low violence.
----@return boolean low_violence # Low violence state.
+---Check if a rigid body is sleeping.
+---@param rigid_body table # Rigid body handle.
+---@return boolean sleep # Rigid body sleep state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L373)
-function steam:get_low_violence() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2694)
+function rapier:get_rigid_body_sleep(rigid_body) end
 
----Check if the user has a subscription to the current AppID.
----@return boolean subscription # Subscription state.
+---Put a rigid body to sleep, or wake it up.
+---@param rigid_body table # Rigid body handle.
+---@param sleep boolean # Rigid body sleep state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L387)
-function steam:get_subscribe() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2720)
+function rapier:set_rigid_body_sleep(rigid_body,sleep) end
 
----Get the build ID for the current AppID.
----@return number ID # The build ID.
+---Enable or disable continuous collision detection (CCD) for a rigid body. Useful for fast-moving rigid bodies, which might otherwise tunnel through thin geometry.
+---@param rigid_body table # Rigid body handle.
+---@param continuous boolean # Rigid body CCD state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L401)
-function steam:get_app_build_ID() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2752)
+function rapier:set_rigid_body_continuous(rigid_body,continuous) end
 
----Get the installation path for the given AppID. NOTE: this will work even if the app is not in disk.
----@param ID number # The AppID.
----@return string path # Installation path.
+---Create a joint between two rigid bodies. A revolute or prismatic joint takes an additional axis (vector_3) argument, a rope joint takes an additional maximum length (number) argument.
+---@param kind joint_kind # Joint kind.
+---@param rigid_body_a table # Rigid body handle (A).
+---@param rigid_body_b table # Rigid body handle (B).
+---@param anchor_a vector_3 # Joint anchor, local to rigid body (A).
+---@param anchor_b vector_3 # Joint anchor, local to rigid body (B).
+---@param ... any # Joint axis (revolute, prismatic) or length (rope).
+---@return table joint # Joint handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L418)
-function steam:get_app_install_path(ID) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2788)
+function rapier:joint(kind,rigid_body_a,rigid_body_b,anchor_a,anchor_b,...) end
 
----Get the SteamID of the owner's current AppID.
----@return string ID # The SteamID.
+---Get the rigid bodies a joint is binding together.
+---@param joint table # Joint handle.
+---@return table rigid_body_a # Rigid body handle (A).
+---@return table rigid_body_b # Rigid body handle (B).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L432)
-function steam:get_app_owner() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2877)
+function rapier:get_joint_rigid_body(joint) end
 
----TO-DO
+---Set the enable state of a joint.
+---@param joint table # Joint handle.
+---@param enable boolean # Joint enable state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L443)
-function steam:get_game_language_list() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2900)
+function rapier:set_joint_enable(joint,enable) end
 
----TO-DO
+---Set whether or not the rigid bodies bound by a joint can collide with each other.
+---@param joint table # Joint handle.
+---@param contact boolean # Joint contact state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L454)
-function steam:get_game_language() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2927)
+function rapier:set_joint_contact(joint,contact) end
 
----TO-DO
+---Set the limit of a joint, along a given axis. The free axis of a revolute joint is always ANGULAR_X, and the free axis of a prismatic or rope joint is always LINEAR_X.
+---@param joint table # Joint handle.
+---@param axis joint_axis # Joint axis.
+---@param min number # Minimum limit.
+---@param max number # Maximum limit.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L465)
-function steam:get_beta_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2956)
+function rapier:set_joint_limit(joint,axis,min,max) end
 
----TO-DO
+---Set the motor model of a joint, along a given axis.
+---@param joint table # Joint handle.
+---@param axis joint_axis # Joint axis.
+---@param model motor_model # Motor model.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L476)
-function steam:get_launch_command_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L2985)
+function rapier:set_joint_motor_model(joint,axis,model) end
 
----TO-DO
+---Set the target velocity of a joint's motor, along a given axis.
+---@param joint table # Joint handle.
+---@param axis joint_axis # Joint axis.
+---@param velocity number # Target velocity.
+---@param factor number # How fast the motor will reach the velocity.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L491)
-function steam:get_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3019)
+function rapier:set_joint_motor_velocity(joint,axis,velocity,factor) end
 
----TO-DO
+---Set the target position of a joint's motor, along a given axis.
+---@param joint table # Joint handle.
+---@param axis joint_axis # Joint axis.
+---@param position number # Target position.
+---@param stiffness number # Motor stiffness.
+---@param damping number # Motor damping.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L502)
-function steam:activate_overlay() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3050)
+function rapier:set_joint_motor_position(joint,axis,position,stiffness,damping) end
 
----TO-DO
+---Set the maximum force a joint's motor can deliver, along a given axis.
+---@param joint table # Joint handle.
+---@param axis joint_axis # Joint axis.
+---@param force number # Maximum force.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L514)
-function steam:activate_overlay_link() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3083)
+function rapier:set_joint_motor_max_force(joint,axis,force) end
 
----TO-DO
+---Remove a joint.
+---@param joint table # Joint handle.
+---@param wake_up boolean # Whether or not to wake up the rigid bodies bound by the joint.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L526)
-function steam:activate_overlay_store() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3111)
+function rapier:joint_remove(joint,wake_up) end
 
----TO-DO
+---Get the user data of a solid body.
+---@param solid_body userdata # Solid body handle.
+---@return number user_data # Solid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L547)
-function steam:activate_overlay_user() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3137)
+function rapier:get_solid_body_user_data(solid_body) end
 
----TO-DO
+---Set the user data of a solid body.
+---@param solid_body userdata # Solid body handle.
+---@param user_data number # Solid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L563)
-function steam:activate_invite_dialog() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3163)
+function rapier:set_solid_body_user_data(solid_body,user_data) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L585)
-function steam:get_steam_ID() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3188)
+function rapier:solid_body() end
 
----TO-DO
+---Create a solid body from the geometry of a mesh in a model. A tri-mesh will merge any duplicate vertex, and remove any degenerate or duplicate triangle, so that a non-manifold mesh can still be used.
+---```lua
+----- Create a new simulation, and load a model.
+---local rapier = alicia.rapier.new()
+---local model  = alicia.model.new("test/data/model/cube.obj")
+---
+----- Create a solid body of every kind from the first mesh of the model.
+---for _, kind in pairs(MODEL_SHAPE_KIND) do
+---    local solid = rapier:solid_body_from_model(nil, model, 0, kind)
+---
+---    assert(solid)
+---end
+---
+----- An invalid mesh index or kind is rejected.
+---local success, error = pcall(rapier.solid_body_from_model, rapier, nil, model, 1, MODEL_SHAPE_KIND.TRI_MESH)
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid mesh index"))
+---
+---local success, error = pcall(rapier.solid_body_from_model, rapier, nil, model, 0, 3)
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid kind"))
+---
+---```
+---@param rigid_body table? # OPTIONAL: Rigid body handle to bind the solid body to.
+---@param model model # Model to get the geometry from.
+---@param mesh_index number # Index of mesh.
+---@param kind model_shape_kind # Model shape kind.
+---@return table solid_body # Solid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L596)
-function steam:get_level() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3285)
+function rapier:solid_body_from_model(rigid_body,model,mesh_index,kind) end
 
----TO-DO
+---Create a height-field solid body from an image. The red channel of every pixel is used as the height, from 0.0 (black) to 1.0 (red). The height-field is centered on the origin.
+---@param rigid_body table? # OPTIONAL: Rigid body handle to bind the solid body to.
+---@param image image # Image to get the height data from.
+---@param scale vector_3 # Scale of the height-field.
+---@return table solid_body # Solid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L605)
-function steam:get_log_on() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3395)
+function rapier:solid_body_from_image(rigid_body,image,scale) end
 
----TO-DO
+---Get the gravity of the simulation.
+---@return number gravity_x # Gravity (X).
+---@return number gravity_y # Gravity (Y).
+---@return number gravity_z # Gravity (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L620)
-function steam:get_leader_board() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3440)
+function rapier:get_gravity() end
 
----TO-DO
+---Set the gravity of the simulation. Default: (0.0, -9.81, 0.0).
+---@param gravity vector_3 # Gravity.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L640)
-function steam:get_or_create_leader_board() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3454)
+function rapier:set_gravity(gravity) end
 
----TO-DO
+---Get the time step of the simulation.
+---@return number time_step # Time step, in seconds.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L673)
-function steam:upload_leader_board_score() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3470)
+function rapier:get_time_step() end
 
----TO-DO
+---Set the time step of the simulation. Default: 1.0 / 60.0.
+---@param time_step number # Time step, in seconds.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L732)
-function steam:get_leader_board_show_kind() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3484)
+function rapier:set_time_step(time_step) end
 
----TO-DO
+---Get the solver iteration count of the simulation.
+---@return number count # Solver iteration count.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L764)
-function steam:get_leader_board_sort_kind() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3505)
+function rapier:get_solver_iteration() end
 
----TO-DO
+---Set the solver iteration count of the simulation. A higher count will give a more accurate simulation, at a performance cost. Default: 4.
+---@param count number # Solver iteration count.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L795)
-function steam:get_leader_board_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3519)
+function rapier:set_solver_iteration(count) end
 
----TO-DO
+---Get the maximum CCD sub-step count of the simulation.
+---@return number count # Maximum CCD sub-step count.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L822)
-function steam:get_leader_board_entry_count() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3540)
+function rapier:get_ccd_substep() end
 
----TO-DO
+---Set the maximum CCD sub-step count of the simulation. Default: 1.
+---@param count number # Maximum CCD sub-step count.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L849)
-function steam:pull_user_statistic() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3554)
+function rapier:set_ccd_substep(count) end
 
----TO-DO
+---Get the contact prediction distance of the simulation.
+---@return number distance # Contact prediction distance.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L862)
-function steam:push_user_statistic() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3569)
+function rapier:get_prediction_distance() end
 
----TO-DO
+---Set the contact prediction distance of the simulation. Contacts closer than this distance will be computed ahead of time. Default: 0.002.
+---@param distance number # Contact prediction distance.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L875)
-function steam:reset_user_statistic() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3583)
+function rapier:set_prediction_distance(distance) end
 
----TO-DO
+---Save the Rapier simulation to a data buffer. Every rigid body, solid body, joint, vehicle and the simulation state are saved, so that a simulation loaded with alicia.rapier.load will step exactly like the original.
+---```lua
+----- Create a new simulation.
+---local rapier = alicia.rapier.new()
+---
+----- Create a fixed floor, with no rigid body parent.
+---rapier:solid_body(nil, 0, vector_3:new(8.0, 0.5, 8.0))
+---
+----- Create a dynamic rigid body, and bind a cuboid solid body to it.
+---local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---local solid = rapier:solid_body(rigid, 0, vector_3:new(0.5, 0.5, 0.5))
+---
+---rapier:set_rigid_body_position(rigid, vector_3:new(0.0, 4.0, 0.0), true)
+---
+----- Step the simulation for half a second, then save it.
+---for x = 1, 30 do
+---    rapier:step()
+---end
+---
+---local data = rapier:save()
+---
+----- Load the saved simulation. Handles are kept as-is.
+---local other = alicia.rapier.load(data)
+---
+----- Step both simulations for another second.
+---for x = 1, 60 do
+---    rapier:step()
+---    other:step()
+---end
+---
+----- Both simulations should be in the exact same state.
+---local a_x, a_y, a_z = rapier:get_solid_body_position(solid)
+---local b_x, b_y, b_z = other:get_solid_body_position(solid)
+---
+---assert(a_x == b_x)
+---assert(a_y == b_y)
+---assert(a_z == b_z)
+---
+----- Create a vehicle, with a chassis and four wheels, and drive it forward.
+---local chassis = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---local body = rapier:solid_body(chassis, 0, vector_3:new(1.0, 0.25, 0.5))
+---rapier:set_rigid_body_position(chassis, vector_3:new(0.0, 2.0, 4.0), true)
+---
+---local vehicle = rapier:vehicle(chassis)
+---
+---for _, point in ipairs({
+---    vector_3:new(0.75, 0.0, 0.5),
+---    vector_3:new(0.75, 0.0, -0.5),
+---    vector_3:new(-0.75, 0.0, 0.5),
+---    vector_3:new(-0.75, 0.0, -0.5),
+---}) do
+---    local wheel = rapier:vehicle_add_wheel(vehicle, point, vector_3:new(0.0, -1.0, 0.0),
+---        vector_3:new(0.0, 0.0, 1.0), 0.5, 0.25)
+---
+---    rapier:set_vehicle_wheel_engine_force(vehicle, wheel, 4.0)
+---end
+---
+---for x = 1, 30 do
+---    rapier:step()
+---end
+---
+----- Load the saved simulation. The vehicle handle is kept as-is.
+---other = alicia.rapier.load(rapier:save())
+---
+---for x = 1, 60 do
+---    rapier:step()
+---    other:step()
+---end
+---
+----- Both vehicles should be in the exact same state.
+---local a_x, a_y, a_z = rapier:get_solid_body_position(body)
+---local b_x, b_y, b_z = other:get_solid_body_position(body)
+---
+---assert(a_x == b_x)
+---assert(a_y == b_y)
+---assert(a_z == b_z)
+---assert(rapier:get_vehicle_speed(vehicle) == other:get_vehicle_speed(vehicle))
+---
+---```
+---@return data data # Data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L895)
-function steam:get_user_statistic() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3600)
+function rapier:save() end
 
----TO-DO
+---Step the Rapier simulation. If no frame time is given, the simulation is stepped once by the time step. Otherwise, the frame time is added to an accumulator, and the simulation is stepped by the time step for as long as the accumulator allows it, so that the simulation does not depend on the frame rate. Every vehicle is updated before each step.
+---```lua
+----- Create a new simulation, with a dynamic rigid body.
+---local rapier = alicia.rapier.new()
+---
+---local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---rapier:solid_body(rigid, 0, vector_3:new(0.5, 0.5, 0.5))
+---
+----- Step by a frame time. Half of a time step is kept in the accumulator.
+---rapier:set_time_step(0.1)
+---
+---local _, _, alpha = rapier:step(0.25)
+---
+---assert(math.abs(alpha - 0.5) < 0.001)
+---
+----- An infinite, negative or NaN frame time is rejected, and the accumulator is left as-is.
+---for _, time in ipairs({ math.huge, -1.0, 0.0 / 0.0 }) do
+---    local success, error = pcall(rapier.step, rapier, time)
+---
+---    assert(not success)
+---    assert(string.find(tostring(error), "Invalid frame time"))
+---end
+---
+---local _, _, alpha = rapier:step(0.0)
+---
+---assert(math.abs(alpha - 0.5) < 0.001)
+---
+----- An infinite, zero or NaN time step is rejected too.
+---for _, time_step in ipairs({ math.huge, 0.0, 0.0 / 0.0 }) do
+---    assert(not pcall(rapier.set_time_step, rapier, time_step))
+---end
+---
+---assert(math.abs(rapier:get_time_step() - 0.1) < 0.001)
+---
+---```
+---@param time number? # OPTIONAL: Frame time.
+---@param step_maximum number? # OPTIONAL: Maximum step count in a single call. Any remaining time in the accumulator is dropped. Default: 8.
+---@return table? event_list # Collision event list, or nil if there were none.
+---@return table? force_list # Contact force event list, or nil if there were none.
+---@return number alpha # Interpolation alpha (0.0 to 1.0) between the last step and the next one, for rendering. 0.0 if no frame time is given.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L917)
-function steam:set_user_statistic() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3624)
+function rapier:step(time,step_maximum) end
 
----TO-DO
+---Render the Rapier simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L941)
-function steam:get_achievement() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3695)
+function rapier:debug_render() end
 
----TO-DO
+---A table describing which solid bodies to take into account in a scene query. Every field is optional.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3729)
+---@class query_filter
+---@field group number? # Collision group bit-mask of the query.
+---@field mask number? # Collision mask bit-mask of the query. A solid body is only considered if its group intersects this mask, and its mask intersects the query group.
+---@field exclude_rigid_body table? # Exclude every solid body bound to this rigid body.
+---@field exclude_solid_body table? # Exclude this solid body.
+---@field exclude_sensor boolean? # Exclude every sensor solid body.
+---@field exclude_solid boolean? # Exclude every non-sensor solid body.
+---@field exclude_fixed boolean? # Exclude every solid body bound to a fixed rigid body, or not bound to any rigid body.
+---@field exclude_kinematic boolean? # Exclude every solid body bound to a kinematic rigid body.
+---@field exclude_dynamic boolean? # Exclude every solid body bound to a dynamic rigid body.
+---@field predicate function? # Function called with a solid body handle, which must return true to consider it. It must not call any method of the rapier simulation itself.
+query_filter = {}
+
+---A table describing the suspension and friction settings of a vehicle wheel. Every field is optional.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier.rs#L3782)
+---@class wheel_tuning
+---@field suspension_stiffness number? # Suspension stiffness. Default: 5.88.
+---@field suspension_compression number? # Suspension damping, when compressed. Default: 0.83.
+---@field suspension_damping number? # Suspension damping, when relaxed. Default: 0.88.
+---@field max_suspension_travel number? # Maximum suspension travel distance. Default: 5.0.
+---@field max_suspension_force number? # Maximum suspension force. Default: 6000.0.
+---@field side_friction_stiffness number? # Side friction stiffness. Default: 1.0.
+---@field friction_slip number? # Friction slip. A higher value will give more grip. Default: 10.5.
+wheel_tuning = {}
+
+---The Steam API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L960)
-function steam:get_achievement_list() end
+---*Available with compile feature: `steam`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L64)
+---@class alicia.steam
+alicia.steam = {}
 
----TO-DO
+---An unique handle to a Steam client.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L977)
-function steam:set_achievement() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L78)
+---@class steam
+steam = {}
 
----TO-DO
+---Update the Steam state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1003)
-function steam:get_achievement_percent() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L136)
+function steam:update() end
 
----TO-DO
+---Get the current AppID.
+---@return number ID # The current AppID.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1022)
-function steam:get_achievement_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L155)
+function steam:get_app_ID() end
 
----TO-DO
+---Get the current country code from the user's IP.
+---@return string code # The current country code.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1041)
-function steam:get_achievement_() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L169)
+function steam:get_IP_country() end
 
----TO-DO
+---Get the current language for the Steam client.
+---@return string language # The current language.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1060)
-function steam:get_achievement_hidden() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L183)
+function steam:get_UI_language() end
 
----TO-DO
+---Get the current Steam server time, as an UNIX time-stamp.
+---@return number time # The current server time.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1079)
-function steam:get_achievement_icon() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L197)
+function steam:get_server_time() end
 
----TO-DO
+---Set the position of the Steam overlay.
+---@param position steam_overlay_position # The Steam overlay position.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1108)
-function steam:get_session_user() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L211)
+function steam:set_overlay_position(position) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1121)
-function steam:get_session_client_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L245)
+function steam:set_message() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1134)
-function steam:get_session_client_form_factor() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L269)
+function steam:get_message() end
 
----TO-DO
+---Check if a given AppID is currently on the system.
+---@param ID number # The AppID.
+---@return boolean install # The state of the AppID.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1159)
-function steam:get_session_client_resolution() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L297)
+function steam:get_app_install(ID) end
 
----TO-DO
+---Check if a given DLC is currently on the system.
+---@param ID number # The AppID.
+---@return boolean install # The state of the AppID.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1179)
-function steam:invite_session() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L314)
+function steam:get_DLC_install(ID) end
 
----TO-DO
+---Check if the user has a subscription to the given AppID.
+---@param ID number # The AppID.
+---@return boolean subscription # Subscription state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1203)
-function steam:set_cloud_app() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L331)
+function steam:get_app_subscribe(ID) end
 
----TO-DO
+---Check if the user has a VAC ban on record.
+---@return boolean ban # The VAC ban state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1215)
-function steam:get_cloud_app() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L345)
+function steam:get_VAC_ban() end
 
----TO-DO
+---Check if the user has a VAC ban on record.
+---@return boolean ban # The VAC ban state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1226)
-function steam:get_cloud_account() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L359)
+function steam:get_cyber_cafe() end
 
----TO-DO
+---Check if the current AppID has support for low violence.
+---@return boolean low_violence # Low violence state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1237)
-function steam:get_file_list() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L373)
+function steam:get_low_violence() end
 
----TO-DO
+---Check if the user has a subscription to the current AppID.
+---@return boolean subscription # Subscription state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1257)
-function steam:file_delete() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L387)
+function steam:get_subscribe() end
 
----TO-DO
+---Get the build ID for the current AppID.
+---@return number ID # The build ID.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1270)
-function steam:file_forget() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L401)
+function steam:get_app_build_ID() end
 
----TO-DO
+---Get the installation path for the given AppID. NOTE: this will work even if the app is not in disk.
+---@param ID number # The AppID.
+---@return string path # Installation path.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1283)
-function steam:file_exist() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L418)
+function steam:get_app_install_path(ID) end
 
----TO-DO
+---Get the SteamID of the owner's current AppID.
+---@return string ID # The SteamID.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1296)
-function steam:get_file_persist() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L432)
+function steam:get_app_owner() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1309)
-function steam:get_file_time_stamp() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L443)
+function steam:get_game_language_list() end
 
----Create a new Steam client.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1331)
-function alicia.steam.new() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L454)
+function steam:get_game_language() end
 
----The request API.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L63)
----@class alicia.request
-alicia.request = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L465)
+function steam:get_beta_name() end
 
----Perform a GET request.
----```lua
----local link = "https://raw.githubusercontent.com/luxreduxdelux/alicia/refs/heads/main/test/asset/sample.txt"
----
------ Get the data from the link. As we know it's not binary, we pass false to the function.
----local response = alicia.request.get(link, false)
----
----assert(response == "Hello, world!")
----
----```
----@param link string # The target URL link.
----@param binary boolean # Receive as binary.
----@return string | data value # The return value.
+---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L93)
-function alicia.request.get(link,binary) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L476)
+function steam:get_launch_command_line() end
 
----Perform a POST request.
----```lua
------ POST request with a body. We pass false to get the result as a string.
----local body = alicia.request.post("http://httpbin.org/post", "hello, body", nil, nil, false)
----
------ POST request with a form.
----local form = alicia.request.post("http://httpbin.org/post", nil, {
----    foo = "bar",
----    bar = "baz",
----}, nil, false)
----
------ POST request with a JSON.
----local JSON = alicia.request.post("http://httpbin.org/post", nil, nil, {
----    foo = "bar",
----    bar = "baz",
----}, false)
----
------ Deserialize the JSON result to a Lua table.
----body = alicia.data.deserialize(body)
----form = alicia.data.deserialize(form)
----JSON = alicia.data.deserialize(JSON)
----
------ Assert that the request with a body's data is correct.
----assert(body.data, "hello, body")
----
------ Assert that the request with a form's data is correct.
----assert(form.form.foo, "bar")
----assert(form.form.bar, "baz")
----
------ Assert that the request with a JSON's data is correct.
----assert(JSON.json.foo, "bar")
----assert(JSON.json.bar, "baz")
----
------ POST request with binary data as the body.
----local body = alicia.request.post("http://httpbin.org/post", alicia.data.new({ 255, 0, 255, 0 }), nil, nil, false)
----
------ Deserialize the JSON result to a Lua table.
----body = alicia.data.deserialize(body)
----
------ Get the Base64 string representation of the data.
----body = string.tokenize(body.data, ",")[2]
----
------ Convert the string representation to a byte representation.
----body = alicia.data.to_data(body, 2)
----
------ Decode the data from Base64.
----body = alicia.data.decode(body)
----
------ Get the data as a Lua table.
----body = body:get_buffer()
----
------ Assert that the data sent is the same as the data we got.
----assert(body[1], 255)
----assert(body[2], 0)
----assert(body[3], 255)
----assert(body[4], 0)
----
----```
----@param link string # The target URL link.
----@param data string? # OPTIONAL: The "data" payload.
----@param form table? # OPTIONAL: The "form" payload.
----@param json table? # OPTIONAL: The "json" payload.
----@param binary boolean # Receive as binary.
----@return string | data value # The return value.
+---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L135)
-function alicia.request.post(link,data,form,json,binary) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L491)
+function steam:get_name() end
 
----The texture API.
+---TO-DO
 ---
 --- ---
----*Not available in head-less mode.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L64)
----@class alicia.texture
-alicia.texture = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L502)
+function steam:activate_overlay() end
 
----An unique handle for a texture in memory.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L219)
----@class texture
----@field shape_x number # Shape of the texture (X).
----@field shape_y number # Shape of the texture (Y).
-texture = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L514)
+function steam:activate_overlay_link() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L246)
-function texture:to_image() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L526)
+function steam:activate_overlay_store() end
 
----Set the mipmap for a texture.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L257)
-function texture:set_mipmap() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L547)
+function steam:activate_overlay_user() end
 
----Set the filter for a texture.
----@param filter texture_filter # LuaTexture filter.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L272)
-function texture:set_filter(filter) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L563)
+function steam:activate_invite_dialog() end
 
----Set the wrap for a texture.
----@param wrap texture_wrap # LuaTexture wrap.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L287)
-function texture:set_wrap(wrap) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L585)
+function steam:get_steam_ID() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L301)
-function texture:draw_plane() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L596)
+function steam:get_level() end
 
----Draw a texture.
----@param point vector_2 # TO-DO
----@param angle number # TO-DO
----@param scale number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L334)
-function texture:draw(point,angle,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L605)
+function steam:get_log_on() end
 
----Draw a texture (pro).
----@param box_a box_2 # TO-DO
----@param box_b box_2 # TO-DO
----@param point vector_2 # TO-DO
----@param angle number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L355)
-function texture:draw_pro(box_a,box_b,point,angle,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L620)
+function steam:get_leader_board() end
 
----Draw a billboard texture.
----@param camera camera_3d # TO-DO
----@param point vector_3 # TO-DO
----@param scale number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L386)
-function texture:draw_billboard(camera,point,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L640)
+function steam:get_or_create_leader_board() end
 
----Draw a billboard texture (pro).
----@param camera camera_3d # TO-DO
----@param source box_3 # TO-DO
----@param point vector_3 # TO-DO
----@param up vector_3 # TO-DO
----@param scale vector_2 # TO-DO
----@param origin vector_2 # TO-DO
----@param angle number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L415)
-function texture:draw_billboard_pro(camera,source,point,up,scale,origin,angle,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L673)
+function steam:upload_leader_board_score() end
 
----Create a new texture resource.
----@param path string # Path to texture file.
----@return texture texture # LuaTexture resource.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L454)
-function alicia.texture.new(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L732)
+function steam:get_leader_board_show_kind() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L478)
-function alicia.texture.new_from_memory() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L764)
+function steam:get_leader_board_sort_kind() end
 
----An unique handle for a render texture in memory.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L520)
----@class render_texture
----@field shape_x number # Shape of the texture (X).
----@field shape_y number # Shape of the texture (Y).
-render_texture = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L795)
+function steam:get_leader_board_name() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L555)
-function render_texture:set_R3D() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L822)
+function steam:get_leader_board_entry_count() end
 
----Initialize drawing to the render texture.
----@param call function # The draw code.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L575)
-function render_texture:begin(call) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L849)
+function steam:pull_user_statistic() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L598)
-function render_texture:draw_plane() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L862)
+function steam:push_user_statistic() end
 
----Draw a texture.
----@param point vector_2 # TO-DO
----@param angle number # TO-DO
----@param scale number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L639)
-function render_texture:draw(point,angle,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L875)
+function steam:reset_user_statistic() end
 
----Draw a texture (pro).
----@param box_a box_2 # TO-DO
----@param box_b box_2 # TO-DO
----@param point vector_2 # TO-DO
----@param angle number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L663)
-function render_texture:draw_pro(box_a,box_b,point,angle,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L895)
+function steam:get_user_statistic() end
 
----Create a new render texture resource.
----@param shape vector_2 # TO-DO
----@return render_texture render_texture # Render texture resource.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L697)
-function alicia.render_texture.new(shape) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L917)
+function steam:set_user_statistic() end
 
----The ZIP API.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L64)
----@class alicia.zip
-alicia.zip = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L941)
+function steam:get_achievement() end
 
----An unique handle to a ZIP in memory.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L83)
----@class zip
-zip = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L960)
+function steam:get_achievement_list() end
 
----Get a file from the ZIP file.
----@param path string # Path to the file.
----@param binary boolean # Read as binary.
----@return string | data file # The file.
+---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L104)
-function zip:get_file(path,binary) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L977)
+function steam:set_achievement() end
 
----Get a list of every file in the ZIP file.
----@return table list # The list of every file.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L141)
-function zip:get_list() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1003)
+function steam:get_achievement_percent() end
 
----Check if the given path is a file.
----@param path string # Path to the file.
----@return boolean value # True if the path is a file, false otherwise.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L160)
-function zip:is_file(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1022)
+function steam:get_achievement_name() end
 
----Check if the given path is a folder.
----@param path string # Path to the folder.
----@return boolean value # True if the path is a folder, false otherwise.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L180)
-function zip:is_path(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1041)
+function steam:get_achievement_() end
 
----Create a new ZIP resource.
----@param path string # Path to ZIP file.
----@return zip zip # ZIP resource.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L203)
-function alicia.zip.new(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1060)
+function steam:get_achievement_hidden() end
 
----The model API.
+---TO-DO
 ---
 --- ---
----*Not available in head-less mode.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L64)
----@class alicia.model
-alicia.model = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1079)
+function steam:get_achievement_icon() end
 
----An unique handle for a model in memory.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L93)
----@class model
----@field mesh_count number # Mesh count.
----@field bone_count number # Bone count.
----@field material_count number # Material count.
-model = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1108)
+function steam:get_session_user() end
 
----Create a new LuaModel resource.
----@param path string # Path to model file.
----@return model model # LuaModel resource.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L137)
-function alicia.model.new(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1121)
+function steam:get_session_client_name() end
 
----Bind a texture to the model.
----@param index number # TO-DO
----@param which number # TO-DO
----@param texture texture # Texture to bind to model.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L214)
-function model:bind(index,which,texture) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1134)
+function steam:get_session_client_form_factor() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L262)
-function model:draw_mesh() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1159)
+function steam:get_session_client_resolution() end
 
----Draw the model.
----@param point vector_3 # TO-DO
----@param scale number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L296)
-function model:draw(point,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1179)
+function steam:invite_session() end
 
----Draw the model (wire-frame).
----@param point vector_3 # TO-DO
----@param scale number # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L331)
-function model:draw_wire(point,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1203)
+function steam:set_cloud_app() end
 
----Draw the model with a transformation.
----@param point vector_3 # TO-DO
----@param angle vector_3 # TO-DO
----@param scale vector_3 # TO-DO
----@param color color # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L355)
-function model:draw_transform(point,angle,scale,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1215)
+function steam:get_cloud_app() end
 
 ---TO-DO
----@return number min_x # Minimum vector. (X)
----@return number min_y # Minimum vector. (Y)
----@return number min_z # Minimum vector. (Z)
----@return number max_x # Maximum vector. (X)
----@return number max_y # Maximum vector. (Y)
----@return number max_z # Maximum vector. (Z)
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L404)
-function model:get_box_3() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1226)
+function steam:get_cloud_account() end
 
----Get the vertex data of a specific mesh in the model.
----@param index number # Index of mesh.
----@return table table # Vector3 table.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L429)
-function model:mesh_vertex(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1237)
+function steam:get_file_list() end
 
----Get the index data of a specific mesh in the model.
----@param index number # Index of mesh.
----@return table table # Number table.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L452)
-function model:mesh_index(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1257)
+function steam:file_delete() end
 
----An unique handle for a model animation in memory.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L473)
----@class model_animation
-model_animation = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1270)
+function steam:file_forget() end
 
----Create a new model animation resource.
----@param path string # Path to model file.
----@return model_animation model_animation # Model animation resource.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L491)
-function alicia.model_animation.new(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1283)
+function steam:file_exist() end
 
----Update model with new model animation data.
----@param model model # TO-DO
----@param frame number # TO-DO
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L541)
-function model_animation:update(model,frame) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1296)
+function steam:get_file_persist() end
 
----The drawing API.
+---TO-DO
 ---
 --- ---
----*Not available in head-less mode.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L64)
----@class alicia.draw
-alicia.draw = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1309)
+function steam:get_file_time_stamp() end
 
----Clear the screen with a color.
----@param color color # The color to use for clearing.
+---Create a new Steam client.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L95)
-function alicia.draw.clear(color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/steam.rs#L1331)
+function alicia.steam.new() end
 
----Initialize drawing to the screen.
----@param call function # The draw code.
----@param ... any # Variadic data.
+---The request API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L115)
-function alicia.draw.begin(call,...) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L80)
+---@class alicia.request
+alicia.request = {}
 
----Initialize drawing (blend mode) to the screen.
----@param call function # The draw code.
----@param mode function # The draw code.
----@param ... any # Variadic data.
+---Perform a GET request.
+---```lua
+---local link = "https://raw.githubusercontent.com/luxreduxdelux/alicia/refs/heads/main/test/asset/sample.txt"
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L145)
-function alicia.draw.begin_blend(call,mode,...) end
-
----Initialize drawing (scissor mode) to the screen.
----@param call function # The draw code.
----@param view box_2 # The clip test region.
----@param ... any # Variadic data.
+----- Get the data from the link. As we know it's not binary, we pass false to the function.
+---local response = alicia.request.get(link, false)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L174)
-function alicia.draw.begin_scissor(call,view,...) end
-
----The 3D drawing API.
+---assert(response == "Hello, world!")
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L204)
----@class alicia.draw_3d
-alicia.draw_3d = {}
-
----Initialize the 3D draw mode.
----@param call function # The draw code.
----@param camera camera_3d # The 2D camera.
----@param ... any # Variadic data.
+---```
+---@param link string # The target URL link.
+---@param binary boolean # Receive as binary.
+---@return string | data value # The return value.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L246)
-function alicia.draw_3d.begin(call,camera,...) end
-
----Get a ray for a 2D screen-space point.
----@param camera camera_3d # The current camera.
----@param point vector_2 # The screen-space point.
----@param shape vector_2 # The size of the view-port.
----@return number position_x # The 3D ray position. (X).
----@return number position_y # The 3D ray position. (Y).
----@return number position_z # The 3D ray position. (Z).
----@return number direction_x # The 3D ray direction. (X).
----@return number direction_y # The 3D ray direction. (Y).
----@return number direction_z # The 3D ray direction. (Z).
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L285)
-function alicia.draw_3d.get_screen_to_world(camera,point,shape) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L114)
+function alicia.request.get(link,binary) end
 
----Get a 2D screen-space point for a 3D world-space point.
----@param camera camera_3d # The current camera.
----@param point vector_3 # The world-space point.
----@param shape vector_2 # The size of the view-port.
----@return number point_x # The 2D screen-space point (X).
----@return number point_y # The 2D screen-space point (Y).
+---Perform a POST request.
+---```lua
+----- POST request with a body. We pass false to get the result as a string.
+---local body = alicia.request.post("http://httpbin.org/post", "hello, body", nil, nil, false)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L323)
-function alicia.draw_3d.get_world_to_screen(camera,point,shape) end
-
----Draw a line.
----@param point_a vector_3 # The point A of the line.
----@param point_b vector_3 # The point B of the line.
----@param color color # The color of the line.
+----- POST request with a form.
+---local form = alicia.request.post("http://httpbin.org/post", nil, {
+---    foo = "bar",
+---    bar = "baz",
+---}, nil, false)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L350)
-function alicia.draw_3d.draw_line(point_a,point_b,color) end
-
----Draw a point.
----@param point vector_3 # The point.
----@param color color # The color of the point.
+----- POST request with a JSON.
+---local JSON = alicia.request.post("http://httpbin.org/post", nil, nil, {
+---    foo = "bar",
+---    bar = "baz",
+---}, false)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L375)
-function alicia.draw_3d.draw_point(point,color) end
-
----Draw a circle.
----@param point vector_3 # The point of the circle.
----@param range number # The range of the circle.
+----- Deserialize the JSON result to a Lua table.
+---body = alicia.data.deserialize(body)
+---form = alicia.data.deserialize(form)
+---JSON = alicia.data.deserialize(JSON)
+---
+----- Assert that the request with a body's data is correct.
+---assert(body.data, "hello, body")
+---
+----- Assert that the request with a form's data is correct.
+---assert(form.form.foo, "bar")
+---assert(form.form.bar, "baz")
+---
+----- Assert that the request with a JSON's data is correct.
+---assert(JSON.json.foo, "bar")
+---assert(JSON.json.bar, "baz")
+---
+----- POST request with binary data as the body.
+---local body = alicia.request.post("http://httpbin.org/post", alicia.data.new({ 255, 0, 255, 0 }), nil, nil, false)
+---
+----- Deserialize the JSON result to a Lua table.
+---body = alicia.data.deserialize(body)
+---
+----- Get the Base64 string representation of the data.
+---body = string.tokenize(body.data, ",")[2]
+---
+----- Convert the string representation to a byte representation.
+---body = alicia.data.to_data(body, 2)
+---
+----- Decode the data from Base64.
+---body = alicia.data.decode(body)
+---
+----- Get the data as a Lua table.
+---body = body:get_buffer()
+---
+----- Assert that the data sent is the same as the data we got.
+---assert(body[1], 255)
+---assert(body[2], 0)
+---assert(body[3], 255)
+---assert(body[4], 0)
+---
+---```
+---@param link string # The target URL link.
+---@param data string? # OPTIONAL: The "data" payload.
+---@param form table? # OPTIONAL: The "form" payload.
+---@param json table? # OPTIONAL: The "json" payload.
+---@param binary boolean # Receive as binary.
+---@return string | data value # The return value.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L156)
+function alicia.request.post(link,data,form,json,binary) end
+
+---A table describing a request. Every field is optional. Only one of data, form, or json should be given.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L245)
+---@class request_info
+---@field method string? # Method, such as "GET", "POST", "PUT", "DELETE", "PATCH" or "HEAD". Default: "GET".
+---@field header table? # Header table, as name and value pairs. Default: nil.
+---@field query table? # Query table, as name and value pairs, to append to the link. Default: nil.
+---@field data (string | data)? # Body payload. Default: nil.
+---@field form table? # Form payload. Default: nil.
+---@field json table? # JSON payload. Default: nil.
+---@field timeout number? # Time (in seconds) to wait for the whole request, overriding the client time-out. Default: nil.
+---@field binary boolean? # Receive the response body as binary (data), rather than as a string. Not used by download. Default: false.
+request_info = {}
+
+---A table describing a response. A response with an error status (such as 404) is still a response, and will not raise an error.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L377)
+---@class request_response
+---@field status number # Status code.
+---@field link string # Final URL link, after every redirect.
+---@field header table # Header table, as name and value pairs. A repeated header has its values joined by ", ".
+---@field data (string | data)? # Response body. Not set by download.
+request_response = {}
+
+---Perform a request, with a shared client. Use a request client instead for cookies or a default header table.
+---```lua
+----- Create a request client, with a header sent on every request, and a cookie store.
+---local client = alicia.request.new_client({
+---    header  = { ["X-Game"] = "alicia" },
+---    cookie  = true,
+---    timeout = 10.0,
+---})
+---
+----- PUT request with a JSON payload and a custom header.
+---local response = client:send("http://httpbin.org/put", {
+---    method = "PUT",
+---    header = { ["X-Version"] = "1.0.0" },
+---    json   = { foo = "bar" },
+---})
+---
+---assert(response.status == 200)
+---assert(string.find(response.header["content-type"], "application/json"))
+---
+---local body = alicia.data.deserialize(response.data)
+---
+---assert(body.json.foo == "bar")
+---assert(body.headers["X-Game"] == "alicia")
+---assert(body.headers["X-Version"] == "1.0.0")
+---
+----- HEAD request. There is no body, but there is still a status and a header table.
+---local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+---
+---assert(response.status == 200)
+---assert(response.data == "")
+---
+----- An error status is still a response, rather than an error.
+---local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+---
+---assert(response.status == 404)
+---
+----- Cookies are kept by the client, and sent back on every later request.
+---local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+---
+---assert(response.link == "http://httpbin.org/cookies")
+---assert(alicia.data.deserialize(response.data).cookies.session == "123")
+---
+----- A request that takes longer than its time-out will fail with a time-out error.
+---local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Request timed out"))
+---
+----- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+---local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid time-out"))
+---
+---local success = pcall(alicia.request.new_client, { timeout = math.huge })
+---
+---assert(not success)
+---
+----- Download a file, with a progress call-back.
+---local progress = 0
+---
+---local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+---    assert(total == 4096)
+---    progress = receive
+---end)
+---
+---assert(response.status == 200)
+---assert(progress == 4096)
+---assert(alicia.file.get_file_size("work/download.bin") == 4096)
+---
+----- A failed download will never leave a partial file behind.
+---local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+---    error("failure")
+---end)
+---
+---assert(not success)
+---assert(not alicia.file.get_file_exist("work/failure.bin"))
+---assert(not alicia.file.get_file_exist("work/failure.bin.part"))
+---
+---```
+---@param link string # The target URL link.
+---@param info request_info? # OPTIONAL: Request configuration. Default: nil (GET).
+---@return request_response response # Response.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L511)
+function alicia.request.send(link,info) end
+
+---Download a file, with a shared client. The body is written to the file as it is received. Nothing is written if the response has an error status.
+---```lua
+----- Create a request client, with a header sent on every request, and a cookie store.
+---local client = alicia.request.new_client({
+---    header  = { ["X-Game"] = "alicia" },
+---    cookie  = true,
+---    timeout = 10.0,
+---})
+---
+----- PUT request with a JSON payload and a custom header.
+---local response = client:send("http://httpbin.org/put", {
+---    method = "PUT",
+---    header = { ["X-Version"] = "1.0.0" },
+---    json   = { foo = "bar" },
+---})
+---
+---assert(response.status == 200)
+---assert(string.find(response.header["content-type"], "application/json"))
+---
+---local body = alicia.data.deserialize(response.data)
+---
+---assert(body.json.foo == "bar")
+---assert(body.headers["X-Game"] == "alicia")
+---assert(body.headers["X-Version"] == "1.0.0")
+---
+----- HEAD request. There is no body, but there is still a status and a header table.
+---local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+---
+---assert(response.status == 200)
+---assert(response.data == "")
+---
+----- An error status is still a response, rather than an error.
+---local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+---
+---assert(response.status == 404)
+---
+----- Cookies are kept by the client, and sent back on every later request.
+---local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+---
+---assert(response.link == "http://httpbin.org/cookies")
+---assert(alicia.data.deserialize(response.data).cookies.session == "123")
+---
+----- A request that takes longer than its time-out will fail with a time-out error.
+---local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Request timed out"))
+---
+----- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+---local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid time-out"))
+---
+---local success = pcall(alicia.request.new_client, { timeout = math.huge })
+---
+---assert(not success)
+---
+----- Download a file, with a progress call-back.
+---local progress = 0
+---
+---local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+---    assert(total == 4096)
+---    progress = receive
+---end)
+---
+---assert(response.status == 200)
+---assert(progress == 4096)
+---assert(alicia.file.get_file_size("work/download.bin") == 4096)
+---
+----- A failed download will never leave a partial file behind.
+---local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+---    error("failure")
+---end)
+---
+---assert(not success)
+---assert(not alicia.file.get_file_exist("work/failure.bin"))
+---assert(not alicia.file.get_file_exist("work/failure.bin.part"))
+---
+---```
+---@param link string # The target URL link.
+---@param path string # Path to the file to write to.
+---@param info request_info? # OPTIONAL: Request configuration. Default: nil (GET).
+---@param call function? # OPTIONAL: Progress call-back, called with the byte count received and the byte count total (nil if unknown).
+---@return request_response response # Response, with no body.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L533)
+function alicia.request.download(link,path,info,call) end
+
+---A table describing the configuration of a request client. Every field is optional.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L557)
+---@class request_client_info
+---@field header table? # Header table, sent with every request, as name and value pairs. Default: nil.
+---@field user_agent string? # User agent. Default: nil.
+---@field cookie boolean? # Keep every cookie received, and send it back on every later request. Default: false.
+---@field timeout number? # Time (in seconds) to wait for a whole request. Default: nil (no time-out).
+---@field timeout_connect number? # Time (in seconds) to wait for a connection. Default: nil (no time-out).
+---@field redirect number? # Largest number of redirects to follow. Default: 10.
+request_client_info = {}
+
+---An unique handle to a request client in memory. Every request made with the same client will re-use the same connection pool, and cookie store.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L584)
+---@class request_client
+request_client = {}
+
+---Perform a request.
+---```lua
+----- Create a request client, with a header sent on every request, and a cookie store.
+---local client = alicia.request.new_client({
+---    header  = { ["X-Game"] = "alicia" },
+---    cookie  = true,
+---    timeout = 10.0,
+---})
+---
+----- PUT request with a JSON payload and a custom header.
+---local response = client:send("http://httpbin.org/put", {
+---    method = "PUT",
+---    header = { ["X-Version"] = "1.0.0" },
+---    json   = { foo = "bar" },
+---})
+---
+---assert(response.status == 200)
+---assert(string.find(response.header["content-type"], "application/json"))
+---
+---local body = alicia.data.deserialize(response.data)
+---
+---assert(body.json.foo == "bar")
+---assert(body.headers["X-Game"] == "alicia")
+---assert(body.headers["X-Version"] == "1.0.0")
+---
+----- HEAD request. There is no body, but there is still a status and a header table.
+---local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+---
+---assert(response.status == 200)
+---assert(response.data == "")
+---
+----- An error status is still a response, rather than an error.
+---local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+---
+---assert(response.status == 404)
+---
+----- Cookies are kept by the client, and sent back on every later request.
+---local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+---
+---assert(response.link == "http://httpbin.org/cookies")
+---assert(alicia.data.deserialize(response.data).cookies.session == "123")
+---
+----- A request that takes longer than its time-out will fail with a time-out error.
+---local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Request timed out"))
+---
+----- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+---local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid time-out"))
+---
+---local success = pcall(alicia.request.new_client, { timeout = math.huge })
+---
+---assert(not success)
+---
+----- Download a file, with a progress call-back.
+---local progress = 0
+---
+---local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+---    assert(total == 4096)
+---    progress = receive
+---end)
+---
+---assert(response.status == 200)
+---assert(progress == 4096)
+---assert(alicia.file.get_file_size("work/download.bin") == 4096)
+---
+----- A failed download will never leave a partial file behind.
+---local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+---    error("failure")
+---end)
+---
+---assert(not success)
+---assert(not alicia.file.get_file_exist("work/failure.bin"))
+---assert(not alicia.file.get_file_exist("work/failure.bin.part"))
+---
+---```
+---@param link string # The target URL link.
+---@param info request_info? # OPTIONAL: Request configuration. Default: nil (GET).
+---@return request_response response # Response.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L606)
+function request_client:send(link,info) end
+
+---Download a file. The body is written to the file as it is received. Nothing is written if the response has an error status.
+---```lua
+----- Create a request client, with a header sent on every request, and a cookie store.
+---local client = alicia.request.new_client({
+---    header  = { ["X-Game"] = "alicia" },
+---    cookie  = true,
+---    timeout = 10.0,
+---})
+---
+----- PUT request with a JSON payload and a custom header.
+---local response = client:send("http://httpbin.org/put", {
+---    method = "PUT",
+---    header = { ["X-Version"] = "1.0.0" },
+---    json   = { foo = "bar" },
+---})
+---
+---assert(response.status == 200)
+---assert(string.find(response.header["content-type"], "application/json"))
+---
+---local body = alicia.data.deserialize(response.data)
+---
+---assert(body.json.foo == "bar")
+---assert(body.headers["X-Game"] == "alicia")
+---assert(body.headers["X-Version"] == "1.0.0")
+---
+----- HEAD request. There is no body, but there is still a status and a header table.
+---local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+---
+---assert(response.status == 200)
+---assert(response.data == "")
+---
+----- An error status is still a response, rather than an error.
+---local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+---
+---assert(response.status == 404)
+---
+----- Cookies are kept by the client, and sent back on every later request.
+---local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+---
+---assert(response.link == "http://httpbin.org/cookies")
+---assert(alicia.data.deserialize(response.data).cookies.session == "123")
+---
+----- A request that takes longer than its time-out will fail with a time-out error.
+---local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Request timed out"))
+---
+----- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+---local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid time-out"))
+---
+---local success = pcall(alicia.request.new_client, { timeout = math.huge })
+---
+---assert(not success)
+---
+----- Download a file, with a progress call-back.
+---local progress = 0
+---
+---local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+---    assert(total == 4096)
+---    progress = receive
+---end)
+---
+---assert(response.status == 200)
+---assert(progress == 4096)
+---assert(alicia.file.get_file_size("work/download.bin") == 4096)
+---
+----- A failed download will never leave a partial file behind.
+---local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+---    error("failure")
+---end)
+---
+---assert(not success)
+---assert(not alicia.file.get_file_exist("work/failure.bin"))
+---assert(not alicia.file.get_file_exist("work/failure.bin.part"))
+---
+---```
+---@param link string # The target URL link.
+---@param path string # Path to the file to write to.
+---@param info request_info? # OPTIONAL: Request configuration. Default: nil (GET).
+---@param call function? # OPTIONAL: Progress call-back, called with the byte count received and the byte count total (nil if unknown).
+---@return request_response response # Response, with no body.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L631)
+function request_client:download(link,path,info,call) end
+
+---Create a request client.
+---```lua
+----- Create a request client, with a header sent on every request, and a cookie store.
+---local client = alicia.request.new_client({
+---    header  = { ["X-Game"] = "alicia" },
+---    cookie  = true,
+---    timeout = 10.0,
+---})
+---
+----- PUT request with a JSON payload and a custom header.
+---local response = client:send("http://httpbin.org/put", {
+---    method = "PUT",
+---    header = { ["X-Version"] = "1.0.0" },
+---    json   = { foo = "bar" },
+---})
+---
+---assert(response.status == 200)
+---assert(string.find(response.header["content-type"], "application/json"))
+---
+---local body = alicia.data.deserialize(response.data)
+---
+---assert(body.json.foo == "bar")
+---assert(body.headers["X-Game"] == "alicia")
+---assert(body.headers["X-Version"] == "1.0.0")
+---
+----- HEAD request. There is no body, but there is still a status and a header table.
+---local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+---
+---assert(response.status == 200)
+---assert(response.data == "")
+---
+----- An error status is still a response, rather than an error.
+---local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+---
+---assert(response.status == 404)
+---
+----- Cookies are kept by the client, and sent back on every later request.
+---local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+---
+---assert(response.link == "http://httpbin.org/cookies")
+---assert(alicia.data.deserialize(response.data).cookies.session == "123")
+---
+----- A request that takes longer than its time-out will fail with a time-out error.
+---local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Request timed out"))
+---
+----- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+---local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+---
+---assert(not success)
+---assert(string.find(tostring(error), "Invalid time-out"))
+---
+---local success = pcall(alicia.request.new_client, { timeout = math.huge })
+---
+---assert(not success)
+---
+----- Download a file, with a progress call-back.
+---local progress = 0
+---
+---local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+---    assert(total == 4096)
+---    progress = receive
+---end)
+---
+---assert(response.status == 200)
+---assert(progress == 4096)
+---assert(alicia.file.get_file_size("work/download.bin") == 4096)
+---
+----- A failed download will never leave a partial file behind.
+---local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+---    error("failure")
+---end)
+---
+---assert(not success)
+---assert(not alicia.file.get_file_exist("work/failure.bin"))
+---assert(not alicia.file.get_file_exist("work/failure.bin.part"))
+---
+---```
+---@param info request_client_info? # OPTIONAL: Client configuration. Any missing field will use its default.
+---@return request_client client # Request client.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L657)
+function alicia.request.new_client(info) end
+
+---A table describing a request, as given to a route call-back.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L729)
+---@class request_server_request
+---@field method string # Method, such as "GET".
+---@field path string # Path, without the query.
+---@field query table # Query table, as name and value pairs.
+---@field header table # Header table, as name and value pairs. Every name is in lower-case.
+---@field data string # Request body.
+---@field address string # Address of the remote socket.
+request_server_request = {}
+
+---A table describing a reply, as returned by a route call-back. Every field is optional. Only one of data, json, or file should be given.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L760)
+---@class request_server_reply
+---@field status number? # Status code. Default: 200.
+---@field header table? # Header table, as name and value pairs. Default: nil.
+---@field data (string | data)? # Body. Default: nil.
+---@field json table? # Table to send as a JSON body. Default: nil.
+---@field file string? # Path to a file to send as the body, read in the background. Default: nil.
+request_server_reply = {}
+
+---An unique handle to an HTTP server in memory. Incoming connections are accepted in the background, while every request is handed to a route call-back, in the main thread, on update. Call update once per frame.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1012)
+---@class request_server
+request_server = {}
+
+---Set a route call-back. A path ending in "*" will match any path with the same prefix, with the longest prefix taking priority. The call-back is given a request_server_request table, and must return either a string, a data buffer, a request_server_reply table, or nil.
+---```lua
+----- Create an HTTP server, listening in the background.
+---local server = alicia.request.new_server("127.0.0.1:7783")
+---
+----- Reply to a GET request on "/status" with a JSON body.
+---server:set_route("GET", "/status", function(request)
+---    return { json = { name = "alicia", level = request.query.level } }
+---end)
+---
+----- Reply to any request on a path starting with "/echo/" with the request body.
+---server:set_route(nil, "/echo/*", function(request)
+---    return { status = 201, header = { ["X-Path"] = request.path }, data = request.data }
+---end)
+---
+----- Serve every file in "test/data" on "/file/".
+---server:set_static("/file/", "test/data")
+---
+----- Send a raw HTTP request, and update the server until the whole reply is received.
+---local function send(request)
+---    local stream = alicia.socket.new_TCP_stream("127.0.0.1:7783")
+---
+---    stream:set_raw(request)
+---
+---    local reply = ""
+---    local clock = os.clock()
+---
+---    while os.clock() - clock < 5.0 do
+---        -- Every accept error is reported on update, and the server keeps on listening.
+---        local _, failure = server:update()
+---
+---        assert(failure == nil)
+---
+---        local success, data = pcall(stream.get_raw, stream)
+---
+---        -- The server closes the connection once it has replied.
+---        if not success then
+---            break
+---        end
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            reply = reply .. alicia.data.from_data(data, 2)
+---        end
+---    end
+---
+---    return reply
+---end
+---
+---local reply = send("GET /status?level=2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "application/json"))
+---assert(string.find(reply, "\"level\":\"2\""))
+---
+---local reply = send("POST /echo/test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello")
+---
+---assert(string.find(reply, "HTTP/1.1 201"))
+---assert(string.find(reply, "x-path: /echo/test"))
+---assert(string.find(reply, "hello"))
+---
+---local reply = send("GET /file/sample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "text/plain"))
+---
+----- A percent-encoded path is decoded first.
+---local reply = send("GET /file/%73ample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---
+----- A request with no route, or outside the static directory, is given a 404 reply.
+---local reply = send("GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/../Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file//etc/hosts HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/%2E%2E/Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---```
+---@param method string? # Method to match, such as "GET" or "POST". Use nil to match any method.
+---@param path string # Path to match, such as "/status".
+---@param call function # Route call-back.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1046)
+function request_server:set_route(method,path,call) end
+
+---Serve every file in a directory, for every path with the given prefix. A path ending in "/" will serve the index.html file in it. This is done entirely in the background.
+---```lua
+----- Create an HTTP server, listening in the background.
+---local server = alicia.request.new_server("127.0.0.1:7783")
+---
+----- Reply to a GET request on "/status" with a JSON body.
+---server:set_route("GET", "/status", function(request)
+---    return { json = { name = "alicia", level = request.query.level } }
+---end)
+---
+----- Reply to any request on a path starting with "/echo/" with the request body.
+---server:set_route(nil, "/echo/*", function(request)
+---    return { status = 201, header = { ["X-Path"] = request.path }, data = request.data }
+---end)
+---
+----- Serve every file in "test/data" on "/file/".
+---server:set_static("/file/", "test/data")
+---
+----- Send a raw HTTP request, and update the server until the whole reply is received.
+---local function send(request)
+---    local stream = alicia.socket.new_TCP_stream("127.0.0.1:7783")
+---
+---    stream:set_raw(request)
+---
+---    local reply = ""
+---    local clock = os.clock()
+---
+---    while os.clock() - clock < 5.0 do
+---        -- Every accept error is reported on update, and the server keeps on listening.
+---        local _, failure = server:update()
+---
+---        assert(failure == nil)
+---
+---        local success, data = pcall(stream.get_raw, stream)
+---
+---        -- The server closes the connection once it has replied.
+---        if not success then
+---            break
+---        end
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            reply = reply .. alicia.data.from_data(data, 2)
+---        end
+---    end
+---
+---    return reply
+---end
+---
+---local reply = send("GET /status?level=2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "application/json"))
+---assert(string.find(reply, "\"level\":\"2\""))
+---
+---local reply = send("POST /echo/test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello")
+---
+---assert(string.find(reply, "HTTP/1.1 201"))
+---assert(string.find(reply, "x-path: /echo/test"))
+---assert(string.find(reply, "hello"))
+---
+---local reply = send("GET /file/sample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "text/plain"))
+---
+----- A percent-encoded path is decoded first.
+---local reply = send("GET /file/%73ample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---
+----- A request with no route, or outside the static directory, is given a 404 reply.
+---local reply = send("GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/../Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file//etc/hosts HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/%2E%2E/Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---```
+---@param prefix string # Path prefix, such as "/static/".
+---@param path string # Path to the directory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1072)
+function request_server:set_static(prefix,path) end
+
+---Reply to every request received since the last call, with its route call-back. A request with no route is given a 404 reply. This call does not block.
+---```lua
+----- Create an HTTP server, listening in the background.
+---local server = alicia.request.new_server("127.0.0.1:7783")
+---
+----- Reply to a GET request on "/status" with a JSON body.
+---server:set_route("GET", "/status", function(request)
+---    return { json = { name = "alicia", level = request.query.level } }
+---end)
+---
+----- Reply to any request on a path starting with "/echo/" with the request body.
+---server:set_route(nil, "/echo/*", function(request)
+---    return { status = 201, header = { ["X-Path"] = request.path }, data = request.data }
+---end)
+---
+----- Serve every file in "test/data" on "/file/".
+---server:set_static("/file/", "test/data")
+---
+----- Send a raw HTTP request, and update the server until the whole reply is received.
+---local function send(request)
+---    local stream = alicia.socket.new_TCP_stream("127.0.0.1:7783")
+---
+---    stream:set_raw(request)
+---
+---    local reply = ""
+---    local clock = os.clock()
+---
+---    while os.clock() - clock < 5.0 do
+---        -- Every accept error is reported on update, and the server keeps on listening.
+---        local _, failure = server:update()
+---
+---        assert(failure == nil)
+---
+---        local success, data = pcall(stream.get_raw, stream)
+---
+---        -- The server closes the connection once it has replied.
+---        if not success then
+---            break
+---        end
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            reply = reply .. alicia.data.from_data(data, 2)
+---        end
+---    end
+---
+---    return reply
+---end
+---
+---local reply = send("GET /status?level=2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "application/json"))
+---assert(string.find(reply, "\"level\":\"2\""))
+---
+---local reply = send("POST /echo/test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello")
+---
+---assert(string.find(reply, "HTTP/1.1 201"))
+---assert(string.find(reply, "x-path: /echo/test"))
+---assert(string.find(reply, "hello"))
+---
+---local reply = send("GET /file/sample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "text/plain"))
+---
+----- A percent-encoded path is decoded first.
+---local reply = send("GET /file/%73ample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---
+----- A request with no route, or outside the static directory, is given a 404 reply.
+---local reply = send("GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/../Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file//etc/hosts HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/%2E%2E/Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---```
+---@return number count # Request count handled.
+---@return table? failure # List of every error raised while accepting a connection since the last call, or nil if there were none.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1100)
+function request_server:update() end
+
+---Get the local address the server is bound to.
+---@return string address # Local address.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1166)
+function request_server:get_address() end
+
+---Create an HTTP server, and listen for incoming connections in the background.
+---```lua
+----- Create an HTTP server, listening in the background.
+---local server = alicia.request.new_server("127.0.0.1:7783")
+---
+----- Reply to a GET request on "/status" with a JSON body.
+---server:set_route("GET", "/status", function(request)
+---    return { json = { name = "alicia", level = request.query.level } }
+---end)
+---
+----- Reply to any request on a path starting with "/echo/" with the request body.
+---server:set_route(nil, "/echo/*", function(request)
+---    return { status = 201, header = { ["X-Path"] = request.path }, data = request.data }
+---end)
+---
+----- Serve every file in "test/data" on "/file/".
+---server:set_static("/file/", "test/data")
+---
+----- Send a raw HTTP request, and update the server until the whole reply is received.
+---local function send(request)
+---    local stream = alicia.socket.new_TCP_stream("127.0.0.1:7783")
+---
+---    stream:set_raw(request)
+---
+---    local reply = ""
+---    local clock = os.clock()
+---
+---    while os.clock() - clock < 5.0 do
+---        -- Every accept error is reported on update, and the server keeps on listening.
+---        local _, failure = server:update()
+---
+---        assert(failure == nil)
+---
+---        local success, data = pcall(stream.get_raw, stream)
+---
+---        -- The server closes the connection once it has replied.
+---        if not success then
+---            break
+---        end
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            reply = reply .. alicia.data.from_data(data, 2)
+---        end
+---    end
+---
+---    return reply
+---end
+---
+---local reply = send("GET /status?level=2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "application/json"))
+---assert(string.find(reply, "\"level\":\"2\""))
+---
+---local reply = send("POST /echo/test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello")
+---
+---assert(string.find(reply, "HTTP/1.1 201"))
+---assert(string.find(reply, "x-path: /echo/test"))
+---assert(string.find(reply, "hello"))
+---
+---local reply = send("GET /file/sample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---assert(string.find(reply, "text/plain"))
+---
+----- A percent-encoded path is decoded first.
+---local reply = send("GET /file/%73ample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 200"))
+---
+----- A request with no route, or outside the static directory, is given a 404 reply.
+---local reply = send("GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/../Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file//etc/hosts HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---local reply = send("GET /file/%2E%2E/Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+---
+---assert(string.find(reply, "HTTP/1.1 404"))
+---
+---```
+---@param address string # Address to bind to, such as "127.0.0.1:8080". Use port 0 for any free port.
+---@return request_server server # HTTP server.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/request.rs#L1186)
+function alicia.request.new_server(address) end
+
+---The texture API.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L65)
+---@class alicia.texture
+alicia.texture = {}
+
+---An unique handle for a texture in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L229)
+---@class texture
+---@field shape_x number # Shape of the texture (X).
+---@field shape_y number # Shape of the texture (Y).
+texture = {}
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L256)
+function texture:to_image() end
+
+---Set the mipmap for a texture.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L267)
+function texture:set_mipmap() end
+
+---Set the filter for a texture.
+---@param filter texture_filter # LuaTexture filter.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L282)
+function texture:set_filter(filter) end
+
+---Set the wrap for a texture.
+---@param wrap texture_wrap # LuaTexture wrap.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L297)
+function texture:set_wrap(wrap) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L311)
+function texture:draw_plane() end
+
+---Draw a texture.
+---@param point vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param scale number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L344)
+function texture:draw(point,angle,scale,color) end
+
+---Draw a texture (pro).
+---@param box_a box_2 # TO-DO
+---@param box_b box_2 # TO-DO
+---@param point vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L365)
+function texture:draw_pro(box_a,box_b,point,angle,color) end
+
+---Draw a billboard texture.
+---@param camera camera_3d # TO-DO
+---@param point vector_3 # TO-DO
+---@param scale number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L396)
+function texture:draw_billboard(camera,point,scale,color) end
+
+---Draw a billboard texture (pro).
+---@param camera camera_3d # TO-DO
+---@param source box_3 # TO-DO
+---@param point vector_3 # TO-DO
+---@param up vector_3 # TO-DO
+---@param scale vector_2 # TO-DO
+---@param origin vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L425)
+function texture:draw_billboard_pro(camera,source,point,up,scale,origin,angle,color) end
+
+---Create a new texture resource.
+---@param path string # Path to texture file.
+---@return texture texture # LuaTexture resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L464)
+function alicia.texture.new(path) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L489)
+function alicia.texture.new_from_memory() end
+
+---An unique handle for a render texture in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L532)
+---@class render_texture
+---@field shape_x number # Shape of the texture (X).
+---@field shape_y number # Shape of the texture (Y).
+render_texture = {}
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L567)
+function render_texture:set_R3D() end
+
+---Initialize drawing to the render texture.
+---@param call function # The draw code.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L587)
+function render_texture:begin(call) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L610)
+function render_texture:draw_plane() end
+
+---Draw a texture.
+---@param point vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param scale number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L651)
+function render_texture:draw(point,angle,scale,color) end
+
+---Draw a texture (pro).
+---@param box_a box_2 # TO-DO
+---@param box_b box_2 # TO-DO
+---@param point vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L675)
+function render_texture:draw_pro(box_a,box_b,point,angle,color) end
+
+---Create a new render texture resource.
+---@param shape vector_2 # TO-DO
+---@return render_texture render_texture # Render texture resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/texture.rs#L709)
+function alicia.render_texture.new(shape) end
+
+---The ZIP API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L64)
+---@class alicia.zip
+alicia.zip = {}
+
+---An unique handle to a ZIP in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L83)
+---@class zip
+zip = {}
+
+---Get a file from the ZIP file.
+---@param path string # Path to the file.
+---@param binary boolean # Read as binary.
+---@return string | data file # The file.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L104)
+function zip:get_file(path,binary) end
+
+---Get a list of every file in the ZIP file.
+---@return table list # The list of every file.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L141)
+function zip:get_list() end
+
+---Check if the given path is a file.
+---@param path string # Path to the file.
+---@return boolean value # True if the path is a file, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L160)
+function zip:is_file(path) end
+
+---Check if the given path is a folder.
+---@param path string # Path to the folder.
+---@return boolean value # True if the path is a folder, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L180)
+function zip:is_path(path) end
+
+---Create a new ZIP resource.
+---@param path string # Path to ZIP file.
+---@return zip zip # ZIP resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/zip.rs#L203)
+function alicia.zip.new(path) end
+
+---The model API.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L65)
+---@class alicia.model
+alicia.model = {}
+
+---An unique handle for a model in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L103)
+---@class model
+---@field mesh_count number # Mesh count.
+---@field bone_count number # Bone count.
+---@field material_count number # Material count.
+model = {}
+
+---Create a new LuaModel resource.
+---@param path string # Path to model file.
+---@return model model # LuaModel resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L147)
+function alicia.model.new(path) end
+
+---Bind a texture to the model.
+---@param index number # TO-DO
+---@param which number # TO-DO
+---@param texture texture # Texture to bind to model.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L225)
+function model:bind(index,which,texture) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L273)
+function model:draw_mesh() end
+
+---Draw the model.
+---@param point vector_3 # TO-DO
+---@param scale number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L307)
+function model:draw(point,scale,color) end
+
+---Draw the model (wire-frame).
+---@param point vector_3 # TO-DO
+---@param scale number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L342)
+function model:draw_wire(point,scale,color) end
+
+---Draw the model with a transformation.
+---@param point vector_3 # TO-DO
+---@param angle vector_3 # TO-DO
+---@param scale vector_3 # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L366)
+function model:draw_transform(point,angle,scale,color) end
+
+---TO-DO
+---@return number min_x # Minimum vector. (X)
+---@return number min_y # Minimum vector. (Y)
+---@return number min_z # Minimum vector. (Z)
+---@return number max_x # Maximum vector. (X)
+---@return number max_y # Maximum vector. (Y)
+---@return number max_z # Maximum vector. (Z)
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L415)
+function model:get_box_3() end
+
+---Get the vertex data of a specific mesh in the model.
+---@param index number # Index of mesh.
+---@return table table # Vector3 table.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L440)
+function model:mesh_vertex(index) end
+
+---Get the index data of a specific mesh in the model.
+---@param index number # Index of mesh.
+---@return table table # Number table.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L463)
+function model:mesh_index(index) end
+
+---An unique handle for a model animation in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L484)
+---@class model_animation
+model_animation = {}
+
+---Create a new model animation resource.
+---@param path string # Path to model file.
+---@return model_animation model_animation # Model animation resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L502)
+function alicia.model_animation.new(path) end
+
+---Update model with new model animation data.
+---@param model model # TO-DO
+---@param frame number # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/model.rs#L553)
+function model_animation:update(model,frame) end
+
+---The drawing API.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L65)
+---@class alicia.draw
+alicia.draw = {}
+
+---Clear the screen with a color.
+---@param color color # The color to use for clearing.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L105)
+function alicia.draw.clear(color) end
+
+---Initialize drawing to the screen. In hot-reload mode, every Lua module that has changed will be re-executed at the end of the frame.
+---@param call function # The draw code.
+---@param ... any # Variadic data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L125)
+function alicia.draw.begin(call,...) end
+
+---Initialize drawing (blend mode) to the screen.
+---@param call function # The draw code.
+---@param mode function # The draw code.
+---@param ... any # Variadic data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L169)
+function alicia.draw.begin_blend(call,mode,...) end
+
+---Initialize drawing (scissor mode) to the screen.
+---@param call function # The draw code.
+---@param view box_2 # The clip test region.
+---@param ... any # Variadic data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L198)
+function alicia.draw.begin_scissor(call,view,...) end
+
+---The 3D drawing API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L228)
+---@class alicia.draw_3d
+alicia.draw_3d = {}
+
+---Initialize the 3D draw mode.
+---@param call function # The draw code.
+---@param camera camera_3d # The 2D camera.
+---@param ... any # Variadic data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L270)
+function alicia.draw_3d.begin(call,camera,...) end
+
+---Get a ray for a 2D screen-space point.
+---@param camera camera_3d # The current camera.
+---@param point vector_2 # The screen-space point.
+---@param shape vector_2 # The size of the view-port.
+---@return number position_x # The 3D ray position. (X).
+---@return number position_y # The 3D ray position. (Y).
+---@return number position_z # The 3D ray position. (Z).
+---@return number direction_x # The 3D ray direction. (X).
+---@return number direction_y # The 3D ray direction. (Y).
+---@return number direction_z # The 3D ray direction. (Z).
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L309)
+function alicia.draw_3d.get_screen_to_world(camera,point,shape) end
+
+---Get a 2D screen-space point for a 3D world-space point.
+---@param camera camera_3d # The current camera.
+---@param point vector_3 # The world-space point.
+---@param shape vector_2 # The size of the view-port.
+---@return number point_x # The 2D screen-space point (X).
+---@return number point_y # The 2D screen-space point (Y).
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L347)
+function alicia.draw_3d.get_world_to_screen(camera,point,shape) end
+
+---Draw a line.
+---@param point_a vector_3 # The point A of the line.
+---@param point_b vector_3 # The point B of the line.
+---@param color color # The color of the line.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L374)
+function alicia.draw_3d.draw_line(point_a,point_b,color) end
+
+---Draw a point.
+---@param point vector_3 # The point.
+---@param color color # The color of the point.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L399)
+function alicia.draw_3d.draw_point(point,color) end
+
+---Draw a circle.
+---@param point vector_3 # The point of the circle.
+---@param range number # The range of the circle.
 ---@param axis vector_3 # The axis of the circle.
 ---@param range number # The angle of the circle.
 ---@param color color # The color of the circle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L399)
-function alicia.draw_3d.draw_circle(point,range,axis,range,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L423)
+function alicia.draw_3d.draw_circle(point,range,axis,range,color) end
+
+---Draw a triangle.
+---@param point_a vector_3 # The point (A) of the triangle.
+---@param point_b vector_3 # The point (B) of the triangle.
+---@param point_c vector_3 # The point (C) of the triangle.
+---@param color color # The color of the triangle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L450)
+function alicia.draw_3d.draw_triangle(point_a,point_b,point_c,color) end
+
+---Draw a triangle strip.
+---@param point table # The point list of the triangle.
+---@param color color # The color of the triangle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L476)
+function alicia.draw_3d.draw_triangle_strip(point,color) end
+
+---Draw a cube.
+---@param point vector_3 # The point of the cube.
+---@param shape vector_3 # The shape of the cube.
+---@param color color # The color of the cube.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L498)
+function alicia.draw_3d.draw_cube(point,shape,color) end
+
+---Draw a cube (wire-frame).
+---@param point vector_3 # The point of the cube.
+---@param shape vector_3 # The shape of the cube.
+---@param color color # The color of the cube.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L524)
+function alicia.draw_3d.draw_cube_wire(point,shape,color) end
+
+---Draw a sphere.
+---@param point vector_3 # The point of the sphere.
+---@param color color # The color of the triangle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L549)
+function alicia.draw_3d.draw_sphere(point,color) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L569)
+function alicia.draw_3d.draw_sphere_wire() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L589)
+function alicia.draw_3d.draw_cylinder() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L617)
+function alicia.draw_3d.draw_cylinder_wire() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L645)
+function alicia.draw_3d.draw_capsule() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L673)
+function alicia.draw_3d.draw_capsule_wire() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L701)
+function alicia.draw_3d.draw_plane() end
+
+---Draw a ray.
+---@param ray ray # The ray.
+---@param color color # The color of the ray.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L725)
+function alicia.draw_3d.draw_ray(ray,color) end
+
+---Draw a grid.
+---@param slice number # The slice count of the grid.
+---@param space number # The space shift of the grid.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L745)
+function alicia.draw_3d.draw_grid(slice,space) end
+
+---Draw a 3D box.
+---@param shape box_3 # The shape of the ball.
+---@param color color # The color of the ball.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L762)
+function alicia.draw_3d.draw_box_3(shape,color) end
+
+---The 2D drawing API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L779)
+---@class alicia.draw_2d
+alicia.draw_2d = {}
+
+---Initialize the 2D draw mode.
+---@param call function # The draw code.
+---@param camera camera_2d # The 2D camera.
+---@param ... any # Variadic data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L838)
+function alicia.draw_2d.begin(call,camera,...) end
+
+---Get a screen-space point for a 2D world-space point.
+---@param camera camera_2d # The current camera.
+---@param point vector_2 # The world-space point.
+---@return number point_x # The 2D screen-space point (X).
+---@return number point_y # The 2D screen-space point (Y).
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L872)
+function alicia.draw_2d.get_world_to_screen(camera,point) end
+
+---Get a world-space point for a 2D screen-space point.
+---@param camera camera_2d # The current camera.
+---@param point vector_2 # The screen-space point.
+---@return number point_x # The 2D world-space point (X).
+---@return number point_y # The 2D world-space point (Y).
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L901)
+function alicia.draw_2d.get_screen_to_world(camera,point) end
+
+---Draw pixel.
+---@param point vector_2 # The point of the pixel.
+---@param color color # The color of the pixel.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L926)
+function alicia.draw_2d.draw_pixel(point,color) end
+
+---Draw a line.
+---@param point_a vector_2 # The point A of the line.
+---@param point_b vector_2 # The point B of the line.
+---@param thick number # The thickness of the line.
+---@param color color # The color of the line.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L949)
+function alicia.draw_2d.draw_line(point_a,point_b,thick,color) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L970)
+function alicia.draw_2d.draw_line_strip() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L987)
+function alicia.draw_2d.draw_line_bezier() end
+
+---Draw a circle.
+---@param point vector_2 # TO-DO
+---@param range number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1013)
+function alicia.draw_2d.draw_circle(point,range,color) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1033)
+function alicia.draw_2d.draw_circle_line() end
+
+---Draw the sector of a circle.
+---@param point vector_2 # TO-DO
+---@param range number # TO-DO
+---@param begin_angle number # TO-DO
+---@param close_angle number # TO-DO
+---@param segment_count number # TO-DO
+---@param color color # TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1061)
+function alicia.draw_2d.draw_circle_sector(point,range,begin_angle,close_angle,segment_count,color) end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1088)
+function alicia.draw_2d.draw_circle_sector_line() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1108)
+function alicia.draw_2d.draw_circle_gradient() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1129)
+function alicia.draw_2d.draw_ellipse() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1150)
+function alicia.draw_2d.draw_ellipse_line() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1171)
+function alicia.draw_2d.draw_ring() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1199)
+function alicia.draw_2d.draw_ring_line() end
+
+---Draw 2D box.
+---@param shape box_2 # The shape of the box.
+---@param point vector_2 # The point of the box.
+---@param angle number # The angle of the box.
+---@param color color # The color of the box.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1233)
+function alicia.draw_2d.draw_box_2(shape,point,angle,color) end
+
+---Draw 2D box with a 4-point gradient.
+---@param shape box_2 # The shape of the box.
+---@param color_a color # The color A (T.L.) of the box.
+---@param color_b color # The color B (B.L.) of the box.
+---@param color_c color # The color C (T.R.) of the box.
+---@param color_d color # The color D (B.R.) of the box.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1261)
+function alicia.draw_2d.draw_box_2_gradient(shape,color_a,color_b,color_c,color_d) end
+
+---Draw 2D box (out-line).
+---@param shape box_2 # The shape of the box.
+---@param thick number # The thickness of the box.
+---@param color color # The color of the box.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1295)
+function alicia.draw_2d.draw_box_2_line(shape,thick,color) end
+
+---Draw 2D box (round).
+---@param shape box_2 # The shape of the box.
+---@param round number # The roundness of the box.
+---@param count number # The segment count of the box.
+---@param color color # The color of the box.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1321)
+function alicia.draw_2d.draw_box_2_round(shape,round,count,color) end
+
+---Draw 2D box (out-line, round).
+---@param shape box_2 # The shape of the box.
+---@param round number # The roundness of the box.
+---@param count number # The segment count of the box.
+---@param thick number # The thickness of the box.
+---@param color color # The color of the box.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1348)
+function alicia.draw_2d.draw_box_2_line_round(shape,round,count,thick,color) end
+
+---Draw 2D triangle.
+---@param point_a vector_2 # The point A of the triangle.
+---@param point_b vector_2 # The point B of the triangle.
+---@param point_c vector_2 # The point C of the triangle.
+---@param color color # The color of the triangle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1374)
+function alicia.draw_2d.draw_triangle(point_a,point_b,point_c,color) end
+
+---Draw 2D triangle (out-line).
+---@param point_a vector_2 # The point A of the triangle.
+---@param point_b vector_2 # The point B of the triangle.
+---@param point_c vector_2 # The point C of the triangle.
+---@param color color # The color of the triangle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1402)
+function alicia.draw_2d.draw_triangle_line(point_a,point_b,point_c,color) end
+
+---The data API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L64)
+---@class alicia.data
+alicia.data = {}
+
+---An unique handle for a data buffer in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L103)
+---@class data
+data = {}
+
+---Get the length of the data buffer.
+---@return number length # The length of the data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L169)
+function data:get_length() end
+
+---Get the data buffer.
+---@return table buffer # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L181)
+function data:get_buffer() end
+
+---Get a slice out of the data buffer, as another data buffer.
+---@param index_a number # Index A into the data buffer.
+---@param index_b number # Index B into the data buffer.
+---@return data slice # The slice, as another data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L197)
+function data:get_slice(index_a,index_b) end
+
+---Compress a given data buffer (DEFLATE).
+---```lua
+----- Create a table with no data in it.
+---local data = {}
+---
+----- Insert some dummy data in it.
+---for x = 1, 63 do
+---    table.insert(data, 0)
+---end
+---
+----- Insert some data of significance at the last index.
+---table.insert(data, 1)
+---
+----- Check that the last value is 1.
+---assert(data[64] == 1)
+---
+----- Get a data buffer user-data from Alicia.
+---local data = alicia.data.new(data)
+---
+----- Compress the data.
+---local data = alicia.data.compress(data)
+---
+----- Decompress the data.
+---local data = alicia.data.decompress(data)
+---
+----- Get the data back as a Lua table.
+---local data = data:get_buffer()
+---
+----- Check that the last value is 1.
+---assert(data[64] == 1)
+---
+---```
+---@param data data # The data buffer to compress.
+---@return data data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L228)
+function alicia.data.compress(data) end
+
+---Decompress a given data buffer (DEFLATE).
+---```lua
+----- Create a table with no data in it.
+---local data = {}
+---
+----- Insert some dummy data in it.
+---for x = 1, 63 do
+---    table.insert(data, 0)
+---end
+---
+----- Insert some data of significance at the last index.
+---table.insert(data, 1)
+---
+----- Check that the last value is 1.
+---assert(data[64] == 1)
+---
+----- Get a data buffer user-data from Alicia.
+---local data = alicia.data.new(data)
+---
+----- Compress the data.
+---local data = alicia.data.compress(data)
+---
+----- Decompress the data.
+---local data = alicia.data.decompress(data)
+---
+----- Get the data back as a Lua table.
+---local data = data:get_buffer()
+---
+----- Check that the last value is 1.
+---assert(data[64] == 1)
+---
+---```
+---@param data data # The data buffer to decompress.
+---@return data data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L254)
+function alicia.data.decompress(data) end
+
+---Encode a given data buffer (Base64).
+---@param data data # The data buffer to encode.
+---@return data data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L281)
+function alicia.data.encode(data) end
+
+---Decode a given data buffer (Base64).
+---@param data data # The data buffer to decode.
+---@return data data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L306)
+function alicia.data.decode(data) end
+
+---Hash a given data buffer.
+---@param data data # The data buffer to encode.
+---@param data hash_kind? # OPTIONAL: The hash method. Default: CRC32.
+---@return data data # The hash code.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L334)
+function alicia.data.hash(data,data) end
+
+---Serialize a given Lua value as another format, in the form of a string.
+---@param text any # Lua value to serialize.
+---@param kind format_kind? # OPTIONAL: The format to serialize to. Default: JSON.
+---@return string value # The value, in string form.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L385)
+function alicia.data.serialize(text,kind) end
+
+---Deserialize a given format string as a Lua value.
+---@param text string # String to deserialize.
+---@param kind format_kind? # OPTIONAL: The format to deserialize from. Default: JSON.
+---@return any value # The value, in Lua value form.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L433)
+function alicia.data.deserialize(text,kind) end
+
+---Convert a given Lua value to a data buffer.
+---@param data any # Lua value to convert to a data buffer.
+---@param kind data_kind # The data kind to convert to.
+---@return data value # The value, in data buffer form.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L489)
+function alicia.data.to_data(data,kind) end
+
+---Convert a given data buffer to a Lua value.
+---@param data data # Data buffer to convert to a Lua value.
+---@param kind data_kind # The data kind to convert to.
+---@return number | string value # The value, in Lua value form.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L520)
+function alicia.data.from_data(data,kind) end
+
+---Get a file from the embed file.
+---@param path string # Path to the file.
+---@param binary boolean # Read as binary.
+---@return string | data file # The file.
+---
+--- ---
+---*Available with compile feature: `embed`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L564)
+function alicia.data.get_embed_file(path,binary) end
+
+---Get a list of every file in the embed data.
+---@return table list # The list of every file.
+---
+--- ---
+---*Available with compile feature: `embed`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L593)
+function alicia.data.get_embed_list() end
+
+---The socket API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L103)
+---@class alicia.socket
+alicia.socket = {}
+
+---A table describing the TLS configuration of a TCP socket. Every field is optional. A client will verify the server certificate against the web PKI root list, and the given authority, if any. A server must give both a certificate and a key.
+---
+--- ---
+---*Available with compile feature: `tls`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L403)
+---@class TLS_info
+---@field domain string? # Client: Name to verify the server certificate with. Default: host part of the address.
+---@field authority string? # Client: Path to a PEM certificate authority to trust, such as the one a self-signed certificate was made with. Default: nil.
+---@field insecure boolean? # Client: Accept any server certificate. Only use this for development, never for a release. Default: false.
+---@field certificate string? # Server: Path to a PEM certificate chain. Default: nil.
+---@field key string? # Server: Path to a PEM private key. Default: nil.
+TLS_info = {}
+
+---An unique handle to a TCP (stream) socket in memory. Every message sent with set is prefixed with its length (as a big-endian, 32-bit integer), so that get will always return a complete message.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L644)
+---@class socket_TCP_stream
+socket_TCP_stream = {}
+
+---Get the next message from the socket, if any has been fully received. This call does not block.
+---@return data? data # Message, or nil if no message is available.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L668)
+function socket_TCP_stream:get() end
+
+---Get every byte received from the socket, with no framing. This call does not block.
+---@return data? data # Received bytes, or nil if no byte is available.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L694)
+function socket_TCP_stream:get_raw() end
+
+---Send a message through the socket.
+---@param data string | data # Message to send.
+---@return number length # Length of the message sent, including the length prefix.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L726)
+function socket_TCP_stream:set(data) end
+
+---Send bytes through the socket, with no framing.
+---@param data string | data # Bytes to send.
+---@return number length # Length of the bytes sent.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L757)
+function socket_TCP_stream:set_raw(data) end
+
+---Connect to a TCP socket.
+---```lua
+----- A certificate and key for "localhost", made with a local certificate authority.
+---local server_info = {
+---    certificate = "test/data/tls/certificate.pem",
+---    key         = "test/data/tls/key.pem",
+---}
+---
+----- Trust the local certificate authority, on top of the web PKI root list.
+---local client_info = {
+---    authority = "test/data/tls/authority.pem",
+---}
+---
+----- Create a TLS server, listening in the background.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7780", nil, server_info)
+---
+---local clock = os.clock()
+---
+---while server:get_state() ~= SOCKET_STATE.CONNECT and os.clock() - clock < 5.0 do
+---    server:poll()
+---end
+---
+----- Connect to the server. The TLS handshake is made before returning.
+---local stream = alicia.socket.new_TCP_stream("127.0.0.1:7780", nil, client_info)
+---
+---stream:set("hello")
+---
+---local message = nil
+---local clock = os.clock()
+---
+---while not message and os.clock() - clock < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            message = alicia.data.from_data(event.data, 2)
+---            server:set(event.client, "world")
+---        end
+---    end
+---end
+---
+---assert(message == "hello")
+---
+---local reply = nil
+---local clock = os.clock()
+---
+---while not reply and os.clock() - clock < 5.0 do
+---    reply = stream:get()
+---end
+---
+---assert(alicia.data.from_data(reply, 2) == "world")
+---
+----- Without the local certificate authority, the server certificate can not be verified.
+---assert(not pcall(alicia.socket.new_TCP_stream, "127.0.0.1:7780", nil, {}))
+---
+----- Create a TLS listen socket, and connect to it in the background. A development build can
+----- skip verifying the server certificate altogether.
+---local listen = alicia.socket.new_TCP_listen("127.0.0.1:7781", nil, server_info)
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7781", nil, { insecure = true })
+---
+----- Accept the connection. The TLS handshake is made before returning.
+---local accept = listen:accept()
+---
+---accept:set("hello")
+---
+---local message = nil
+---local clock = os.clock()
+---
+---while not message and os.clock() - clock < 5.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---end
+---
+---assert(message == "hello")
+---assert(client:get_state() == SOCKET_STATE.CONNECT)
+---
+----- A peer that never makes the TLS handshake will time out, rather than hold up accept forever.
+---local plain = alicia.socket.new_TCP_client("127.0.0.1:7781")
+---local success, error = pcall(listen.accept, listen)
+---
+---assert(not success)
+---assert(string.find(tostring(error), "TLS handshake timed out"))
+---
+---```
+---@param address string # Address to connect to.
+---@param length number? # OPTIONAL: Buffer size, which is also the largest message size. Default: 65536.
+---@param tls TLS_info? # OPTIONAL: TLS configuration. The TLS handshake is made before returning. Default: nil (plain).
+---@return socket_TCP_stream socket # TCP (stream) socket.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L786)
+function alicia.socket.new_TCP_stream(address,length,tls) end
+
+---An unique handle to a TCP (listen) socket in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L860)
+---@class socket_TCP_listen
+socket_TCP_listen = {}
+
+---Accept an incoming connection. The new socket will use the same buffer size and TLS configuration as the listen socket. The TLS handshake, if any, is made before returning, and will time out after 10 seconds.
+---@return socket_TCP_stream socket # TCP (stream) socket.
+---@return string address # Address of the remote socket.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L884)
+function socket_TCP_listen:accept() end
+
+---Create a TCP socket, listening for incoming connections.
+---```lua
+----- A certificate and key for "localhost", made with a local certificate authority.
+---local server_info = {
+---    certificate = "test/data/tls/certificate.pem",
+---    key         = "test/data/tls/key.pem",
+---}
+---
+----- Trust the local certificate authority, on top of the web PKI root list.
+---local client_info = {
+---    authority = "test/data/tls/authority.pem",
+---}
+---
+----- Create a TLS server, listening in the background.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7780", nil, server_info)
+---
+---local clock = os.clock()
+---
+---while server:get_state() ~= SOCKET_STATE.CONNECT and os.clock() - clock < 5.0 do
+---    server:poll()
+---end
+---
+----- Connect to the server. The TLS handshake is made before returning.
+---local stream = alicia.socket.new_TCP_stream("127.0.0.1:7780", nil, client_info)
+---
+---stream:set("hello")
+---
+---local message = nil
+---local clock = os.clock()
+---
+---while not message and os.clock() - clock < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            message = alicia.data.from_data(event.data, 2)
+---            server:set(event.client, "world")
+---        end
+---    end
+---end
+---
+---assert(message == "hello")
+---
+---local reply = nil
+---local clock = os.clock()
+---
+---while not reply and os.clock() - clock < 5.0 do
+---    reply = stream:get()
+---end
+---
+---assert(alicia.data.from_data(reply, 2) == "world")
+---
+----- Without the local certificate authority, the server certificate can not be verified.
+---assert(not pcall(alicia.socket.new_TCP_stream, "127.0.0.1:7780", nil, {}))
+---
+----- Create a TLS listen socket, and connect to it in the background. A development build can
+----- skip verifying the server certificate altogether.
+---local listen = alicia.socket.new_TCP_listen("127.0.0.1:7781", nil, server_info)
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7781", nil, { insecure = true })
+---
+----- Accept the connection. The TLS handshake is made before returning.
+---local accept = listen:accept()
+---
+---accept:set("hello")
+---
+---local message = nil
+---local clock = os.clock()
+---
+---while not message and os.clock() - clock < 5.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---end
+---
+---assert(message == "hello")
+---assert(client:get_state() == SOCKET_STATE.CONNECT)
+---
+----- A peer that never makes the TLS handshake will time out, rather than hold up accept forever.
+---local plain = alicia.socket.new_TCP_client("127.0.0.1:7781")
+---local success, error = pcall(listen.accept, listen)
+---
+---assert(not success)
+---assert(string.find(tostring(error), "TLS handshake timed out"))
+---
+---```
+---@param address string # Address to bind to.
+---@param length number? # OPTIONAL: Buffer size for every accepted socket, which is also the largest message size. Default: 65536.
+---@param tls TLS_info? # OPTIONAL: TLS configuration, with a certificate and key. Default: nil (plain).
+---@return socket_TCP_listen socket # TCP (listen) socket.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L914)
+function alicia.socket.new_TCP_listen(address,length,tls) end
+
+---An unique handle to a TCP (client) socket in memory. The connection is made in the background, and every event must be drained with poll, once per frame. Messages use the same framing as socket_TCP_stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L930)
+---@class socket_TCP_client
+socket_TCP_client = {}
+
+---Drain every event received since the last call. Each event has a kind (socket_event), and depending on the kind, an address (connect), a data buffer (message), or an error message (failure). This call does not block.
+---@return table event_list # Event list.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L958)
+function socket_TCP_client:poll() end
+
+---Get the connection state, as of the last poll call.
+---@return socket_state state # Connection state.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L986)
+function socket_TCP_client:get_state() end
+
+---Queue a message to send. Messages queued while the socket is still connecting will be sent once connected. This call does not block.
+---@param data string | data # Message to send.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L998)
+function socket_TCP_client:set(data) end
+
+---Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped.
+---```lua
+----- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+---local function run(seed, address)
+---    local send = alicia.socket.new_UDP("127.0.0.1:0")
+---    local receive = alicia.socket.new_UDP(address)
+---
+---    -- Drop half of every datagram, and duplicate a tenth of them.
+---    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+---
+---    for x = 1, 100 do
+---        send:set_at(tostring(x), address)
+---    end
+---
+---    local list = {}
+---    local clock = alicia.general.get_time()
+---
+---    while alicia.general.get_time() - clock < 0.5 do
+---        local data = receive:get_at()
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(list, alicia.data.from_data(data, 2))
+---        else
+---            alicia.general.sleep(0.01)
+---        end
+---    end
+---
+---    return list
+---end
+---
+---local list_a = run(1234, "127.0.0.1:7784")
+---local list_b = run(1234, "127.0.0.1:7785")
+---
+----- Some datagrams are lost, but not all of them.
+---assert(#list_a > 0 and #list_a < 100)
+---
+----- The same seed will always drop and duplicate the same datagrams.
+---assert(#list_a == #list_b)
+---
+---for x = 1, #list_a do
+---    assert(list_a[x] == list_b[x])
+---end
+---
+----- Delay every datagram by 0.2 seconds.
+---local send = alicia.socket.new_UDP("127.0.0.1:0")
+---local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+---
+---send:set_simulate({ latency = 0.2 })
+---send:set_at("late", "127.0.0.1:7786")
+---
+---assert(receive:get_at() == nil)
+---
+---local data = nil
+---local clock = alicia.general.get_time()
+---
+----- A delayed datagram is sent in the background once due, with no further call on the socket.
+---while not data and alicia.general.get_time() - clock < 1.0 do
+---    alicia.general.sleep(0.01)
+---
+---    data = receive:get_at()
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(alicia.data.from_data(data, 2) == "late")
+---
+----- A stream is simulated too. Every message is delayed, but always kept in order.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+---
+---client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+---
+---local message = {}
+---local number = nil
+---local clock = alicia.general.get_time()
+---
+---for x = 1, 10 do
+---    client:set(tostring(x))
+---end
+---
+---while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            number = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---
+---    client:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(#message == 10)
+---
+---for x = 1, 10 do
+---    assert(message[x] == tostring(x))
+---end
+---
+----- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+---server:set_simulate({ latency = 0.1, loss = 1.0 })
+---server:set(number, "stall")
+---
+---local message = nil
+---local clock = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.35)
+---assert(message == "stall")
+---
+---client:close()
+---server:close()
+---
+----- A web socket is simulated the same way, if available.
+---if alicia.socket.new_web_socket_server then
+---    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+---    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+---
+---    client:set_simulate({ latency = 0.2 })
+---    client:set("late", true)
+---
+---    local message = nil
+---    local clock = alicia.general.get_time()
+---
+---    while not message and alicia.general.get_time() - clock < 2.0 do
+---        for _, event in ipairs(server:poll()) do
+---            if event.kind == SOCKET_EVENT.MESSAGE then
+---                message = event.data
+---            end
+---        end
+---
+---        client:poll()
+---        alicia.general.sleep(0.01)
+---    end
+---
+---    assert(alicia.general.get_time() - clock >= 0.15)
+---    assert(message == "late")
+---
+---    server:close()
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1028)
+function socket_TCP_client:set_simulate(info) end
+
+---Close the connection. Any queued message that has not been sent yet is dropped.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1041)
+function socket_TCP_client:close() end
+
+---Create a TCP (client) socket, and connect to a TCP socket in the background. This call does not block.
+---```lua
+----- Create a TCP server, listening in the background.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7783")
+---
+---local time = alicia.general.get_time()
+---
+---while server:get_state() ~= SOCKET_STATE.CONNECT and alicia.general.get_time() - time < 5.0 do
+---    server:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Connect to the server in the background, and queue a message before the connection is made.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---
+---client:set("hello")
+---
+----- Wait for the server to receive the message, and reply to it.
+---local message = nil
+---local peer    = nil
+---local time    = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            peer = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            message = alicia.data.from_data(event.data, 2)
+---            server:set(event.client, "world")
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(peer)
+---assert(message == "hello")
+---
+----- Wait for the client to receive the reply.
+---local reply = nil
+---local time  = alicia.general.get_time()
+---
+---while not reply and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            reply = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(reply == "world")
+---assert(client:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Close the client. The server will raise a disconnect event, and keep on listening.
+---client:close()
+---
+---local close = false
+---local time  = alicia.general.get_time()
+---
+---while not close and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.DISCONNECT then
+---            close = event.client == peer
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(close)
+---
+----- A second client can still connect.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---local join   = false
+---local time   = alicia.general.get_time()
+---
+---while not join and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            join = true
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(join)
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
+---
+---server:close()
+---
+---```
+---@param address string # Address to connect to.
+---@param length number? # OPTIONAL: Buffer size, which is also the largest message size. Default: 65536.
+---@param tls TLS_info? # OPTIONAL: TLS configuration. The TLS handshake is made before the connect event. Default: nil (plain).
+---@return socket_TCP_client socket # TCP (client) socket.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1067)
+function alicia.socket.new_TCP_client(address,length,tls) end
+
+---An unique handle to a TCP (server) socket in memory. Incoming connections are accepted in the background, and every event must be drained with poll, once per frame. Every connection is given a client number. Messages use the same framing as socket_TCP_stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1108)
+---@class socket_TCP_server
+socket_TCP_server = {}
+
+---Drain every event received since the last call. Each event has a kind (socket_event), a client number, and depending on the kind, an address (connect), a data buffer (message), or an error message (failure). A failure event with no client number means the server socket has failed to bind, or to accept a connection, in which case it will keep on listening. This call does not block.
+---@return table event_list # Event list.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1137)
+function socket_TCP_server:poll() end
+
+---Get the server state, as of the last poll call. The server is in the connect state once it is listening for incoming connections.
+---@return socket_state state # Server state.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1181)
+function socket_TCP_server:get_state() end
+
+---Queue a message to send to a client. This call does not block.
+---@param client number # Client number.
+---@param data string | data # Message to send.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1194)
+function socket_TCP_server:set(client,data) end
+
+---Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped.
+---```lua
+----- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+---local function run(seed, address)
+---    local send = alicia.socket.new_UDP("127.0.0.1:0")
+---    local receive = alicia.socket.new_UDP(address)
+---
+---    -- Drop half of every datagram, and duplicate a tenth of them.
+---    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+---
+---    for x = 1, 100 do
+---        send:set_at(tostring(x), address)
+---    end
+---
+---    local list = {}
+---    local clock = alicia.general.get_time()
+---
+---    while alicia.general.get_time() - clock < 0.5 do
+---        local data = receive:get_at()
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(list, alicia.data.from_data(data, 2))
+---        else
+---            alicia.general.sleep(0.01)
+---        end
+---    end
+---
+---    return list
+---end
+---
+---local list_a = run(1234, "127.0.0.1:7784")
+---local list_b = run(1234, "127.0.0.1:7785")
+---
+----- Some datagrams are lost, but not all of them.
+---assert(#list_a > 0 and #list_a < 100)
+---
+----- The same seed will always drop and duplicate the same datagrams.
+---assert(#list_a == #list_b)
+---
+---for x = 1, #list_a do
+---    assert(list_a[x] == list_b[x])
+---end
+---
+----- Delay every datagram by 0.2 seconds.
+---local send = alicia.socket.new_UDP("127.0.0.1:0")
+---local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+---
+---send:set_simulate({ latency = 0.2 })
+---send:set_at("late", "127.0.0.1:7786")
+---
+---assert(receive:get_at() == nil)
+---
+---local data = nil
+---local clock = alicia.general.get_time()
+---
+----- A delayed datagram is sent in the background once due, with no further call on the socket.
+---while not data and alicia.general.get_time() - clock < 1.0 do
+---    alicia.general.sleep(0.01)
+---
+---    data = receive:get_at()
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(alicia.data.from_data(data, 2) == "late")
+---
+----- A stream is simulated too. Every message is delayed, but always kept in order.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+---
+---client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+---
+---local message = {}
+---local number = nil
+---local clock = alicia.general.get_time()
+---
+---for x = 1, 10 do
+---    client:set(tostring(x))
+---end
+---
+---while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            number = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---
+---    client:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(#message == 10)
+---
+---for x = 1, 10 do
+---    assert(message[x] == tostring(x))
+---end
+---
+----- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+---server:set_simulate({ latency = 0.1, loss = 1.0 })
+---server:set(number, "stall")
+---
+---local message = nil
+---local clock = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.35)
+---assert(message == "stall")
+---
+---client:close()
+---server:close()
+---
+----- A web socket is simulated the same way, if available.
+---if alicia.socket.new_web_socket_server then
+---    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+---    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+---
+---    client:set_simulate({ latency = 0.2 })
+---    client:set("late", true)
+---
+---    local message = nil
+---    local clock = alicia.general.get_time()
+---
+---    while not message and alicia.general.get_time() - clock < 2.0 do
+---        for _, event in ipairs(server:poll()) do
+---            if event.kind == SOCKET_EVENT.MESSAGE then
+---                message = event.data
+---            end
+---        end
+---
+---        client:poll()
+---        alicia.general.sleep(0.01)
+---    end
+---
+---    assert(alicia.general.get_time() - clock >= 0.15)
+---    assert(message == "late")
+---
+---    server:close()
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1226)
+function socket_TCP_server:set_simulate(info) end
+
+---Close the connection to a client. If no client is given, close the server socket, and every connection.
+---@param client number? # OPTIONAL: Client number.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1242)
+function socket_TCP_server:close(client) end
+
+---Create a TCP (server) socket, and listen for incoming connections in the background. This call does not block.
+---```lua
+----- Create a TCP server, listening in the background.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7783")
+---
+---local time = alicia.general.get_time()
+---
+---while server:get_state() ~= SOCKET_STATE.CONNECT and alicia.general.get_time() - time < 5.0 do
+---    server:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Connect to the server in the background, and queue a message before the connection is made.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---
+---client:set("hello")
+---
+----- Wait for the server to receive the message, and reply to it.
+---local message = nil
+---local peer    = nil
+---local time    = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            peer = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            message = alicia.data.from_data(event.data, 2)
+---            server:set(event.client, "world")
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(peer)
+---assert(message == "hello")
+---
+----- Wait for the client to receive the reply.
+---local reply = nil
+---local time  = alicia.general.get_time()
+---
+---while not reply and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            reply = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(reply == "world")
+---assert(client:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Close the client. The server will raise a disconnect event, and keep on listening.
+---client:close()
+---
+---local close = false
+---local time  = alicia.general.get_time()
+---
+---while not close and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.DISCONNECT then
+---            close = event.client == peer
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(close)
+---
+----- A second client can still connect.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---local join   = false
+---local time   = alicia.general.get_time()
+---
+---while not join and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            join = true
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(join)
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
+---
+---server:close()
+---
+---```
+---@param address string # Address to bind to.
+---@param length number? # OPTIONAL: Buffer size for every connection, which is also the largest message size. Default: 65536.
+---@param tls TLS_info? # OPTIONAL: TLS configuration, with a certificate and key. Default: nil (plain).
+---@return socket_TCP_server socket # TCP (server) socket.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1274)
+function alicia.socket.new_TCP_server(address,length,tls) end
+
+---An unique handle to a web socket (client) in memory. The connection is made in the background, and every event must be drained with poll, once per frame.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1464)
+---@class socket_web_socket
+socket_web_socket = {}
+
+---Drain every event received since the last call. Each event has a kind (socket_event), and depending on the kind, an address (connect), a data buffer (binary message, or pong) or a text string (text message), a close code and reason (disconnect, if the other end sent a close frame), or an error message (failure). This call does not block.
+---@return table event_list # Event list.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1512)
+function socket_web_socket:poll() end
+
+---Get the connection state, as of the last poll call.
+---@return socket_state state # Connection state.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1541)
+function socket_web_socket:get_state() end
+
+---Queue a message to send. Messages queued while the socket is still connecting will be sent once connected. This call does not block.
+---@param data string | data # Message to send.
+---@param text boolean? # OPTIONAL: Send as a text message, rather than a binary message. Default: false.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1555)
+function socket_web_socket:set(data,text) end
+
+---Queue a ping to send. The other end will answer with a pong event, with the same data. This call does not block.
+---@param data string | data | nil # OPTIONAL: Ping data, up to 125 bytes.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1572)
+function socket_web_socket:ping(data) end
+
+---Close the connection, by sending a close frame. A disconnect event will be raised once the other end has answered.
+---@param code number? # OPTIONAL: Close code. Default: 1000.
+---@param reason string? # OPTIONAL: Close reason. Default: empty.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1590)
+function socket_web_socket:close(code,reason) end
+
+---Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped. A close frame is delayed, but never dropped.
+---```lua
+----- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+---local function run(seed, address)
+---    local send = alicia.socket.new_UDP("127.0.0.1:0")
+---    local receive = alicia.socket.new_UDP(address)
+---
+---    -- Drop half of every datagram, and duplicate a tenth of them.
+---    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+---
+---    for x = 1, 100 do
+---        send:set_at(tostring(x), address)
+---    end
+---
+---    local list = {}
+---    local clock = alicia.general.get_time()
+---
+---    while alicia.general.get_time() - clock < 0.5 do
+---        local data = receive:get_at()
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(list, alicia.data.from_data(data, 2))
+---        else
+---            alicia.general.sleep(0.01)
+---        end
+---    end
+---
+---    return list
+---end
+---
+---local list_a = run(1234, "127.0.0.1:7784")
+---local list_b = run(1234, "127.0.0.1:7785")
+---
+----- Some datagrams are lost, but not all of them.
+---assert(#list_a > 0 and #list_a < 100)
+---
+----- The same seed will always drop and duplicate the same datagrams.
+---assert(#list_a == #list_b)
+---
+---for x = 1, #list_a do
+---    assert(list_a[x] == list_b[x])
+---end
+---
+----- Delay every datagram by 0.2 seconds.
+---local send = alicia.socket.new_UDP("127.0.0.1:0")
+---local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+---
+---send:set_simulate({ latency = 0.2 })
+---send:set_at("late", "127.0.0.1:7786")
+---
+---assert(receive:get_at() == nil)
+---
+---local data = nil
+---local clock = alicia.general.get_time()
+---
+----- A delayed datagram is sent in the background once due, with no further call on the socket.
+---while not data and alicia.general.get_time() - clock < 1.0 do
+---    alicia.general.sleep(0.01)
+---
+---    data = receive:get_at()
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(alicia.data.from_data(data, 2) == "late")
+---
+----- A stream is simulated too. Every message is delayed, but always kept in order.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+---
+---client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+---
+---local message = {}
+---local number = nil
+---local clock = alicia.general.get_time()
+---
+---for x = 1, 10 do
+---    client:set(tostring(x))
+---end
+---
+---while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            number = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---
+---    client:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(#message == 10)
+---
+---for x = 1, 10 do
+---    assert(message[x] == tostring(x))
+---end
+---
+----- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+---server:set_simulate({ latency = 0.1, loss = 1.0 })
+---server:set(number, "stall")
+---
+---local message = nil
+---local clock = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.35)
+---assert(message == "stall")
+---
+---client:close()
+---server:close()
+---
+----- A web socket is simulated the same way, if available.
+---if alicia.socket.new_web_socket_server then
+---    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+---    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+---
+---    client:set_simulate({ latency = 0.2 })
+---    client:set("late", true)
+---
+---    local message = nil
+---    local clock = alicia.general.get_time()
+---
+---    while not message and alicia.general.get_time() - clock < 2.0 do
+---        for _, event in ipairs(server:poll()) do
+---            if event.kind == SOCKET_EVENT.MESSAGE then
+---                message = event.data
+---            end
+---        end
+---
+---        client:poll()
+---        alicia.general.sleep(0.01)
+---    end
+---
+---    assert(alicia.general.get_time() - clock >= 0.15)
+---    assert(message == "late")
+---
+---    server:close()
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1609)
+function socket_web_socket:set_simulate(info) end
+
+---Create a web socket (client), and connect to a web socket server in the background. This call does not block.
+---```lua
+----- Create a web socket server, and a web socket client. Both connect in the background.
+---local server = alicia.socket.new_web_socket_server("127.0.0.1:8080")
+---local client = alicia.socket.new_web_socket("ws://127.0.0.1:8080")
+---
+---local text  = nil
+---local pong  = false
+---local close = false
+---local code  = nil
+---
+----- Both sockets work in the background, so poll them for up to five seconds.
+---local time = alicia.general.get_time()
+---
+---while alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        -- Send a text message to every new client.
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            server:set(event.client, "Hello, world!", true)
+---        end
+---    end
+---
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            client:ping("ping")
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            text = event.text
+---        elseif event.kind == SOCKET_EVENT.PONG then
+---            pong = true
+---        elseif event.kind == SOCKET_EVENT.DISCONNECT then
+---            code = event.code
+---        end
+---    end
+---
+---    -- Close the connection once every message has been received.
+---    if text and pong and not close then
+---        client:close(1000, "Goodbye.")
+---        close = true
+---    end
+---
+---    if code then
+---        break
+---    end
+---
+---    -- Yield to the background tasks between each poll.
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(text == "Hello, world!")
+---assert(pong)
+---assert(code == 1000)
+---
+---```
+---@param URL string # URL to connect to, such as "ws://127.0.0.1:8080" or "wss://example.com/path".
+---@return socket_web_socket socket # Web socket (client).
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1634)
+function alicia.socket.new_web_socket(URL) end
+
+---An unique handle to a web socket (server) in memory. Incoming connections are accepted in the background, and every event must be drained with poll, once per frame. Every connection is given a client number.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1665)
+---@class socket_web_socket_server
+socket_web_socket_server = {}
+
+---Drain every event received since the last call. Each event has a kind (socket_event), a client number, and depending on the kind, an address (connect), a data buffer (binary message, or pong) or a text string (text message), a close code and reason (disconnect, if the client sent a close frame), or an error message (failure). A failure event with no client number means the server socket has failed to bind, or to accept a connection, in which case it will keep on listening. This call does not block.
+---@return table event_list # Event list.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1716)
+function socket_web_socket_server:poll() end
+
+---Get the server state, as of the last poll call. The server is in the connect state once it is listening for incoming connections.
+---@return socket_state state # Server state.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1763)
+function socket_web_socket_server:get_state() end
+
+---Queue a message to send to a client. This call does not block.
+---@param client number # Client number.
+---@param data string | data # Message to send.
+---@param text boolean? # OPTIONAL: Send as a text message, rather than a binary message. Default: false.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1778)
+function socket_web_socket_server:set(client,data,text) end
+
+---Queue a ping to send to a client. The client will answer with a pong event, with the same data. This call does not block.
+---@param client number # Client number.
+---@param data string | data | nil # OPTIONAL: Ping data, up to 125 bytes.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1799)
+function socket_web_socket_server:ping(client,data) end
+
+---Close the connection to a client, by sending a close frame. If no client is given, close the server socket, and drop every connection.
+---@param client number? # OPTIONAL: Client number.
+---@param code number? # OPTIONAL: Close code. Default: 1000.
+---@param reason string? # OPTIONAL: Close reason. Default: empty.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1818)
+function socket_web_socket_server:close(client,code,reason) end
+
+---Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped. A close frame is delayed, but never dropped.
+---```lua
+----- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+---local function run(seed, address)
+---    local send = alicia.socket.new_UDP("127.0.0.1:0")
+---    local receive = alicia.socket.new_UDP(address)
+---
+---    -- Drop half of every datagram, and duplicate a tenth of them.
+---    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+---
+---    for x = 1, 100 do
+---        send:set_at(tostring(x), address)
+---    end
+---
+---    local list = {}
+---    local clock = alicia.general.get_time()
+---
+---    while alicia.general.get_time() - clock < 0.5 do
+---        local data = receive:get_at()
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(list, alicia.data.from_data(data, 2))
+---        else
+---            alicia.general.sleep(0.01)
+---        end
+---    end
+---
+---    return list
+---end
+---
+---local list_a = run(1234, "127.0.0.1:7784")
+---local list_b = run(1234, "127.0.0.1:7785")
+---
+----- Some datagrams are lost, but not all of them.
+---assert(#list_a > 0 and #list_a < 100)
+---
+----- The same seed will always drop and duplicate the same datagrams.
+---assert(#list_a == #list_b)
+---
+---for x = 1, #list_a do
+---    assert(list_a[x] == list_b[x])
+---end
+---
+----- Delay every datagram by 0.2 seconds.
+---local send = alicia.socket.new_UDP("127.0.0.1:0")
+---local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+---
+---send:set_simulate({ latency = 0.2 })
+---send:set_at("late", "127.0.0.1:7786")
+---
+---assert(receive:get_at() == nil)
+---
+---local data = nil
+---local clock = alicia.general.get_time()
+---
+----- A delayed datagram is sent in the background once due, with no further call on the socket.
+---while not data and alicia.general.get_time() - clock < 1.0 do
+---    alicia.general.sleep(0.01)
+---
+---    data = receive:get_at()
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(alicia.data.from_data(data, 2) == "late")
+---
+----- A stream is simulated too. Every message is delayed, but always kept in order.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+---
+---client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+---
+---local message = {}
+---local number = nil
+---local clock = alicia.general.get_time()
+---
+---for x = 1, 10 do
+---    client:set(tostring(x))
+---end
+---
+---while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            number = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---
+---    client:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(#message == 10)
+---
+---for x = 1, 10 do
+---    assert(message[x] == tostring(x))
+---end
+---
+----- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+---server:set_simulate({ latency = 0.1, loss = 1.0 })
+---server:set(number, "stall")
+---
+---local message = nil
+---local clock = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.35)
+---assert(message == "stall")
+---
+---client:close()
+---server:close()
+---
+----- A web socket is simulated the same way, if available.
+---if alicia.socket.new_web_socket_server then
+---    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+---    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+---
+---    client:set_simulate({ latency = 0.2 })
+---    client:set("late", true)
+---
+---    local message = nil
+---    local clock = alicia.general.get_time()
+---
+---    while not message and alicia.general.get_time() - clock < 2.0 do
+---        for _, event in ipairs(server:poll()) do
+---            if event.kind == SOCKET_EVENT.MESSAGE then
+---                message = event.data
+---            end
+---        end
+---
+---        client:poll()
+---        alicia.general.sleep(0.01)
+---    end
+---
+---    assert(alicia.general.get_time() - clock >= 0.15)
+---    assert(message == "late")
+---
+---    server:close()
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1851)
+function socket_web_socket_server:set_simulate(info) end
+
+---Create a web socket (server), and listen for incoming connections in the background. This call does not block.
+---```lua
+----- Create a web socket server, and a web socket client. Both connect in the background.
+---local server = alicia.socket.new_web_socket_server("127.0.0.1:8080")
+---local client = alicia.socket.new_web_socket("ws://127.0.0.1:8080")
+---
+---local text  = nil
+---local pong  = false
+---local close = false
+---local code  = nil
+---
+----- Both sockets work in the background, so poll them for up to five seconds.
+---local time = alicia.general.get_time()
+---
+---while alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        -- Send a text message to every new client.
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            server:set(event.client, "Hello, world!", true)
+---        end
+---    end
+---
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            client:ping("ping")
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            text = event.text
+---        elseif event.kind == SOCKET_EVENT.PONG then
+---            pong = true
+---        elseif event.kind == SOCKET_EVENT.DISCONNECT then
+---            code = event.code
+---        end
+---    end
+---
+---    -- Close the connection once every message has been received.
+---    if text and pong and not close then
+---        client:close(1000, "Goodbye.")
+---        close = true
+---    end
+---
+---    if code then
+---        break
+---    end
+---
+---    -- Yield to the background tasks between each poll.
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(text == "Hello, world!")
+---assert(pong)
+---assert(code == 1000)
+---
+---```
+---@param address string # Address to bind to.
+---@return socket_web_socket_server socket # Web socket (server).
+---
+--- ---
+---*Available with compile feature: `web_socket`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1876)
+function alicia.socket.new_web_socket_server(address) end
+
+---A table describing a network condition simulation, applied to every datagram or message sent. Every field is optional. Only outgoing datagrams or messages are affected, so both ends should set a simulation to affect both directions. The same seed, with the same datagrams sent in the same order, will always drop, duplicate and re-order the same datagrams. A stream (TCP or web socket) is reliable and always in order, so that a message is never dropped, duplicated or re-ordered. A lost message is instead re-sent, stalling it and every later message by a round trip.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L1998)
+---@class simulate_info
+---@field latency number? # Delay (in seconds) of every datagram. Default: 0.0.
+---@field jitter number? # Largest random delay (in seconds) added on top of the latency. Default: 0.0.
+---@field loss number? # Chance of a datagram being dropped (or a stream message being stalled), from 0.0 to 1.0. Default: 0.0.
+---@field duplicate number? # Chance of a datagram being sent twice, from 0.0 to 1.0. Default: 0.0.
+---@field reorder number? # Chance of a datagram being held back, so that later datagrams will arrive before it, from 0.0 to 1.0. Default: 0.0.
+---@field reorder_delay number? # Extra delay (in seconds) of a held back datagram. Default: 0.1.
+---@field seed number? # Random seed. Default: nil (random).
+simulate_info = {}
+
+---An unique handle to a UDP socket in memory.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2227)
+---@class socket_UDP
+socket_UDP = {}
+
+---Connect the socket to a remote address, for use with get and set.
+---@param address string # Address to connect to.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2250)
+function socket_UDP:connect(address) end
+
+---Get the next datagram from the connected address, if any. This call does not block.
+---@return data? data # Datagram, or nil if no datagram is available.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2266)
+function socket_UDP:get() end
+
+---Send a datagram to the connected address.
+---@param data string | data # Datagram to send.
+---@return number length # Length of the datagram sent.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2288)
+function socket_UDP:set(data) end
+
+---Get the next datagram from any address, if any. This call does not block.
+---@return data? data # Datagram, or nil if no datagram is available.
+---@return string? address # Address of the sender.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2310)
+function socket_UDP:get_at() end
+
+---Send a datagram to an address.
+---@param data string | data # Datagram to send.
+---@param address string # Address to send to.
+---@return number length # Length of the datagram sent.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2336)
+function socket_UDP:set_at(data,address) end
+
+---Set a network condition simulation, for testing. Every delayed datagram is sent in the background once due. Any datagram still delayed by the last simulation is dropped.
+---```lua
+----- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+---local function run(seed, address)
+---    local send = alicia.socket.new_UDP("127.0.0.1:0")
+---    local receive = alicia.socket.new_UDP(address)
+---
+---    -- Drop half of every datagram, and duplicate a tenth of them.
+---    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+---
+---    for x = 1, 100 do
+---        send:set_at(tostring(x), address)
+---    end
+---
+---    local list = {}
+---    local clock = alicia.general.get_time()
+---
+---    while alicia.general.get_time() - clock < 0.5 do
+---        local data = receive:get_at()
+---
+---        if data then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(list, alicia.data.from_data(data, 2))
+---        else
+---            alicia.general.sleep(0.01)
+---        end
+---    end
+---
+---    return list
+---end
+---
+---local list_a = run(1234, "127.0.0.1:7784")
+---local list_b = run(1234, "127.0.0.1:7785")
+---
+----- Some datagrams are lost, but not all of them.
+---assert(#list_a > 0 and #list_a < 100)
+---
+----- The same seed will always drop and duplicate the same datagrams.
+---assert(#list_a == #list_b)
+---
+---for x = 1, #list_a do
+---    assert(list_a[x] == list_b[x])
+---end
+---
+----- Delay every datagram by 0.2 seconds.
+---local send = alicia.socket.new_UDP("127.0.0.1:0")
+---local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+---
+---send:set_simulate({ latency = 0.2 })
+---send:set_at("late", "127.0.0.1:7786")
+---
+---assert(receive:get_at() == nil)
+---
+---local data = nil
+---local clock = alicia.general.get_time()
+---
+----- A delayed datagram is sent in the background once due, with no further call on the socket.
+---while not data and alicia.general.get_time() - clock < 1.0 do
+---    alicia.general.sleep(0.01)
+---
+---    data = receive:get_at()
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(alicia.data.from_data(data, 2) == "late")
+---
+----- A stream is simulated too. Every message is delayed, but always kept in order.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+---
+---client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+---
+---local message = {}
+---local number = nil
+---local clock = alicia.general.get_time()
+---
+---for x = 1, 10 do
+---    client:set(tostring(x))
+---end
+---
+---while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            number = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---
+---    client:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.15)
+---assert(#message == 10)
+---
+---for x = 1, 10 do
+---    assert(message[x] == tostring(x))
+---end
+---
+----- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+---server:set_simulate({ latency = 0.1, loss = 1.0 })
+---server:set(number, "stall")
+---
+---local message = nil
+---local clock = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - clock < 2.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            message = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(alicia.general.get_time() - clock >= 0.35)
+---assert(message == "stall")
+---
+---client:close()
+---server:close()
+---
+----- A web socket is simulated the same way, if available.
+---if alicia.socket.new_web_socket_server then
+---    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+---    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+---
+---    client:set_simulate({ latency = 0.2 })
+---    client:set("late", true)
+---
+---    local message = nil
+---    local clock = alicia.general.get_time()
+---
+---    while not message and alicia.general.get_time() - clock < 2.0 do
+---        for _, event in ipairs(server:poll()) do
+---            if event.kind == SOCKET_EVENT.MESSAGE then
+---                message = event.data
+---            end
+---        end
+---
+---        client:poll()
+---        alicia.general.sleep(0.01)
+---    end
+---
+---    assert(alicia.general.get_time() - clock >= 0.15)
+---    assert(message == "late")
+---
+---    server:close()
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2368)
+function socket_UDP:set_simulate(info) end
+
+---Set whether the socket may send datagrams to a broadcast address.
+---@param broadcast boolean # Broadcast state.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2384)
+function socket_UDP:set_broadcast(broadcast) end
+
+---Join a multicast group. Any datagram sent to the group address will be received by this socket.
+---@param group string # Group address (IPv4 or IPv6), without a port.
+---@param interface string? # OPTIONAL: Interface address (IPv4) or index (IPv6). Default: any interface.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2399)
+function socket_UDP:join_multicast(group,interface) end
+
+---Leave a multicast group.
+---@param group string # Group address (IPv4 or IPv6), without a port.
+---@param interface string? # OPTIONAL: Interface address (IPv4) or index (IPv6). Default: any interface.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2424)
+function socket_UDP:leave_multicast(group,interface) end
+
+---Set whether datagrams sent to a multicast group are looped back to this machine.
+---@param loop boolean # Loop state.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2448)
+function socket_UDP:set_multicast_loop(loop) end
+
+---Set the time-to-live of datagrams sent to a multicast group (IPv4 only), which is the largest number of hops a datagram can take. Default: 1, for the local network only.
+---@param TTL number # Time-to-live.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2466)
+function socket_UDP:set_multicast_TTL(TTL) end
+
+---Create a UDP socket.
+---@param address string # Address to bind to.
+---@param length number? # OPTIONAL: Buffer size. Any datagram larger than this will be truncated. Default: 65507.
+---@return socket_UDP socket # UDP socket.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2488)
+function alicia.socket.new_UDP(address,length) end
+
+---A table describing a server, as advertised to every discovery client on the local network. Every field is optional.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2515)
+---@class discovery_info
+---@field name string? # Server name.
+---@field port number? # Port to connect to the server with, which is not the discovery port. Default: 0.
+---@field player number? # Player count. Default: 0.
+---@field player_maximum number? # Player maximum. Default: 0.
+---@field meta table? # Custom metadata. Default: nil.
+discovery_info = {}
+
+---An unique handle to a discovery server in memory. Every discovery query on the local network will be answered with the server info. update must be called once per frame.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2538)
+---@class socket_discovery_server
+socket_discovery_server = {}
+
+---Answer every pending discovery query. This call does not block.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2555)
+function socket_discovery_server:update() end
+
+---Set the server info, such as when the player count has changed.
+---@param info discovery_info # Server info.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2575)
+function socket_discovery_server:set_info(info) end
+
+---Create a discovery server, which will answer every discovery query sent to the discovery port, by broadcast or multicast.
+---```lua
+----- Create a discovery server on port 7779. The game itself would be on port 7777.
+---local server = alicia.socket.new_discovery_server(7779, {
+---    name           = "Server",
+---    port           = 7777,
+---    player         = 2,
+---    player_maximum = 8,
+---    meta           = { map = "level_1" },
+---})
+---
+----- Create a discovery client. On a local network, this would be a broadcast address,
+----- such as "255.255.255.255:7779", or a multicast group address.
+---local client = alicia.socket.new_discovery_client("127.0.0.1:7779")
+---
+---local list = {}
+---local clock = alicia.general.get_time()
+---
+---while #list == 0 and alicia.general.get_time() - clock < 2.0 do
+---    server:update()
+---    list = client:poll()
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(#list == 1)
+---assert(list[1].name == "Server")
+---assert(list[1].address == "127.0.0.1:7777")
+---assert(list[1].player == 2)
+---assert(list[1].meta.map == "level_1")
+---
+----- Update the player count, which will be sent on the next discovery reply.
+---server:set_info({ name = "Server", port = 7777, player = 3, player_maximum = 8 })
+---
+----- A discovery query is sent every second, so the update will reach the client within a second.
+---local clock = alicia.general.get_time()
+---
+---while not (list[1] and list[1].player == 3) and alicia.general.get_time() - clock < 3.0 do
+---    server:update()
+---    list = client:poll()
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(#list == 1)
+---assert(list[1].player == 3)
+---assert(list[1].meta == nil)
+---
+---```
+---@param port number # Discovery port.
+---@param info discovery_info # Server info.
+---@param group string? # OPTIONAL: Multicast group address (IPv4 or IPv6) to join, for use with a multicast discovery client. Default: nil.
+---@return socket_discovery_server server # Discovery server.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2602)
+function alicia.socket.new_discovery_server(port,info,group) end
+
+---An unique handle to a discovery client in memory. A discovery query is sent every second, and any server that has not answered in the last three seconds is dropped from the server list.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2638)
+---@class socket_discovery_client
+socket_discovery_client = {}
+
+---Send a discovery query if one is due, receive every pending reply, and get the refreshed server list. Each server has every field of its discovery_info, and the address to connect to the server with. This call does not block.
+---@return table server_list # Server list, sorted by address.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2660)
+function socket_discovery_client:poll() end
+
+---Create a discovery client.
+---```lua
+----- Create a discovery server on port 7779. The game itself would be on port 7777.
+---local server = alicia.socket.new_discovery_server(7779, {
+---    name           = "Server",
+---    port           = 7777,
+---    player         = 2,
+---    player_maximum = 8,
+---    meta           = { map = "level_1" },
+---})
+---
+----- Create a discovery client. On a local network, this would be a broadcast address,
+----- such as "255.255.255.255:7779", or a multicast group address.
+---local client = alicia.socket.new_discovery_client("127.0.0.1:7779")
+---
+---local list = {}
+---local clock = alicia.general.get_time()
+---
+---while #list == 0 and alicia.general.get_time() - clock < 2.0 do
+---    server:update()
+---    list = client:poll()
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(#list == 1)
+---assert(list[1].name == "Server")
+---assert(list[1].address == "127.0.0.1:7777")
+---assert(list[1].player == 2)
+---assert(list[1].meta.map == "level_1")
+---
+----- Update the player count, which will be sent on the next discovery reply.
+---server:set_info({ name = "Server", port = 7777, player = 3, player_maximum = 8 })
+---
+----- A discovery query is sent every second, so the update will reach the client within a second.
+---local clock = alicia.general.get_time()
+---
+---while not (list[1] and list[1].player == 3) and alicia.general.get_time() - clock < 3.0 do
+---    server:update()
+---    list = client:poll()
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(#list == 1)
+---assert(list[1].player == 3)
+---assert(list[1].meta == nil)
+---
+---```
+---@param address string # Address to send every discovery query to. Either a broadcast address (such as "255.255.255.255:7000") or a multicast group address (such as "239.255.0.1:7000"), with the discovery port.
+---@return socket_discovery_client client # Discovery client.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L2730)
+function alicia.socket.new_discovery_client(address) end
+
+---The R3D API.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L63)
+---@class alicia.r3d
+alicia.r3d = {}
+
+---An unique handle for a light in the R3D scene.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L201)
+---@class light_handle
+light_handle = {}
+
+---Create a new light handle.
+---@param kind number # Light kind.
+---@return light_handle light # Light handle.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L225)
+function alicia.r3d.light.new(kind) end
+
+---Get the kind of the light.
+---@return number kind # Light kind.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L246)
+function light:get_kind() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L257)
+function light:get_state() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L268)
+function light:get_color() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L280)
+function light:get_point() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L292)
+function light:get_focus() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L304)
+function light:set_state() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L316)
+function light:set_color() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L328)
+function light:set_point() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L340)
+function light:set_focus() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L354)
+function light:get_shadow() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L365)
+function light:get_shadow_update() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L379)
+function light:set_shadow() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L401)
+function light:set_shadow_update() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L427)
+function light:shadow_update() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L443)
+---@class sky_handle
+sky_handle = {}
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L462)
+function alicia.r3d.sky.new() end
+
+---The Discord API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L74)
+---@class alicia.discord
+alicia.discord = {}
+
+---An unique handle to a Discord client.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L88)
+---@class discord
+discord = {}
+
+---Set the rich presence.
+---@param activity table # The rich presence.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L145)
+function discord:set_rich_presence(activity) end
+
+---Clear the rich presence.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L255)
+function discord:clear_rich_presence() end
+
+---TO-DO
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L268)
+function discord:update() end
+
+---Create a new Discord client.
+---@param ID string # Discord App ID.
+---@param name string? # OPTIONAL: The application name.
+---@param command number? | string? | table? # OPTIONAL: The application command to run on the rich presence state.
+---@return string | data file # The file.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L308)
+function alicia.discord.new(ID,name,command) end
+
+---The file API.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L66)
+---@class alicia.file
+alicia.file = {}
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L125)
+---@class file_watcher
+file_watcher = {}
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L136)
+function alicia.file_watcher.new() end
+
+---TO-DO
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L176)
+function file_watcher:poll() end
+
+---Get the data of a file.
+---```lua
+----- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
+---alicia.file.set("work/foo.txt", "123", false)
+---
+----- Read the data back. Again, since we know the file isn't binary, we pass false.
+---local data = alicia.file.get("work/foo.txt", false)
+---
+---assert(data == "123")
+---
+---```
+---@param path string # Path to file.
+---@param binary boolean # Read as binary.
+---@return string data # File data.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L209)
+function alicia.file.get_file(path,binary) end
+
+---Set the data of a file.
+---```lua
+----- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
+---alicia.file.set("work/foo.txt", "123", false)
+---
+----- Read the data back. Again, since we know the file isn't binary, we pass false.
+---local data = alicia.file.get("work/foo.txt", false)
+---
+---assert(data == "123")
+---
+---```
+---@param path string # Path to file.
+---@param data string | data # Data to copy.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L248)
+function alicia.file.set_file(path,data) end
+
+---Move a file.
+---@param source string # The source path.
+---@param target string # The target path.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L278)
+function alicia.file.move_file(source,target) end
+
+---Copy a file.
+---@param source string # The source path.
+---@param target string # The target path.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L298)
+function alicia.file.copy_file(source,target) end
+
+---Remove a file.
+---@param path string # The path to the file to remove.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L317)
+function alicia.file.remove_file(path) end
 
----Draw a triangle.
----@param point_a vector_3 # The point (A) of the triangle.
----@param point_b vector_3 # The point (B) of the triangle.
----@param point_c vector_3 # The point (C) of the triangle.
----@param color color # The color of the triangle.
+---Remove a folder.
+---@param path string # The path to the folder to remove.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L426)
-function alicia.draw_3d.draw_triangle(point_a,point_b,point_c,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L335)
+function alicia.file.remove_path(path) end
 
----Draw a triangle strip.
----@param point table # The point list of the triangle.
----@param color color # The color of the triangle.
+---Set the state of the path sand-box.
+---@param state boolean # The state of the path sand-box.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L452)
-function alicia.draw_3d.draw_triangle_strip(point,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L353)
+function alicia.file.set_path_escape(state) end
 
----Draw a cube.
----@param point vector_3 # The point of the cube.
----@param shape vector_3 # The shape of the cube.
----@param color color # The color of the cube.
+---Set the file save call-back.
+---@param call function # The call-back. Must accept a file-name and a data parameter, and return a boolean (true on success, false on failure).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L474)
-function alicia.draw_3d.draw_cube(point,shape,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L489)
+function alicia.file.set_call_save_file(call) end
 
----Draw a cube (wire-frame).
----@param point vector_3 # The point of the cube.
----@param shape vector_3 # The shape of the cube.
----@param color color # The color of the cube.
+---Set the file load call-back.
+---@param call function # The call-back. Must accept a file-name, and return a data buffer. Return anything else to indicate failure.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L500)
-function alicia.draw_3d.draw_cube_wire(point,shape,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L509)
+function alicia.file.set_call_load_file(call) end
 
----Draw a sphere.
----@param point vector_3 # The point of the sphere.
----@param color color # The color of the triangle.
+---Set the file text save call-back.
+---@param call function # The call-back. Must accept a file-name and a string parameter, and return a boolean (true on success, false on failure).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L525)
-function alicia.draw_3d.draw_sphere(point,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L529)
+function alicia.file.set_call_save_text(call) end
+
+---Set the file load call-back.
+---@param call function # The call-back. Must accept a file-name, and return a string. Return anything else to indicate failure.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L549)
+function alicia.file.set_call_load_text(call) end
+
+---Check if a file does exist.
+---```lua
+----- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
+---alicia.file.set("work/foo.txt", "123", false)
+---
+---assert(alicia.file.get_file_exist("work/foo.txt"))
+---
+---```
+---@param path string # Path to file.
+---@return boolean exist # True if file does exist, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L573)
+function alicia.file.get_file_exist(path) end
+
+---Check if a path does exist.
+---```lua
+----- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
+---alicia.file.set("work/foo.txt", "123", false)
+---
+---assert(alicia.file.get_file_exist("work/foo.txt"))
+---
+---```
+---@param path string # Path.
+---@return boolean exist # True if path does exist, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L593)
+function alicia.file.get_path_exist(path) end
+
+---Check if a file's extension is the same as a given one.
+---@param path string # Path to file.
+---@param extension string # Extension. MUST include dot (.png, .wav, etc.).
+---@return boolean check # True if file extension is the same as the given one, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L613)
+function alicia.file.get_file_extension_check(path,extension) end
+
+---Get the size of a file.
+---@param path string # Path to file.
+---@return number size # File size.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L634)
+function alicia.file.get_file_size(path) end
+
+---Get the extension of a file.
+---@param path string # Path to file.
+---@return string extension # File extension.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L653)
+function alicia.file.get_file_extension(path) end
+
+---Get the name of a file.
+---@param path string # Path to file.
+---@param extension boolean # File extension. If true, will return file name with the extension.
+---@return string name # File name.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L676)
+function alicia.file.get_file_name(path,extension) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L545)
-function alicia.draw_3d.draw_sphere_wire() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L697)
+function alicia.file.get_absolute_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L565)
-function alicia.draw_3d.draw_cylinder() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L714)
+function alicia.file.get_previous_path() end
+
+---Get the current work path.
+---@return string path # Work path.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L734)
+function alicia.file.get_work_directory() end
+
+---Get the current application path.
+---@return string path # Application path.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L751)
+function alicia.file.get_application_directory() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L593)
-function alicia.draw_3d.draw_cylinder_wire() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L765)
+function alicia.file.create_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L621)
-function alicia.draw_3d.draw_capsule() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L787)
+function alicia.file.change_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L649)
-function alicia.draw_3d.draw_capsule_wire() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L809)
+function alicia.file.get_path_file() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L677)
-function alicia.draw_3d.draw_plane() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L824)
+function alicia.file.get_file_name_valid() end
 
----Draw a ray.
----@param ray ray # The ray.
----@param color color # The color of the ray.
+---Scan a path.
+---@param path string # Path to scan.
+---@param filter string? # OPTIONAL: Extension filter. If filter is 'DIR', will include every directory in the result.
+---@param recursive boolean # If true, recursively scan the directory.
+---@param absolute boolean # If true, return path relatively.
+---@return table list # File list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L701)
-function alicia.draw_3d.draw_ray(ray,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L844)
+function alicia.file.scan_path(path,filter,recursive,absolute) end
 
----Draw a grid.
----@param slice number # The slice count of the grid.
----@param space number # The space shift of the grid.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L721)
-function alicia.draw_3d.draw_grid(slice,space) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L895)
+function alicia.file.get_file_drop() end
 
----Draw a 3D box.
----@param shape box_3 # The shape of the ball.
----@param color color # The color of the ball.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L738)
-function alicia.draw_3d.draw_box_3(shape,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L906)
+function alicia.file.get_file_drop_list() end
 
----The 2D drawing API.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L755)
----@class alicia.draw_2d
-alicia.draw_2d = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L933)
+function alicia.file.get_file_modification() end
 
----Initialize the 2D draw mode.
----@param call function # The draw code.
----@param camera camera_2d # The 2D camera.
----@param ... any # Variadic data.
+---The general API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L814)
-function alicia.draw_2d.begin(call,camera,...) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L90)
+---@class alicia.general
+alicia.general = {}
 
----Get a screen-space point for a 2D world-space point.
----@param camera camera_2d # The current camera.
----@param point vector_2 # The world-space point.
----@return number point_x # The 2D screen-space point (X).
----@return number point_y # The 2D screen-space point (Y).
+---Get the standard input.
+---@return string input # The standard input.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L848)
-function alicia.draw_2d.get_world_to_screen(camera,point) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L130)
+function alicia.general.standard_input() end
 
----Get a world-space point for a 2D screen-space point.
----@param camera camera_2d # The current camera.
----@param point vector_2 # The screen-space point.
----@return number point_x # The 2D world-space point (X).
----@return number point_y # The 2D world-space point (Y).
+---Get the next line of the standard input, without blocking. Lines are read in the background, so this should not be used alongside standard_input.
+---@return string? input # The next line of the standard input, or nil if no line is available.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L877)
-function alicia.draw_2d.get_screen_to_world(camera,point) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L147)
+function alicia.general.get_standard_input() end
 
----Draw pixel.
----@param point vector_2 # The point of the pixel.
----@param color color # The color of the pixel.
+---Load the standard Lua library.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L902)
-function alicia.draw_2d.draw_pixel(point,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L162)
+function alicia.general.load_base() end
 
----Draw a line.
----@param point_a vector_2 # The point A of the line.
----@param point_b vector_2 # The point B of the line.
----@param thick number # The thickness of the line.
----@param color color # The color of the line.
+---Set the log level.
+---@param level number # The log level.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L925)
-function alicia.draw_2d.draw_line(point_a,point_b,thick,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L189)
+function alicia.general.set_log_level(level) end
 
----TO-DO
+---Open an URL link.
+---@param link string # The URL link.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L946)
-function alicia.draw_2d.draw_line_strip() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L206)
+function alicia.general.open_link(link) end
 
----TO-DO
+---Get the current time. Will count up since the initialization of the window, or since the first call in head-less mode.
+---@return number time # Current time.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L222)
+function alicia.general.get_time() end
+
+---Wait for a given amount of wall-clock time. Every background task (socket, request, etc.) will keep running in the mean-time.
+---```lua
+----- Create a TCP server, listening in the background.
+---local server = alicia.socket.new_TCP_server("127.0.0.1:7783")
+---
+---local time = alicia.general.get_time()
+---
+---while server:get_state() ~= SOCKET_STATE.CONNECT and alicia.general.get_time() - time < 5.0 do
+---    server:poll()
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Connect to the server in the background, and queue a message before the connection is made.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---
+---client:set("hello")
+---
+----- Wait for the server to receive the message, and reply to it.
+---local message = nil
+---local peer    = nil
+---local time    = alicia.general.get_time()
+---
+---while not message and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            peer = event.client
+---        elseif event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            message = alicia.data.from_data(event.data, 2)
+---            server:set(event.client, "world")
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(peer)
+---assert(message == "hello")
+---
+----- Wait for the client to receive the reply.
+---local reply = nil
+---local time  = alicia.general.get_time()
+---
+---while not reply and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            reply = alicia.data.from_data(event.data, 2)
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
+---
+---assert(reply == "world")
+---assert(client:get_state() == SOCKET_STATE.CONNECT)
+---
+----- Close the client. The server will raise a disconnect event, and keep on listening.
+---client:close()
+---
+---local close = false
+---local time  = alicia.general.get_time()
+---
+---while not close and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.DISCONNECT then
+---            close = event.client == peer
+---        end
+---    end
+---
+---    alicia.general.sleep(0.01)
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L963)
-function alicia.draw_2d.draw_line_bezier() end
-
----Draw a circle.
----@param point vector_2 # TO-DO
----@param range number # TO-DO
----@param color color # TO-DO
+---assert(close)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L989)
-function alicia.draw_2d.draw_circle(point,range,color) end
-
----TO-DO
+----- A second client can still connect.
+---local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+---local join   = false
+---local time   = alicia.general.get_time()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1009)
-function alicia.draw_2d.draw_circle_line() end
-
----Draw the sector of a circle.
----@param point vector_2 # TO-DO
----@param range number # TO-DO
----@param begin_angle number # TO-DO
----@param close_angle number # TO-DO
----@param segment_count number # TO-DO
----@param color color # TO-DO
+---while not join and alicia.general.get_time() - time < 5.0 do
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            join = true
+---        end
+---    end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1037)
-function alicia.draw_2d.draw_circle_sector(point,range,begin_angle,close_angle,segment_count,color) end
-
----TO-DO
+---    alicia.general.sleep(0.01)
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1064)
-function alicia.draw_2d.draw_circle_sector_line() end
-
----TO-DO
+---assert(join)
+---assert(server:get_state() == SOCKET_STATE.CONNECT)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1084)
-function alicia.draw_2d.draw_circle_gradient() end
-
----TO-DO
+---server:close()
+---
+---```
+---@param time number # Time to wait for, in seconds.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1105)
-function alicia.draw_2d.draw_ellipse() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L242)
+function alicia.general.sleep(time) end
 
----TO-DO
+---Get the time in UNIX time-stamp format.
+---@param add number? # OPTIONAL: Add (or subtract) by this amount.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1126)
-function alicia.draw_2d.draw_ellipse_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L260)
+function alicia.general.get_time_unix(add) end
 
----TO-DO
+---Get the current frame time.
+---@return number frame_time # Current frame time.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1147)
-function alicia.draw_2d.draw_ring() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L277)
+function alicia.general.get_frame_time() end
 
----TO-DO
+---Get the current frame rate.
+---@return number frame_rate # Current frame rate.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1175)
-function alicia.draw_2d.draw_ring_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L296)
+function alicia.general.get_frame_rate() end
 
----Draw 2D box.
----@param shape box_2 # The shape of the box.
----@param point vector_2 # The point of the box.
----@param angle number # The angle of the box.
----@param color color # The color of the box.
+---Set the current frame rate.
+---@param frame_rate number # Current frame rate.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1209)
-function alicia.draw_2d.draw_box_2(shape,point,angle,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L325)
+function alicia.general.set_frame_rate(frame_rate) end
 
----Draw 2D box with a 4-point gradient.
----@param shape box_2 # The shape of the box.
----@param color_a color # The color A (T.L.) of the box.
----@param color_b color # The color B (B.L.) of the box.
----@param color_c color # The color C (T.R.) of the box.
----@param color_d color # The color D (B.R.) of the box.
+---Get the argument list.
+---@return table list # The list of every argument.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1237)
-function alicia.draw_2d.draw_box_2_gradient(shape,color_a,color_b,color_c,color_d) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L352)
+function alicia.general.get_argument() end
 
----Draw 2D box (out-line).
----@param shape box_2 # The shape of the box.
----@param thick number # The thickness of the box.
----@param color color # The color of the box.
+---Get the system info.
+---@return table info # The system info.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1271)
-function alicia.draw_2d.draw_box_2_line(shape,thick,color) end
+---*Available with compile feature: `system_info`.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L369)
+function alicia.general.get_system() end
 
----Draw 2D box (round).
----@param shape box_2 # The shape of the box.
----@param round number # The roundness of the box.
----@param count number # The segment count of the box.
----@param color color # The color of the box.
+---Get the currently in-use memory by the Lua VM.
+---@return number memory # The currently in-use memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1297)
-function alicia.draw_2d.draw_box_2_round(shape,round,count,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L387)
+function alicia.general.get_memory() end
 
----Draw 2D box (out-line, round).
----@param shape box_2 # The shape of the box.
----@param round number # The roundness of the box.
----@param count number # The segment count of the box.
----@param thick number # The thickness of the box.
----@param color color # The color of the box.
+---Get the current info manifest.
+---@return table info # The info manifest.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1324)
-function alicia.draw_2d.draw_box_2_line_round(shape,round,count,thick,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L402)
+function alicia.general.get_info() end
 
----Draw 2D triangle.
----@param point_a vector_2 # The point A of the triangle.
----@param point_b vector_2 # The point B of the triangle.
----@param point_c vector_2 # The point C of the triangle.
----@param color color # The color of the triangle.
+---The collision API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1350)
-function alicia.draw_2d.draw_triangle(point_a,point_b,point_c,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L64)
+---@class alicia.collision
+alicia.collision = {}
 
----Draw 2D triangle (out-line).
----@param point_a vector_2 # The point A of the triangle.
----@param point_b vector_2 # The point B of the triangle.
----@param point_c vector_2 # The point C of the triangle.
----@param color color # The color of the triangle.
+---Check if a 2D box is intersecting against another 2D box.
+---@param box_a box_2 # 2D box (A).
+---@param box_b box_2 # 2D box (B).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/draw.rs#L1378)
-function alicia.draw_2d.draw_triangle_line(point_a,point_b,point_c,color) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L111)
+function alicia.collision.get_box_2_box_2(box_a,box_b) end
 
----The data API.
+---Check if a circle is intersecting against another circle.
+---@param point_a vector_2 # Circle point (A).
+---@param range_a number # Circle range (A).
+---@param point_b vector_2 # Circle point (B).
+---@param range_b number # Circle range (B).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L64)
----@class alicia.data
-alicia.data = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L134)
+function alicia.collision.get_circle_circle(point_a,range_a,point_b,range_b) end
 
----An unique handle for a data buffer in memory.
+---Check if a circle is intersecting against another 2D box.
+---@param point_a vector_2 # Circle point.
+---@param range_a number # Circle range.
+---@param box_a box_2 # 2D box.
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L103)
----@class data
-data = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L159)
+function alicia.collision.get_circle_box_2(point_a,range_a,box_a) end
 
----Get the length of the data buffer.
----@return number length # The length of the data buffer.
+---Check if a circle is intersecting against another line.
+---@param point_a vector_2 # Circle point.
+---@param range_a number # Circle range.
+---@param line_a vector_2 # Line point (A).
+---@param line_b vector_2 # Line point (B).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L157)
-function data:get_length() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L185)
+function alicia.collision.get_circle_line(point_a,range_a,line_a,line_b) end
 
----Get the data buffer.
----@return table buffer # The data buffer.
+---Check if a point is intersecting against another 2D box.
+---@param point_a vector_2 # Point.
+---@param box_a box_2 # 2D box.
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L169)
-function data:get_buffer() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L210)
+function alicia.collision.get_point_box_2(point_a,box_a) end
 
----Get a slice out of the data buffer, as another data buffer.
----@param index_a number # Index A into the data buffer.
----@param index_b number # Index B into the data buffer.
----@return data slice # The slice, as another data buffer.
+---Check if a point is intersecting against another circle.
+---@param point_a vector_2 # Point (A).
+---@param point_b vector_2 # Circle point (B).
+---@param range_b number # Circle range (B).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L185)
-function data:get_slice(index_a,index_b) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L232)
+function alicia.collision.get_point_circle(point_a,point_b,range_b) end
 
----Compress a given data buffer (DEFLATE).
----```lua
------ Create a table with no data in it.
----local data = {}
----
------ Insert some dummy data in it.
----for x = 1, 63 do
----    table.insert(data, 0)
----end
----
------ Insert some data of significance at the last index.
----table.insert(data, 1)
----
------ Check that the last value is 1.
----assert(data[64] == 1)
----
------ Get a data buffer user-data from Alicia.
----local data = alicia.data.new(data)
----
------ Compress the data.
----local data = alicia.data.compress(data)
----
------ Decompress the data.
----local data = alicia.data.decompress(data)
----
------ Get the data back as a Lua table.
----local data = data:get_buffer()
----
------ Check that the last value is 1.
----assert(data[64] == 1)
----
----```
----@param data data # The data buffer to compress.
----@return data data # The data buffer.
+---Check if a point is intersecting against another triangle.
+---@param point_a vector_2 # Point (A).
+---@param point_b vector_2 # Triangle point (B).
+---@param point_c vector_2 # Triangle point (C).
+---@param point_d vector_2 # Triangle point (D).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L216)
-function alicia.data.compress(data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L258)
+function alicia.collision.get_point_triangle(point_a,point_b,point_c,point_d) end
 
----Decompress a given data buffer (DEFLATE).
----```lua
------ Create a table with no data in it.
----local data = {}
----
------ Insert some dummy data in it.
----for x = 1, 63 do
----    table.insert(data, 0)
----end
----
------ Insert some data of significance at the last index.
----table.insert(data, 1)
----
------ Check that the last value is 1.
----assert(data[64] == 1)
----
------ Get a data buffer user-data from Alicia.
----local data = alicia.data.new(data)
----
------ Compress the data.
----local data = alicia.data.compress(data)
----
------ Decompress the data.
----local data = alicia.data.decompress(data)
----
------ Get the data back as a Lua table.
----local data = data:get_buffer()
----
------ Check that the last value is 1.
----assert(data[64] == 1)
----
----```
----@param data data # The data buffer to decompress.
----@return data data # The data buffer.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L242)
-function alicia.data.decompress(data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L281)
+function alicia.collision.get_point_line() end
 
----Encode a given data buffer (Base64).
----@param data data # The data buffer to encode.
----@return data data # The data buffer.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L269)
-function alicia.data.encode(data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L299)
+function alicia.collision.get_point_poly() end
 
----Decode a given data buffer (Base64).
----@param data data # The data buffer to decode.
----@return data data # The data buffer.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L294)
-function alicia.data.decode(data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L319)
+function alicia.collision.get_line_line() end
 
----Hash a given data buffer.
----@param data data # The data buffer to encode.
----@param data hash_kind? # OPTIONAL: The hash method. Default: CRC32.
----@return data data # The hash code.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L322)
-function alicia.data.hash(data,data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L345)
+function alicia.collision.get_box_2_box_2_difference() end
 
----Serialize a given Lua value as another format, in the form of a string.
----@param text any # Lua value to serialize.
----@param kind format_kind? # OPTIONAL: The format to serialize to. Default: JSON.
----@return string value # The value, in string form.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L373)
-function alicia.data.serialize(text,kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L367)
+function alicia.collision.get_sphere_sphere() end
 
----Deserialize a given format string as a Lua value.
----@param text string # String to deserialize.
----@param kind format_kind? # OPTIONAL: The format to deserialize from. Default: JSON.
----@return any value # The value, in Lua value form.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L421)
-function alicia.data.deserialize(text,kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L384)
+function alicia.collision.get_box_3_box_3() end
 
----Convert a given Lua value to a data buffer.
----@param data any # Lua value to convert to a data buffer.
----@param kind data_kind # The data kind to convert to.
----@return data value # The value, in data buffer form.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L477)
-function alicia.data.to_data(data,kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L398)
+function alicia.collision.get_box_3_sphere() end
 
----Convert a given data buffer to a Lua value.
----@param data data # Data buffer to convert to a Lua value.
----@param kind data_kind # The data kind to convert to.
----@return number | string value # The value, in Lua value form.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L508)
-function alicia.data.from_data(data,kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L415)
+function alicia.collision.get_ray_sphere() end
 
----Get a file from the embed file.
----@param path string # Path to the file.
----@param binary boolean # Read as binary.
----@return string | data file # The file.
+---TO-DO
 ---
 --- ---
----*Available with compile feature: `embed`.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L543)
-function alicia.data.get_embed_file(path,binary) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L456)
+function alicia.collision.get_ray_box_3() end
 
----Get a list of every file in the embed data.
----@return table list # The list of every file.
+---TO-DO
 ---
 --- ---
----*Available with compile feature: `embed`.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L497)
+function alicia.collision.get_ray_triangle() end
+
+---TO-DO
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/data.rs#L572)
-function alicia.data.get_embed_list() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L540)
+function alicia.collision.get_ray_quad() end
 
----The socket API.
+---The shader API.
 ---
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L65)
----@class alicia.socket
-alicia.socket = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L65)
+---@class alicia.shader
+alicia.shader = {}
 
----An unique handle to a TCP (stream) socket in memory.
+---An unique handle for a shader in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L83)
----@class socket_TCP_stream
-socket_TCP_stream = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L89)
+---@class shader
+shader = {}
 
----TO-DO
+---Create a new shader resource.
+---@param v_path string # Path to .vs file.
+---@param f_path string # Path to .fs file.
+---@return shader shader # LuaShader resource.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L99)
-function socket_TCP_stream:get() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L108)
+function alicia.shader.new(v_path,f_path) end
 
----TO-DO
+---Create a new shader resource, from memory.
+---@param v_data string # .vs file data.
+---@param f_data string # .fs file data.
+---@return shader shader # LuaShader resource.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L154)
+function alicia.shader.new_from_memory(v_data,f_data) end
+
+---Initialize the shader.
+---@param call function # The draw code.
+---@param ... any # Variadic data.
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L119)
-function socket_TCP_stream:set() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L205)
+function shader:begin(call,...) end
 
----TO-DO
+---Get the location of a shader variable, by name.
+---@param name string # Variable name.
+---@return number location # Shader variable location.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L235)
+function shader:get_location_name(name) end
+
+---Get the location of a shader attribute, by name.
+---@param name string # Attribute name.
+---@return number location # Shader attribute location.
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L137)
-function alicia.socket.new_TCP_stream() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L255)
+function shader:get_location_attribute_name(name) end
 
----An unique handle to a TCP (listen) socket in memory.
+---Get the location of a shader variable, by index.
+---@param location number # Variable index.
+---@return number location # Shader variable location.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L151)
----@class socket_TCP_listen
-socket_TCP_listen = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L278)
+function shader:get_location(location) end
 
----TO-DO
+---Set the location of a shader variable.
+---@param location number # Variable index.
+---@param value number # Variable value.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L299)
+function shader:set_location(location,value) end
+
+---Set the value of a shader variable.
+---@param location number # Variable index.
+---@param class number # Variable class.
+---@param value any # Variable value.
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L167)
-function socket_TCP:accept() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L326)
+function shader:set_shader_value(location,class,value) end
 
----TO-DO
+---The image API.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L66)
+---@class alicia.image
+alicia.image = {}
+
+---An unique handle for a image in memory.
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L186)
-function alicia.socket.new_TCP_listen() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L102)
+---@class image
+---@field shape_x number # Shape of the image (X).
+---@field shape_y number # Shape of the image (Y).
+image = {}
 
----An unique handle to a UDP socket in memory.
+---Get a texture resource from an image.
+---@return texture texture # Texture resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L198)
----@class socket_UDP
-socket_UDP = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L133)
+function image:to_texture() end
 
 ---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L214)
-function socket_UDP:connect() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L144)
+function image:power_of_two() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L227)
-function socket_UDP:get() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L162)
+function image:crop() end
 
 ---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L247)
-function socket_UDP:set() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L178)
+function image:crop_alpha() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L261)
-function socket_UDP:get_at() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L190)
+function image:crop_alpha() end
 
 ---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L211)
+function image:blur_gaussian() end
+
+---TO-DO
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L281)
-function socket_UDP:set_at() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L223)
+function image:kernel_convolution() end
 
 ---TO-DO
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L238)
+function image:resize() end
+
+---TO-DO
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/socket.rs#L302)
-function alicia.socket.new_UDP() end
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L259)
+function image:extend() end
 
----The R3D API.
+---TO-DO
 ---
 --- ---
----*Not available in head-less mode.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L285)
+function image:mipmap() end
+
+---TO-DO
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L63)
----@class alicia.r3d
-alicia.r3d = {}
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L297)
+function image:dither() end
 
----An unique handle for a light in the R3D scene.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L201)
----@class light_handle
-light_handle = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L312)
+function image:flip() end
 
----Create a new light handle.
----@param kind number # Light kind.
----@return light_handle light # Light handle.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L225)
-function alicia.r3d.light.new(kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L328)
+function image:rotate() end
 
----Get the kind of the light.
----@return number kind # Light kind.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L246)
-function light:get_kind() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L342)
+function image:color_tint() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L257)
-function light:get_state() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L356)
+function image:color_invert() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L268)
-function light:get_color() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L368)
+function image:color_gray_scale() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L280)
-function light:get_point() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L380)
+function image:color_contrast() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L292)
-function light:get_focus() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L392)
+function image:color_contrast() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L304)
-function light:set_state() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L412)
+function image:get_alpha_border() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L316)
-function light:set_color() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L424)
+function image:get_alpha_border() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L328)
-function light:set_point() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L438)
+function image:draw_pixel() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L340)
-function light:set_focus() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L453)
+function image:draw_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L354)
-function light:get_shadow() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L472)
+function image:draw_circle() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L365)
-function light:get_shadow_update() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L490)
+function image:draw_circle_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L379)
-function light:set_shadow() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L508)
+function image:draw_box_2() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L401)
-function light:set_shadow_update() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L523)
+function image:draw_box_2_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/r3d.rs#L427)
-function light:shadow_update() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L541)
+function image:draw_triangle() end
 
----The Discord API.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L74)
----@class alicia.discord
-alicia.discord = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L571)
+function image:draw_triangle_line() end
 
----An unique handle to a Discord client.
+---Create a new image resource.
+---@param path string # Path to image file.
+---@return image image # LuaImage resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L88)
----@class discord
-discord = {}
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L603)
+function alicia.image.new(path) end
 
----Set the rich presence.
----@param activity table # The rich presence.
+---Create a new image resource, from memory.
+---@param data data # The data buffer.
+---@param kind string # The kind of image file (.png, etc.).
+---@return image image # LuaImage resource.
 ---
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L145)
-function discord:set_rich_presence(activity) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L637)
+function alicia.image.new_from_memory(data,kind) end
 
----Clear the rich presence.
+---Create a new image resource, from the current screen buffer.
+---@return image image # LuaImage resource.
 ---
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L255)
-function discord:clear_rich_presence() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L667)
+function alicia.image.new_from_screen() end
 
 ---TO-DO
 ---
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L268)
-function discord:update() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L689)
+function alicia.image.new_color() end
 
----Create a new Discord client.
----@param ID string # Discord App ID.
----@param name string? # OPTIONAL: The application name.
----@param command number? | string? | table? # OPTIONAL: The application command to run on the rich presence state.
----@return string | data file # The file.
+---TO-DO
 ---
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/discord.rs#L308)
-function alicia.discord.new(ID,name,command) end
-
----The file API.
----
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L66)
----@class alicia.file
-alicia.file = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L714)
+function alicia.image.new_gradient_linear() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L125)
----@class file_watcher
-file_watcher = {}
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L744)
+function alicia.image.new_gradient_radial() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L136)
-function alicia.file_watcher.new() end
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L774)
+function alicia.image.new_gradient_square() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L176)
-function file_watcher:poll() end
-
----Get the data of a file.
----```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----alicia.file.set("work/foo.txt", "123", false)
----
------ Read the data back. Again, since we know the file isn't binary, we pass false.
----local data = alicia.file.get("work/foo.txt", false)
----
----assert(data == "123")
----
----```
----@param path string # Path to file.
----@param binary boolean # Read as binary.
----@return string data # File data.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L209)
-function alicia.file.get_file(path,binary) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L804)
+function alicia.image.new_check() end
 
----Set the data of a file.
----```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----alicia.file.set("work/foo.txt", "123", false)
----
------ Read the data back. Again, since we know the file isn't binary, we pass false.
----local data = alicia.file.get("work/foo.txt", false)
----
----assert(data == "123")
----
----```
----@param path string # Path to file.
----@param data string | data # Data to copy.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L248)
-function alicia.file.set_file(path,data) end
-
----Move a file.
----@param source string # The source path.
----@param target string # The target path.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L278)
-function alicia.file.move_file(source,target) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L841)
+function alicia.image.new_white_noise() end
 
----Copy a file.
----@param source string # The source path.
----@param target string # The target path.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L298)
-function alicia.file.copy_file(source,target) end
-
----Remove a file.
----@param path string # The path to the file to remove.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L317)
-function alicia.file.remove_file(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L865)
+function alicia.image.new_perlin_noise() end
 
----Remove a folder.
----@param path string # The path to the folder to remove.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L335)
-function alicia.file.remove_path(path) end
-
----Set the state of the path sand-box.
----@param state boolean # The state of the path sand-box.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L353)
-function alicia.file.set_path_escape(state) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L899)
+function alicia.image.new_cellular() end
 
----Set the file save call-back.
----@param call function # The call-back. Must accept a file-name and a data parameter, and return a boolean (true on success, false on failure).
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L489)
-function alicia.file.set_call_save_file(call) end
-
----Set the file load call-back.
----@param call function # The call-back. Must accept a file-name, and return a data buffer. Return anything else to indicate failure.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L509)
-function alicia.file.set_call_load_file(call) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L923)
+function alicia.image.new_text() end
 
----Set the file text save call-back.
----@param call function # The call-back. Must accept a file-name and a string parameter, and return a boolean (true on success, false on failure).
+---The font API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L529)
-function alicia.file.set_call_save_text(call) end
-
----Set the file load call-back.
----@param call function # The call-back. Must accept a file-name, and return a string. Return anything else to indicate failure.
+---*Not available in head-less mode.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L549)
-function alicia.file.set_call_load_text(call) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L66)
+---@class alicia.font
+alicia.font = {}
 
----Check if a file does exist.
----```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----alicia.file.set("work/foo.txt", "123", false)
----
----assert(alicia.file.get_file_exist("work/foo.txt"))
----
----```
----@param path string # Path to file.
----@return boolean exist # True if file does exist, false otherwise.
+---An unique handle to a font in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L573)
-function alicia.file.get_file_exist(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L101)
+---@class font
+font = {}
 
----Check if a path does exist.
----```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----alicia.file.set("work/foo.txt", "123", false)
----
----assert(alicia.file.get_file_exist("work/foo.txt"))
----
----```
----@param path string # Path.
----@return boolean exist # True if path does exist, false otherwise.
+---Draw a font.
+---@param label string # Label of font to draw.
+---@param point vector_2 # Point of font to draw.
+---@param origin vector_2 # TO-DO
+---@param angle number # TO-DO
+---@param scale number # Scale of font to draw.
+---@param space number # Space of font to draw.
+---@param color color # Color of font to draw.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L593)
-function alicia.file.get_path_exist(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L133)
+function font:draw(label,point,origin,angle,scale,space,color) end
 
----Check if a file's extension is the same as a given one.
----@param path string # Path to file.
----@param extension string # Extension. MUST include dot (.png, .wav, etc.).
----@return boolean check # True if file extension is the same as the given one, false otherwise.
+---Measure the size of a given text on screen, with a given font.
+---@param label string # Label of font to measure.
+---@param scale number # Scale of font to measure.
+---@param space number # Space of font to measure.
+---@return number size_x # Size of text (X).
+---@return number size_y # Size of text (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L613)
-function alicia.file.get_file_extension_check(path,extension) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L225)
+function font:measure_text(label,scale,space) end
 
----Get the size of a file.
----@param path string # Path to file.
----@return number size # File size.
+---Create a new font resource.
+---@param path string # Path to font file.
+---@param size number # Size for font.
+---@return font font # LuaFont resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L634)
-function alicia.file.get_file_size(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L282)
+function alicia.font.new(path,size) end
 
----Get the extension of a file.
----@param path string # Path to file.
----@return string extension # File extension.
+---Create a new font resource, from memory.
+---@param data data # The data buffer.
+---@param kind string # The kind of font file (.ttf, etc.).
+---@param size number # Size for font.
+---@return font font # LuaFont resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L653)
-function alicia.file.get_file_extension(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L313)
+function alicia.font.new_from_memory(data,kind,size) end
 
----Get the name of a file.
----@param path string # Path to file.
----@param extension boolean # File extension. If true, will return file name with the extension.
----@return string name # File name.
+---Create a new font resource, from the default font.
+---@param size number # Size for font.
+---@return font font # LuaFont resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L676)
-function alicia.file.get_file_name(path,extension) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L351)
+function alicia.font.new_default(size) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L697)
-function alicia.file.get_absolute_path() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L382)
+function alicia.font.draw_frame_rate() end
 
----TO-DO
+---Draw text.
+---@param point vector_2 # The point of the text.
+---@param label string # The label of the text.
+---@param scale number # The angle of the text.
+---@param color color # The color of the text.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L714)
-function alicia.file.get_previous_path() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L404)
+function alicia.font.draw_text(point,label,scale,color) end
 
----Get the current work path.
----@return string path # Work path.
+---Set the vertical space between each line-break.
+---@param space number # Vertical space.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L734)
-function alicia.file.get_work_directory() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L428)
+function alicia.font.set_text_line_space(space) end
 
----Get the current application path.
----@return string path # Application path.
+---The automation API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L751)
-function alicia.file.get_application_directory() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L64)
+---@class alicia.automation
+alicia.automation = {}
 
----TO-DO
+---An unique handle to an automation event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L765)
-function alicia.file.create_path() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L80)
+---@class automation_event
+automation_event = {}
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L787)
-function alicia.file.change_path() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L100)
+function alicia.automation.new() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L809)
-function alicia.file.get_path_file() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L131)
+function automation_event:save() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L824)
-function alicia.file.get_file_name_valid() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L146)
+function automation_event:set_active() end
 
----Scan a path.
----@param path string # Path to scan.
----@param filter string? # OPTIONAL: Extension filter. If filter is 'DIR', will include every directory in the result.
----@param recursive boolean # If true, recursively scan the directory.
----@param absolute boolean # If true, return path relatively.
----@return table list # File list.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L844)
-function alicia.file.scan_path(path,filter,recursive,absolute) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L158)
+function automation_event:set_frame() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L895)
-function alicia.file.get_file_drop() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L170)
+function automation_event:start() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L906)
-function alicia.file.get_file_drop_list() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L182)
+function automation_event:stop() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/file.rs#L933)
-function alicia.file.get_file_modification() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L194)
+function automation_event:play() end
 
----The general API.
+---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L70)
----@class alicia.general
-alicia.general = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L213)
+function automation_event:get_event() end
 
----Get the standard input.
----@return string input # The standard input.
+---The window API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L108)
-function alicia.general.standard_input() end
-
----Load the standard Lua library.
+---*Not available in head-less mode.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L122)
-function alicia.general.load_base() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L65)
+---@class alicia.window
+alicia.window = {}
 
----Set the log level.
----@param level number # The log level.
+---Create a new native OS file dialog.
+---@param kind file_dialog_kind # The kind of file dialog to display.
+---@param title string? # OPTIONAL: Window title.
+---@param path string? # OPTIONAL: Default file path.
+---@param name string? # OPTIONAL: Default file name.
+---@param filter table? # OPTIONAL: Extension filter table. Must be in { extension = { ".txt", ".ini" } format.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L148)
-function alicia.general.set_log_level(level) end
-
----Open an URL link.
----@param link string # The URL link.
+---*This function is asynchronous and can run within a co-routine.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L165)
-function alicia.general.open_link(link) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L173)
+function alicia.window.file_dialog(kind,title,path,name,filter) end
 
----Get the current time. Will count up since the initialization of the window.
----@return number time # Current time.
+---Create a new native OS text dialog.
+---@param kind text_dialog_kind? # OPTIONAL: The kind of text dialog to display.
+---@param title string? # OPTIONAL: Window title.
+---@param label string? # OPTIONAL: Window label.
+---@param button text_dialog_button? # OPTIONAL: Window button layout.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L181)
-function alicia.general.get_time() end
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L267)
+function alicia.window.text_dialog(kind,title,label,button) end
 
----Get the time in UNIX time-stamp format.
----@param add number? # OPTIONAL: Add (or subtract) by this amount.
+---Get if the window should close.
+---@return boolean close # True if the window should close.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L194)
-function alicia.general.get_time_unix(add) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L317)
+function alicia.window.get_close() end
 
----Get the current frame time.
----@return number frame_time # Current frame time.
+---Get the state of the window (full-screen).
+---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L211)
-function alicia.general.get_frame_time() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L331)
+function alicia.window.get_fullscreen() end
 
----Get the current frame rate.
----@return number frame_rate # Current frame rate.
+---Get the state of the window (hidden).
+---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L224)
-function alicia.general.get_frame_rate() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L345)
+function alicia.window.get_hidden() end
 
----Set the current frame rate.
----@param frame_rate number # Current frame rate.
+---Get the state of the window (minimize).
+---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L237)
-function alicia.general.set_frame_rate(frame_rate) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L359)
+function alicia.window.get_minimize() end
 
----Get the argument list.
----@return table list # The list of every argument.
+---Get the state of the window (maximize).
+---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L254)
-function alicia.general.get_argument() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L373)
+function alicia.window.get_maximize() end
 
----Get the system info.
----@return table info # The system info.
+---Get the state of the window (focus).
+---@return boolean state # State of the window.
 ---
 --- ---
----*Available with compile feature: `system_info`.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L271)
-function alicia.general.get_system() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L387)
+function alicia.window.get_focus() end
 
----Get the currently in-use memory by the Lua VM.
----@return number memory # The currently in-use memory.
+---Get the state of the window (resize).
+---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L289)
-function alicia.general.get_memory() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L401)
+function alicia.window.get_resize() end
 
----Get the current info manifest.
----@return table info # The info manifest.
+---Get the state of a window flag.
+---@param flag window_flag # Window flag.
+---@return boolean state # Window flag state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/general.rs#L304)
-function alicia.general.get_info() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L418)
+function alicia.window.get_state(flag) end
 
----The collision API.
+---Set the state of a window flag.
+---@param flag window_flag # Window flag.
+---@param state boolean # Window flag state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L64)
----@class alicia.collision
-alicia.collision = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L433)
+function alicia.window.set_state(flag,state) end
 
----Check if a 2D box is intersecting against another 2D box.
----@param box_a box_2 # 2D box (A).
----@param box_b box_2 # 2D box (B).
----@return boolean intersect # Result of intersection.
+---Set the window to full-screen mode.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L111)
-function alicia.collision.get_box_2_box_2(box_a,box_b) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L448)
+function alicia.window.set_fullscreen() end
 
----Check if a circle is intersecting against another circle.
----@param point_a vector_2 # Circle point (A).
----@param range_a number # Circle range (A).
----@param point_b vector_2 # Circle point (B).
----@param range_b number # Circle range (B).
----@return boolean intersect # Result of intersection.
+---Set the window to border-less mode.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L134)
-function alicia.collision.get_circle_circle(point_a,range_a,point_b,range_b) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L458)
+function alicia.window.set_borderless() end
 
----Check if a circle is intersecting against another 2D box.
----@param point_a vector_2 # Circle point.
----@param range_a number # Circle range.
----@param box_a box_2 # 2D box.
----@return boolean intersect # Result of intersection.
+---Minimize the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L159)
-function alicia.collision.get_circle_box_2(point_a,range_a,box_a) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L468)
+function alicia.window.set_minimize() end
 
----Check if a circle is intersecting against another line.
----@param point_a vector_2 # Circle point.
----@param range_a number # Circle range.
----@param line_a vector_2 # Line point (A).
----@param line_b vector_2 # Line point (B).
----@return boolean intersect # Result of intersection.
+---Maximize the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L185)
-function alicia.collision.get_circle_line(point_a,range_a,line_a,line_b) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L478)
+function alicia.window.set_maximize() end
 
----Check if a point is intersecting against another 2D box.
----@param point_a vector_2 # Point.
----@param box_a box_2 # 2D box.
----@return boolean intersect # Result of intersection.
+---Restore the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L210)
-function alicia.collision.get_point_box_2(point_a,box_a) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L488)
+function alicia.window.set_restore() end
 
----Check if a point is intersecting against another circle.
----@param point_a vector_2 # Point (A).
----@param point_b vector_2 # Circle point (B).
----@param range_b number # Circle range (B).
----@return boolean intersect # Result of intersection.
+---Set the window icon.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L232)
-function alicia.collision.get_point_circle(point_a,point_b,range_b) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L498)
+function alicia.window.set_icon() end
 
----Check if a point is intersecting against another triangle.
----@param point_a vector_2 # Point (A).
----@param point_b vector_2 # Triangle point (B).
----@param point_c vector_2 # Triangle point (C).
----@param point_d vector_2 # Triangle point (D).
----@return boolean intersect # Result of intersection.
+---Set the window name.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L258)
-function alicia.collision.get_point_triangle(point_a,point_b,point_c,point_d) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L517)
+function alicia.window.set_name() end
 
----TO-DO
+---Set the window point.
+---@param point vector_2 # Point of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L281)
-function alicia.collision.get_point_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L536)
+function alicia.window.set_point(point) end
 
----TO-DO
+---Set the window monitor.
+---@param index number # Index of monitor to move window to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L299)
-function alicia.collision.get_point_poly() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L555)
+function alicia.window.set_screen(index) end
 
----TO-DO
+---Set the minimum window shape.
+---@param shape vector_2 # Minimum shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L319)
-function alicia.collision.get_line_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L572)
+function alicia.window.set_shape_min(shape) end
 
----TO-DO
+---Set the maximum window shape.
+---@param shape vector_2 # Maximum shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L345)
-function alicia.collision.get_box_2_box_2_difference() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L591)
+function alicia.window.set_shape_max(shape) end
 
----TO-DO
+---Set the current window shape.
+---@param shape vector_2 # Shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L367)
-function alicia.collision.get_sphere_sphere() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L610)
+function alicia.window.set_shape(shape) end
 
----TO-DO
+---Set the window alpha.
+---@param alpha number # Alpha of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L384)
-function alicia.collision.get_box_3_box_3() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L629)
+function alicia.window.set_alpha(alpha) end
 
----TO-DO
+---Focus the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L398)
-function alicia.collision.get_box_3_sphere() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L639)
+function alicia.window.set_focus() end
 
----TO-DO
+---Get the shape of the window.
+---@return number shape_x # Shape of the window (X).
+---@return number shape_y # Shape of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L415)
-function alicia.collision.get_ray_sphere() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L657)
+function alicia.window.get_shape() end
 
----TO-DO
+---Get the shape of the current render view.
+---@return number shape_x # Shape of the render view (X).
+---@return number shape_y # Shape of the render view (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L456)
-function alicia.collision.get_ray_box_3() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L672)
+function alicia.window.get_render_shape() end
 
----TO-DO
+---Get the available monitor amount.
+---@return number count # Monitor count.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L497)
-function alicia.collision.get_ray_triangle() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L686)
+function alicia.window.get_screen_count() end
 
----TO-DO
+---Get the current active monitor, where the window is.
+---@return number index # Current active monitor index.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/collision.rs#L540)
-function alicia.collision.get_ray_quad() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L700)
+function alicia.window.get_screen_focus() end
 
----The shader API.
+---Get the point of the given monitor.
+---@param index number # Index of the monitor.
+---@return number point_x # Point of the monitor (X).
+---@return number point_y # Point of the monitor (Y).
 ---
 --- ---
----*Not available in head-less mode.*
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L718)
+function alicia.window.get_screen_point(index) end
+
+---Get the shape of the given monitor.
+---@param index number # Index of the monitor.
+---@return number shape_x # Shape of the window (X).
+---@return number shape_y # Shape of the window (Y).
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L65)
----@class alicia.shader
-alicia.shader = {}
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L739)
+function alicia.window.get_screen_shape(index) end
 
----An unique handle for a shader in memory.
+---Get the physical shape of the given monitor.
+---@param index number # Index of the monitor.
+---@return number shape_x # Physical shape of the window (X).
+---@return number shape_y # Physical shape of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L80)
----@class shader
-shader = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L757)
+function alicia.window.get_screen_shape_physical(index) end
 
----Create a new shader resource.
----@param v_path string # Path to .vs file.
----@param f_path string # Path to .fs file.
----@return shader shader # LuaShader resource.
+---Get the refresh rate of the given monitor.
+---@param index number # Index of the monitor.
+---@return number rate # Refresh rate of the monitor.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L99)
-function alicia.shader.new(v_path,f_path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L779)
+function alicia.window.get_screen_rate(index) end
 
----Create a new shader resource, from memory.
----@param v_data string # .vs file data.
----@param f_data string # .fs file data.
----@return shader shader # LuaShader resource.
+---Get the point of the window.
+---@return number point_x # Point of the window (X).
+---@return number point_y # Point of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L145)
-function alicia.shader.new_from_memory(v_data,f_data) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L794)
+function alicia.window.get_point() end
 
----Initialize the shader.
----@param call function # The draw code.
----@param ... any # Variadic data.
+---Get the DPI scale of the window.
+---@return number scale_x # Scale of the window (X).
+---@return number scale_y # Scale of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L196)
-function shader:begin(call,...) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L813)
+function alicia.window.get_scale() end
 
----Get the location of a shader variable, by name.
----@param name string # Variable name.
----@return number location # Shader variable location.
+---Get the name of the given monitor.
+---@param index number # Index of the monitor.
+---@return string name # Name of the monitor.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L226)
-function shader:get_location_name(name) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L834)
+function alicia.window.get_screen_name(index) end
 
----Get the location of a shader attribute, by name.
----@param name string # Attribute name.
----@return number location # Shader attribute location.
+---Get a screen-shot of the current frame.
+---@param path string # Path to save the screen-shot to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L246)
-function shader:get_location_attribute_name(name) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L851)
+function alicia.window.get_screen_shot(path) end
 
----Get the location of a shader variable, by index.
----@param location number # Variable index.
----@return number location # Shader variable location.
+---The music API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L269)
-function shader:get_location(location) end
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L64)
+---@class alicia.music
+alicia.music = {}
 
----Set the location of a shader variable.
----@param location number # Variable index.
----@param value number # Variable value.
+---An unique handle for music in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L290)
-function shader:set_location(location,value) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L88)
+---@class music
+music = {}
 
----Set the value of a shader variable.
----@param location number # Variable index.
----@param class number # Variable class.
----@param value any # Variable value.
+---Create a new music resource.
+---@param path string # Path to music file.
+---@return music music # LuaMusic resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/shader.rs#L317)
-function shader:set_shader_value(location,class,value) end
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L116)
+function alicia.music.new(path) end
 
----The image API.
+---Create a new music resource, from memory.
+---@param data data # The data buffer.
+---@param kind string # The kind of music file (.png, etc.).
+---@return music music # LuaMusic resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L66)
----@class alicia.image
-alicia.image = {}
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L149)
+function alicia.music.new_from_memory(data,kind) end
 
----An unique handle for a image in memory.
+---Play the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L102)
----@class image
----@field shape_x number # Shape of the image (X).
----@field shape_y number # Shape of the image (Y).
-image = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L180)
+function music:play() end
 
----Get a texture resource from an image.
----@return texture texture # Texture resource.
+---Check if music is currently playing.
+---@return boolean state # State of the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L133)
-function image:to_texture() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L195)
+function music:get_playing() end
 
----TO-DO
+---Stop the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L144)
-function image:power_of_two() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L202)
+function music:stop() end
 
----TO-DO
+---Pause the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L162)
-function image:crop() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L210)
+function music:pause() end
 
----TO-DO
+---Resume the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L178)
-function image:crop_alpha() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L218)
+function music:resume() end
 
----TO-DO
+---Set volume for the music. (range: 0.0 - 1.0)
+---@param volume number # Current volume.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L190)
-function image:crop_alpha() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L233)
+function music:set_volume(volume) end
 
----TO-DO
+---Set pitch for the music.
+---@param pitch number # Current pitch.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L211)
-function image:blur_gaussian() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L248)
+function music:set_pitch(pitch) end
 
----TO-DO
+---Set pan for the music. (range: 0.0 - 1.0; 0.5 is center)
+---@param pan number # Current pan.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L223)
-function image:kernel_convolution() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L263)
+function music:set_pan(pan) end
 
----TO-DO
+---Update the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L238)
-function image:resize() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L271)
+function music:update() end
 
----TO-DO
+---Set position for the music.
+---@param position number # Current position.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L259)
-function image:extend() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L286)
+function music:set_position(position) end
 
----TO-DO
+---Get time length for the music.
+---@return number length # Time length.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L285)
-function image:mipmap() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L301)
+function music:get_length() end
 
----TO-DO
+---Get time played for the music.
+---@return number played # Time played.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L297)
-function image:dither() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L315)
+function music:get_played() end
 
----TO-DO
+---The net API. A connection-based transport on top of UDP, with reliable and unreliable channels.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L312)
-function image:flip() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L71)
+---@class alicia.net
+alicia.net = {}
 
----TO-DO
+---A table describing the configuration of a net transport. Every field is optional. Both ends of a connection should use the same channel list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L328)
-function image:rotate() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L172)
+---@class net_info
+---@field listen boolean? # Accept incoming connections. Default: false.
+---@field peer_maximum number? # Largest number of connected peers. Default: 32.
+---@field timeout number? # Time (in seconds) without hearing from a peer before disconnecting it. Default: 10.0.
+---@field message_maximum number? # Largest message size. Default: 1048576.
+---@field channel table? # Kind of each channel (net_channel). Default: { RELIABLE_ORDERED, RELIABLE_UNORDERED, UNRELIABLE }.
+net_info = {}
 
----TO-DO
+---An unique handle to a net transport in memory. The same transport can both connect to other transports, and accept connections from other transports. Every connection is given a peer number. Every event must be drained with poll, once per frame.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L342)
-function image:color_tint() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L425)
+---@class net
+net = {}
 
----TO-DO
+---Connect to another transport. The handshake is made in the background, and a connect event will be raised once accepted.
+---```lua
+----- Create a server transport, accepting incoming connections, and a client transport.
+---local server = alicia.net.new("127.0.0.1:7777", { listen = true })
+---local client = alicia.net.new("0.0.0.0:0")
+---
+----- Connect to the server. The handshake is made in the background.
+---local peer = client:connect("127.0.0.1:7777")
+---
+---assert(client:get_state(peer) == SOCKET_STATE.CONNECTING)
+---
+----- A message large enough to be split into fragments.
+---local large = string.rep("alicia", 1024)
+---
+---local message = {}
+---
+---for x = 1, 1000 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "first")
+---            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "second")
+---            client:set(peer, NET_CHANNEL.RELIABLE_UNORDERED, large)
+---        end
+---    end
+---
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, event)
+---        end
+---    end
+---
+---    if #message == 3 then
+---        break
+---    end
+---end
+---
+---assert(client:get_state(peer) == SOCKET_STATE.CONNECT)
+---assert(#message == 3)
+---
+----- Messages in an ordered channel are received in the order they were sent.
+----- Data kind 2 converts a data buffer to a string.
+---local ordered = {}
+---
+---for _, event in ipairs(message) do
+---    if event.channel == NET_CHANNEL.RELIABLE_ORDERED then
+---        table.insert(ordered, alicia.data.from_data(event.data, 2))
+---    else
+---        assert(alicia.data.from_data(event.data, 2) == large)
+---    end
+---end
+---
+---assert(ordered[1] == "first")
+---assert(ordered[2] == "second")
+---
+----- Disconnect. The server will be notified on its next poll.
+---client:disconnect(peer)
+---
+---assert(client:get_state(peer) == SOCKET_STATE.DISCONNECT)
+---
+----- An infinite, negative or NaN time-out is rejected.
+---for _, timeout in ipairs({ math.huge, -1.0, 0.0 / 0.0 }) do
+---    local success, error = pcall(alicia.net.new, "0.0.0.0:0", { timeout = timeout })
+---
+---    assert(not success)
+---    assert(string.find(tostring(error), "Invalid time-out"))
+---end
+---
+---```
+---@param address string # Address to connect to.
+---@return number peer # Peer number.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L356)
-function image:color_invert() end
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L459)
+function net:connect(address) end
 
----TO-DO
+---Receive every pending packet, send any acknowledgement, ping or resend that is due, and drain every event raised since the last call. Each event has a kind (socket_event), a peer number, and depending on the kind, an address (connect), a channel number and data buffer (message), or a reason (disconnect: "close", "reject" or "timeout"). This call does not block.
+---@return table event_list # Event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L368)
-function image:color_gray_scale() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L492)
+function net:poll() end
 
----TO-DO
+---Send a message to a peer. Any message larger than the packet size is split into fragments, and re-assembled on the other end. Messages sent while connecting are resent until connected, if the channel is reliable. This call does not block.
+---@param peer number # Peer number.
+---@param channel number # Channel number.
+---@param data string | data # Message to send.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L380)
-function image:color_contrast() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L522)
+function net:set(peer,channel,data) end
 
----TO-DO
+---Disconnect from a peer. The peer is notified, but any message waiting on an acknowledgement is dropped. No disconnect event is raised for this peer.
+---@param peer number # Peer number.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L392)
-function image:color_contrast() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L587)
+function net:disconnect(peer) end
 
----TO-DO
+---Get the connection state of a peer, as of the last poll call.
+---@param peer number # Peer number.
+---@return socket_state state # Connection state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L412)
-function image:get_alpha_border() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L608)
+function net:get_state(peer) end
 
----TO-DO
+---Get the address of a peer.
+---@param peer number # Peer number.
+---@return string address # Peer address.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L424)
-function image:get_alpha_border() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L629)
+function net:get_address(peer) end
 
----TO-DO
+---Get every peer number, connected or connecting.
+---@return table peer_list # Peer number list.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L438)
-function image:draw_pixel() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L647)
+function net:get_peer_list() end
 
----TO-DO
+---Get the connection statistics of a peer.
+---@param peer number # Peer number.
+---@return number rtt # Round-trip time, in seconds. 0.0 if not yet measured.
+---@return number loss # Estimated packet loss, from 0.0 to 1.0.
+---@return number byte_send # Total byte count sent to the peer, including any resend.
+---@return number byte_receive # Total byte count received from the peer.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L453)
-function image:draw_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L670)
+function net:get_stat(peer) end
 
----TO-DO
+---Set a network condition simulation, for testing. Every packet sent, including any acknowledgement, ping and resend, goes through the simulation. Every delayed packet is sent in the background once due. Any packet still delayed by the last simulation is dropped.
+---```lua
+----- Create a server and client transport, on a bad network: every packet is delayed, some are
+----- dropped, duplicated or re-ordered.
+---local server = alicia.net.new("127.0.0.1:7787", { listen = true })
+---local client = alicia.net.new("0.0.0.0:0")
+---
+---local simulate = {
+---    latency   = 0.02,
+---    jitter    = 0.02,
+---    loss      = 0.1,
+---    duplicate = 0.1,
+---    reorder   = 0.1,
+---    seed      = 1,
+---}
+---
+---server:set_simulate(simulate)
+---client:set_simulate(simulate)
+---
+---local peer = client:connect("127.0.0.1:7787")
+---
+----- Send 20 messages on the ordered channel, once connected.
+---local message = {}
+---local clock = os.clock()
+---
+---while #message < 20 and os.clock() - clock < 10.0 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            for x = 1, 20 do
+---                client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, tostring(x))
+---            end
+---        end
+---    end
+---
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            -- Data kind 2 converts a data buffer to a string.
+---            table.insert(message, alicia.data.from_data(event.data, 2))
+---        end
+---    end
+---end
+---
+----- Every message on a reliable, ordered channel still arrives, once, and in order.
+---assert(#message == 20)
+---
+---for x = 1, 20 do
+---    assert(message[x] == tostring(x))
+---end
+---
+---```
+---@param info simulate_info? # OPTIONAL: Simulation configuration. Use nil to turn the simulation off.
+---@return number? seed # Random seed in use, or nil if the simulation is off. Use it to reproduce a run.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L472)
-function image:draw_circle() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L697)
+function net:set_simulate(info) end
 
----TO-DO
+---Create a net transport.
+---```lua
+----- Create a server transport, accepting incoming connections, and a client transport.
+---local server = alicia.net.new("127.0.0.1:7777", { listen = true })
+---local client = alicia.net.new("0.0.0.0:0")
+---
+----- Connect to the server. The handshake is made in the background.
+---local peer = client:connect("127.0.0.1:7777")
+---
+---assert(client:get_state(peer) == SOCKET_STATE.CONNECTING)
+---
+----- A message large enough to be split into fragments.
+---local large = string.rep("alicia", 1024)
+---
+---local message = {}
+---
+---for x = 1, 1000 do
+---    for _, event in ipairs(client:poll()) do
+---        if event.kind == SOCKET_EVENT.CONNECT then
+---            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "first")
+---            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "second")
+---            client:set(peer, NET_CHANNEL.RELIABLE_UNORDERED, large)
+---        end
+---    end
+---
+---    for _, event in ipairs(server:poll()) do
+---        if event.kind == SOCKET_EVENT.MESSAGE then
+---            table.insert(message, event)
+---        end
+---    end
+---
+---    if #message == 3 then
+---        break
+---    end
+---end
+---
+---assert(client:get_state(peer) == SOCKET_STATE.CONNECT)
+---assert(#message == 3)
+---
+----- Messages in an ordered channel are received in the order they were sent.
+----- Data kind 2 converts a data buffer to a string.
+---local ordered = {}
+---
+---for _, event in ipairs(message) do
+---    if event.channel == NET_CHANNEL.RELIABLE_ORDERED then
+---        table.insert(ordered, alicia.data.from_data(event.data, 2))
+---    else
+---        assert(alicia.data.from_data(event.data, 2) == large)
+---    end
+---end
+---
+---assert(ordered[1] == "first")
+---assert(ordered[2] == "second")
+---
+----- Disconnect. The server will be notified on its next poll.
+---client:disconnect(peer)
+---
+---assert(client:get_state(peer) == SOCKET_STATE.DISCONNECT)
+---
+----- An infinite, negative or NaN time-out is rejected.
+---for _, timeout in ipairs({ math.huge, -1.0, 0.0 / 0.0 }) do
+---    local success, error = pcall(alicia.net.new, "0.0.0.0:0", { timeout = timeout })
+---
+---    assert(not success)
+---    assert(string.find(tostring(error), "Invalid time-out"))
+---end
+---
+---```
+---@param address string # Address to bind to. Use "0.0.0.0:0" for a client.
+---@param info net_info? # OPTIONAL: Transport configuration. Any missing field will use its default.
+---@return net net # Net transport.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L490)
-function image:draw_circle_line() end
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/net.rs#L722)
+function alicia.net.new(address,info) end
 
----TO-DO
+---The profiler API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L508)
-function image:draw_box_2() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L72)
+---@class alicia.profiler
+alicia.profiler = {}
 
----TO-DO
+---Begin recording. Every previous record is cleared. Draw calls, physics steps and asset loads are always recorded.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
+---
+---local function work()
+---    local value = 0.0
+---
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
+---
+---    return value
+---end
+---
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
+---
+---assert(value > 0.0)
+---
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
+---
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
+---
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
+---
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
+---
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
+---
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
+---
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
+---
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
+---
+---    return 0
+---end
+---
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
+---
+---    return value
+---end
+---
+---assert(outer() == 512)
+---
+---alicia.profiler.new_frame()
+---
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
+---
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
+---
+---alicia.profiler.close()
+---
+---```
+---@param hook boolean? # OPTIONAL: Also record every Lua function call. This is slow.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L523)
-function image:draw_box_2_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L257)
+function alicia.profiler.begin(hook) end
 
----TO-DO
+---Close recording. Every record is kept, until the next call to begin.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
+---
+---local function work()
+---    local value = 0.0
+---
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
+---
+---    return value
+---end
+---
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
+---
+---assert(value > 0.0)
+---
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
+---
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
+---
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
+---
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
+---
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
+---
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
+---
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
+---
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
+---
+---    return 0
+---end
+---
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
+---
+---    return value
+---end
+---
+---assert(outer() == 512)
+---
+---alicia.profiler.new_frame()
+---
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
+---
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
+---
+---alicia.profiler.close()
+---
+---```
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L541)
-function image:draw_triangle() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L276)
+function alicia.profiler.close() end
 
----TO-DO
+---Get the state of the profiler.
+---@return boolean state # True if recording, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L571)
-function image:draw_triangle_line() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L294)
+function alicia.profiler.get_state() end
 
----Create a new image resource.
----@param path string # Path to image file.
----@return image image # LuaImage resource.
+---Time a call, as a named scope.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---local function work()
+---    local value = 0.0
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L603)
-function alicia.image.new(path) end
-
----Create a new image resource, from memory.
----@param data data # The data buffer.
----@param kind string # The kind of image file (.png, etc.).
----@return image image # LuaImage resource.
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---    return value
+---end
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L637)
-function alicia.image.new_from_memory(data,kind) end
-
----Create a new image resource, from the current screen buffer.
----@return image image # LuaImage resource.
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---assert(value > 0.0)
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L667)
-function alicia.image.new_from_screen() end
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
+---
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
+---
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
+---
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
+---
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
+---
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
+---
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
+---
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
+---
+---    return 0
+---end
+---
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
+---
+---    return value
+---end
+---
+---assert(outer() == 512)
+---
+---alicia.profiler.new_frame()
+---
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
+---
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
+---
+---alicia.profiler.close()
+---
+---```
+---@param name string # Scope name.
+---@param call function # Call to time.
+---@param ... any # Variadic data.
+---@return any ... # Every value returned by the call.
+---
+--- ---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L314)
+function alicia.profiler.scope(name,call,...) end
 
----TO-DO
+---Mark the end of a frame. Called automatically by alicia.draw.begin, and once per tick in head-less mode.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
+---
+---local function work()
+---    local value = 0.0
+---
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
+---
+---    return value
+---end
+---
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
+---
+---assert(value > 0.0)
+---
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
+---
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
+---
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
+---
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
+---
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
+---
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
+---
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
+---
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
+---
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
+---
+---    return 0
+---end
+---
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---    return value
+---end
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L689)
-function alicia.image.new_color() end
-
----TO-DO
+---assert(outer() == 512)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---alicia.profiler.new_frame()
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L714)
-function alicia.image.new_gradient_linear() end
-
----TO-DO
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L744)
-function alicia.image.new_gradient_radial() end
-
----TO-DO
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---alicia.profiler.close()
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L774)
-function alicia.image.new_gradient_square() end
-
----TO-DO
+---```
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L804)
-function alicia.image.new_check() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L337)
+function alicia.profiler.new_frame() end
 
----TO-DO
+---Get every scope in the last frame, from slowest to fastest. Every time is in milli-seconds.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---local function work()
+---    local value = 0.0
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L841)
-function alicia.image.new_white_noise() end
-
----TO-DO
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---    return value
+---end
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L865)
-function alicia.image.new_perlin_noise() end
-
----TO-DO
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---assert(value > 0.0)
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L899)
-function alicia.image.new_cellular() end
-
----TO-DO
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/image.rs#L923)
-function alicia.image.new_text() end
-
----The font API.
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
 ---
---- ---
----*Not available in head-less mode.*
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L66)
----@class alicia.font
-alicia.font = {}
-
----An unique handle to a font in memory.
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L92)
----@class font
-font = {}
-
----Draw a font.
----@param label string # Label of font to draw.
----@param point vector_2 # Point of font to draw.
----@param origin vector_2 # TO-DO
----@param angle number # TO-DO
----@param scale number # Scale of font to draw.
----@param space number # Space of font to draw.
----@param color color # Color of font to draw.
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L124)
-function font:draw(label,point,origin,angle,scale,space,color) end
-
----Measure the size of a given text on screen, with a given font.
----@param label string # Label of font to measure.
----@param scale number # Scale of font to measure.
----@param space number # Space of font to measure.
----@return number size_x # Size of text (X).
----@return number size_y # Size of text (Y).
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L216)
-function font:measure_text(label,scale,space) end
-
----Create a new font resource.
----@param path string # Path to font file.
----@param size number # Size for font.
----@return font font # LuaFont resource.
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L273)
-function alicia.font.new(path,size) end
-
----Create a new font resource, from memory.
----@param data data # The data buffer.
----@param kind string # The kind of font file (.ttf, etc.).
----@param size number # Size for font.
----@return font font # LuaFont resource.
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L304)
-function alicia.font.new_from_memory(data,kind,size) end
-
----Create a new font resource, from the default font.
----@param size number # Size for font.
----@return font font # LuaFont resource.
+---    return 0
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L342)
-function alicia.font.new_default(size) end
-
----TO-DO
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L373)
-function alicia.font.draw_frame_rate() end
-
----Draw text.
----@param point vector_2 # The point of the text.
----@param label string # The label of the text.
----@param scale number # The angle of the text.
----@param color color # The color of the text.
+---    return value
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L395)
-function alicia.font.draw_text(point,label,scale,color) end
-
----Set the vertical space between each line-break.
----@param space number # Vertical space.
+---assert(outer() == 512)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/font.rs#L419)
-function alicia.font.set_text_line_space(space) end
-
----The automation API.
+---alicia.profiler.new_frame()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L64)
----@class alicia.automation
-alicia.automation = {}
-
----An unique handle to an automation event list.
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L80)
----@class automation_event
-automation_event = {}
-
----TO-DO
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L100)
-function alicia.automation.new() end
-
----TO-DO
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L131)
-function automation_event:save() end
-
----TO-DO
+---alicia.profiler.close()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L146)
-function automation_event:set_active() end
-
----TO-DO
+---```
+---@return number frame_time # Frame time.
+---@return table list # Scope list. Each entry has a name, time and count key.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L158)
-function automation_event:set_frame() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L355)
+function alicia.profiler.get_frame() end
 
----TO-DO
+---Write every record out as a Chrome trace-event file, for use with chrome://tracing or Perfetto.
+---```lua
+----- Begin recording. Every Lua function call is also recorded.
+---alicia.profiler.begin(true)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L170)
-function automation_event:start() end
-
----TO-DO
+---local function work()
+---    local value = 0.0
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L182)
-function automation_event:stop() end
-
----TO-DO
+---    for x = 1, 100000 do
+---        value = value + math.sqrt(x)
+---    end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L194)
-function automation_event:play() end
-
----TO-DO
+---    return value
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/automation.rs#L213)
-function automation_event:get_event() end
-
----The window API.
+----- Time a call, as a named scope. Every value returned by the call is passed through.
+---local value = alicia.profiler.scope("work", work)
 ---
---- ---
----*Not available in head-less mode.*
+---assert(value > 0.0)
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L65)
----@class alicia.window
-alicia.window = {}
-
----Create a new native OS file dialog.
----@param kind file_dialog_kind # The kind of file dialog to display.
----@param title string? # OPTIONAL: Window title.
----@param path string? # OPTIONAL: Default file path.
----@param name string? # OPTIONAL: Default file name.
----@param filter table? # OPTIONAL: Extension filter table. Must be in { extension = { ".txt", ".ini" } format.
+----- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+---alicia.profiler.new_frame()
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+----- Get every scope in the last frame, from slowest to fastest.
+---local frame_time, list = alicia.profiler.get_frame()
+---local entry = nil
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L173)
-function alicia.window.file_dialog(kind,title,path,name,filter) end
-
----Create a new native OS text dialog.
----@param kind text_dialog_kind? # OPTIONAL: The kind of text dialog to display.
----@param title string? # OPTIONAL: Window title.
----@param label string? # OPTIONAL: Window label.
----@param button text_dialog_button? # OPTIONAL: Window button layout.
+---for _, scope in ipairs(list) do
+---    if scope.name == "work" then
+---        entry = scope
+---    end
+---end
 ---
---- ---
----*This function is asynchronous and can run within a co-routine.*
+---assert(frame_time > 0.0)
+---assert(entry)
+---assert(entry.count == 1)
+---assert(entry.time > 0.0 and entry.time <= frame_time)
 ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L267)
-function alicia.window.text_dialog(kind,title,label,button) end
-
----Get if the window should close.
----@return boolean close # True if the window should close.
+----- Close recording, and write every record out as a Chrome trace-event file.
+---alicia.profiler.close()
+---alicia.profiler.dump("work/trace.json")
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L317)
-function alicia.window.get_close() end
-
----Get the state of the window (full-screen).
----@return boolean state # State of the window.
+---local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L331)
-function alicia.window.get_fullscreen() end
-
----Get the state of the window (hidden).
----@return boolean state # State of the window.
+---assert(#trace.traceEvents > 0)
+---assert(not alicia.profiler.get_state())
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L345)
-function alicia.window.get_hidden() end
-
----Get the state of the window (minimize).
----@return boolean state # State of the window.
+----- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+----- and will not cut short a function further up the stack.
+---alicia.profiler.begin(true)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L359)
-function alicia.window.get_minimize() end
-
----Get the state of the window (maximize).
----@return boolean state # State of the window.
+---local function deep(depth)
+---    if depth > 0 then
+---        return 1 + deep(depth - 1)
+---    end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L373)
-function alicia.window.get_maximize() end
-
----Get the state of the window (focus).
----@return boolean state # State of the window.
+---    return 0
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L387)
-function alicia.window.get_focus() end
-
----Get the state of the window (resize).
----@return boolean state # State of the window.
+---local function outer()
+---    local value = alicia.profiler.scope("deep", deep, 512)
+---
+---    return value
+---end
+---
+---assert(outer() == 512)
+---
+---alicia.profiler.new_frame()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L401)
-function alicia.window.get_resize() end
-
----Get the state of a window flag.
----@param flag window_flag # Window flag.
----@return boolean state # Window flag state.
+---local _, list = alicia.profiler.get_frame()
+---local scope_deep  = nil
+---local scope_outer = nil
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L418)
-function alicia.window.get_state(flag) end
-
----Set the state of a window flag.
----@param flag window_flag # Window flag.
----@param state boolean # Window flag state.
+---for _, scope in ipairs(list) do
+---    if scope.name == "deep" then
+---        scope_deep = scope
+---    elseif scope.name:find("^outer") then
+---        scope_outer = scope
+---    end
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L433)
-function alicia.window.set_state(flag,state) end
-
----Set the window to full-screen mode.
+---assert(scope_deep)
+---assert(scope_outer)
+---assert(scope_outer.time >= scope_deep.time)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L448)
-function alicia.window.set_fullscreen() end
-
----Set the window to border-less mode.
+---alicia.profiler.close()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L458)
-function alicia.window.set_borderless() end
-
----Minimize the window.
+---```
+---@param path string # Path to file.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L468)
-function alicia.window.set_minimize() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/profiler.rs#L383)
+function alicia.profiler.dump(path) end
 
----Maximize the window.
+---The Rapier (2D) API.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L478)
-function alicia.window.set_maximize() end
-
----Restore the window.
+---*Available with compile feature: `rapier2d`.*
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L488)
-function alicia.window.set_restore() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L74)
+---@class alicia.rapier_2d
+alicia.rapier_2d = {}
 
----Set the window icon.
+---An unique handle for a Rapier (2D) simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L498)
-function alicia.window.set_icon() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L88)
+---@class rapier_2d
+rapier_2d = {}
 
----Set the window name.
+---Create a new Rapier (2D) simulation.
+---@return rapier_2d rapier_2d # Rapier (2D) simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L517)
-function alicia.window.set_name() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L116)
+function alicia.rapier_2d.new() end
 
----Set the window point.
----@param point vector_2 # Point of the window.
+---Cast a ray.
+---@param point vector_2 # Ray point.
+---@param direction vector_2 # Ray direction.
+---@param range number # Ray range.
+---@param solid boolean # If true, a ray starting inside of a shape will hit it at once.
+---@param filter query_filter? # OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
+---@return table solid_body # Solid body handle.
+---@return number time # Time of impact.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L536)
-function alicia.window.set_point(point) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L167)
+function rapier_2d:cast_ray(point,direction,range,solid,filter,exclude_solid_body) end
 
----Set the window monitor.
----@param index number # Index of monitor to move window to.
+---Cast a ray, and also get the normal information.
+---@param point vector_2 # Ray point.
+---@param direction vector_2 # Ray direction.
+---@param range number # Ray range.
+---@param solid boolean # If true, a ray starting inside of a shape will hit it at once.
+---@param filter query_filter? # OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
+---@return table solid_body # Solid body handle.
+---@return number time # Time of impact.
+---@return number normal_x # Hit normal (X).
+---@return number normal_y # Hit normal (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L555)
-function alicia.window.set_screen(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L235)
+function rapier_2d:cast_ray_normal(point,direction,range,solid,filter,exclude_solid_body) end
 
----Set the minimum window shape.
----@param shape vector_2 # Minimum shape of the window.
+---Check if a cuboid is intersecting against another cuboid.
+---@param point_a vector_2 # Point of cuboid (A).
+---@param angle_a number # Angle of cuboid (A).
+---@param shape_a vector_2 # Shape of cuboid (A).
+---@param point_b vector_2 # Point of cuboid (B).
+---@param angle_b number # Angle of cuboid (B).
+---@param shape_b vector_2 # Shape of cuboid (B).
+---@return boolean intersect # Result of intersection.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L572)
-function alicia.window.set_shape_min(shape) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L305)
+function rapier_2d:test_intersect_cuboid_cuboid(point_a,angle_a,shape_a,point_b,angle_b,shape_b) end
 
----Set the maximum window shape.
----@param shape vector_2 # Maximum shape of the window.
+---Check if a cuboid is intersecting against any solid body in the simulation.
+---@param point vector_2 # Point of cuboid.
+---@param angle number # Angle of cuboid.
+---@param shape vector_2 # Shape of cuboid.
+---@param filter query_filter? # OPTIONAL: Scene query filter. If nil, every sensor is excluded. A rigid body handle to exclude is also accepted in its place, as in previous versions, and will also exclude every sensor.
+---@param exclude_solid_body table? # OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.
+---@return table solid_body # Solid body handle, if any.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L591)
-function alicia.window.set_shape_max(shape) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L351)
+function rapier_2d:test_intersect_cuboid(point,angle,shape,filter,exclude_solid_body) end
 
----Set the current window shape.
----@param shape vector_2 # Shape of the window.
+---Get the shape of a solid body (ball or cuboid).
+---@param solid_body table # Solid body handle.
+---@return number | table shape # Radius of the ball, or half-shape of the cuboid.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L610)
-function alicia.window.set_shape(shape) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L415)
+function rapier_2d:get_solid_body_shape(solid_body) end
 
----Set the window alpha.
----@param alpha number # Alpha of the window.
+---Set the shape of a solid body.
+---@param solid_body table # Solid body handle.
+---@param ... number | vector_2 # Radius of the ball, or half-shape of the cuboid.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L629)
-function alicia.window.set_alpha(alpha) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L444)
+function rapier_2d:set_solid_body_shape(solid_body,...) end
 
----Focus the window.
+---Get the parent of a solid body.
+---@param solid_body table # Solid body handle.
+---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L639)
-function alicia.window.set_focus() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L495)
+function rapier_2d:get_solid_body_parent(solid_body) end
 
----Get the shape of the window.
----@return number shape_x # Shape of the window (X).
----@return number shape_y # Shape of the window (Y).
+---Get the position of a solid body.
+---@param solid_body table # Solid body handle.
+---@return number position_x # Solid body position (X).
+---@return number position_y # Solid body position (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L657)
-function alicia.window.get_shape() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L525)
+function rapier_2d:get_solid_body_position(solid_body) end
 
----Get the shape of the current render view.
----@return number shape_x # Shape of the render view (X).
----@return number shape_y # Shape of the render view (Y).
+---Get the rotation of a solid body.
+---@param solid_body table # Solid body handle.
+---@return number rotation # Solid body rotation, in radians.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L672)
-function alicia.window.get_render_shape() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L553)
+function rapier_2d:get_solid_body_rotation(solid_body) end
 
----Get the available monitor amount.
----@return number count # Monitor count.
+---Set the position of a solid body.
+---@param solid_body table # Solid body handle.
+---@param position vector_2 # Solid body position.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L686)
-function alicia.window.get_screen_count() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L579)
+function rapier_2d:set_solid_body_position(solid_body,position) end
 
----Get the current active monitor, where the window is.
----@return number index # Current active monitor index.
+---Set the rotation of a solid body.
+---@param solid_body table # Solid body handle.
+---@param rotation number # Solid body rotation, in radians.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L700)
-function alicia.window.get_screen_focus() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L608)
+function rapier_2d:set_solid_body_rotation(solid_body,rotation) end
 
----Get the point of the given monitor.
----@param index number # Index of the monitor.
----@return number point_x # Point of the monitor (X).
----@return number point_y # Point of the monitor (Y).
+---Set the sensor state of a solid body.
+---@param solid_body table # Solid body handle.
+---@param sensor boolean # Solid body sensor state.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L718)
-function alicia.window.get_screen_point(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L635)
+function rapier_2d:set_solid_body_sensor(solid_body,sensor) end
 
----Get the shape of the given monitor.
----@param index number # Index of the monitor.
----@return number shape_x # Shape of the window (X).
----@return number shape_y # Shape of the window (Y).
+---Remove a solid body.
+---@param solid_body table # Solid body handle.
+---@param wake_parent boolean # Whether or not to wake up the rigid body parent this solid body is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L739)
-function alicia.window.get_screen_shape(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L664)
+function rapier_2d:solid_body_remove(solid_body,wake_parent) end
 
----Get the physical shape of the given monitor.
----@param index number # Index of the monitor.
----@return number shape_x # Physical shape of the window (X).
----@return number shape_y # Physical shape of the window (Y).
+---Remove a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param remove_solid_body boolean # Whether or not to remove every solid body this rigid body is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L757)
-function alicia.window.get_screen_shape_physical(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L691)
+function rapier_2d:rigid_body_remove(rigid_body,remove_solid_body) end
 
----Get the refresh rate of the given monitor.
----@param index number # Index of the monitor.
----@return number rate # Refresh rate of the monitor.
+---Create a character controller.
+---@return table character_controller # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L779)
-function alicia.window.get_screen_rate(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L719)
+function rapier_2d:character_controller() end
 
----Get the point of the window.
----@return number point_x # Point of the window (X).
----@return number point_y # Point of the window (Y).
+---Set the up vector of a character controller.
+---@param character table # Character controller.
+---@param up vector_2 # Up vector.
+---@return table character # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L794)
-function alicia.window.get_point() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L743)
+function rapier_2d:set_character_controller_up_vector(character,up) end
 
----Get the DPI scale of the window.
----@return number scale_x # Scale of the window (X).
----@return number scale_y # Scale of the window (Y).
+---Set the slope angle limits of a character controller.
+---@param character table # Character controller.
+---@param slope_min number # Minimum slope angle to slide down on.
+---@param slope_max number # Maximum slope angle to climb up on.
+---@return table character # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L813)
-function alicia.window.get_scale() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L768)
+function rapier_2d:set_character_controller_slope(character,slope_min,slope_max) end
 
----Get the name of the given monitor.
----@param index number # Index of the monitor.
----@return string name # Name of the monitor.
+---Set the auto-step data of a character controller. Pass 0 as either kind to disable auto-step.
+---@param character table # Character controller.
+---@param kind_a number # Maximum height kind. 1: absolute, 2: relative.
+---@param kind_b number # Minimum width kind. 1: absolute, 2: relative.
+---@param value_a number # Maximum height.
+---@param value_b number # Minimum width.
+---@param dynamic boolean # Whether or not to step on dynamic rigid bodies too.
+---@return table character # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L834)
-function alicia.window.get_screen_name(index) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L796)
+function rapier_2d:set_character_auto_step(character,kind_a,kind_b,value_a,value_b,dynamic) end
 
----Get a screen-shot of the current frame.
----@param path string # Path to save the screen-shot to.
+---Set the snap-to-ground data of a character controller. Pass 0 as the kind to disable snap-to-ground.
+---@param character table # Character controller.
+---@param kind number # Distance kind. 1: absolute, 2: relative.
+---@param value number # Distance.
+---@return table character # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/window.rs#L851)
-function alicia.window.get_screen_shot(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L843)
+function rapier_2d:set_character_snap_ground(character,kind,value) end
 
----The music API.
+---Move a character controller.
+---@param step number # Time step.
+---@param character table # Character controller.
+---@param solid_body table # Solid body handle of the character.
+---@param translation vector_2 # Desired translation, per second.
+---@param filter query_filter? # OPTIONAL: Scene query filter. If nil, every sensor is excluded.
+---@return number movement_x # Translation point (X).
+---@return number movement_y # Translation point (Y).
+---@return boolean floor # Currently on floor.
+---@return boolean slope # Currently on slope.
 ---
 --- ---
----*Not available in head-less mode.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L64)
----@class alicia.music
-alicia.music = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L879)
+function rapier_2d:character_controller_move(step,character,solid_body,translation,filter) end
 
----An unique handle for music in memory.
+---Create a rigid body.
+---@param kind rigid_body_kind # Rigid body kind.
+---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L79)
----@class music
-music = {}
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L943)
+function rapier_2d:rigid_body(kind) end
 
----Create a new music resource.
----@param path string # Path to music file.
----@return music music # LuaMusic resource.
+---Get the user data of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@return number user_data # Rigid body user data.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L107)
-function alicia.music.new(path) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L967)
+function rapier_2d:get_rigid_body_user_data(rigid_body) end
 
----Create a new music resource, from memory.
----@param data data # The data buffer.
----@param kind string # The kind of music file (.png, etc.).
----@return music music # LuaMusic resource.
+---Set the user data of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param user_data number # Rigid body user data.
 ---
 --- ---
----*This function is asynchronous and can run within a co-routine.*
----
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L140)
-function alicia.music.new_from_memory(data,kind) end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L993)
+function rapier_2d:set_rigid_body_user_data(rigid_body,user_data) end
 
----Play the music.
+---Set the position of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param position vector_2 # Rigid body position.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L171)
-function music:play() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1021)
+function rapier_2d:set_rigid_body_position(rigid_body,position,wake_up) end
 
----Check if music is currently playing.
----@return boolean state # State of the music.
+---Set the rotation of a rigid body.
+---@param rigid_body table # Rigid body handle.
+---@param rotation number # Rigid body rotation, in radians.
+---@param wake_up boolean # Whether or not to wake up the rigid body.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L186)
-function music:get_playing() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1050)
+function rapier_2d:set_rigid_body_rotation(rigid_body,rotation,wake_up) end
 
----Stop the music.
+---Get the user data of a solid body.
+---@param solid_body table # Solid body handle.
+---@return number user_data # Solid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L193)
-function music:stop() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1081)
+function rapier_2d:get_solid_body_user_data(solid_body) end
 
----Pause the music.
+---Set the user data of a solid body.
+---@param solid_body table # Solid body handle.
+---@param user_data number # Solid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L201)
-function music:pause() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1107)
+function rapier_2d:set_solid_body_user_data(solid_body,user_data) end
 
----Resume the music.
+---Create a solid body. Kind 0 is a cuboid (vector_2 half-shape), kind 1 is a ball (number radius), kind 2 is a poly-line (vector_2 point table), any other kind is a convex hull (vector_2 point table).
+---@param rigid_body table? # OPTIONAL: Rigid body handle to bind the solid body to.
+---@param kind number # Solid body kind.
+---@param ... any # Solid body shape data.
+---@return table solid_body # Solid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L209)
-function music:resume() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1140)
+function rapier_2d:solid_body(rigid_body,kind,...) end
 
----Set volume for the music. (range: 0.0 - 1.0)
----@param volume number # Current volume.
+---Step the Rapier (2D) simulation.
+---```lua
+----- Create a new 2D simulation.
+---local rapier = alicia.rapier_2d.new()
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L224)
-function music:set_volume(volume) end
-
----Set pitch for the music.
----@param pitch number # Current pitch.
+----- Create a fixed floor, with no rigid body parent.
+---rapier:solid_body(nil, 0, vector_2:new(8.0, 0.5))
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L239)
-function music:set_pitch(pitch) end
-
----Set pan for the music. (range: 0.0 - 1.0; 0.5 is center)
----@param pan number # Current pan.
+----- Create a dynamic rigid body, and bind a ball solid body to it.
+---local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+---local solid = rapier:solid_body(rigid, 1, 0.5)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L254)
-function music:set_pan(pan) end
-
----Update the music.
+----- Move the ball above the floor. Raylib's screen-space is Y-down, so "above" is negative Y.
+---rapier:set_rigid_body_position(rigid, vector_2:new(0.0, -4.0), true)
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L262)
-function music:update() end
-
----Set position for the music.
----@param position number # Current position.
+----- Step the simulation for two seconds.
+---for x = 1, 120 do
+---    rapier:step()
+---end
 ---
---- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L277)
-function music:set_position(position) end
-
----Get time length for the music.
----@return number length # Time length.
+----- The ball should have fallen, and come to a rest on the floor.
+---local _, y = rapier:get_solid_body_position(solid)
+---
+---assert(y > -4.0)
+---assert(y < 0.0)
+---
+---```
+---@return table? event_list # Collision event list, or nil if there were none.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L292)
-function music:get_length() end
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1219)
+function rapier_2d:step() end
 
----Get time played for the music.
----@return number played # Time played.
+---Render the Rapier (2D) simulation. Must be called within a 2D draw call.
 ---
 --- ---
----[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/music.rs#L306)
-function music:get_played() end
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/luxreduxdelux/alicia/tree/main/source/rust/base/rapier_2d.rs#L1261)
+function rapier_2d:debug_render() end
 
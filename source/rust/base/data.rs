@@ -140,6 +140,18 @@ impl<T: Clone + IntoLua + Send + 'static> Data<T> {
     }
 }
 
+impl Data<u8> {
+    pub fn get_byte(value: LuaValue) -> mlua::Result<Vec<u8>> {
+        match value {
+            LuaValue::String(data) => Ok(data.as_bytes().to_vec()),
+            LuaValue::UserData(_) => Ok(Self::get_buffer(value)?.0.clone()),
+            _ => Err(mlua::Error::runtime(
+                "Data::get_byte(): Value is not a string or a Data user-data.",
+            )),
+        }
+    }
+}
+
 impl<T: Clone + IntoLua + Send + 'static> mlua::UserData for Data<T> {
     fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
 
@@ -509,17 +521,26 @@ fn from_data(lua: &Lua, (data, kind): (LuaValue, i32)) -> mlua::Result<LuaValue>
     let data = Data::get_buffer(data)?;
     let data = &data.0;
 
+    // a number must be at least four bytes long.
+    let number = || -> mlua::Result<[u8; 4]> {
+        data.get(0..4).and_then(|x| x.try_into().ok()).ok_or(mlua::Error::runtime(
+            "alicia.data.from_data(): Data is too short for a number.",
+        ))
+    };
+
     match kind {
         0 => {
-            let data = i32::from_ne_bytes([data[0], data[1], data[2], data[3]]);
+            let data = i32::from_ne_bytes(number()?);
             lua.to_value(&data)
         }
         1 => {
-            let data = f32::from_ne_bytes([data[0], data[1], data[2], data[3]]);
+            let data = f32::from_ne_bytes(number()?);
             lua.to_value(&data)
         }
         _ => {
-            let data = String::from_utf8(data.to_vec()).unwrap();
+            let data = String::from_utf8(data.to_vec()).map_err(|_| {
+                mlua::Error::runtime("alicia.data.from_data(): Data is not valid UTF-8.")
+            })?;
             lua.to_value(&data)
         }
     }
@@ -54,6 +54,7 @@ use crate::status::*;
 //================================================================
 
 use crate::base::helper::*;
+use crate::base::profiler::*;
 use mlua::prelude::*;
 
 //================================================================
@@ -68,6 +69,15 @@ pub fn set_global(
     status_info: &StatusInfo,
     script_info: Option<&ScriptInfo>,
 ) -> mlua::Result<()> {
+    // part of head API. only run on head API pass, if we are running in head mode.
+    if let Some(info) = script_info {
+        if !info.head {
+            return Ok(())
+        }
+    } else {
+        return Ok(())
+    }
+
     let draw = lua.create_table()?;
 
     draw.set("clear", lua.create_function(self::clear)?)?;                 // ClearBackground
@@ -105,26 +115,40 @@ fn clear(lua: &Lua, color: LuaValue) -> mlua::Result<()> {
 {
     "version": "1.0.0",
     "name": "alicia.draw.begin",
-    "info": "Initialize drawing to the screen.",
+    "info": "Initialize drawing to the screen. In hot-reload mode, every Lua module that has changed will be re-executed at the end of the frame.",
     "member": [
         { "name": "call", "info": "The draw code.", "kind": "function" },
         { "name": "...",  "info": "Variadic data.", "kind": "any"      }
     ]
 }
 */
+#[allow(unused_variables)]
 fn begin(
-    _: &Lua,
+    lua: &Lua,
     (call, variadic): (mlua::Function, mlua::Variadic<LuaValue>),
 ) -> mlua::Result<()> {
+    Profiler::frame();
+
+    let _scope = ProfileScope::new("draw.begin", "draw");
+
     unsafe {
         BeginDrawing();
 
         let call = call.call::<()>(variadic);
 
-        EndDrawing();
+        // time the end of the frame separately, as it will include the buffer swap.
+        {
+            let _scope = ProfileScope::new("draw.close", "draw");
+
+            EndDrawing();
+        }
 
         call?;
 
+        // hot-reload any changed Lua module, once per frame.
+        #[cfg(feature = "file_notify")]
+        Script::reload(lua)?;
+
         Ok(())
     }
 }
@@ -64,7 +64,16 @@ use super::helper;
 { "version": "1.0.0", "name": "alicia.font", "info": "The font API.", "head": true }
 */
 #[rustfmt::skip]
-pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, script_info: Option<&ScriptInfo>) -> mlua::Result<()> {
+    // part of head API. only run on head API pass, if we are running in head mode.
+    if let Some(info) = script_info {
+        if !info.head {
+            return Ok(())
+        }
+    } else {
+        return Ok(())
+    }
+
     let font = lua.create_table()?;
 
     font.set("new_default",         lua.create_function(self::LuaFont::new_default)?)?;     // GetFontDefault
@@ -48,7 +48,9 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::mpsc::Receiver;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::script::*;
 use crate::status::*;
@@ -64,6 +66,24 @@ use sysinfo::System;
 
 //================================================================
 
+// time-stamp to count up from, in head-less mode.
+static TIME: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+// standard input line queue. the thread reading the standard input is only made on first use.
+static STANDARD_INPUT: LazyLock<Mutex<Receiver<String>>> = LazyLock::new(|| {
+    let (send, receive) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines().map_while(Result::ok) {
+            if send.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    Mutex::new(receive)
+});
+
 /* class
 { "version": "1.0.0", "name": "alicia.general", "info": "The general API." }
 */
@@ -71,16 +91,18 @@ use sysinfo::System;
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
     let general = lua.create_table()?;
 
-    general.set("load_base",       lua.create_function(self::load_base)?)?;
-    general.set("set_log_level",   lua.create_function(self::set_log_level)?)?;
-    general.set("open_link",       lua.create_function(self::open_link)?)?;
-    general.set("standard_input",  lua.create_function(self::standard_input)?)?;
-    general.set("get_frame_time",  lua.create_function(self::get_frame_time)?)?;
-    general.set("get_frame_rate",  lua.create_function(self::get_frame_rate)?)?;
-    general.set("set_frame_rate",  lua.create_function(self::set_frame_rate)?)?;
-    general.set("get_time",        lua.create_function(self::get_time)?)?;
-    general.set("get_time_unix",   lua.create_function(self::get_time_unix)?)?;
-    general.set("get_argument",    lua.create_function(self::get_argument)?)?;
+    general.set("load_base",          lua.create_function(self::load_base)?)?;
+    general.set("set_log_level",      lua.create_function(self::set_log_level)?)?;
+    general.set("open_link",          lua.create_function(self::open_link)?)?;
+    general.set("standard_input",     lua.create_function(self::standard_input)?)?;
+    general.set("get_standard_input", lua.create_function(self::get_standard_input)?)?;
+    general.set("get_frame_time",     lua.create_function(self::get_frame_time)?)?;
+    general.set("get_frame_rate",     lua.create_function(self::get_frame_rate)?)?;
+    general.set("set_frame_rate",     lua.create_function(self::set_frame_rate)?)?;
+    general.set("get_time",           lua.create_function(self::get_time)?)?;
+    general.set("get_time_unix",      lua.create_function(self::get_time_unix)?)?;
+    general.set("sleep",              lua.create_async_function(self::sleep)?)?;
+    general.set("get_argument",       lua.create_function(self::get_argument)?)?;
 
     #[cfg(feature = "system_info")]
     general.set("get_system", lua.create_function(self::get_system)?)?;
@@ -112,6 +134,24 @@ fn standard_input(_: &Lua, _: ()) -> mlua::Result<String> {
     Ok(buffer.trim().to_string())
 }
 
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.general.get_standard_input",
+    "info": "Get the next line of the standard input, without blocking. Lines are read in the background, so this should not be used alongside standard_input.",
+    "result": [
+        { "name": "input", "info": "The next line of the standard input, or nil if no line is available.", "kind": "string?" }
+    ]
+}
+*/
+fn get_standard_input(_: &Lua, _: ()) -> mlua::Result<Option<String>> {
+    let receive = STANDARD_INPUT
+        .lock()
+        .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+    Ok(receive.try_recv().ok().map(|x| x.trim().to_string()))
+}
+
 /* entry
 {
     "version": "1.0.0",
@@ -122,11 +162,12 @@ fn standard_input(_: &Lua, _: ()) -> mlua::Result<String> {
 fn load_base(lua: &Lua, _: ()) -> mlua::Result<()> {
     // TO-DO only for debug. do not re-load from disk on release.
     for base in crate::script::Script::FILE_BASE {
-        // load the base library from disk if using a debug build.
+        // load the base library from disk if using a debug build, and if the source is in reach.
         let data = if cfg!(debug_assertions) {
-            &std::fs::read_to_string(format!("../source/lua/{}", base.name)).unwrap()
+            std::fs::read_to_string(format!("../source/lua/{}", base.name))
+                .unwrap_or(base.data.to_string())
         } else {
-            base.data
+            base.data.to_string()
         };
 
         lua.load(data).set_name(format!("@{}", base.name)).exec()?;
@@ -172,14 +213,39 @@ fn open_link(_: &Lua, link: String) -> mlua::Result<()> {
 /* entry
 {
     "version": "1.0.0", "name": "alicia.general.get_time",
-    "info": "Get the current time. Will count up since the initialization of the window.",
+    "info": "Get the current time. Will count up since the initialization of the window, or since the first call in head-less mode.",
     "result": [
         { "name": "time", "info": "Current time.", "kind": "number" }
     ]
 }
 */
 fn get_time(_: &Lua, _: ()) -> mlua::Result<f64> {
-    unsafe { Ok(GetTime()) }
+    unsafe {
+        if IsWindowReady() {
+            Ok(GetTime())
+        } else {
+            Ok(TIME.elapsed().as_secs_f64())
+        }
+    }
+}
+
+/* entry
+{
+    "version": "1.0.0", "name": "alicia.general.sleep",
+    "info": "Wait for a given amount of wall-clock time. Every background task (socket, request, etc.) will keep running in the mean-time.",
+    "member": [
+        { "name": "time", "info": "Time to wait for, in seconds.", "kind": "number" }
+    ],
+    "test": "socket/TCP.lua"
+}
+*/
+async fn sleep(_: Lua, time: f32) -> mlua::Result<()> {
+    let time = Duration::try_from_secs_f32(time)
+        .map_err(|_| mlua::Error::runtime("alicia.general.sleep(): Invalid time."))?;
+
+    tokio::time::sleep(time).await;
+
+    Ok(())
 }
 
 /* entry
@@ -208,7 +274,13 @@ fn get_time_unix(_: &Lua, add: Option<i64>) -> mlua::Result<String> {
     ]
 }
 */
-fn get_frame_time(_: &Lua, _: ()) -> mlua::Result<f32> {
+fn get_frame_time(lua: &Lua, _: ()) -> mlua::Result<f32> {
+    if let Some(script_data) = lua.app_data_ref::<ScriptData>()
+        && !script_data.script_info.head
+    {
+        return Ok(script_data.frame_time);
+    }
+
     unsafe { Ok(GetFrameTime()) }
 }
 
@@ -221,8 +293,24 @@ fn get_frame_time(_: &Lua, _: ()) -> mlua::Result<f32> {
     ]
 }
 */
-fn get_frame_rate(_: &Lua, _: ()) -> mlua::Result<i32> {
-    unsafe { Ok(GetFPS()) }
+fn get_frame_rate(lua: &Lua, _: ()) -> mlua::Result<i32> {
+    if let Some(script_data) = lua.app_data_ref::<ScriptData>()
+        && !script_data.script_info.head
+    {
+        if script_data.frame_time > 0.0 {
+            return Ok((1.0 / script_data.frame_time).round() as i32);
+        }
+
+        return Ok(0);
+    }
+
+    unsafe {
+        if IsWindowReady() {
+            Ok(GetFPS())
+        } else {
+            Ok(0)
+        }
+    }
 }
 
 /* entry
@@ -234,11 +322,21 @@ fn get_frame_rate(_: &Lua, _: ()) -> mlua::Result<i32> {
     ]
 }
 */
-fn set_frame_rate(_: &Lua, rate: i32) -> mlua::Result<()> {
+fn set_frame_rate(lua: &Lua, rate: i32) -> mlua::Result<()> {
+    // the tick rate in head-less mode. the script data is only set once main.lua has run, so a
+    // call made from main.lua itself will fall back to the window's target frame rate.
+    if let Some(mut script_data) = lua.app_data_mut::<ScriptData>()
+        && !script_data.script_info.head
+    {
+        script_data.frame_rate = rate.max(1) as u32;
+        return Ok(());
+    }
+
     unsafe {
         SetTargetFPS(rate);
-        Ok(())
     }
+
+    Ok(())
 }
 
 /* entry
@@ -63,6 +63,8 @@ pub mod image;
 pub mod input;
 pub mod model;
 pub mod music;
+pub mod net;
+pub mod profiler;
 pub mod shader;
 pub mod socket;
 pub mod sound;
@@ -72,6 +74,12 @@ pub mod window;
 #[cfg(feature = "rapier3d")]
 pub mod rapier;
 
+#[cfg(feature = "rapier2d")]
+pub mod rapier_2d;
+
+#[cfg(any(feature = "rapier3d", feature = "rapier2d"))]
+pub mod rapier_filter;
+
 #[cfg(feature = "zip")]
 pub mod zip;
 
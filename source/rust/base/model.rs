@@ -54,6 +54,7 @@ use crate::status::*;
 //================================================================
 
 use crate::base::helper::*;
+use crate::base::profiler::*;
 use mlua::prelude::*;
 
 //================================================================
@@ -62,7 +63,16 @@ use mlua::prelude::*;
 { "version": "1.0.0", "name": "alicia.model", "info": "The model API.", "head": true }
 */
 #[rustfmt::skip]
-pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, script_info: Option<&ScriptInfo>) -> mlua::Result<()> {
+    // part of head API. only run on head API pass, if we are running in head mode.
+    if let Some(info) = script_info {
+        if !info.head {
+            return Ok(())
+        }
+    } else {
+        return Ok(())
+    }
+
     let model = lua.create_table()?;
 
     model.set("new", lua.create_function(self::LuaModel::new)?)?;
@@ -90,7 +100,7 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
     ]
 }
 */
-struct LuaModel(Model);
+pub struct LuaModel(pub Model);
 
 unsafe impl Send for LuaModel {}
 
@@ -135,6 +145,7 @@ impl LuaModel {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Self> {
+        let _scope = ProfileScope::new("model.new", "asset");
         let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
 
         unsafe {
@@ -489,6 +500,7 @@ impl LuaModelAnimation {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Vec<Self>> {
+        let _scope = ProfileScope::new("model_animation.new", "asset");
         let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
 
         unsafe {
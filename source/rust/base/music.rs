@@ -62,7 +62,16 @@ use mlua::prelude::*;
 { "version": "1.0.0", "name": "alicia.music", "info": "The music API.", "head": true }
 */
 #[rustfmt::skip]
-pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, script_info: Option<&ScriptInfo>) -> mlua::Result<()> {
+    // part of head API. only run on head API pass, if we are running in head mode.
+    if let Some(info) = script_info {
+        if !info.head {
+            return Ok(())
+        }
+    } else {
+        return Ok(())
+    }
+
     let music = lua.create_table()?;
 
     music.set("new",             lua.create_async_function(self::LuaMusic::new)?)?;
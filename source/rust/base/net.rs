@@ -0,0 +1,980 @@
+/*
+* Copyright (c) 2025 luxreduxdelux
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::data::Data;
+use crate::base::socket::Simulate;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::net::UdpSocket;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "alicia.net", "info": "The net API. A connection-based transport on top of UDP, with reliable and unreliable channels." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let net = lua.create_table()?;
+
+    net.set("new", lua.create_async_function(self::Net::new)?)?;
+
+    table.set("net", net)?;
+
+    Ok(())
+}
+
+// protocol identifier at the start of every packet. any other datagram is ignored.
+const PROTOCOL: [u8; 4] = *b"ALNT";
+// size of the protocol identifier and the packet kind.
+const HEADER: usize = 5;
+// size of the channel, sequence, fragment index and fragment count of a message packet.
+const HEADER_MESSAGE: usize = 9;
+// largest payload of a message packet. any larger message is split into fragments.
+const FRAGMENT: usize = 1024;
+// largest datagram size read from the socket.
+const DATAGRAM: usize = 2048;
+// time between each handshake request, and between each ping once connected.
+const INTERVAL: Duration = Duration::from_millis(250);
+// shortest and longest time to wait for an acknowledgement before a resend.
+const RESEND_MINIMUM: Duration = Duration::from_millis(50);
+const RESEND_MAXIMUM: Duration = Duration::from_millis(1000);
+// time to keep an incomplete unreliable message before dropping it.
+const FRAGMENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+// packet kind.
+const PACKET_CONNECT: u8 = 0;
+const PACKET_ACCEPT: u8 = 1;
+const PACKET_DISCONNECT: u8 = 2;
+const PACKET_MESSAGE: u8 = 3;
+const PACKET_ACK: u8 = 4;
+const PACKET_PING: u8 = 5;
+const PACKET_PONG: u8 = 6;
+
+// channel kind, matching NET_CHANNEL in constant.lua.
+const CHANNEL_RELIABLE_ORDERED: i32 = 0;
+const CHANNEL_RELIABLE_UNORDERED: i32 = 1;
+const CHANNEL_UNRELIABLE: i32 = 2;
+
+// peer state, matching SOCKET_STATE in constant.lua.
+const STATE_CONNECTING: i32 = 0;
+const STATE_CONNECT: i32 = 1;
+const STATE_DISCONNECT: i32 = 2;
+
+//================================================================
+
+// an event raised by the transport, drained by net:poll.
+enum NetEvent {
+    Connect(usize, SocketAddr),
+    Message(usize, usize, Vec<u8>),
+    Disconnect(usize, &'static str),
+}
+
+impl NetEvent {
+    // the kind of each event, matching SOCKET_EVENT in constant.lua.
+    fn to_table(self, lua: &Lua) -> mlua::Result<LuaTable> {
+        let table = lua.create_table()?;
+
+        match self {
+            Self::Connect(peer, address) => {
+                table.set("kind", 0)?;
+                table.set("peer", peer)?;
+                table.set("address", address.to_string())?;
+            }
+            Self::Message(peer, channel, data) => {
+                table.set("kind", 1)?;
+                table.set("peer", peer)?;
+                table.set("channel", channel)?;
+                table.set("data", Data::new(lua, data)?)?;
+            }
+            Self::Disconnect(peer, info) => {
+                table.set("kind", 2)?;
+                table.set("peer", peer)?;
+                table.set("info", info)?;
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "net_info",
+    "info": "A table describing the configuration of a net transport. Every field is optional. Both ends of a connection should use the same channel list.",
+    "member": [
+        { "name": "listen",          "info": "Accept incoming connections. Default: false.",                                                       "kind": "boolean?" },
+        { "name": "peer_maximum",    "info": "Largest number of connected peers. Default: 32.",                                                    "kind": "number?"  },
+        { "name": "timeout",         "info": "Time (in seconds) without hearing from a peer before disconnecting it. Default: 10.0.",              "kind": "number?"  },
+        { "name": "message_maximum", "info": "Largest message size. Default: 1048576.",                                                            "kind": "number?"  },
+        { "name": "channel",         "info": "Kind of each channel (net_channel). Default: { RELIABLE_ORDERED, RELIABLE_UNORDERED, UNRELIABLE }.", "kind": "table?"   }
+    ]
+}
+*/
+#[derive(Deserialize)]
+#[serde(default)]
+struct NetInfo {
+    listen: bool,
+    peer_maximum: usize,
+    timeout: f32,
+    message_maximum: usize,
+    channel: Vec<i32>,
+}
+
+impl Default for NetInfo {
+    fn default() -> Self {
+        Self {
+            listen: false,
+            peer_maximum: 32,
+            timeout: 10.0,
+            message_maximum: 1048576,
+            channel: vec![
+                CHANNEL_RELIABLE_ORDERED,
+                CHANNEL_RELIABLE_UNORDERED,
+                CHANNEL_UNRELIABLE,
+            ],
+        }
+    }
+}
+
+//================================================================
+
+struct Channel {
+    kind: i32,
+    // sequence of the next message to send.
+    send: u32,
+    // every sequence before this one has been received.
+    base: u32,
+    // received sequences past the base, for an unordered channel.
+    receive: HashSet<u32>,
+    // received messages past the base, waiting on an earlier message, for an ordered channel.
+    order: HashMap<u32, Vec<u8>>,
+}
+
+impl Channel {
+    fn new(kind: i32) -> Self {
+        Self {
+            kind,
+            send: 0,
+            base: 0,
+            receive: HashSet::new(),
+            order: HashMap::new(),
+        }
+    }
+
+    fn is_reliable(&self) -> bool {
+        self.kind != CHANNEL_UNRELIABLE
+    }
+
+    // check if a message has been received already. sequences are compared with wrap-around.
+    fn is_receive(&self, sequence: u32) -> bool {
+        (sequence.wrapping_sub(self.base) as i32) < 0
+            || self.receive.contains(&sequence)
+            || self.order.contains_key(&sequence)
+    }
+
+    // receive a complete message, and return every message that is ready to be delivered.
+    fn receive(&mut self, sequence: u32, data: Vec<u8>) -> Vec<Vec<u8>> {
+        match self.kind {
+            CHANNEL_RELIABLE_ORDERED => {
+                let mut list = Vec::new();
+
+                self.order.insert(sequence, data);
+
+                while let Some(data) = self.order.remove(&self.base) {
+                    list.push(data);
+                    self.base = self.base.wrapping_add(1);
+                }
+
+                list
+            }
+            CHANNEL_RELIABLE_UNORDERED => {
+                self.receive.insert(sequence);
+
+                while self.receive.remove(&self.base) {
+                    self.base = self.base.wrapping_add(1);
+                }
+
+                vec![data]
+            }
+            _ => vec![data],
+        }
+    }
+}
+
+// a message in the middle of being re-assembled.
+struct Fragment {
+    list: Vec<Option<Vec<u8>>>,
+    count: usize,
+    time: Instant,
+}
+
+// a reliable packet waiting on an acknowledgement.
+struct Pending {
+    packet: Vec<u8>,
+    time: Instant,
+    count: u32,
+}
+
+struct Peer {
+    address: SocketAddr,
+    state: i32,
+    // connection token, picked by the side that made the handshake request. a new token from
+    // the same address means the other end has restarted.
+    token: u32,
+    // true if the other end made the handshake request.
+    accept: bool,
+    channel: Vec<Channel>,
+    fragment: HashMap<(u8, u32), Fragment>,
+    pending: HashMap<(u8, u32, u16), Pending>,
+    // time of the last packet received from this peer.
+    receive: Instant,
+    // time of the last handshake request or ping sent to this peer.
+    ping: Instant,
+    rtt: Option<f32>,
+    loss: f32,
+    byte_send: usize,
+    byte_receive: usize,
+}
+
+impl Peer {
+    fn new(address: SocketAddr, state: i32, token: Option<u32>, channel: &[i32]) -> Self {
+        let time = Instant::now();
+
+        Self {
+            address,
+            state,
+            token: token.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|time| time.subsec_nanos() ^ time.as_secs() as u32)
+                    .unwrap_or_default()
+            }),
+            accept: token.is_some(),
+            channel: channel.iter().map(|kind| Channel::new(*kind)).collect(),
+            fragment: HashMap::new(),
+            pending: HashMap::new(),
+            receive: time,
+            ping: time,
+            rtt: None,
+            loss: 0.0,
+            byte_send: 0,
+            byte_receive: 0,
+        }
+    }
+
+    fn handshake(&self) -> Vec<u8> {
+        let mut packet = packet(PACKET_CONNECT);
+        packet.extend_from_slice(&self.token.to_be_bytes());
+        packet
+    }
+
+    fn send(
+        &mut self,
+        socket: &Arc<UdpSocket>,
+        simulate: &mut Option<Simulate>,
+        packet: &[u8],
+    ) {
+        let result = match simulate {
+            Some(simulate) => simulate.send(socket, packet, Some(self.address)),
+            None => socket.try_send_to(packet, self.address),
+        };
+
+        // a failed send is the same as a lost packet; reliable packets will be sent again.
+        if let Ok(length) = result {
+            self.byte_send += length;
+        }
+    }
+
+    // time to wait for an acknowledgement before a resend.
+    fn resend_time(&self) -> Duration {
+        Duration::from_secs_f32(self.rtt.unwrap_or(0.1) * 1.5).clamp(RESEND_MINIMUM, RESEND_MAXIMUM)
+    }
+
+    // receive a message fragment, and return every message that is ready to be delivered.
+    fn receive_message(
+        &mut self,
+        channel: u8,
+        sequence: u32,
+        index: u16,
+        count: u16,
+        payload: &[u8],
+        time: Instant,
+    ) -> Vec<Vec<u8>> {
+        let entry = &mut self.channel[channel as usize];
+
+        if entry.is_reliable() && entry.is_receive(sequence) {
+            return Vec::new();
+        }
+
+        let data = if count == 1 {
+            payload.to_vec()
+        } else {
+            let fragment = self
+                .fragment
+                .entry((channel, sequence))
+                .or_insert_with(|| Fragment {
+                    list: vec![None; count as usize],
+                    count: 0,
+                    time,
+                });
+
+            if fragment.list.len() != count as usize {
+                return Vec::new();
+            }
+
+            if fragment.list[index as usize].is_none() {
+                fragment.list[index as usize] = Some(payload.to_vec());
+                fragment.count += 1;
+            }
+
+            if fragment.count < fragment.list.len() {
+                return Vec::new();
+            }
+
+            match self.fragment.remove(&(channel, sequence)) {
+                Some(fragment) => fragment.list.into_iter().flatten().flatten().collect(),
+                None => return Vec::new(),
+            }
+        };
+
+        entry.receive(sequence, data)
+    }
+}
+
+//================================================================
+
+fn packet(kind: u8) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(HEADER + HEADER_MESSAGE + FRAGMENT);
+    packet.extend_from_slice(&PROTOCOL);
+    packet.push(kind);
+    packet
+}
+
+fn read_u16(data: &[u8], index: usize) -> u16 {
+    u16::from_be_bytes([data[index], data[index + 1]])
+}
+
+fn read_u32(data: &[u8], index: usize) -> u32 {
+    u32::from_be_bytes([data[index], data[index + 1], data[index + 2], data[index + 3]])
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "net", "info": "An unique handle to a net transport in memory. The same transport can both connect to other transports, and accept connections from other transports. Every connection is given a peer number. Every event must be drained with poll, once per frame." }
+*/
+struct Net {
+    socket: Arc<UdpSocket>,
+    info: NetInfo,
+    // the validated time-out of the net info.
+    timeout: Duration,
+    peer: HashMap<usize, Peer>,
+    address: HashMap<SocketAddr, usize>,
+    index: usize,
+    event: Vec<NetEvent>,
+    buffer: Vec<u8>,
+    // time of creation, used for ping time-stamps.
+    epoch: Instant,
+    simulate: Option<Simulate>,
+}
+
+impl mlua::UserData for Net {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:connect",
+            "info": "Connect to another transport. The handshake is made in the background, and a connect event will be raised once accepted.",
+            "member": [
+                { "name": "address", "info": "Address to connect to.", "kind": "string" }
+            ],
+            "result": [
+                { "name": "peer", "info": "Peer number.", "kind": "number" }
+            ],
+            "routine": true,
+            "test": "net/connect.lua"
+        }
+        */
+        method.add_async_method_mut("connect", |_: Lua, mut this, address: String| async move {
+            let address = tokio::net::lookup_host(address)
+                .await?
+                .next()
+                .ok_or_else(|| mlua::Error::runtime("net:connect(): Could not resolve address."))?;
+
+            if this.address.contains_key(&address) {
+                return Err(mlua::Error::runtime(
+                    "net:connect(): Already connected to address.",
+                ));
+            }
+
+            let index = this.insert(address, STATE_CONNECTING, None);
+            let this = &mut *this;
+
+            if let Some(peer) = this.peer.get_mut(&index) {
+                let packet = peer.handshake();
+                peer.send(&this.socket, &mut this.simulate, &packet);
+            }
+
+            Ok(index)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:poll",
+            "info": "Receive every pending packet, send any acknowledgement, ping or resend that is due, and drain every event raised since the last call. Each event has a kind (socket_event), a peer number, and depending on the kind, an address (connect), a channel number and data buffer (message), or a reason (disconnect: \"close\", \"reject\" or \"timeout\"). This call does not block.",
+            "result": [
+                { "name": "event_list", "info": "Event list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            while let Ok((read, address)) = this.socket.try_recv_from(&mut this.buffer) {
+                let data = this.buffer[..read].to_vec();
+
+                this.receive(address, &data);
+            }
+
+            this.update();
+
+            let list = lua.create_table()?;
+
+            for event in this.event.drain(..) {
+                list.push(event.to_table(lua)?)?;
+            }
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:set",
+            "info": "Send a message to a peer. Any message larger than the packet size is split into fragments, and re-assembled on the other end. Messages sent while connecting are resent until connected, if the channel is reliable. This call does not block.",
+            "member": [
+                { "name": "peer",    "info": "Peer number.",     "kind": "number"        },
+                { "name": "channel", "info": "Channel number.",  "kind": "number"        },
+                { "name": "data",    "info": "Message to send.", "kind": "string | data" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set",
+            |_, this, (peer, channel, data): (usize, usize, LuaValue)| {
+                let data = Data::get_byte(data)?;
+
+                if data.len() > this.info.message_maximum {
+                    return Err(mlua::Error::runtime(
+                        "net:set(): Message is larger than the message maximum.",
+                    ));
+                }
+
+                let Some(entry) = this.peer.get_mut(&peer) else {
+                    return Err(mlua::Error::runtime("net:set(): Peer is disconnected."));
+                };
+
+                let Some(slot) = entry.channel.get_mut(channel) else {
+                    return Err(mlua::Error::runtime("net:set(): Invalid channel number."));
+                };
+
+                let reliable = slot.is_reliable();
+                let sequence = slot.send;
+                slot.send = slot.send.wrapping_add(1);
+
+                let count = data.len().div_ceil(FRAGMENT).max(1);
+                let time = Instant::now();
+
+                for index in 0..count {
+                    let payload = &data[(index * FRAGMENT).min(data.len())
+                        ..((index + 1) * FRAGMENT).min(data.len())];
+
+                    let mut packet = packet(PACKET_MESSAGE);
+                    packet.push(channel as u8);
+                    packet.extend_from_slice(&sequence.to_be_bytes());
+                    packet.extend_from_slice(&(index as u16).to_be_bytes());
+                    packet.extend_from_slice(&(count as u16).to_be_bytes());
+                    packet.extend_from_slice(payload);
+
+                    entry.send(&this.socket, &mut this.simulate, &packet);
+
+                    if reliable {
+                        entry.pending.insert(
+                            (channel as u8, sequence, index as u16),
+                            Pending {
+                                packet,
+                                time,
+                                count: 1,
+                            },
+                        );
+                    }
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:disconnect",
+            "info": "Disconnect from a peer. The peer is notified, but any message waiting on an acknowledgement is dropped. No disconnect event is raised for this peer.",
+            "member": [
+                { "name": "peer", "info": "Peer number.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("disconnect", |_, this, peer: usize| {
+            if let Some(mut entry) = this.remove(peer) {
+                entry.send(&this.socket, &mut this.simulate, &packet(PACKET_DISCONNECT));
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:get_state",
+            "info": "Get the connection state of a peer, as of the last poll call.",
+            "member": [
+                { "name": "peer", "info": "Peer number.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "state", "info": "Connection state.", "kind": "socket_state" }
+            ]
+        }
+        */
+        method.add_method("get_state", |_, this, peer: usize| {
+            Ok(this
+                .peer
+                .get(&peer)
+                .map(|peer| peer.state)
+                .unwrap_or(STATE_DISCONNECT))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:get_address",
+            "info": "Get the address of a peer.",
+            "member": [
+                { "name": "peer", "info": "Peer number.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "address", "info": "Peer address.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method("get_address", |_, this, peer: usize| {
+            if let Some(peer) = this.peer.get(&peer) {
+                return Ok(peer.address.to_string());
+            }
+
+            Err(mlua::Error::runtime("net:get_address(): Peer is disconnected."))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:get_peer_list",
+            "info": "Get every peer number, connected or connecting.",
+            "result": [
+                { "name": "peer_list", "info": "Peer number list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method("get_peer_list", |_, this, _: ()| {
+            let mut list: Vec<usize> = this.peer.keys().copied().collect();
+            list.sort();
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:get_stat",
+            "info": "Get the connection statistics of a peer.",
+            "member": [
+                { "name": "peer", "info": "Peer number.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "rtt",          "info": "Round-trip time, in seconds. 0.0 if not yet measured.",    "kind": "number" },
+                { "name": "loss",         "info": "Estimated packet loss, from 0.0 to 1.0.",                  "kind": "number" },
+                { "name": "byte_send",    "info": "Total byte count sent to the peer, including any resend.", "kind": "number" },
+                { "name": "byte_receive", "info": "Total byte count received from the peer.",                 "kind": "number" }
+            ]
+        }
+        */
+        method.add_method("get_stat", |_, this, peer: usize| {
+            if let Some(peer) = this.peer.get(&peer) {
+                return Ok((
+                    peer.rtt.unwrap_or_default(),
+                    peer.loss,
+                    peer.byte_send,
+                    peer.byte_receive,
+                ));
+            }
+
+            Err(mlua::Error::runtime("net:get_stat(): Peer is disconnected."))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "net:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every packet sent, including any acknowledgement, ping and resend, goes through the simulation. Every delayed packet is sent in the background once due. Any packet still delayed by the last simulation is dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "net/simulate.lua"
+        }
+        */
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
+
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
+        });
+    }
+}
+
+impl Net {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.net.new",
+        "info": "Create a net transport.",
+        "member": [
+            { "name": "address", "info": "Address to bind to. Use \"0.0.0.0:0\" for a client.",                        "kind": "string"    },
+            { "name": "info",    "info": "OPTIONAL: Transport configuration. Any missing field will use its default.", "kind": "net_info?" }
+        ],
+        "result": [
+            { "name": "net", "info": "Net transport.", "kind": "net" }
+        ],
+        "routine": true,
+        "test": "net/connect.lua"
+    }
+    */
+    async fn new(lua: Lua, (address, info): (String, Option<LuaValue>)) -> mlua::Result<Self> {
+        let info: NetInfo = if let Some(info) = info {
+            lua.from_value(info)?
+        } else {
+            NetInfo::default()
+        };
+
+        if info.channel.len() > u8::MAX as usize
+            || info.channel.iter().any(|kind| !(0..=CHANNEL_UNRELIABLE).contains(kind))
+        {
+            return Err(mlua::Error::runtime("alicia.net.new(): Invalid channel list."));
+        }
+
+        if info.message_maximum > u16::MAX as usize * FRAGMENT {
+            return Err(mlua::Error::runtime("alicia.net.new(): Message maximum is too large."));
+        }
+
+        let timeout = Duration::try_from_secs_f32(info.timeout)
+            .map_err(|_| mlua::Error::runtime("alicia.net.new(): Invalid time-out."))?;
+
+        let socket = UdpSocket::bind(address).await?;
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            info,
+            timeout,
+            peer: HashMap::new(),
+            address: HashMap::new(),
+            index: 1,
+            event: Vec::new(),
+            buffer: vec![0; DATAGRAM],
+            epoch: Instant::now(),
+            simulate: None,
+        })
+    }
+
+    fn insert(&mut self, address: SocketAddr, state: i32, token: Option<u32>) -> usize {
+        let index = self.index;
+        self.index += 1;
+
+        self.peer
+            .insert(index, Peer::new(address, state, token, &self.info.channel));
+        self.address.insert(address, index);
+
+        index
+    }
+
+    fn remove(&mut self, index: usize) -> Option<Peer> {
+        let peer = self.peer.remove(&index)?;
+        self.address.remove(&peer.address);
+
+        Some(peer)
+    }
+
+    // time-stamp in milliseconds, for a ping.
+    fn stamp(&self) -> u32 {
+        self.epoch.elapsed().as_millis() as u32
+    }
+
+    // handle a datagram from an address.
+    fn receive(&mut self, address: SocketAddr, data: &[u8]) {
+        if data.len() < HEADER || data[..PROTOCOL.len()] != PROTOCOL {
+            return;
+        }
+
+        let kind = data[PROTOCOL.len()];
+        let body = &data[HEADER..];
+        let time = Instant::now();
+
+        let Some(index) = self.address.get(&address).copied() else {
+            // unknown address: only a handshake request is valid.
+            if kind == PACKET_CONNECT && body.len() >= 4 {
+                if self.info.listen && self.peer.len() < self.info.peer_maximum {
+                    let index = self.insert(address, STATE_CONNECT, Some(read_u32(body, 0)));
+
+                    if let Some(peer) = self.peer.get_mut(&index) {
+                        peer.byte_receive += data.len();
+                        peer.send(&self.socket, &mut self.simulate, &packet(PACKET_ACCEPT));
+                    }
+
+                    self.event.push(NetEvent::Connect(index, address));
+                } else {
+                    let packet = packet(PACKET_DISCONNECT);
+
+                    let _ = match &mut self.simulate {
+                        Some(simulate) => simulate.send(&self.socket, &packet, Some(address)),
+                        None => self.socket.try_send_to(&packet, address),
+                    };
+                }
+            }
+
+            return;
+        };
+
+        let stamp = self.stamp();
+
+        let Some(peer) = self.peer.get_mut(&index) else {
+            return;
+        };
+
+        // a handshake request with a new token: drop the old connection, and start over.
+        if kind == PACKET_CONNECT
+            && peer.accept
+            && body.len() >= 4
+            && read_u32(body, 0) != peer.token
+        {
+            self.remove(index);
+            self.event.push(NetEvent::Disconnect(index, "close"));
+            self.receive(address, data);
+
+            return;
+        }
+
+        peer.receive = time;
+        peer.byte_receive += data.len();
+
+        if kind == PACKET_DISCONNECT {
+            let info = if peer.state == STATE_CONNECTING {
+                "reject"
+            } else {
+                "close"
+            };
+
+            self.remove(index);
+            self.event.push(NetEvent::Disconnect(index, info));
+
+            return;
+        }
+
+        // any packet means the other end has accepted us, even if the accept packet itself was
+        // lost. a handshake request means both ends are connecting to each other at once.
+        if peer.state == STATE_CONNECTING {
+            peer.state = STATE_CONNECT;
+            peer.ping = time;
+            self.event.push(NetEvent::Connect(index, address));
+        }
+
+        match kind {
+            PACKET_CONNECT => peer.send(&self.socket, &mut self.simulate, &packet(PACKET_ACCEPT)),
+            PACKET_MESSAGE => {
+                if body.len() < HEADER_MESSAGE {
+                    return;
+                }
+
+                let channel = body[0];
+                let sequence = read_u32(body, 1);
+                let fragment = read_u16(body, 5);
+                let count = read_u16(body, 7);
+                let payload = &body[HEADER_MESSAGE..];
+
+                if channel as usize >= peer.channel.len()
+                    || fragment >= count
+                    || count as usize > self.info.message_maximum.div_ceil(FRAGMENT).max(1)
+                {
+                    return;
+                }
+
+                if peer.channel[channel as usize].is_reliable() {
+                    let mut packet = packet(PACKET_ACK);
+                    packet.extend_from_slice(&body[..7]);
+                    peer.send(&self.socket, &mut self.simulate, &packet);
+                }
+
+                let list = peer.receive_message(channel, sequence, fragment, count, payload, time);
+
+                for data in list {
+                    self.event.push(NetEvent::Message(index, channel as usize, data));
+                }
+            }
+            PACKET_ACK => {
+                if body.len() < 7 {
+                    return;
+                }
+
+                let key = (body[0], read_u32(body, 1), read_u16(body, 5));
+
+                if let Some(pending) = peer.pending.remove(&key) {
+                    let sample = (pending.count - 1) as f32 / pending.count as f32;
+                    peer.loss = peer.loss * 0.95 + sample * 0.05;
+                }
+            }
+            PACKET_PING => {
+                if body.len() < 4 {
+                    return;
+                }
+
+                let mut packet = packet(PACKET_PONG);
+                packet.extend_from_slice(&body[..4]);
+                peer.send(&self.socket, &mut self.simulate, &packet);
+            }
+            PACKET_PONG => {
+                if body.len() < 4 {
+                    return;
+                }
+
+                let sample = stamp.wrapping_sub(read_u32(body, 0)) as f32 / 1000.0;
+
+                peer.rtt = Some(match peer.rtt {
+                    Some(rtt) => rtt * 0.9 + sample * 0.1,
+                    None => sample,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // send every handshake request, ping and resend that is due, and time-out any silent peer.
+    fn update(&mut self) {
+        let time = Instant::now();
+        let stamp = self.stamp();
+        let mut remove = Vec::new();
+
+        for (index, peer) in self.peer.iter_mut() {
+            if time.duration_since(peer.receive) > self.timeout {
+                remove.push(*index);
+                continue;
+            }
+
+            if time.duration_since(peer.ping) >= INTERVAL {
+                peer.ping = time;
+
+                if peer.state == STATE_CONNECTING {
+                    let packet = peer.handshake();
+                    peer.send(&self.socket, &mut self.simulate, &packet);
+                } else {
+                    let mut packet = packet(PACKET_PING);
+                    packet.extend_from_slice(&stamp.to_be_bytes());
+                    peer.send(&self.socket, &mut self.simulate, &packet);
+                }
+            }
+
+            let resend = peer.resend_time();
+            let mut list = Vec::new();
+
+            for pending in peer.pending.values_mut() {
+                if time.duration_since(pending.time) >= resend {
+                    pending.time = time;
+                    pending.count += 1;
+                    list.push(pending.packet.clone());
+                }
+            }
+
+            for packet in list {
+                peer.send(&self.socket, &mut self.simulate, &packet);
+            }
+
+            // only an unreliable message can be left incomplete for good.
+            peer.fragment.retain(|(channel, _), fragment| {
+                peer.channel[*channel as usize].is_reliable()
+                    || time.duration_since(fragment.time) < FRAGMENT_TIMEOUT
+            });
+        }
+
+        for index in remove {
+            self.remove(index);
+            self.event.push(NetEvent::Disconnect(index, "timeout"));
+        }
+    }
+}
@@ -0,0 +1,406 @@
+/*
+* Copyright (c) 2025 luxreduxdelux
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+//================================================================
+
+// fast check for every scope, so that a scope costs next to nothing while not recording.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PROFILER: LazyLock<Mutex<Profiler>> = LazyLock::new(|| Mutex::new(Profiler::new()));
+
+/* class
+{ "version": "1.0.0", "name": "alicia.profiler", "info": "The profiler API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let profiler = lua.create_table()?;
+
+    profiler.set("begin",     lua.create_function(self::begin)?)?;
+    profiler.set("close",     lua.create_function(self::close)?)?;
+    profiler.set("get_state", lua.create_function(self::get_state)?)?;
+    profiler.set("scope",     lua.create_function(self::scope)?)?;
+    profiler.set("new_frame", lua.create_function(self::new_frame)?)?;
+    profiler.set("get_frame", lua.create_function(self::get_frame)?)?;
+    profiler.set("dump",      lua.create_function(self::dump)?)?;
+
+    table.set("profiler", profiler)?;
+
+    Ok(())
+}
+
+//================================================================
+
+// a single timed event, relative to the profiler epoch.
+struct ProfileEvent {
+    name: String,
+    kind: &'static str,
+    time: Duration,
+    span: Duration,
+}
+
+pub struct Profiler {
+    epoch: Instant,
+    event: VecDeque<ProfileEvent>,
+    // every scope in the current frame, by name: total time, and call count.
+    frame: HashMap<String, (Duration, u32)>,
+    frame_last: Vec<(String, Duration, u32)>,
+    frame_last_time: Duration,
+    frame_time: Instant,
+    // every Lua function that has been called, but has not yet returned.
+    stack: Vec<(String, Instant)>,
+    // every Lua function that has been called past the stack limit, and was not pushed.
+    skip: usize,
+}
+
+impl Profiler {
+    // maximum event count to keep for trace export. the oldest event is dropped first.
+    const EVENT_COUNT: usize = 262144;
+    const STACK_COUNT: usize = 256;
+
+    fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+            event: VecDeque::new(),
+            frame: HashMap::new(),
+            frame_last: Vec::new(),
+            frame_last_time: Duration::ZERO,
+            frame_time: Instant::now(),
+            stack: Vec::new(),
+            skip: 0,
+        }
+    }
+
+    fn record(&mut self, name: String, kind: &'static str, time: Instant, span: Duration) {
+        let entry = self.frame.entry(name.clone()).or_default();
+        entry.0 += span;
+        entry.1 += 1;
+
+        if self.event.len() >= Self::EVENT_COUNT {
+            self.event.pop_front();
+        }
+
+        self.event.push_back(ProfileEvent {
+            name,
+            kind,
+            time: time.saturating_duration_since(self.epoch),
+            span,
+        });
+    }
+
+    // mark the end of a frame. called by alicia.draw.begin, and once per tick in head-less mode.
+    pub fn frame() {
+        if !ACTIVE.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut profiler = PROFILER.lock().unwrap();
+        let time = profiler.frame_time;
+        let span = time.elapsed();
+
+        profiler.record("frame".to_string(), "frame", time, span);
+
+        let mut list: Vec<(String, Duration, u32)> = profiler
+            .frame
+            .drain()
+            .filter(|(name, _)| name != "frame")
+            .map(|(name, (span, count))| (name, span, count))
+            .collect();
+
+        list.sort_by(|a, b| b.1.cmp(&a.1));
+
+        profiler.frame_last = list;
+        profiler.frame_last_time = span;
+        profiler.frame_time = Instant::now();
+    }
+
+    // Lua call/return hook, called by the Lua hook dispatcher.
+    pub fn hook(debug: mlua::Debug) -> mlua::Result<()> {
+        let mut profiler = PROFILER.lock().unwrap();
+
+        match debug.event() {
+            mlua::DebugEvent::Call | mlua::DebugEvent::TailCall => {
+                // a tail call will replace the calling function, which will never return.
+                if matches!(debug.event(), mlua::DebugEvent::TailCall) {
+                    if profiler.skip > 0 {
+                        profiler.skip -= 1;
+                    } else if let Some((name, time)) = profiler.stack.pop() {
+                        profiler.record(name, "lua", time, time.elapsed());
+                    }
+                }
+
+                if profiler.stack.len() >= Self::STACK_COUNT {
+                    profiler.skip += 1;
+                } else {
+                    let source = debug.source();
+                    let name = debug.names().name.unwrap_or("?".into());
+                    let file = source.short_src.unwrap_or("?".into());
+                    let line = source.line_defined.unwrap_or_default();
+                    let name = format!("{name} ({file}:{line})");
+
+                    profiler.stack.push((name, Instant::now()));
+                }
+            }
+            mlua::DebugEvent::Ret => {
+                // only pop a function that was pushed in the first place.
+                if profiler.skip > 0 {
+                    profiler.skip -= 1;
+                } else if let Some((name, time)) = profiler.stack.pop() {
+                    profiler.record(name, "lua", time, time.elapsed());
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+// time a scope, from creation until drop.
+pub struct ProfileScope {
+    name: &'static str,
+    kind: &'static str,
+    time: Option<Instant>,
+}
+
+impl ProfileScope {
+    pub fn new(name: &'static str, kind: &'static str) -> Self {
+        Self {
+            name,
+            kind,
+            time: ACTIVE.load(Ordering::Relaxed).then(Instant::now),
+        }
+    }
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        if let Some(time) = self.time
+            && ACTIVE.load(Ordering::Relaxed)
+        {
+            let mut profiler = PROFILER.lock().unwrap();
+            profiler.record(self.name.to_string(), self.kind, time, time.elapsed());
+        }
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.begin",
+    "info": "Begin recording. Every previous record is cleared. Draw calls, physics steps and asset loads are always recorded.",
+    "test": "profiler/profiler.lua",
+    "member": [
+        { "name": "hook", "info": "OPTIONAL: Also record every Lua function call. This is slow.", "kind": "boolean?" }
+    ]
+}
+*/
+fn begin(lua: &Lua, hook: Option<bool>) -> mlua::Result<()> {
+    *PROFILER.lock().unwrap() = Profiler::new();
+
+    // the call hook is shared with the debug adapter's line hook, if attached.
+    ScriptHook::set(lua, |x| x.profile = hook.unwrap_or_default());
+
+    ACTIVE.store(true, Ordering::Relaxed);
+
+    Ok(())
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.close",
+    "info": "Close recording. Every record is kept, until the next call to begin.",
+    "test": "profiler/profiler.lua"
+}
+*/
+fn close(lua: &Lua, _: ()) -> mlua::Result<()> {
+    ACTIVE.store(false, Ordering::Relaxed);
+
+    ScriptHook::set(lua, |x| x.profile = false);
+
+    Ok(())
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.get_state",
+    "info": "Get the state of the profiler.",
+    "result": [
+        { "name": "state", "info": "True if recording, false otherwise.", "kind": "boolean" }
+    ]
+}
+*/
+fn get_state(_: &Lua, _: ()) -> mlua::Result<bool> {
+    Ok(ACTIVE.load(Ordering::Relaxed))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.scope",
+    "info": "Time a call, as a named scope.",
+    "test": "profiler/profiler.lua",
+    "member": [
+        { "name": "name", "info": "Scope name.",     "kind": "string"   },
+        { "name": "call", "info": "Call to time.",   "kind": "function" },
+        { "name": "...",  "info": "Variadic data.", "kind": "any"      }
+    ],
+    "result": [
+        { "name": "...", "info": "Every value returned by the call.", "kind": "any" }
+    ]
+}
+*/
+fn scope(
+    _: &Lua,
+    (name, call, variadic): (String, mlua::Function, mlua::Variadic<LuaValue>),
+) -> mlua::Result<mlua::MultiValue> {
+    let time = Instant::now();
+    let result = call.call::<mlua::MultiValue>(variadic);
+
+    if ACTIVE.load(Ordering::Relaxed) {
+        let mut profiler = PROFILER.lock().unwrap();
+        profiler.record(name, "scope", time, time.elapsed());
+    }
+
+    result
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.new_frame",
+    "info": "Mark the end of a frame. Called automatically by alicia.draw.begin, and once per tick in head-less mode.",
+    "test": "profiler/profiler.lua"
+}
+*/
+fn new_frame(_: &Lua, _: ()) -> mlua::Result<()> {
+    Profiler::frame();
+
+    Ok(())
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.get_frame",
+    "info": "Get every scope in the last frame, from slowest to fastest. Every time is in milli-seconds.",
+    "test": "profiler/profiler.lua",
+    "result": [
+        { "name": "frame_time", "info": "Frame time.",                                           "kind": "number" },
+        { "name": "list",       "info": "Scope list. Each entry has a name, time and count key.", "kind": "table"  }
+    ]
+}
+*/
+fn get_frame(lua: &Lua, _: ()) -> mlua::Result<(f64, Vec<mlua::Table>)> {
+    let profiler = PROFILER.lock().unwrap();
+    let mut list = Vec::new();
+
+    for (name, span, count) in &profiler.frame_last {
+        let entry = lua.create_table()?;
+
+        entry.set("name", name.as_str())?;
+        entry.set("time", span.as_secs_f64() * 1000.0)?;
+        entry.set("count", *count)?;
+
+        list.push(entry);
+    }
+
+    Ok((profiler.frame_last_time.as_secs_f64() * 1000.0, list))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.profiler.dump",
+    "info": "Write every record out as a Chrome trace-event file, for use with chrome://tracing or Perfetto.",
+    "test": "profiler/profiler.lua",
+    "member": [
+        { "name": "path", "info": "Path to file.", "kind": "string" }
+    ]
+}
+*/
+fn dump(lua: &Lua, path: String) -> mlua::Result<()> {
+    let profiler = PROFILER.lock().unwrap();
+
+    let list: Vec<serde_json::Value> = profiler
+        .event
+        .iter()
+        .map(|event| {
+            json!({
+                "name": event.name,
+                "cat": event.kind,
+                "ph": "X",
+                "ts": event.time.as_secs_f64() * 1000000.0,
+                "dur": event.span.as_secs_f64() * 1000000.0,
+                "pid": 1,
+                "tid": 1,
+            })
+        })
+        .collect();
+
+    let data = json!({ "traceEvents": list, "displayTimeUnit": "ms" });
+
+    std::fs::write(ScriptData::get_path(lua, &path)?, data.to_string())
+        .map_err(|e| mlua::Error::runtime(format!("profiler.dump(): {e}")))
+}
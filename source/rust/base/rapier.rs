@@ -54,14 +54,20 @@ use crate::status::*;
 //================================================================
 
 use crate::base::helper::*;
+use crate::base::profiler::*;
+use crate::base::rapier_filter::alicia_filter;
 use mlua::prelude::*;
 use rapier3d::control::CharacterLength;
 use rapier3d::{
-    control::{CharacterAutostep, KinematicCharacterController},
+    control::{
+        CharacterAutostep, DynamicRayCastVehicleController, KinematicCharacterController,
+        WheelTuning,
+    },
+    data::{Arena, Index},
     parry,
     prelude::*,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
 
 //================================================================
@@ -73,7 +79,8 @@ use std::sync::{Arc, Mutex};
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
     let rapier = lua.create_table()?;
 
-    rapier.set("new", lua.create_function(self::Rapier::new)?)?;
+    rapier.set("new",  lua.create_function(self::Rapier::new)?)?;
+    rapier.set("load", lua.create_function(self::Rapier::load)?)?;
 
     table.set("rapier", rapier)?;
 
@@ -83,9 +90,12 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
 /* class
 { "version": "1.0.0", "name": "rapier", "info": "An unique handle for a Rapier simulation." }
 */
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 struct Rapier {
+    gravity: Vector<f32>,
+    accumulator: f32,
     integration_parameter: IntegrationParameters,
+    #[serde(skip)]
     simulation_pipeline: PhysicsPipeline,
     island_manager: IslandManager,
     broad_phase: DefaultBroadPhase,
@@ -96,7 +106,10 @@ struct Rapier {
     multibody_joint_set: MultibodyJointSet,
     ccd_solver: CCDSolver,
     query_pipeline: QueryPipeline,
+    vehicle_set: Arena<AliciaVehicle>,
+    #[serde(skip)]
     event_handler: AliciaHandler,
+    #[serde(skip)]
     debug_render: DebugRenderPipeline,
 }
 
@@ -112,7 +125,31 @@ impl Rapier {
     }
     */
     fn new(_: &Lua, _: ()) -> mlua::Result<Self> {
-        Ok(Self::default())
+        Ok(Self {
+            gravity: vector![0.0, -9.81, 0.0],
+            ..Default::default()
+        })
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.rapier.load",
+        "info": "Load a Rapier simulation from a data buffer, made with rapier:save.",
+        "member": [
+            { "name": "data", "info": "Data buffer.", "kind": "data" }
+        ],
+        "result": [
+            { "name": "rapier", "info": "Rapier simulation.", "kind": "rapier" }
+        ],
+        "test": "rapier/save_load.lua"
+    }
+    */
+    fn load(_: &Lua, data: LuaValue) -> mlua::Result<Self> {
+        let data = crate::base::data::Data::<u8>::get_buffer(data)?;
+
+        bincode::deserialize(&data.0)
+            .map_err(|e| mlua::Error::runtime(format!("alicia.rapier.load(): {e}")))
     }
 
     fn insert_collider(
@@ -121,6 +158,8 @@ impl Rapier {
         collider: ColliderBuilder,
         rigid_body: Option<LuaValue>,
     ) -> mlua::Result<LuaValue> {
+        // the default for every new solid body, which can later be changed with
+        // set_solid_body_collision_event and set_solid_body_collision_type.
         let collider = collider
             .active_events(ActiveEvents::COLLISION_EVENTS)
             .active_collision_types(ActiveCollisionTypes::all());
@@ -137,6 +176,96 @@ impl Rapier {
             lua.to_value(&self.collider_set.insert(collider))
         }
     }
+
+    fn simulate(&mut self) {
+        // every vehicle is updated by the same time step as the simulation, right before it.
+        for (_, vehicle) in self.vehicle_set.iter_mut() {
+            let chassis = vehicle.controller.chassis;
+
+            // the chassis rigid body was removed, so the vehicle is left as-is.
+            if !self.rigid_body_set.contains(chassis) {
+                continue;
+            }
+
+            vehicle.controller.update_vehicle(
+                self.integration_parameter.dt,
+                &mut self.rigid_body_set,
+                &self.collider_set,
+                &self.query_pipeline,
+                vehicle.filter.query(chassis),
+            );
+        }
+
+        self.simulation_pipeline.step(
+            &self.gravity,
+            &self.integration_parameter,
+            &mut self.island_manager,
+            &mut self.broad_phase,
+            &mut self.narrow_phase,
+            &mut self.rigid_body_set,
+            &mut self.collider_set,
+            &mut self.impulse_joint_set,
+            &mut self.multibody_joint_set,
+            &mut self.ccd_solver,
+            Some(&mut self.query_pipeline),
+            &(),
+            &self.event_handler,
+        );
+    }
+
+    fn get_shape(kind: i32, shape: Vector3) -> SharedShape {
+        match kind {
+            0 => SharedShape::ball(shape.x),
+            1 => SharedShape::capsule_y(shape.y, shape.x),
+            2 => SharedShape::cuboid(shape.x, shape.y, shape.z),
+            _ => SharedShape::cylinder(shape.y, shape.x),
+        }
+    }
+
+    fn get_group(group: Option<u32>, mask: Option<u32>) -> InteractionGroups {
+        InteractionGroups::new(
+            Group::from_bits_truncate(group.unwrap_or(u32::MAX)),
+            Group::from_bits_truncate(mask.unwrap_or(u32::MAX)),
+        )
+    }
+
+    // bit-mask matching COLLISION_TYPE in constant.lua.
+    fn get_collision_type(kind: Option<u32>) -> ActiveCollisionTypes {
+        let Some(kind) = kind else {
+            return ActiveCollisionTypes::all();
+        };
+
+        let mut result = ActiveCollisionTypes::empty();
+
+        for (bit, flag) in [
+            (1, ActiveCollisionTypes::DYNAMIC_DYNAMIC),
+            (2, ActiveCollisionTypes::DYNAMIC_KINEMATIC),
+            (4, ActiveCollisionTypes::DYNAMIC_FIXED),
+            (8, ActiveCollisionTypes::KINEMATIC_KINEMATIC),
+            (16, ActiveCollisionTypes::KINEMATIC_FIXED),
+            (32, ActiveCollisionTypes::FIXED_FIXED),
+        ] {
+            if kind & bit != 0 {
+                result.insert(flag);
+            }
+        }
+
+        result
+    }
+
+    fn get_joint_axis(axis: i32) -> mlua::Result<JointAxis> {
+        match axis {
+            0 => Ok(JointAxis::LinX),
+            1 => Ok(JointAxis::LinY),
+            2 => Ok(JointAxis::LinZ),
+            3 => Ok(JointAxis::AngX),
+            4 => Ok(JointAxis::AngY),
+            5 => Ok(JointAxis::AngZ),
+            _ => Err(mlua::Error::runtime(format!(
+                "rapier: Invalid joint axis \"{axis}\"."
+            ))),
+        }
+    }
 }
 
 impl mlua::UserData for Rapier {
@@ -149,22 +278,23 @@ impl mlua::UserData for Rapier {
             "name": "rapier:cast_ray",
             "info": "Cast a ray.",
             "member": [
-                { "name": "ray",              "info": "Ray to cast.", "kind": "ray"     },
-                { "name": "range",            "info": "Ray range.",   "kind": "number"  },
-                { "name": "solid",            "info": "TO-DO",        "kind": "boolean" },
-                { "name": "exclude_rigid",    "info": "TO-DO",        "kind": "table"   },
-                { "name": "exclude_collider", "info": "TO-DO",        "kind": "table"   }
+                { "name": "ray",                "info": "Ray to cast.",                                                                                                         "kind": "ray"           },
+                { "name": "range",              "info": "Ray range.",                                                                                                           "kind": "number"        },
+                { "name": "solid",              "info": "TO-DO",                                                                                                                "kind": "boolean"       },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",         "kind": "table?"        }
             ],
             "result": [
                 { "name": "collider_handle", "info": "Solid body handle.", "kind": "table" }
-            ]
+            ],
+            "test": "rapier/filter.lua"
         }
         */
         method.add_method_mut(
             "cast_ray",
             |lua,
              this,
-             (ray, range, solid, exclude_rigid, exclude_collider): (
+             (ray, range, solid, filter, exclude_solid_body): (
                 LuaValue,
                 f32,
                 bool,
@@ -177,24 +307,27 @@ impl mlua::UserData for Rapier {
                     vector![ray.direction.x, ray.direction.y, ray.direction.z],
                 );
 
-                let mut filter = QueryFilter::default();
-
-                if let Some(rigid) = exclude_rigid {
-                    filter = filter.exclude_rigid_body(lua.from_value(rigid)?);
-                }
-
-                if let Some(collider) = exclude_collider {
-                    filter = filter.exclude_collider(lua.from_value(collider)?);
-                }
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::empty(),
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
 
-                if let Some((handle, time)) = this.query_pipeline.cast_ray(
+                let hit = this.query_pipeline.cast_ray(
                     &this.rigid_body_set,
                     &this.collider_set,
                     &ray,
                     range,
                     solid,
                     filter,
-                ) {
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, time)) = hit {
                     return Ok((lua.to_value(&handle)?, time));
                 }
 
@@ -208,11 +341,11 @@ impl mlua::UserData for Rapier {
             "name": "rapier:cast_ray_normal",
             "info": "Cast a ray, and also get the normal information..",
             "member": [
-                { "name": "ray",              "info": "Ray to cast.", "kind": "ray"     },
-                { "name": "range",            "info": "Ray range.",   "kind": "number"  },
-                { "name": "solid",            "info": "TO-DO",        "kind": "boolean" },
-                { "name": "exclude_rigid",    "info": "TO-DO",        "kind": "table"   },
-                { "name": "exclude_collider", "info": "TO-DO",        "kind": "table"   }
+                { "name": "ray",                "info": "Ray to cast.",                                                                                                         "kind": "ray"           },
+                { "name": "range",              "info": "Ray range.",                                                                                                           "kind": "number"        },
+                { "name": "solid",              "info": "TO-DO",                                                                                                                "kind": "boolean"       },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",         "kind": "table?"        }
             ],
             "result": [
                 { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
@@ -223,7 +356,7 @@ impl mlua::UserData for Rapier {
             "cast_ray_normal",
             |lua,
              this,
-             (ray, range, solid, exclude_rigid, exclude_collider): (
+             (ray, range, solid, filter, exclude_solid_body): (
                 LuaValue,
                 f32,
                 bool,
@@ -236,24 +369,27 @@ impl mlua::UserData for Rapier {
                     vector![ray.direction.x, ray.direction.y, ray.direction.z],
                 );
 
-                let mut filter = QueryFilter::default();
-
-                if let Some(rigid) = exclude_rigid {
-                    filter = filter.exclude_rigid_body(lua.from_value(rigid)?);
-                }
-
-                if let Some(collider) = exclude_collider {
-                    filter = filter.exclude_collider(lua.from_value(collider)?);
-                }
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::empty(),
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
 
-                if let Some((handle, normal)) = this.query_pipeline.cast_ray_and_get_normal(
+                let hit = this.query_pipeline.cast_ray_and_get_normal(
                     &this.rigid_body_set,
                     &this.collider_set,
                     &ray,
                     range,
                     solid,
                     filter,
-                ) {
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, normal)) = hit {
                     return Ok((
                         lua.to_value(&handle)?,
                         normal.time_of_impact,
@@ -326,14 +462,24 @@ impl mlua::UserData for Rapier {
         {
             "version": "1.0.0",
             "name": "rapier:test_intersect_cuboid",
-            "info": "TO-DO"
+            "info": "Check if a cuboid is intersecting against any solid body in the simulation.",
+            "member": [
+                { "name": "point",              "info": "Point of cuboid.",                                                                                                                                                                           "kind": "vector_3"      },
+                { "name": "angle",              "info": "Angle of cuboid.",                                                                                                                                                                           "kind": "vector_3"      },
+                { "name": "shape",              "info": "Shape of cuboid.",                                                                                                                                                                           "kind": "vector_3"      },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. If nil, every sensor is excluded. A rigid body handle to exclude is also accepted in its place, as in previous versions, and will also exclude every sensor.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",                                                                               "kind": "table?"        }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle, or nil if there is no intersection.", "kind": "table?" }
+            ]
         }
         */
         method.add_method_mut(
             "test_intersect_cuboid",
             |lua,
              this,
-             (point, angle, shape, exclude_rigid, exclude_collider): (
+             (point, angle, shape, filter, exclude_solid_body): (
                 LuaValue,
                 LuaValue,
                 LuaValue,
@@ -349,18 +495,14 @@ impl mlua::UserData for Rapier {
                 );
                 let shape = Cuboid::new(vector![shape.x, shape.y, shape.z]);
 
-                let mut filter = QueryFilter::default();
-
-                if let Some(rigid) = exclude_rigid {
-                    filter = filter.exclude_rigid_body(lua.from_value(rigid)?);
-                }
-
-                if let Some(collider) = exclude_collider {
-                    filter = filter.exclude_collider(lua.from_value(collider)?);
-                }
-
-                // TO-DO remove?
-                filter = filter.exclude_sensors();
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::EXCLUDE_SENSORS,
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
 
                 let mut hit: Option<ColliderHandle> = None;
 
@@ -376,6 +518,8 @@ impl mlua::UserData for Rapier {
                     },
                 );
 
+                query_filter.error()?;
+
                 if let Some(hit) = hit {
                     lua.to_value(&hit)
                 } else {
@@ -384,6 +528,303 @@ impl mlua::UserData for Rapier {
             },
         );
 
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:cast_ray_list",
+            "info": "Cast a ray, and get every solid body hit along it, sorted by distance.",
+            "member": [
+                { "name": "ray",    "info": "Ray to cast.",                  "kind": "ray"           },
+                { "name": "range",  "info": "Ray range.",                    "kind": "number"        },
+                { "name": "solid",  "info": "TO-DO",                         "kind": "boolean"       },
+                { "name": "filter", "info": "OPTIONAL: Scene query filter.", "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "hit_list", "info": "Hit list. Each hit has a solid body handle, time of impact and normal.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "cast_ray_list",
+            |lua, this, (ray, range, solid, filter): (LuaValue, f32, bool, Option<LuaTable>)| {
+                let ray: crate::base::helper::Ray = lua.from_value(ray)?;
+                let ray = rapier3d::geometry::Ray::new(
+                    point![ray.position.x, ray.position.y, ray.position.z],
+                    vector![ray.direction.x, ray.direction.y, ray.direction.z],
+                );
+
+                let query_filter = AliciaFilter::new(lua, filter)?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let mut list: Vec<AliciaHit> = Vec::new();
+
+                this.query_pipeline.intersections_with_ray(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &ray,
+                    range,
+                    solid,
+                    filter,
+                    |handle, hit| {
+                        list.push(AliciaHit {
+                            solid_body: handle,
+                            time: hit.time_of_impact,
+                            normal: Vector3 {
+                                x: hit.normal.x,
+                                y: hit.normal.y,
+                                z: hit.normal.z,
+                            },
+                        });
+                        true
+                    },
+                );
+
+                query_filter.error()?;
+
+                list.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+                lua.to_value(&list)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:cast_shape",
+            "info": "Cast (sweep) a shape along a direction, and get the first solid body hit.",
+            "member": [
+                { "name": "point",     "info": "Point of shape.",                                                                                                                              "kind": "vector_3"      },
+                { "name": "angle",     "info": "Angle of shape.",                                                                                                                              "kind": "vector_3"      },
+                { "name": "kind",      "info": "Shape kind.",                                                                                                                                  "kind": "shape_kind"    },
+                { "name": "shape",     "info": "Shape. For a ball, (X) is the radius. For a capsule or cylinder, (X) is the radius and (Y) is the half-height. For a cuboid, the half-shape.", "kind": "vector_3"      },
+                { "name": "direction", "info": "Direction (velocity) of the cast.",                                                                                                            "kind": "vector_3"      },
+                { "name": "range",     "info": "Cast range, as a multiple of the direction vector.",                                                                                           "kind": "number"        },
+                { "name": "filter",    "info": "OPTIONAL: Scene query filter.",                                                                                                                "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle, or nil if there was no hit.", "kind": "table?" },
+                { "name": "time",       "info": "Time of impact.",                                 "kind": "number" },
+                { "name": "point_x",    "info": "Hit point on the solid body (X).",                "kind": "number" },
+                { "name": "point_y",    "info": "Hit point on the solid body (Y).",                "kind": "number" },
+                { "name": "point_z",    "info": "Hit point on the solid body (Z).",                "kind": "number" },
+                { "name": "normal_x",   "info": "Hit normal on the solid body (X).",               "kind": "number" },
+                { "name": "normal_y",   "info": "Hit normal on the solid body (Y).",               "kind": "number" },
+                { "name": "normal_z",   "info": "Hit normal on the solid body (Z).",               "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "cast_shape",
+            |lua,
+             this,
+             (point, angle, kind, shape, direction, range, filter): (
+                LuaValue,
+                LuaValue,
+                i32,
+                LuaValue,
+                LuaValue,
+                f32,
+                Option<LuaTable>,
+            )| {
+                let point: Vector3 = lua.from_value(point)?;
+                let angle: Vector3 = lua.from_value(angle)?;
+                let shape: Vector3 = lua.from_value(shape)?;
+                let direction: Vector3 = lua.from_value(direction)?;
+                let point = Isometry::new(
+                    vector![point.x, point.y, point.z],
+                    vector![angle.x, angle.y, angle.z],
+                );
+                let shape = Rapier::get_shape(kind, shape);
+
+                let query_filter = AliciaFilter::new(lua, filter)?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let hit = this.query_pipeline.cast_shape(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &point,
+                    &vector![direction.x, direction.y, direction.z],
+                    &*shape,
+                    parry::query::ShapeCastOptions::with_max_time_of_impact(range),
+                    filter,
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, hit)) = hit {
+                    return Ok((
+                        lua.to_value(&handle)?,
+                        hit.time_of_impact,
+                        hit.witness1.x,
+                        hit.witness1.y,
+                        hit.witness1.z,
+                        hit.normal1.x,
+                        hit.normal1.y,
+                        hit.normal1.z,
+                    ));
+                }
+
+                Ok((mlua::Nil, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:project_point",
+            "info": "Project a point on the closest solid body.",
+            "member": [
+                { "name": "point",  "info": "Point to project.",                                                                             "kind": "vector_3"      },
+                { "name": "solid",  "info": "If true, a point inside a solid body will be projected on itself, rather than on its surface.", "kind": "boolean"       },
+                { "name": "filter", "info": "OPTIONAL: Scene query filter.",                                                                 "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle, or nil if there is no solid body.", "kind": "table?"  },
+                { "name": "point_x",    "info": "Projected point (X).",                                 "kind": "number"  },
+                { "name": "point_y",    "info": "Projected point (Y).",                                 "kind": "number"  },
+                { "name": "point_z",    "info": "Projected point (Z).",                                 "kind": "number"  },
+                { "name": "inside",     "info": "Whether or not the point was inside the solid body.",  "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "project_point",
+            |lua, this, (point, solid, filter): (LuaValue, bool, Option<LuaTable>)| {
+                let point: Vector3 = lua.from_value(point)?;
+
+                let query_filter = AliciaFilter::new(lua, filter)?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let hit = this.query_pipeline.project_point(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &point![point.x, point.y, point.z],
+                    solid,
+                    filter,
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, projection)) = hit {
+                    return Ok((
+                        lua.to_value(&handle)?,
+                        projection.point.x,
+                        projection.point.y,
+                        projection.point.z,
+                        projection.is_inside,
+                    ));
+                }
+
+                Ok((mlua::Nil, 0.0, 0.0, 0.0, false))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:test_intersect_point",
+            "info": "Get every solid body containing a point.",
+            "member": [
+                { "name": "point",  "info": "Point to test.",                "kind": "vector_3"      },
+                { "name": "filter", "info": "OPTIONAL: Scene query filter.", "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "solid_body_list", "info": "Solid body handle list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "test_intersect_point",
+            |lua, this, (point, filter): (LuaValue, Option<LuaTable>)| {
+                let point: Vector3 = lua.from_value(point)?;
+
+                let query_filter = AliciaFilter::new(lua, filter)?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let mut list: Vec<ColliderHandle> = Vec::new();
+
+                this.query_pipeline.intersections_with_point(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &point![point.x, point.y, point.z],
+                    filter,
+                    |handle| {
+                        list.push(handle);
+                        true
+                    },
+                );
+
+                query_filter.error()?;
+
+                lua.to_value(&list)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:test_intersect_shape",
+            "info": "Get every solid body intersecting a shape.",
+            "member": [
+                { "name": "point",  "info": "Point of shape.",                                                                                                                              "kind": "vector_3"      },
+                { "name": "angle",  "info": "Angle of shape.",                                                                                                                              "kind": "vector_3"      },
+                { "name": "kind",   "info": "Shape kind.",                                                                                                                                  "kind": "shape_kind"    },
+                { "name": "shape",  "info": "Shape. For a ball, (X) is the radius. For a capsule or cylinder, (X) is the radius and (Y) is the half-height. For a cuboid, the half-shape.", "kind": "vector_3"      },
+                { "name": "filter", "info": "OPTIONAL: Scene query filter.",                                                                                                                "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "solid_body_list", "info": "Solid body handle list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "test_intersect_shape",
+            |lua,
+             this,
+             (point, angle, kind, shape, filter): (
+                LuaValue,
+                LuaValue,
+                i32,
+                LuaValue,
+                Option<LuaTable>,
+            )| {
+                let point: Vector3 = lua.from_value(point)?;
+                let angle: Vector3 = lua.from_value(angle)?;
+                let shape: Vector3 = lua.from_value(shape)?;
+                let point = Isometry::new(
+                    vector![point.x, point.y, point.z],
+                    vector![angle.x, angle.y, angle.z],
+                );
+                let shape = Rapier::get_shape(kind, shape);
+
+                let query_filter = AliciaFilter::new(lua, filter)?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let mut list: Vec<ColliderHandle> = Vec::new();
+
+                this.query_pipeline.intersections_with_shape(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &point,
+                    &*shape,
+                    filter,
+                    |handle| {
+                        list.push(handle);
+                        true
+                    },
+                );
+
+                query_filter.error()?;
+
+                lua.to_value(&list)
+            },
+        );
+
         //================================================================
 
         /* entry
@@ -623,32 +1064,325 @@ impl mlua::UserData for Rapier {
             },
         );
 
-        //================================================================
-
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:solid_body_remove",
-            "info": "Remove a solid body.",
+            "name": "rapier:get_solid_body_collision_group",
+            "info": "Get the collision group and mask of a solid body.",
             "member": [
-                { "name": "solid_body",    "info": "Solid body handle.",                                                         "kind": "table"   },
-                { "name": "wake_parent", "info": "Whether or not to wake up the rigid body parent this solid body is bound to.", "kind": "boolean" }
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "group", "info": "Collision group bit-mask.", "kind": "number" },
+                { "name": "mask",  "info": "Collision mask bit-mask of the query. A solid body is only considered if its group intersects this mask, and its mask intersects the query group.",  "kind": "number" }
             ]
         }
         */
         method.add_method_mut(
-            "collider_remove",
-            |lua, this, (collider, wake_parent): (LuaValue, bool)| {
+            "get_solid_body_collision_group",
+            |lua, this, collider: LuaValue| {
                 let collider: ColliderHandle = lua.from_value(collider)?;
 
-                this.collider_set.remove(
-                    collider,
-                    &mut this.island_manager,
-                    &mut this.rigid_body_set,
-                    wake_parent,
-                );
+                if let Some(collider) = this.collider_set.get(collider) {
+                    let group = collider.collision_groups();
+                    return Ok((group.memberships.bits(), group.filter.bits()));
+                }
 
-                Ok(())
+                Err(mlua::Error::runtime(
+                    "rapier:get_solid_body_collision_group(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_collision_group",
+            "info": "Set the collision group and mask of a solid body. Two solid bodies will only be tested for contact or intersection if the group of each one intersects the mask of the other.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",                                            "kind": "table"   },
+                { "name": "group",      "info": "OPTIONAL: Collision group bit-mask. If nil, every bit is set.", "kind": "number?" },
+                { "name": "mask",       "info": "OPTIONAL: Collision mask bit-mask. If nil, every bit is set.",  "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_collision_group",
+            |lua, this, (collider, group, mask): (LuaValue, Option<u32>, Option<u32>)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_collision_groups(Rapier::get_group(group, mask));
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_collision_group(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_solid_body_solver_group",
+            "info": "Get the solver group and mask of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "group", "info": "Solver group bit-mask.", "kind": "number" },
+                { "name": "mask",  "info": "Solver mask bit-mask.",  "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_solver_group",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    let group = collider.solver_groups();
+                    return Ok((group.memberships.bits(), group.filter.bits()));
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_solid_body_solver_group(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_solver_group",
+            "info": "Set the solver group and mask of a solid body. Two solid bodies in contact will only have forces applied to them if the group of each one intersects the mask of the other.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",                                         "kind": "table"   },
+                { "name": "group",      "info": "OPTIONAL: Solver group bit-mask. If nil, every bit is set.", "kind": "number?" },
+                { "name": "mask",       "info": "OPTIONAL: Solver mask bit-mask. If nil, every bit is set.",  "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_solver_group",
+            |lua, this, (collider, group, mask): (LuaValue, Option<u32>, Option<u32>)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_solver_groups(Rapier::get_group(group, mask));
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_solver_group(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_collision_event",
+            "info": "Set if a solid body will report a collision event (contact start and stop) in rapier:step. Enabled by default.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",     "kind": "table"   },
+                { "name": "state",      "info": "Collision event state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_collision_event",
+            |lua, this, (collider, state): (LuaValue, bool)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    let mut event = collider.active_events();
+                    event.set(ActiveEvents::COLLISION_EVENTS, state);
+                    collider.set_active_events(event);
+
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_collision_event(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_collision_type",
+            "info": "Set every pair of rigid body kinds a solid body will be tested for contact with. By default, every pair is tested, including a pair of fixed or kinematic rigid bodies.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",                                                                 "kind": "table"   },
+                { "name": "kind",       "info": "OPTIONAL: Collision type bit-mask (see COLLISION_TYPE). If nil, every pair is set.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_collision_type",
+            |lua, this, (collider, kind): (LuaValue, Option<u32>)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_active_collision_types(Rapier::get_collision_type(kind));
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_collision_type(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_contact_force_threshold",
+            "info": "Set the contact force threshold of a solid body. A contact force event will be reported by rapier:step whenever the total force magnitude applied on the solid body is above this threshold.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",                                                          "kind": "table"   },
+                { "name": "threshold",  "info": "OPTIONAL: Contact force threshold. If nil, contact force events are disabled.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_contact_force_threshold",
+            |lua, this, (collider, threshold): (LuaValue, Option<f32>)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    let mut event = collider.active_events();
+
+                    if let Some(threshold) = threshold {
+                        event.insert(ActiveEvents::CONTACT_FORCE_EVENTS);
+                        collider.set_contact_force_event_threshold(threshold);
+                    } else {
+                        event.remove(ActiveEvents::CONTACT_FORCE_EVENTS);
+                    }
+
+                    collider.set_active_events(event);
+
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_contact_force_threshold(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_solid_body_contact",
+            "info": "Get every contact point between two solid bodies, from the last simulation step. Each contact point has a world-space point on either solid body, a normal pointing from solid body (A) to solid body (B), a penetration depth and the impulse applied.",
+            "member": [
+                { "name": "solid_body_a", "info": "Solid body handle (A).", "kind": "table" },
+                { "name": "solid_body_b", "info": "Solid body handle (B).", "kind": "table" }
+            ],
+            "result": [
+                { "name": "contact_list", "info": "Contact point list, or nil if the solid bodies are not in contact.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_contact",
+            |lua, this, (collider_a, collider_b): (LuaValue, LuaValue)| {
+                let collider_a: ColliderHandle = lua.from_value(collider_a)?;
+                let collider_b: ColliderHandle = lua.from_value(collider_b)?;
+
+                let Some(pair) = this.narrow_phase.contact_pair(collider_a, collider_b) else {
+                    return Ok(mlua::Nil);
+                };
+
+                if !pair.has_any_active_contact {
+                    return Ok(mlua::Nil);
+                }
+
+                let (Some(solid_1), Some(solid_2)) = (
+                    this.collider_set.get(pair.collider1),
+                    this.collider_set.get(pair.collider2),
+                ) else {
+                    return Err(mlua::Error::runtime(
+                        "rapier:get_solid_body_contact(): Invalid solid body handle.",
+                    ));
+                };
+
+                // the narrow-phase might have the pair stored in the opposite order.
+                let flip = pair.collider1 != collider_a;
+                let mut list: Vec<AliciaContact> = Vec::new();
+
+                for manifold in &pair.manifolds {
+                    let normal = if flip {
+                        -manifold.data.normal
+                    } else {
+                        manifold.data.normal
+                    };
+
+                    for point in &manifold.points {
+                        let point_1 = solid_1.position() * point.local_p1;
+                        let point_2 = solid_2.position() * point.local_p2;
+                        let (point_a, point_b) = if flip {
+                            (point_2, point_1)
+                        } else {
+                            (point_1, point_2)
+                        };
+
+                        list.push(AliciaContact {
+                            point_a: Vector3 {
+                                x: point_a.x,
+                                y: point_a.y,
+                                z: point_a.z,
+                            },
+                            point_b: Vector3 {
+                                x: point_b.x,
+                                y: point_b.y,
+                                z: point_b.z,
+                            },
+                            normal: Vector3 {
+                                x: normal.x,
+                                y: normal.y,
+                                z: normal.z,
+                            },
+                            depth: -point.dist,
+                            impulse: point.data.impulse,
+                        });
+                    }
+                }
+
+                lua.to_value(&list)
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:solid_body_remove",
+            "info": "Remove a solid body.",
+            "member": [
+                { "name": "solid_body",    "info": "Solid body handle.",                                                         "kind": "table"   },
+                { "name": "wake_parent", "info": "Whether or not to wake up the rigid body parent this solid body is bound to.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "collider_remove",
+            |lua, this, (collider, wake_parent): (LuaValue, bool)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                this.collider_set.remove(
+                    collider,
+                    &mut this.island_manager,
+                    &mut this.rigid_body_set,
+                    wake_parent,
+                );
+
+                Ok(())
             },
         );
 
@@ -805,10 +1539,11 @@ impl mlua::UserData for Rapier {
             "name": "rapier:character_controller_move",
             "info": "Move a character controller.",
             "member": [
-                { "name": "step",        "info": "TO-DO", "kind": "number"   },
-                { "name": "character",   "info": "TO-DO", "kind": "table"    },
-                { "name": "solid_body",    "info": "TO-DO", "kind": "table"  },
-                { "name": "translation", "info": "TO-DO", "kind": "vector_3" }
+                { "name": "step",        "info": "TO-DO",                                                           "kind": "number"        },
+                { "name": "character",   "info": "TO-DO",                                                           "kind": "table"         },
+                { "name": "solid_body",  "info": "TO-DO",                                                           "kind": "table"         },
+                { "name": "translation", "info": "TO-DO",                                                           "kind": "vector_3"      },
+                { "name": "filter",      "info": "OPTIONAL: Scene query filter. If nil, every sensor is excluded.", "kind": "query_filter?" }
             ],
             "result": [
                 { "name": "movement_x", "info": "Translation point (X).", "kind": "number"  },
@@ -821,12 +1556,30 @@ impl mlua::UserData for Rapier {
         */
         method.add_method_mut(
             "character_controller_move",
-            |lua, this, (step, character, collider, translation): (f32, LuaValue, LuaValue, LuaValue)| {
+            |lua,
+             this,
+             (step, character, collider, translation, filter): (
+                f32,
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                Option<LuaTable>,
+            )| {
                 let character: KinematicCharacterController = lua.from_value(character)?;
                 let collider_h: ColliderHandle = lua.from_value(collider)?;
                 let collider_r = this.collider_set.get(collider_h).unwrap();
                 let translation: Vector3 = lua.from_value(translation)?;
 
+                let query_filter = if filter.is_some() {
+                    AliciaFilter::new(lua, filter)?
+                } else {
+                    AliciaFilter {
+                        flag: QueryFilterFlags::EXCLUDE_SENSORS,
+                        ..Default::default()
+                    }
+                };
+                let predicate = query_filter.predicate(lua);
+
                 let movement = character.move_shape(
                     step,
                     &this.rigid_body_set,
@@ -835,12 +1588,12 @@ impl mlua::UserData for Rapier {
                     collider_r.shape(),
                     collider_r.position(),
                     vector![translation.x * step, translation.y * step, translation.z * step],
-                    QueryFilter::default()
-                        .exclude_collider(collider_h)
-                        .exclude_sensors(),
+                    query_filter.query(&predicate).exclude_collider(collider_h),
                     |_| {}
                 );
 
+                query_filter.error()?;
+
                 Ok((
                     movement.translation.x,
                     movement.translation.y,
@@ -854,69 +1607,397 @@ impl mlua::UserData for Rapier {
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:rigid_body",
-            "info": "Create a rigid body.",
+            "name": "rapier:vehicle",
+            "info": "Create a vehicle, using a rigid body as the chassis. Wheels are simulated with ray-casts, and must be added with rapier:vehicle_add_wheel. Every vehicle is updated on every simulation step, applying the suspension, engine and brake forces to the chassis.",
             "member": [
-                { "name": "kind", "info": "Rigid body kind.", "kind": "rigid_body_kind" }
+                { "name": "rigid_body",   "info": "Rigid body handle for the chassis.",                                                                                    "kind": "table"         },
+                { "name": "up_axis",      "info": "OPTIONAL: Up axis of the chassis (0: X, 1: Y, 2: Z). Default: 1.",                                                      "kind": "number?"       },
+                { "name": "forward_axis", "info": "OPTIONAL: Forward axis of the chassis (0: X, 1: Y, 2: Z). Default: 0.",                                                 "kind": "number?"       },
+                { "name": "filter",       "info": "OPTIONAL: Scene query filter for the wheel ray-casts. The chassis is always excluded, and a predicate is not allowed.", "kind": "query_filter?" }
             ],
             "result": [
-                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+                { "name": "vehicle", "info": "Vehicle handle.", "kind": "table" }
             ]
         }
         */
-        method.add_method_mut("rigid_body", |lua, this, kind: i32| {
-            let rigid = match kind {
-                1 => RigidBodyBuilder::dynamic(),
-                2 => RigidBodyBuilder::kinematic_position_based(),
-                3 => RigidBodyBuilder::kinematic_velocity_based(),
-                _ => RigidBodyBuilder::fixed(),
-            };
+        method.add_method_mut(
+            "vehicle",
+            |lua,
+             this,
+             (rigid_body, up_axis, forward_axis, filter): (
+                LuaValue,
+                Option<usize>,
+                Option<usize>,
+                Option<LuaTable>,
+            )| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+                let filter = AliciaVehicleFilter::new(lua, "rapier:vehicle()", filter)?;
 
-            lua.to_value(&this.rigid_body_set.insert(rigid))
-        });
+                if !this.rigid_body_set.contains(rigid_body) {
+                    return Err(mlua::Error::runtime(
+                        "rapier:vehicle(): Invalid rigid body handle.",
+                    ));
+                }
+
+                let mut vehicle = DynamicRayCastVehicleController::new(rigid_body);
+
+                vehicle.index_up_axis = up_axis.unwrap_or(1).min(2);
+                vehicle.index_forward_axis = forward_axis.unwrap_or(0).min(2);
+
+                lua.to_value(&this.vehicle_set.insert(AliciaVehicle {
+                    controller: vehicle,
+                    filter,
+                }))
+            },
+        );
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:get_rigid_body_user_data",
-            "info": "Get the user data of a rigid_body.",
+            "name": "rapier:vehicle_remove",
+            "info": "Remove a vehicle. The chassis rigid body is left as-is.",
             "member": [
-                { "name": "rigid_body", "info": "Rigid body handle.",  "kind": "userdata" }
-            ],
-            "result": [
-                { "name": "user_data",  "info": "Rigid body user data.", "kind": "number" }
+                { "name": "vehicle", "info": "Vehicle handle.", "kind": "table" }
             ]
         }
         */
-        method.add_method_mut(
-            "get_rigid_body_user_data",
-            |lua, this, rigid_body: LuaValue| {
-                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+        method.add_method_mut("vehicle_remove", |lua, this, vehicle: LuaValue| {
+            let vehicle: Index = lua.from_value(vehicle)?;
 
-                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
-                    return Ok(rigid_body.user_data);
-                }
+            if this.vehicle_set.remove(vehicle).is_some() {
+                return Ok(());
+            }
 
-                Err(mlua::Error::runtime(
-                    "rapier:get_rigid_body_user_data(): Invalid rigid body handle.",
-                ))
-            },
-        );
+            Err(mlua::Error::runtime(
+                "rapier:vehicle_remove(): Invalid vehicle handle.",
+            ))
+        });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:set_rigid_body_user_data",
-            "info": "Set the user data of a rigid_body.",
+            "name": "rapier:vehicle_add_wheel",
+            "info": "Add a wheel to a vehicle.",
             "member": [
-                { "name": "rigid_body", "info": "Rigid body handle.",    "kind": "userdata" },
-                { "name": "user_data",  "info": "Rigid body user data.", "kind": "number"   }
+                { "name": "vehicle",     "info": "Vehicle handle.",                                                                     "kind": "table"         },
+                { "name": "point",       "info": "Point of the suspension on the chassis, in chassis-space.",                           "kind": "vector_3"      },
+                { "name": "direction",   "info": "Direction of the suspension, in chassis-space.",                                      "kind": "vector_3"      },
+                { "name": "axle",        "info": "Axle of the wheel, in chassis-space.",                                                "kind": "vector_3"      },
+                { "name": "rest_length", "info": "Rest length of the suspension.",                                                      "kind": "number"        },
+                { "name": "radius",      "info": "Radius of the wheel.",                                                                "kind": "number"        },
+                { "name": "tuning",      "info": "OPTIONAL: Suspension and friction settings. Any missing field will use its default.", "kind": "wheel_tuning?" }
+            ],
+            "result": [
+                { "name": "wheel", "info": "Wheel index.", "kind": "number" }
             ]
         }
         */
         method.add_method_mut(
-            "set_rigid_body_user_data",
-            |lua, this, (rigid_body, user_data): (LuaValue, u128)| {
+            "vehicle_add_wheel",
+            |lua,
+             this,
+             (vehicle, point, direction, axle, rest_length, radius, tuning): (
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                f32,
+                f32,
+                Option<LuaValue>,
+            )| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+                let point: Vector3 = lua.from_value(point)?;
+                let direction: Vector3 = lua.from_value(direction)?;
+                let axle: Vector3 = lua.from_value(axle)?;
+                let tuning: AliciaWheelTuning = if let Some(tuning) = tuning {
+                    lua.from_value(tuning)?
+                } else {
+                    AliciaWheelTuning::default()
+                };
+
+                if let Some(vehicle) = this.vehicle_set.get_mut(vehicle) {
+                    vehicle.controller.add_wheel(
+                        point![point.x, point.y, point.z],
+                        vector![direction.x, direction.y, direction.z],
+                        vector![axle.x, axle.y, axle.z],
+                        rest_length,
+                        radius,
+                        &tuning.into(),
+                    );
+
+                    return Ok(vehicle.controller.wheels().len() - 1);
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:vehicle_add_wheel(): Invalid vehicle handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_vehicle_wheel_engine_force",
+            "info": "Set the engine force of a vehicle wheel.",
+            "member": [
+                { "name": "vehicle",      "info": "Vehicle handle.",                    "kind": "table"  },
+                { "name": "wheel",        "info": "Wheel index.",                       "kind": "number" },
+                { "name": "engine_force", "info": "Engine force applied on the wheel.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_vehicle_wheel_engine_force",
+            |lua, this, (vehicle, wheel, engine_force): (LuaValue, usize, f32)| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+
+                if let Some(vehicle) = this.vehicle_set.get_mut(vehicle)
+                    && let Some(wheel) = vehicle.controller.wheels_mut().get_mut(wheel)
+                {
+                    wheel.engine_force = engine_force;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_vehicle_wheel_engine_force(): Invalid vehicle handle or wheel index.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_vehicle_wheel_brake",
+            "info": "Set the brake of a vehicle wheel.",
+            "member": [
+                { "name": "vehicle", "info": "Vehicle handle.",                   "kind": "table"  },
+                { "name": "wheel",   "info": "Wheel index.",                      "kind": "number" },
+                { "name": "brake",   "info": "Brake force applied on the wheel.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_vehicle_wheel_brake",
+            |lua, this, (vehicle, wheel, brake): (LuaValue, usize, f32)| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+
+                if let Some(vehicle) = this.vehicle_set.get_mut(vehicle)
+                    && let Some(wheel) = vehicle.controller.wheels_mut().get_mut(wheel)
+                {
+                    wheel.brake = brake;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_vehicle_wheel_brake(): Invalid vehicle handle or wheel index.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_vehicle_wheel_steering",
+            "info": "Set the steering of a vehicle wheel.",
+            "member": [
+                { "name": "vehicle",  "info": "Vehicle handle.",                          "kind": "table"  },
+                { "name": "wheel",    "info": "Wheel index.",                             "kind": "number" },
+                { "name": "steering", "info": "Steering angle of the wheel, in radians.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_vehicle_wheel_steering",
+            |lua, this, (vehicle, wheel, steering): (LuaValue, usize, f32)| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+
+                if let Some(vehicle) = this.vehicle_set.get_mut(vehicle)
+                    && let Some(wheel) = vehicle.controller.wheels_mut().get_mut(wheel)
+                {
+                    wheel.steering = steering;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_vehicle_wheel_steering(): Invalid vehicle handle or wheel index.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_vehicle_wheel",
+            "info": "Get the state of a vehicle wheel, as of the last simulation step.",
+            "member": [
+                { "name": "vehicle", "info": "Vehicle handle.", "kind": "table"  },
+                { "name": "wheel",   "info": "Wheel index.",    "kind": "number" }
+            ],
+            "result": [
+                { "name": "state", "info": "Wheel state. Has the wheel center, contact state, contact point and normal, the solid body in contact with, the suspension length and force, and the wheel rotation.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_vehicle_wheel",
+            |lua, this, (vehicle, wheel): (LuaValue, usize)| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+
+                if let Some(vehicle) = this.vehicle_set.get(vehicle)
+                    && let Some(wheel) = vehicle.controller.wheels().get(wheel)
+                {
+                    let info = wheel.raycast_info();
+                    let center = wheel.center();
+
+                    return lua.to_value(&AliciaWheel {
+                        center: Vector3 {
+                            x: center.x,
+                            y: center.y,
+                            z: center.z,
+                        },
+                        contact: info.is_in_contact,
+                        contact_point: Vector3 {
+                            x: info.contact_point_ws.x,
+                            y: info.contact_point_ws.y,
+                            z: info.contact_point_ws.z,
+                        },
+                        contact_normal: Vector3 {
+                            x: info.contact_normal_ws.x,
+                            y: info.contact_normal_ws.y,
+                            z: info.contact_normal_ws.z,
+                        },
+                        solid_body: info.ground_object,
+                        suspension_length: info.suspension_length,
+                        suspension_force: wheel.wheel_suspension_force,
+                        rotation: wheel.rotation,
+                    });
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_vehicle_wheel(): Invalid vehicle handle or wheel index.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_vehicle_speed",
+            "info": "Get the current forward speed of a vehicle.",
+            "member": [
+                { "name": "vehicle", "info": "Vehicle handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "speed", "info": "Forward speed.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_vehicle_speed", |lua, this, vehicle: LuaValue| {
+            let vehicle: Index = lua.from_value(vehicle)?;
+
+            if let Some(vehicle) = this.vehicle_set.get(vehicle) {
+                return Ok(vehicle.controller.current_vehicle_speed);
+            }
+
+            Err(mlua::Error::runtime(
+                "rapier:get_vehicle_speed(): Invalid vehicle handle.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_vehicle_filter",
+            "info": "Set the scene query filter for the wheel ray-casts of a vehicle.",
+            "member": [
+                { "name": "vehicle", "info": "Vehicle handle.",                                                                               "kind": "table"         },
+                { "name": "filter",  "info": "OPTIONAL: Scene query filter. The chassis is always excluded, and a predicate is not allowed.", "kind": "query_filter?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_vehicle_filter",
+            |lua, this, (vehicle, filter): (LuaValue, Option<LuaTable>)| {
+                let vehicle: Index = lua.from_value(vehicle)?;
+                let filter = AliciaVehicleFilter::new(lua, "rapier:set_vehicle_filter()", filter)?;
+
+                if let Some(vehicle) = this.vehicle_set.get_mut(vehicle) {
+                    vehicle.filter = filter;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_vehicle_filter(): Invalid vehicle handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:rigid_body",
+            "info": "Create a rigid body.",
+            "member": [
+                { "name": "kind", "info": "Rigid body kind.", "kind": "rigid_body_kind" }
+            ],
+            "result": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("rigid_body", |lua, this, kind: i32| {
+            let rigid = match kind {
+                1 => RigidBodyBuilder::dynamic(),
+                2 => RigidBodyBuilder::kinematic_position_based(),
+                3 => RigidBodyBuilder::kinematic_velocity_based(),
+                _ => RigidBodyBuilder::fixed(),
+            };
+
+            lua.to_value(&this.rigid_body_set.insert(rigid))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_user_data",
+            "info": "Get the user data of a rigid_body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",  "kind": "userdata" }
+            ],
+            "result": [
+                { "name": "user_data",  "info": "Rigid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_user_data",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok(rigid_body.user_data);
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_user_data(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_user_data",
+            "info": "Set the user data of a rigid_body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",    "kind": "userdata" },
+                { "name": "user_data",  "info": "Rigid body user data.", "kind": "number"   }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_user_data",
+            |lua, this, (rigid_body, user_data): (LuaValue, u128)| {
                 let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
 
                 if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
@@ -962,217 +2043,1647 @@ impl mlua::UserData for Rapier {
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:set_rigid_body_rotation",
-            "info": "Set the rotation of a rigid_body.",
+            "name": "rapier:set_rigid_body_rotation",
+            "info": "Set the rotation of a rigid_body.",
+            "member": [
+                { "name": "rigid_body", "info": "rigid_body handle.",   "kind": "table"    },
+                { "name": "rotation", "info": "rigid_body rotation.", "kind": "vector_3" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_rotation",
+            |lua, this, (rigid_body, rotation, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+                let rotation: Vector3 = lua.from_value(rotation)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_rotation(
+                        Rotation::new(vector![rotation.x, rotation.y, rotation.z]),
+                        wake_up,
+                    );
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_rotation(): Invalid rigid_body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_linear_velocity",
+            "info": "Get the linear velocity of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "linear_velocity_x", "info": "Rigid body linear velocity (X).", "kind": "number" },
+                { "name": "linear_velocity_y", "info": "Rigid body linear velocity (Y).", "kind": "number" },
+                { "name": "linear_velocity_z", "info": "Rigid body linear velocity (Z).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_linear_velocity",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    let value = rigid_body.linvel();
+                    return Ok((value.x, value.y, value.z));
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_linear_velocity(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_linear_velocity",
+            "info": "Set the linear velocity of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "velocity",   "info": "Rigid body linear velocity.",               "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_linear_velocity",
+            |lua, this, (rigid_body, velocity, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let velocity: Vector3 = lua.from_value(velocity)?;
+                    rigid_body.set_linvel(vector![velocity.x, velocity.y, velocity.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_linear_velocity(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_angular_velocity",
+            "info": "Get the angular velocity of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "angular_velocity_x", "info": "Rigid body angular velocity (X).", "kind": "number" },
+                { "name": "angular_velocity_y", "info": "Rigid body angular velocity (Y).", "kind": "number" },
+                { "name": "angular_velocity_z", "info": "Rigid body angular velocity (Z).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_angular_velocity",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    let value = rigid_body.angvel();
+                    return Ok((value.x, value.y, value.z));
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_angular_velocity(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_angular_velocity",
+            "info": "Set the angular velocity of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "velocity",   "info": "Rigid body angular velocity.",              "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_angular_velocity",
+            |lua, this, (rigid_body, velocity, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let velocity: Vector3 = lua.from_value(velocity)?;
+                    rigid_body.set_angvel(vector![velocity.x, velocity.y, velocity.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_angular_velocity(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:add_rigid_body_force",
+            "info": "Add a force to a rigid body. The force is applied continuously on every step, until reset with rapier:reset_rigid_body_force.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "force",      "info": "Force.",                                    "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "add_rigid_body_force",
+            |lua, this, (rigid_body, force, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let force: Vector3 = lua.from_value(force)?;
+                    rigid_body.add_force(vector![force.x, force.y, force.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:add_rigid_body_force(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:add_rigid_body_torque",
+            "info": "Add a torque to a rigid body. The torque is applied continuously on every step, until reset with rapier:reset_rigid_body_force.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "torque",     "info": "Torque.",                                   "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "add_rigid_body_torque",
+            |lua, this, (rigid_body, torque, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let torque: Vector3 = lua.from_value(torque)?;
+                    rigid_body.add_torque(vector![torque.x, torque.y, torque.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:add_rigid_body_torque(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:apply_rigid_body_impulse",
+            "info": "Apply an impulse to the center of mass of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "impulse",    "info": "Impulse.",                                  "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "apply_rigid_body_impulse",
+            |lua, this, (rigid_body, impulse, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let impulse: Vector3 = lua.from_value(impulse)?;
+                    rigid_body.apply_impulse(vector![impulse.x, impulse.y, impulse.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:apply_rigid_body_impulse(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:apply_rigid_body_torque_impulse",
+            "info": "Apply a torque impulse to a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "impulse",    "info": "Torque impulse.",                           "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "apply_rigid_body_torque_impulse",
+            |lua, this, (rigid_body, impulse, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let impulse: Vector3 = lua.from_value(impulse)?;
+                    rigid_body
+                        .apply_torque_impulse(vector![impulse.x, impulse.y, impulse.z], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:apply_rigid_body_torque_impulse(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:apply_rigid_body_impulse_at_point",
+            "info": "Apply an impulse to a rigid body, at a given world-space point.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"    },
+                { "name": "impulse",    "info": "Impulse.",                                  "kind": "vector_3" },
+                { "name": "point",      "info": "World-space point.",                        "kind": "vector_3" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "apply_rigid_body_impulse_at_point",
+            |lua,
+             this,
+             (rigid_body, impulse, point, wake_up): (
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                bool,
+            )| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    let impulse: Vector3 = lua.from_value(impulse)?;
+                    let point: Vector3 = lua.from_value(point)?;
+                    rigid_body.apply_impulse_at_point(
+                        vector![impulse.x, impulse.y, impulse.z],
+                        point![point.x, point.y, point.z],
+                        wake_up,
+                    );
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:apply_rigid_body_impulse_at_point(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:reset_rigid_body_force",
+            "info": "Reset every force and torque added to a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"   },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "reset_rigid_body_force",
+            |lua, this, (rigid_body, wake_up): (LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.reset_forces(wake_up);
+                    rigid_body.reset_torques(wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:reset_rigid_body_force(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_damping",
+            "info": "Get the damping of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "linear",  "info": "Linear damping.",  "kind": "number" },
+                { "name": "angular", "info": "Angular damping.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_damping",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok((rigid_body.linear_damping(), rigid_body.angular_damping()));
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_damping(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_damping",
+            "info": "Set the damping of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table"  },
+                { "name": "linear",     "info": "Linear damping.",    "kind": "number" },
+                { "name": "angular",    "info": "Angular damping.",   "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_damping",
+            |lua, this, (rigid_body, linear, angular): (LuaValue, f32, f32)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_linear_damping(linear);
+                    rigid_body.set_angular_damping(angular);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_damping(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_gravity_scale",
+            "info": "Get the gravity scale of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "scale", "info": "Gravity scale.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_gravity_scale",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok(rigid_body.gravity_scale());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_gravity_scale(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_gravity_scale",
+            "info": "Set the gravity scale of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"   },
+                { "name": "scale",      "info": "Gravity scale.",                            "kind": "number"  },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_gravity_scale",
+            |lua, this, (rigid_body, scale, wake_up): (LuaValue, f32, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_gravity_scale(scale, wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_gravity_scale(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_mass",
+            "info": "Get the mass of a rigid body, including the mass of every solid body bound to it.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "mass", "info": "Rigid body mass.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_mass",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok(rigid_body.mass());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_mass(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_additional_mass",
+            "info": "Set the additional mass of a rigid body, on top of the mass of every solid body bound to it.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"   },
+                { "name": "mass",       "info": "Additional mass.",                          "kind": "number"  },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_additional_mass",
+            |lua, this, (rigid_body, mass, wake_up): (LuaValue, f32, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_additional_mass(mass, wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_additional_mass(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_density",
+            "info": "Set the density of a solid body. The mass of the solid body will be computed from its shape.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",  "kind": "table"  },
+                { "name": "density",    "info": "Solid body density.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_density",
+            |lua, this, (collider, density): (LuaValue, f32)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_density(density);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_density(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_mass",
+            "info": "Set the mass of a solid body. The density of the solid body will be computed from its shape.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table"  },
+                { "name": "mass",       "info": "Solid body mass.",   "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_mass",
+            |lua, this, (collider, mass): (LuaValue, f32)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_mass(mass);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_mass(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_lock_translation",
+            "info": "Lock the translation of a rigid body, on any axis.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"   },
+                { "name": "lock_x",     "info": "Lock translation (X).",                     "kind": "boolean" },
+                { "name": "lock_y",     "info": "Lock translation (Y).",                     "kind": "boolean" },
+                { "name": "lock_z",     "info": "Lock translation (Z).",                     "kind": "boolean" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_lock_translation",
+            |lua,
+             this,
+             (rigid_body, lock_x, lock_y, lock_z, wake_up): (
+                LuaValue,
+                bool,
+                bool,
+                bool,
+                bool,
+            )| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_enabled_translations(!lock_x, !lock_y, !lock_z, wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_lock_translation(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_lock_rotation",
+            "info": "Lock the rotation of a rigid body, on any axis.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                        "kind": "table"   },
+                { "name": "lock_x",     "info": "Lock rotation (X).",                        "kind": "boolean" },
+                { "name": "lock_y",     "info": "Lock rotation (Y).",                        "kind": "boolean" },
+                { "name": "lock_z",     "info": "Lock rotation (Z).",                        "kind": "boolean" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_lock_rotation",
+            |lua,
+             this,
+             (rigid_body, lock_x, lock_y, lock_z, wake_up): (
+                LuaValue,
+                bool,
+                bool,
+                bool,
+                bool,
+            )| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_enabled_rotations(!lock_x, !lock_y, !lock_z, wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_lock_rotation(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_rigid_body_sleep",
+            "info": "Check if a rigid body is sleeping.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "sleep", "info": "Rigid body sleep state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_sleep",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok(rigid_body.is_sleeping());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_rigid_body_sleep(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_sleep",
+            "info": "Put a rigid body to sleep, or wake it up.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",      "kind": "table"   },
+                { "name": "sleep",      "info": "Rigid body sleep state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_sleep",
+            |lua, this, (rigid_body, sleep): (LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    if sleep {
+                        rigid_body.sleep();
+                    } else {
+                        rigid_body.wake_up(true);
+                    }
+
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_sleep(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_rigid_body_continuous",
+            "info": "Enable or disable continuous collision detection (CCD) for a rigid body. Useful for fast-moving rigid bodies, which might otherwise tunnel through thin geometry.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",    "kind": "table"   },
+                { "name": "continuous", "info": "Rigid body CCD state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_continuous",
+            |lua, this, (rigid_body, continuous): (LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.enable_ccd(continuous);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_rigid_body_continuous(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:joint",
+            "info": "Create a joint between two rigid bodies. A revolute or prismatic joint takes an additional axis (vector_3) argument, a rope joint takes an additional maximum length (number) argument.",
+            "member": [
+                { "name": "kind",         "info": "Joint kind.",                                      "kind": "joint_kind" },
+                { "name": "rigid_body_a", "info": "Rigid body handle (A).",                           "kind": "table"      },
+                { "name": "rigid_body_b", "info": "Rigid body handle (B).",                           "kind": "table"      },
+                { "name": "anchor_a",     "info": "Joint anchor, local to rigid body (A).",           "kind": "vector_3"   },
+                { "name": "anchor_b",     "info": "Joint anchor, local to rigid body (B).",           "kind": "vector_3"   },
+                { "name": "...",          "info": "Joint axis (revolute, prismatic) or length (rope).", "kind": "any"      }
+            ],
+            "result": [
+                { "name": "joint", "info": "Joint handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "joint",
+            |lua,
+             this,
+             (kind, rigid_body_a, rigid_body_b, anchor_a, anchor_b, data): (
+                i32,
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                mlua::Variadic<LuaValue>,
+            )| {
+                let rigid_body_a: RigidBodyHandle = lua.from_value(rigid_body_a)?;
+                let rigid_body_b: RigidBodyHandle = lua.from_value(rigid_body_b)?;
+                let anchor_a: Vector3 = lua.from_value(anchor_a)?;
+                let anchor_b: Vector3 = lua.from_value(anchor_b)?;
+                let anchor_a = point![anchor_a.x, anchor_a.y, anchor_a.z];
+                let anchor_b = point![anchor_b.x, anchor_b.y, anchor_b.z];
+
+                let joint: GenericJoint = match kind {
+                    0 => FixedJointBuilder::new()
+                        .local_anchor1(anchor_a)
+                        .local_anchor2(anchor_b)
+                        .into(),
+                    1 | 2 => {
+                        if let Some(axis) = data.first() {
+                            let axis: Vector3 = lua.from_value(axis.clone())?;
+                            let axis = UnitVector::new_normalize(vector![axis.x, axis.y, axis.z]);
+
+                            if kind == 1 {
+                                RevoluteJointBuilder::new(axis)
+                                    .local_anchor1(anchor_a)
+                                    .local_anchor2(anchor_b)
+                                    .into()
+                            } else {
+                                PrismaticJointBuilder::new(axis)
+                                    .local_anchor1(anchor_a)
+                                    .local_anchor2(anchor_b)
+                                    .into()
+                            }
+                        } else {
+                            return Err(mlua::Error::runtime(
+                                "rapier:joint(): Missing axis (vector_3) argument.",
+                            ));
+                        }
+                    }
+                    3 => SphericalJointBuilder::new()
+                        .local_anchor1(anchor_a)
+                        .local_anchor2(anchor_b)
+                        .into(),
+                    _ => {
+                        if let Some(length) = data.first() {
+                            let length: f32 = lua.from_value(length.clone())?;
+
+                            RopeJointBuilder::new(length)
+                                .local_anchor1(anchor_a)
+                                .local_anchor2(anchor_b)
+                                .into()
+                        } else {
+                            return Err(mlua::Error::runtime(
+                                "rapier:joint(): Missing length (number) argument.",
+                            ));
+                        }
+                    }
+                };
+
+                lua.to_value(&this.impulse_joint_set.insert(
+                    rigid_body_a,
+                    rigid_body_b,
+                    joint,
+                    true,
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_joint_rigid_body",
+            "info": "Get the rigid bodies a joint is binding together.",
+            "member": [
+                { "name": "joint", "info": "Joint handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "rigid_body_a", "info": "Rigid body handle (A).", "kind": "table" },
+                { "name": "rigid_body_b", "info": "Rigid body handle (B).", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("get_joint_rigid_body", |lua, this, joint: LuaValue| {
+            let joint: ImpulseJointHandle = lua.from_value(joint)?;
+
+            if let Some(joint) = this.impulse_joint_set.get(joint) {
+                return Ok((lua.to_value(&joint.body1)?, lua.to_value(&joint.body2)?));
+            }
+
+            Err(mlua::Error::runtime(
+                "rapier:get_joint_rigid_body(): Invalid joint handle.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_enable",
+            "info": "Set the enable state of a joint.",
+            "member": [
+                { "name": "joint",  "info": "Joint handle.",       "kind": "table"   },
+                { "name": "enable", "info": "Joint enable state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_enable",
+            |lua, this, (joint, enable): (LuaValue, bool)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_enabled(enable);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_enable(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_contact",
+            "info": "Set whether or not the rigid bodies bound by a joint can collide with each other.",
+            "member": [
+                { "name": "joint",   "info": "Joint handle.",        "kind": "table"   },
+                { "name": "contact", "info": "Joint contact state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_contact",
+            |lua, this, (joint, contact): (LuaValue, bool)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_contacts_enabled(contact);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_contact(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_limit",
+            "info": "Set the limit of a joint, along a given axis. The free axis of a revolute joint is always ANGULAR_X, and the free axis of a prismatic or rope joint is always LINEAR_X.",
+            "member": [
+                { "name": "joint", "info": "Joint handle.",  "kind": "table"      },
+                { "name": "axis",  "info": "Joint axis.",    "kind": "joint_axis" },
+                { "name": "min",   "info": "Minimum limit.", "kind": "number"     },
+                { "name": "max",   "info": "Maximum limit.", "kind": "number"     }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_limit",
+            |lua, this, (joint, axis, min, max): (LuaValue, i32, f32, f32)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+                let axis = Rapier::get_joint_axis(axis)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_limits(axis, [min, max]);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_limit(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_motor_model",
+            "info": "Set the motor model of a joint, along a given axis.",
+            "member": [
+                { "name": "joint", "info": "Joint handle.", "kind": "table"       },
+                { "name": "axis",  "info": "Joint axis.",   "kind": "joint_axis"  },
+                { "name": "model", "info": "Motor model.",  "kind": "motor_model" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_motor_model",
+            |lua, this, (joint, axis, model): (LuaValue, i32, i32)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+                let axis = Rapier::get_joint_axis(axis)?;
+                let model = match model {
+                    1 => MotorModel::ForceBased,
+                    _ => MotorModel::AccelerationBased,
+                };
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_motor_model(axis, model);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_motor_model(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_motor_velocity",
+            "info": "Set the target velocity of a joint's motor, along a given axis.",
+            "member": [
+                { "name": "joint",    "info": "Joint handle.",                               "kind": "table"      },
+                { "name": "axis",     "info": "Joint axis.",                                 "kind": "joint_axis" },
+                { "name": "velocity", "info": "Target velocity.",                            "kind": "number"     },
+                { "name": "factor",   "info": "How fast the motor will reach the velocity.", "kind": "number"     }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_motor_velocity",
+            |lua, this, (joint, axis, velocity, factor): (LuaValue, i32, f32, f32)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+                let axis = Rapier::get_joint_axis(axis)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_motor_velocity(axis, velocity, factor);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_motor_velocity(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_motor_position",
+            "info": "Set the target position of a joint's motor, along a given axis.",
+            "member": [
+                { "name": "joint",     "info": "Joint handle.",     "kind": "table"      },
+                { "name": "axis",      "info": "Joint axis.",       "kind": "joint_axis" },
+                { "name": "position",  "info": "Target position.",  "kind": "number"     },
+                { "name": "stiffness", "info": "Motor stiffness.",  "kind": "number"     },
+                { "name": "damping",   "info": "Motor damping.",    "kind": "number"     }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_motor_position",
+            |lua,
+             this,
+             (joint, axis, position, stiffness, damping): (LuaValue, i32, f32, f32, f32)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+                let axis = Rapier::get_joint_axis(axis)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint
+                        .data
+                        .set_motor_position(axis, position, stiffness, damping);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_motor_position(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_joint_motor_max_force",
+            "info": "Set the maximum force a joint's motor can deliver, along a given axis.",
+            "member": [
+                { "name": "joint", "info": "Joint handle.",   "kind": "table"      },
+                { "name": "axis",  "info": "Joint axis.",     "kind": "joint_axis" },
+                { "name": "force", "info": "Maximum force.",  "kind": "number"     }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_joint_motor_max_force",
+            |lua, this, (joint, axis, force): (LuaValue, i32, f32)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+                let axis = Rapier::get_joint_axis(axis)?;
+
+                if let Some(joint) = this.impulse_joint_set.get_mut(joint, true) {
+                    joint.data.set_motor_max_force(axis, force);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_joint_motor_max_force(): Invalid joint handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:joint_remove",
+            "info": "Remove a joint.",
+            "member": [
+                { "name": "joint",   "info": "Joint handle.",                                                 "kind": "table"   },
+                { "name": "wake_up", "info": "Whether or not to wake up the rigid bodies bound by the joint.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "joint_remove",
+            |lua, this, (joint, wake_up): (LuaValue, bool)| {
+                let joint: ImpulseJointHandle = lua.from_value(joint)?;
+
+                this.impulse_joint_set.remove(joint, wake_up);
+
+                Ok(())
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_solid_body_user_data",
+            "info": "Get the user data of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "userdata" }
+            ],
+            "result": [
+                { "name": "user_data", "info": "Solid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_user_data",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    return Ok(collider.user_data);
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_solid_body_user_data(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solid_body_user_data",
+            "info": "Set the user data of a solid body.",
+            "member": [
+                { "name": "solid_body",  "info": "Solid body handle.",    "kind": "userdata" },
+                { "name": "user_data", "info": "Solid body user data.", "kind": "number"   }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_user_data",
+            |lua, this, (collider, user_data): (LuaValue, u128)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.user_data = user_data;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:set_solid_body_user_data(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:solid_body",
+            "info": "TO-DO"
+        }
+        */
+        method.add_method_mut(
+            "solid_body",
+            |lua, this, (rigid_body, kind, data): (Option<LuaValue>, i32, mlua::Variadic<LuaValue>)| {
+                match kind {
+                    0 => {
+                        if let Some(half_shape) = data.get(0) {
+                            let half_shape: Vector3 = lua.from_value(half_shape.clone())?;
+
+                            this.insert_collider(
+                                lua,
+                                ColliderBuilder::cuboid(half_shape.x, half_shape.y, half_shape.z),
+                                rigid_body,
+                            )
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier:solid_body(): Missing half-shape (vector_3) argument.",
+                            ))
+                        }
+                    },
+                    1 => {
+                        if let Some(point_table) = data.get(0) && let Some(index_table) = data.get(1) {
+                            let mut p_table: Vec<Point<f32>> = Vec::new();
+                            let mut i_table: Vec<[u32; 3]> = Vec::new();
+                            let point_table: Vec<Vector3> = lua.from_value(point_table.clone())?;
+                            let index_table: Vec<u32> = lua.from_value(index_table.clone())?;
+
+                            for x in point_table {
+                                p_table.push(point![x.x, x.y, x.z]);
+                            }
+
+                            let mut iterator = index_table.iter();
+
+                            while let Some(a) = iterator.next() {
+                                if let Some(b) = iterator.next() {
+                                    if let Some(c) = iterator.next() {
+                                        i_table.push([*a, *b, *c]);
+                                    }
+                                }
+                            }
+
+                            // TO-DO this should really be a convex_mesh call, but for some reason, it doesn't work, no matter what input is sent?
+                            this.insert_collider(
+                                lua,
+                                ColliderBuilder::trimesh_with_flags(
+                                    p_table,
+                                    i_table,
+                                    TriMeshFlags::all(),
+                                ).unwrap(),
+                                rigid_body,
+                            )
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier:solid_body(): Missing point table ({ vector_3 }) or index table ({ vector_3 }) argument.",
+                            ))
+                        }
+                    }
+                    _ => {
+                        if let Some(point_table) = data.get(0) {
+                            let mut p_table: Vec<Point<f32>> = Vec::new();
+                            let point_table: Vec<Vector3> = lua.from_value(point_table.clone())?;
+
+                            for x in point_table {
+                                p_table.push(point![x.x, x.y, x.z]);
+                            }
+
+                            if let Some(collider) = ColliderBuilder::convex_hull(&p_table) {
+                                this.insert_collider(lua, collider, rigid_body)
+                            } else {
+                                Ok(mlua::Nil)
+                            }
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier:solid_body(): Missing point table ({ vector_3 }) argument.",
+                            ))
+                        }
+                    }
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:solid_body_from_model",
+            "info": "Create a solid body from the geometry of a mesh in a model. A tri-mesh will merge any duplicate vertex, and remove any degenerate or duplicate triangle, so that a non-manifold mesh can still be used.",
+            "member": [
+                { "name": "rigid_body", "info": "OPTIONAL: Rigid body handle to bind the solid body to.", "kind": "table?"           },
+                { "name": "model",      "info": "Model to get the geometry from.",                        "kind": "model"            },
+                { "name": "mesh_index", "info": "Index of mesh.",                                         "kind": "number"           },
+                { "name": "kind",       "info": "Model shape kind.",                                      "kind": "model_shape_kind" }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "test": "rapier/model.lua"
+        }
+        */
+        method.add_method_mut(
+            "solid_body_from_model",
+            |lua,
+             this,
+             (rigid_body, model, index, kind): (
+                Option<LuaValue>,
+                LuaAnyUserData,
+                usize,
+                i32,
+            )| unsafe {
+                let model = model.borrow::<crate::base::model::LuaModel>()?;
+
+                if index >= model.0.meshCount as usize {
+                    return Err(mlua::Error::runtime(
+                        "rapier:solid_body_from_model(): Invalid mesh index.",
+                    ));
+                }
+
+                let mesh = *model.0.meshes.wrapping_add(index);
+
+                // a mesh that has been unloaded, or never had vertex data, has no vertex pointer.
+                if mesh.vertices.is_null() {
+                    return Err(mlua::Error::runtime(
+                        "rapier:solid_body_from_model(): Mesh has no vertex data.",
+                    ));
+                }
+
+                let vertex = std::slice::from_raw_parts(
+                    mesh.vertices as *const Vector3,
+                    mesh.vertexCount as usize,
+                );
+
+                let p_table: Vec<Point<f32>> =
+                    vertex.iter().map(|x| point![x.x, x.y, x.z]).collect();
+                let mut i_table: Vec<[u32; 3]> = Vec::new();
+
+                // a mesh without index data is laid out as a plain triangle list.
+                if mesh.indices.is_null() {
+                    for x in 0..mesh.vertexCount as u32 / 3 {
+                        i_table.push([x * 3, x * 3 + 1, x * 3 + 2]);
+                    }
+                } else {
+                    let index = std::slice::from_raw_parts(
+                        mesh.indices as *const u16,
+                        (mesh.triangleCount * 3) as usize,
+                    );
+
+                    if index.iter().any(|x| *x as i32 >= mesh.vertexCount) {
+                        return Err(mlua::Error::runtime(
+                            "rapier:solid_body_from_model(): Mesh index is out of bounds.",
+                        ));
+                    }
+
+                    for x in index.chunks_exact(3) {
+                        i_table.push([x[0] as u32, x[1] as u32, x[2] as u32]);
+                    }
+                }
+
+                match kind {
+                    0 => {
+                        // the other flags (i.e. ORIENTED) need a manifold mesh.
+                        let collider = ColliderBuilder::trimesh_with_flags(
+                            p_table,
+                            i_table,
+                            TriMeshFlags::MERGE_DUPLICATE_VERTICES
+                                | TriMeshFlags::DELETE_DEGENERATE_TRIANGLES
+                                | TriMeshFlags::DELETE_DUPLICATE_TRIANGLES,
+                        )
+                        .map_err(|e| {
+                            mlua::Error::runtime(format!("rapier:solid_body_from_model(): {e}"))
+                        })?;
+
+                        this.insert_collider(lua, collider, rigid_body)
+                    }
+                    1 => {
+                        if let Some(collider) = ColliderBuilder::convex_hull(&p_table) {
+                            this.insert_collider(lua, collider, rigid_body)
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier:solid_body_from_model(): Could not compute convex hull.",
+                            ))
+                        }
+                    }
+                    2 => this.insert_collider(
+                        lua,
+                        ColliderBuilder::convex_decomposition(&p_table, &i_table),
+                        rigid_body,
+                    ),
+                    _ => Err(mlua::Error::runtime(
+                        "rapier:solid_body_from_model(): Invalid kind.",
+                    )),
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:solid_body_from_image",
+            "info": "Create a height-field solid body from an image. The red channel of every pixel is used as the height, from 0.0 (black) to 1.0 (red). The height-field is centered on the origin.",
+            "member": [
+                { "name": "rigid_body", "info": "OPTIONAL: Rigid body handle to bind the solid body to.", "kind": "table?"   },
+                { "name": "image",      "info": "Image to get the height data from.",                     "kind": "image"    },
+                { "name": "scale",      "info": "Scale of the height-field.",                             "kind": "vector_3" }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "solid_body_from_image",
+            |lua,
+             this,
+             (rigid_body, image, scale): (
+                Option<LuaValue>,
+                LuaAnyUserData,
+                LuaValue,
+            )| unsafe {
+                let image = image.borrow::<crate::base::image::LuaImage>()?;
+                let scale: Vector3 = lua.from_value(scale)?;
+
+                if image.0.width < 2 || image.0.height < 2 {
+                    return Err(mlua::Error::runtime(
+                        "rapier:solid_body_from_image(): Image must be at least 2x2 pixels.",
+                    ));
+                }
+
+                // row is along the Z axis, column is along the X axis.
+                let height = DMatrix::from_fn(
+                    image.0.height as usize,
+                    image.0.width as usize,
+                    |r, c| GetImageColor(image.0, c as i32, r as i32).r as f32 / 255.0,
+                );
+
+                this.insert_collider(
+                    lua,
+                    ColliderBuilder::heightfield(height, vector![scale.x, scale.y, scale.z]),
+                    rigid_body,
+                )
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_gravity",
+            "info": "Get the gravity of the simulation.",
+            "result": [
+                { "name": "gravity_x", "info": "Gravity (X).", "kind": "number" },
+                { "name": "gravity_y", "info": "Gravity (Y).", "kind": "number" },
+                { "name": "gravity_z", "info": "Gravity (Z).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_gravity", |_, this, _: ()| {
+            Ok((this.gravity.x, this.gravity.y, this.gravity.z))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_gravity",
+            "info": "Set the gravity of the simulation. Default: (0.0, -9.81, 0.0).",
+            "member": [
+                { "name": "gravity", "info": "Gravity.", "kind": "vector_3" }
+            ]
+        }
+        */
+        method.add_method_mut("set_gravity", |lua, this, gravity: LuaValue| {
+            let gravity: Vector3 = lua.from_value(gravity)?;
+            this.gravity = vector![gravity.x, gravity.y, gravity.z];
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_time_step",
+            "info": "Get the time step of the simulation.",
+            "result": [
+                { "name": "time_step", "info": "Time step, in seconds.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_time_step", |_, this, _: ()| {
+            Ok(this.integration_parameter.dt)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_time_step",
+            "info": "Set the time step of the simulation. Default: 1.0 / 60.0.",
+            "member": [
+                { "name": "time_step", "info": "Time step, in seconds.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("set_time_step", |_, this, time_step: f32| {
+            if !time_step.is_finite() || time_step <= 0.0 {
+                return Err(mlua::Error::runtime(
+                    "rapier:set_time_step(): Time step must be finite and greater than zero.",
+                ));
+            }
+
+            this.integration_parameter.dt = time_step;
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_solver_iteration",
+            "info": "Get the solver iteration count of the simulation.",
+            "result": [
+                { "name": "count", "info": "Solver iteration count.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_solver_iteration", |_, this, _: ()| {
+            Ok(this.integration_parameter.num_solver_iterations.get())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_solver_iteration",
+            "info": "Set the solver iteration count of the simulation. A higher count will give a more accurate simulation, at a performance cost. Default: 4.",
+            "member": [
+                { "name": "count", "info": "Solver iteration count.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("set_solver_iteration", |_, this, count: usize| {
+            if let Some(count) = std::num::NonZeroUsize::new(count) {
+                this.integration_parameter.num_solver_iterations = count;
+                return Ok(());
+            }
+
+            Err(mlua::Error::runtime(
+                "rapier:set_solver_iteration(): Solver iteration count must be greater than zero.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_ccd_substep",
+            "info": "Get the maximum CCD sub-step count of the simulation.",
+            "result": [
+                { "name": "count", "info": "Maximum CCD sub-step count.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_ccd_substep", |_, this, _: ()| {
+            Ok(this.integration_parameter.max_ccd_substeps)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:set_ccd_substep",
+            "info": "Set the maximum CCD sub-step count of the simulation. Default: 1.",
             "member": [
-                { "name": "rigid_body", "info": "rigid_body handle.",   "kind": "table"    },
-                { "name": "rotation", "info": "rigid_body rotation.", "kind": "vector_3" }
+                { "name": "count", "info": "Maximum CCD sub-step count.", "kind": "number" }
             ]
         }
         */
-        method.add_method_mut(
-            "set_rigid_body_rotation",
-            |lua, this, (rigid_body, rotation, wake_up): (LuaValue, LuaValue, bool)| {
-                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
-                let rotation: Vector3 = lua.from_value(rotation)?;
-
-                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
-                    rigid_body.set_rotation(
-                        Rotation::new(vector![rotation.x, rotation.y, rotation.z]),
-                        wake_up,
-                    );
-                    return Ok(());
-                }
-
-                Err(mlua::Error::runtime(
-                    "rapier:set_rigid_body_rotation(): Invalid rigid_body handle.",
-                ))
-            },
-        );
-
-        //================================================================
+        method.add_method_mut("set_ccd_substep", |_, this, count: usize| {
+            this.integration_parameter.max_ccd_substeps = count;
+            Ok(())
+        });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:get_solid_body_user_data",
-            "info": "Get the user data of a solid body.",
-            "member": [
-                { "name": "solid_body", "info": "Solid body handle.", "kind": "userdata" }
-            ],
+            "name": "rapier:get_prediction_distance",
+            "info": "Get the contact prediction distance of the simulation.",
             "result": [
-                { "name": "user_data", "info": "Solid body user data.", "kind": "number" }
+                { "name": "distance", "info": "Contact prediction distance.", "kind": "number" }
             ]
         }
         */
-        method.add_method_mut(
-            "get_solid_body_user_data",
-            |lua, this, collider: LuaValue| {
-                let collider: ColliderHandle = lua.from_value(collider)?;
-
-                if let Some(collider) = this.collider_set.get(collider) {
-                    return Ok(collider.user_data);
-                }
-
-                Err(mlua::Error::runtime(
-                    "rapier:get_solid_body_user_data(): Invalid solid body handle.",
-                ))
-            },
-        );
+        method.add_method_mut("get_prediction_distance", |_, this, _: ()| {
+            Ok(this.integration_parameter.prediction_distance())
+        });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:set_solid_body_user_data",
-            "info": "Set the user data of a solid body.",
+            "name": "rapier:set_prediction_distance",
+            "info": "Set the contact prediction distance of the simulation. Contacts closer than this distance will be computed ahead of time. Default: 0.002.",
             "member": [
-                { "name": "solid_body",  "info": "Solid body handle.",    "kind": "userdata" },
-                { "name": "user_data", "info": "Solid body user data.", "kind": "number"   }
+                { "name": "distance", "info": "Contact prediction distance.", "kind": "number" }
             ]
         }
         */
-        method.add_method_mut(
-            "set_solid_body_user_data",
-            |lua, this, (collider, user_data): (LuaValue, u128)| {
-                let collider: ColliderHandle = lua.from_value(collider)?;
-
-                if let Some(collider) = this.collider_set.get_mut(collider) {
-                    collider.user_data = user_data;
-                    return Ok(());
-                }
+        method.add_method_mut("set_prediction_distance", |_, this, distance: f32| {
+            this.integration_parameter.normalized_prediction_distance =
+                distance / this.integration_parameter.length_unit;
+            Ok(())
+        });
 
-                Err(mlua::Error::runtime(
-                    "rapier:set_solid_body_user_data(): Invalid solid body handle.",
-                ))
-            },
-        );
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:save",
+            "info": "Save the Rapier simulation to a data buffer. Every rigid body, solid body, joint, vehicle and the simulation state are saved, so that a simulation loaded with alicia.rapier.load will step exactly like the original.",
+            "result": [
+                { "name": "data", "info": "Data buffer.", "kind": "data" }
+            ],
+            "test": "rapier/save_load.lua"
+        }
+        */
+        method.add_method("save", |lua, this, _: ()| {
+            let data = bincode::serialize(this)
+                .map_err(|e| mlua::Error::runtime(format!("rapier:save(): {e}")))?;
 
-        //================================================================
+            crate::base::data::Data::new(lua, data)
+        });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "rapier:solid_body",
-            "info": "TO-DO"
+            "name": "rapier:step",
+            "info": "Step the Rapier simulation. If no frame time is given, the simulation is stepped once by the time step. Otherwise, the frame time is added to an accumulator, and the simulation is stepped by the time step for as long as the accumulator allows it, so that the simulation does not depend on the frame rate. Every vehicle is updated before each step.",
+            "member": [
+                { "name": "time",         "info": "OPTIONAL: Frame time.",                                                                                      "kind": "number?" },
+                { "name": "step_maximum", "info": "OPTIONAL: Maximum step count in a single call. Any remaining time in the accumulator is dropped. Default: 8.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "event_list", "info": "Collision event list, or nil if there were none.",                                                                  "kind": "table?" },
+                { "name": "force_list", "info": "Contact force event list, or nil if there were none.",                                                              "kind": "table?" },
+                { "name": "alpha",      "info": "Interpolation alpha (0.0 to 1.0) between the last step and the next one, for rendering. 0.0 if no frame time is given.", "kind": "number" }
+            ],
+            "test": "rapier/step.lua"
         }
         */
         method.add_method_mut(
-            "solid_body",
-            |lua, this, (rigid_body, kind, data): (Option<LuaValue>, i32, mlua::Variadic<LuaValue>)| {
-                match kind {
-                    0 => {
-                        if let Some(half_shape) = data.get(0) {
-                            let half_shape: Vector3 = lua.from_value(half_shape.clone())?;
-
-                            this.insert_collider(
-                                lua,
-                                ColliderBuilder::cuboid(half_shape.x, half_shape.y, half_shape.z),
-                                rigid_body,
-                            )
-                        } else {
-                            Err(mlua::Error::runtime(
-                                "rapier:solid_body(): Missing half-shape (vector_3) argument.",
-                            ))
-                        }
-                    },
-                    1 => {
-                        if let Some(point_table) = data.get(0) && let Some(index_table) = data.get(1) {
-                            let mut p_table: Vec<Point<f32>> = Vec::new();
-                            let mut i_table: Vec<[u32; 3]> = Vec::new();
-                            let point_table: Vec<Vector3> = lua.from_value(point_table.clone())?;
-                            let index_table: Vec<u32> = lua.from_value(index_table.clone())?;
+            "step",
+            |lua, this, (time, step_maximum): (Option<f32>, Option<usize>)| {
+                let _scope = ProfileScope::new("rapier.step", "physics");
 
-                            for x in point_table {
-                                p_table.push(point![x.x, x.y, x.z]);
-                            }
+                if let Some(time) = time
+                    && (!time.is_finite() || time < 0.0)
+                {
+                    return Err(mlua::Error::runtime("rapier:step(): Invalid frame time."));
+                }
 
-                            let mut iterator = index_table.iter();
+                {
+                    let mut list = this.event_handler.event_list.lock().unwrap();
+                    list.clear();
+                    let mut list = this.event_handler.force_list.lock().unwrap();
+                    list.clear();
+                }
 
-                            while let Some(a) = iterator.next() {
-                                if let Some(b) = iterator.next() {
-                                    if let Some(c) = iterator.next() {
-                                        i_table.push([*a, *b, *c]);
-                                    }
-                                }
-                            }
+                let mut alpha = 0.0;
 
-                            // TO-DO this should really be a convex_mesh call, but for some reason, it doesn't work, no matter what input is sent?
-                            this.insert_collider(
-                                lua,
-                                ColliderBuilder::trimesh_with_flags(
-                                    p_table,
-                                    i_table,
-                                    TriMeshFlags::all(),
-                                ).unwrap(),
-                                rigid_body,
-                            )
-                        } else {
-                            Err(mlua::Error::runtime(
-                                "rapier:solid_body(): Missing point table ({ vector_3 }) or index table ({ vector_3 }) argument.",
-                            ))
-                        }
-                    }
-                    _ => {
-                        if let Some(point_table) = data.get(0) {
-                            let mut p_table: Vec<Point<f32>> = Vec::new();
-                            let point_table: Vec<Vector3> = lua.from_value(point_table.clone())?;
+                if let Some(time) = time {
+                    let step = this.integration_parameter.dt;
+                    let step_maximum = step_maximum.unwrap_or(8);
+                    let mut count = 0;
 
-                            for x in point_table {
-                                p_table.push(point![x.x, x.y, x.z]);
-                            }
+                    this.accumulator += time;
 
-                            if let Some(collider) = ColliderBuilder::convex_hull(&p_table) {
-                                this.insert_collider(lua, collider, rigid_body)
-                            } else {
-                                Ok(mlua::Nil)
-                            }
-                        } else {
-                            Err(mlua::Error::runtime(
-                                "rapier:solid_body(): Missing point table ({ vector_3 }) argument.",
-                            ))
+                    while this.accumulator >= step {
+                        if count >= step_maximum {
+                            // drop any remaining time, to avoid spiralling out of control on a
+                            // slow frame.
+                            this.accumulator %= step;
+                            break;
                         }
+
+                        this.simulate();
+                        this.accumulator -= step;
+                        count += 1;
                     }
+
+                    alpha = this.accumulator / step;
+                } else {
+                    this.simulate();
                 }
-            },
-        );
 
-        /* entry
-        {
-            "version": "1.0.0",
-            "name": "rapier:step",
-            "info": "Step the Rapier simulation."
-        }
-        */
-        method.add_method_mut("step", |lua, this, _: ()| {
-            {
-                let mut list = this.event_handler.event_list.lock().unwrap();
-                list.clear();
-            }
+                let event_list = this.event_handler.event_list.lock().unwrap();
+                let force_list = this.event_handler.force_list.lock().unwrap();
 
-            this.simulation_pipeline.step(
-                &vector![0.0, -9.81, 0.0],
-                &this.integration_parameter,
-                &mut this.island_manager,
-                &mut this.broad_phase,
-                &mut this.narrow_phase,
-                &mut this.rigid_body_set,
-                &mut this.collider_set,
-                &mut this.impulse_joint_set,
-                &mut this.multibody_joint_set,
-                &mut this.ccd_solver,
-                Some(&mut this.query_pipeline),
-                &(),
-                &this.event_handler,
-            );
+                let event_list = if !event_list.is_empty() {
+                    lua.to_value(&*event_list)?
+                } else {
+                    mlua::Nil
+                };
 
-            let list = this.event_handler.event_list.lock().unwrap();
+                let force_list = if !force_list.is_empty() {
+                    lua.to_value(&*force_list)?
+                } else {
+                    mlua::Nil
+                };
 
-            if !list.is_empty() {
-                lua.to_value(&*list)
-            } else {
-                Ok(mlua::Nil)
-            }
-        });
+                Ok((event_list, force_list, alpha))
+            },
+        );
 
         /* entry
         {
@@ -1196,9 +3707,31 @@ impl mlua::UserData for Rapier {
     }
 }
 
+/* class
+{
+    "version": "1.0.0",
+    "name": "query_filter",
+    "info": "A table describing which solid bodies to take into account in a scene query. Every field is optional.",
+    "member": [
+        { "name": "group",              "info": "Collision group bit-mask of the query.",                                                                                                            "kind": "number?"   },
+        { "name": "mask",               "info": "Collision mask bit-mask of the query. A solid body is only considered if its group intersects this mask, and its mask intersects the query group.", "kind": "number?"   },
+        { "name": "exclude_rigid_body", "info": "Exclude every solid body bound to this rigid body.",                                                                                                "kind": "table?"    },
+        { "name": "exclude_solid_body", "info": "Exclude this solid body.",                                                                                                                          "kind": "table?"    },
+        { "name": "exclude_sensor",     "info": "Exclude every sensor solid body.",                                                                                                                  "kind": "boolean?"  },
+        { "name": "exclude_solid",      "info": "Exclude every non-sensor solid body.",                                                                                                              "kind": "boolean?"  },
+        { "name": "exclude_fixed",      "info": "Exclude every solid body bound to a fixed rigid body, or not bound to any rigid body.",                                                             "kind": "boolean?"  },
+        { "name": "exclude_kinematic",  "info": "Exclude every solid body bound to a kinematic rigid body.",                                                                                         "kind": "boolean?"  },
+        { "name": "exclude_dynamic",    "info": "Exclude every solid body bound to a dynamic rigid body.",                                                                                           "kind": "boolean?"  },
+        { "name": "predicate",          "info": "Function called with a solid body handle, which must return true to consider it. It must not call any method of the rapier simulation itself.",     "kind": "function?" }
+    ]
+}
+*/
+alicia_filter!(rapier3d);
+
 #[derive(Default)]
 struct AliciaHandler {
     event_list: Arc<Mutex<Vec<AliciaEvent>>>,
+    force_list: Arc<Mutex<Vec<AliciaForceEvent>>>,
 }
 
 #[derive(Serialize)]
@@ -1220,6 +3753,253 @@ impl Default for AliciaEvent {
     }
 }
 
+#[derive(Serialize)]
+struct AliciaForceEvent {
+    handle_a: ColliderHandle,
+    handle_b: ColliderHandle,
+    total_force: Vector3,
+    total_force_magnitude: f32,
+    max_force_direction: Vector3,
+    max_force_magnitude: f32,
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "wheel_tuning",
+    "info": "A table describing the suspension and friction settings of a vehicle wheel. Every field is optional.",
+    "member": [
+        { "name": "suspension_stiffness",    "info": "Suspension stiffness. Default: 5.88.",                              "kind": "number?" },
+        { "name": "suspension_compression",  "info": "Suspension damping, when compressed. Default: 0.83.",               "kind": "number?" },
+        { "name": "suspension_damping",      "info": "Suspension damping, when relaxed. Default: 0.88.",                  "kind": "number?" },
+        { "name": "max_suspension_travel",   "info": "Maximum suspension travel distance. Default: 5.0.",                 "kind": "number?" },
+        { "name": "max_suspension_force",    "info": "Maximum suspension force. Default: 6000.0.",                        "kind": "number?" },
+        { "name": "side_friction_stiffness", "info": "Side friction stiffness. Default: 1.0.",                            "kind": "number?" },
+        { "name": "friction_slip",           "info": "Friction slip. A higher value will give more grip. Default: 10.5.", "kind": "number?" }
+    ]
+}
+*/
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct AliciaWheelTuning {
+    suspension_stiffness: f32,
+    suspension_compression: f32,
+    suspension_damping: f32,
+    max_suspension_travel: f32,
+    max_suspension_force: f32,
+    side_friction_stiffness: f32,
+    friction_slip: f32,
+}
+
+impl Default for AliciaWheelTuning {
+    fn default() -> Self {
+        let tuning = WheelTuning::default();
+
+        Self {
+            suspension_stiffness: tuning.suspension_stiffness,
+            suspension_compression: tuning.suspension_compression,
+            suspension_damping: tuning.suspension_damping,
+            max_suspension_travel: tuning.max_suspension_travel,
+            max_suspension_force: tuning.max_suspension_force,
+            side_friction_stiffness: tuning.side_friction_stiffness,
+            friction_slip: tuning.friction_slip,
+        }
+    }
+}
+
+impl From<AliciaWheelTuning> for WheelTuning {
+    fn from(tuning: AliciaWheelTuning) -> Self {
+        Self {
+            suspension_stiffness: tuning.suspension_stiffness,
+            suspension_compression: tuning.suspension_compression,
+            suspension_damping: tuning.suspension_damping,
+            max_suspension_travel: tuning.max_suspension_travel,
+            max_suspension_force: tuning.max_suspension_force,
+            side_friction_stiffness: tuning.side_friction_stiffness,
+            friction_slip: tuning.friction_slip,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(from = "AliciaVehicleData")]
+struct AliciaVehicle {
+    controller: DynamicRayCastVehicleController,
+    filter: AliciaVehicleFilter,
+}
+
+// the vehicle controller can not be serialized, so only its settings and wheel list are saved,
+// and the controller is built again on load.
+impl Serialize for AliciaVehicle {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AliciaVehicleData::from(self).serialize(serializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AliciaVehicleData {
+    chassis: RigidBodyHandle,
+    up_axis: usize,
+    forward_axis: usize,
+    flag: u32,
+    group: Option<InteractionGroups>,
+    exclude_solid_body: Option<ColliderHandle>,
+    wheel: Vec<AliciaWheelData>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AliciaWheelData {
+    point: Point<f32>,
+    direction: Vector<f32>,
+    axle: Vector<f32>,
+    rest_length: f32,
+    radius: f32,
+    tuning: AliciaWheelTuning,
+    engine_force: f32,
+    brake: f32,
+    steering: f32,
+    rotation: f32,
+}
+
+impl From<&AliciaVehicle> for AliciaVehicleData {
+    fn from(vehicle: &AliciaVehicle) -> Self {
+        let controller = &vehicle.controller;
+
+        Self {
+            chassis: controller.chassis,
+            up_axis: controller.index_up_axis,
+            forward_axis: controller.index_forward_axis,
+            flag: vehicle.filter.flag.bits(),
+            group: vehicle.filter.group,
+            exclude_solid_body: vehicle.filter.exclude_solid_body,
+            wheel: controller
+                .wheels()
+                .iter()
+                .map(|wheel| AliciaWheelData {
+                    point: wheel.chassis_connection_point_cs,
+                    direction: wheel.direction_cs,
+                    axle: wheel.axle_cs,
+                    rest_length: wheel.suspension_rest_length,
+                    radius: wheel.radius,
+                    tuning: AliciaWheelTuning {
+                        suspension_stiffness: wheel.suspension_stiffness,
+                        suspension_compression: wheel.damping_compression,
+                        suspension_damping: wheel.damping_relaxation,
+                        max_suspension_travel: wheel.max_suspension_travel,
+                        max_suspension_force: wheel.max_suspension_force,
+                        side_friction_stiffness: wheel.side_friction_stiffness,
+                        friction_slip: wheel.friction_slip,
+                    },
+                    engine_force: wheel.engine_force,
+                    brake: wheel.brake,
+                    steering: wheel.steering,
+                    rotation: wheel.rotation,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<AliciaVehicleData> for AliciaVehicle {
+    fn from(data: AliciaVehicleData) -> Self {
+        let mut controller = DynamicRayCastVehicleController::new(data.chassis);
+
+        controller.index_up_axis = data.up_axis;
+        controller.index_forward_axis = data.forward_axis;
+
+        for wheel in data.wheel {
+            let result = controller.add_wheel(
+                wheel.point,
+                wheel.direction,
+                wheel.axle,
+                wheel.rest_length,
+                wheel.radius,
+                &wheel.tuning.into(),
+            );
+
+            result.engine_force = wheel.engine_force;
+            result.brake = wheel.brake;
+            result.steering = wheel.steering;
+            result.rotation = wheel.rotation;
+        }
+
+        Self {
+            controller,
+            filter: AliciaVehicleFilter {
+                flag: QueryFilterFlags::from_bits_truncate(data.flag),
+                group: data.group,
+                exclude_solid_body: data.exclude_solid_body,
+            },
+        }
+    }
+}
+
+// the scene query filter of a vehicle, kept to be used on every simulation step. unlike
+// AliciaFilter, it can not have a predicate, as the simulation step has no access to Lua.
+#[derive(Default)]
+struct AliciaVehicleFilter {
+    flag: QueryFilterFlags,
+    group: Option<InteractionGroups>,
+    exclude_solid_body: Option<ColliderHandle>,
+}
+
+impl AliciaVehicleFilter {
+    fn new(lua: &Lua, call: &str, table: Option<LuaTable>) -> mlua::Result<Self> {
+        let filter = AliciaFilter::new(lua, table)?;
+
+        if filter.predicate.is_some() {
+            return Err(mlua::Error::runtime(format!(
+                "{call}: Vehicle filter can not have a predicate."
+            )));
+        }
+
+        Ok(Self {
+            flag: filter.flag,
+            group: filter.group,
+            exclude_solid_body: filter.exclude_solid_body,
+        })
+    }
+
+    fn query(&self, chassis: RigidBodyHandle) -> QueryFilter<'static> {
+        let mut filter = QueryFilter::new();
+
+        filter.flags = self.flag;
+        filter.groups = self.group;
+        filter.exclude_rigid_body = Some(chassis);
+        filter.exclude_collider = self.exclude_solid_body;
+
+        filter
+    }
+}
+
+#[derive(Serialize)]
+struct AliciaWheel {
+    center: Vector3,
+    contact: bool,
+    contact_point: Vector3,
+    contact_normal: Vector3,
+    solid_body: Option<ColliderHandle>,
+    suspension_length: f32,
+    suspension_force: f32,
+    rotation: f32,
+}
+
+#[derive(Serialize)]
+struct AliciaHit {
+    solid_body: ColliderHandle,
+    time: f32,
+    normal: Vector3,
+}
+
+#[derive(Serialize)]
+struct AliciaContact {
+    point_a: Vector3,
+    point_b: Vector3,
+    normal: Vector3,
+    depth: f32,
+    impulse: f32,
+}
+
 impl EventHandler for AliciaHandler {
     fn handle_collision_event(
         &self,
@@ -1259,13 +4039,31 @@ impl EventHandler for AliciaHandler {
 
     fn handle_contact_force_event(
         &self,
-        _: f32,
+        step: f32,
         _: &RigidBodySet,
         _: &ColliderSet,
-        _: &ContactPair,
-        _: f32,
+        contact_pair: &ContactPair,
+        total_force_magnitude: f32,
     ) {
-        println!("bar");
+        let event = ContactForceEvent::from_contact_pair(step, contact_pair, total_force_magnitude);
+        let mut lock = self.force_list.lock().unwrap();
+
+        lock.push(AliciaForceEvent {
+            handle_a: event.collider1,
+            handle_b: event.collider2,
+            total_force: Vector3 {
+                x: event.total_force.x,
+                y: event.total_force.y,
+                z: event.total_force.z,
+            },
+            total_force_magnitude: event.total_force_magnitude,
+            max_force_direction: Vector3 {
+                x: event.max_force_direction.x,
+                y: event.max_force_direction.y,
+                z: event.max_force_direction.z,
+            },
+            max_force_magnitude: event.max_force_magnitude,
+        });
     }
 }
 
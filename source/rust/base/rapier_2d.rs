@@ -0,0 +1,1363 @@
+/*
+* Copyright (c) 2025 luxreduxdelux
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use crate::base::helper::*;
+use crate::base::profiler::*;
+use crate::base::rapier_filter::alicia_filter;
+use mlua::prelude::*;
+use rapier2d::control::CharacterLength;
+use rapier2d::{
+    control::{CharacterAutostep, KinematicCharacterController},
+    parry,
+    prelude::*,
+};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "feature": "rapier2d", "name": "alicia.rapier_2d", "info": "The Rapier (2D) API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let rapier = lua.create_table()?;
+
+    rapier.set("new", lua.create_function(self::Rapier::new)?)?;
+
+    table.set("rapier_2d", rapier)?;
+
+    Ok(())
+}
+
+/* class
+{ "version": "1.0.0", "name": "rapier_2d", "info": "An unique handle for a Rapier (2D) simulation." }
+*/
+#[derive(Default)]
+struct Rapier {
+    integration_parameter: IntegrationParameters,
+    simulation_pipeline: PhysicsPipeline,
+    island_manager: IslandManager,
+    broad_phase: DefaultBroadPhase,
+    narrow_phase: NarrowPhase,
+    rigid_body_set: RigidBodySet,
+    collider_set: ColliderSet,
+    impulse_joint_set: ImpulseJointSet,
+    multibody_joint_set: MultibodyJointSet,
+    ccd_solver: CCDSolver,
+    query_pipeline: QueryPipeline,
+    event_handler: AliciaHandler,
+    debug_render: DebugRenderPipeline,
+}
+
+impl Rapier {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.rapier_2d.new",
+        "info": "Create a new Rapier (2D) simulation.",
+        "result": [
+            { "name": "rapier_2d", "info": "Rapier (2D) simulation.", "kind": "rapier_2d" }
+        ]
+    }
+    */
+    fn new(_: &Lua, _: ()) -> mlua::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn insert_collider(
+        &mut self,
+        lua: &Lua,
+        collider: ColliderBuilder,
+        rigid_body: Option<LuaValue>,
+    ) -> mlua::Result<LuaValue> {
+        let collider = collider
+            .active_events(ActiveEvents::COLLISION_EVENTS)
+            .active_collision_types(ActiveCollisionTypes::all());
+
+        if let Some(rigid_body) = rigid_body {
+            let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+            lua.to_value(&self.collider_set.insert_with_parent(
+                collider,
+                rigid_body,
+                &mut self.rigid_body_set,
+            ))
+        } else {
+            lua.to_value(&self.collider_set.insert(collider))
+        }
+    }
+}
+
+impl mlua::UserData for Rapier {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:cast_ray",
+            "info": "Cast a ray.",
+            "member": [
+                { "name": "point",              "info": "Ray point.",                                                                                                           "kind": "vector_2"      },
+                { "name": "direction",          "info": "Ray direction.",                                                                                                       "kind": "vector_2"      },
+                { "name": "range",              "info": "Ray range.",                                                                                                           "kind": "number"        },
+                { "name": "solid",              "info": "If true, a ray starting inside of a shape will hit it at once.",                                                       "kind": "boolean"       },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",         "kind": "table?"        }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table"  },
+                { "name": "time",       "info": "Time of impact.",    "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "cast_ray",
+            |lua,
+             this,
+             (point, direction, range, solid, filter, exclude_solid_body): (
+                LuaValue,
+                LuaValue,
+                f32,
+                bool,
+                Option<LuaValue>,
+                Option<LuaValue>,
+            )| {
+                let point: Vector2 = lua.from_value(point)?;
+                let direction: Vector2 = lua.from_value(direction)?;
+                let ray = rapier2d::geometry::Ray::new(
+                    point![point.x, point.y],
+                    vector![direction.x, direction.y],
+                );
+
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::empty(),
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let hit = this.query_pipeline.cast_ray(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &ray,
+                    range,
+                    solid,
+                    filter,
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, time)) = hit {
+                    return Ok((lua.to_value(&handle)?, time));
+                }
+
+                Ok((mlua::Nil, 0.0))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:cast_ray_normal",
+            "info": "Cast a ray, and also get the normal information.",
+            "member": [
+                { "name": "point",              "info": "Ray point.",                                                                                                           "kind": "vector_2"      },
+                { "name": "direction",          "info": "Ray direction.",                                                                                                       "kind": "vector_2"      },
+                { "name": "range",              "info": "Ray range.",                                                                                                           "kind": "number"        },
+                { "name": "solid",              "info": "If true, a ray starting inside of a shape will hit it at once.",                                                       "kind": "boolean"       },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. A rigid body handle to exclude is also accepted in its place, as in previous versions.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",         "kind": "table?"        }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table"  },
+                { "name": "time",       "info": "Time of impact.",    "kind": "number" },
+                { "name": "normal_x",   "info": "Hit normal (X).",    "kind": "number" },
+                { "name": "normal_y",   "info": "Hit normal (Y).",    "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "cast_ray_normal",
+            |lua,
+             this,
+             (point, direction, range, solid, filter, exclude_solid_body): (
+                LuaValue,
+                LuaValue,
+                f32,
+                bool,
+                Option<LuaValue>,
+                Option<LuaValue>,
+            )| {
+                let point: Vector2 = lua.from_value(point)?;
+                let direction: Vector2 = lua.from_value(direction)?;
+                let ray = rapier2d::geometry::Ray::new(
+                    point![point.x, point.y],
+                    vector![direction.x, direction.y],
+                );
+
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::empty(),
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let hit = this.query_pipeline.cast_ray_and_get_normal(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &ray,
+                    range,
+                    solid,
+                    filter,
+                );
+
+                query_filter.error()?;
+
+                if let Some((handle, normal)) = hit {
+                    return Ok((
+                        lua.to_value(&handle)?,
+                        normal.time_of_impact,
+                        normal.normal.x,
+                        normal.normal.y,
+                    ));
+                }
+
+                Ok((mlua::Nil, 0.0, 0.0, 0.0))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:test_intersect_cuboid_cuboid",
+            "info": "Check if a cuboid is intersecting against another cuboid.",
+            "member": [
+                { "name": "point_a", "info": "Point of cuboid (A).", "kind": "vector_2" },
+                { "name": "angle_a", "info": "Angle of cuboid (A).", "kind": "number"   },
+                { "name": "shape_a", "info": "Shape of cuboid (A).", "kind": "vector_2" },
+                { "name": "point_b", "info": "Point of cuboid (B).", "kind": "vector_2" },
+                { "name": "angle_b", "info": "Angle of cuboid (B).", "kind": "number"   },
+                { "name": "shape_b", "info": "Shape of cuboid (B).", "kind": "vector_2" }
+            ],
+            "result": [
+                { "name": "intersect", "info": "Result of intersection.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "test_intersect_cuboid_cuboid",
+            |lua,
+             _,
+             (point_a, angle_a, shape_a, point_b, angle_b, shape_b): (
+                LuaValue,
+                f32,
+                LuaValue,
+                LuaValue,
+                f32,
+                LuaValue,
+            )| {
+                let point: Vector2 = lua.from_value(point_a)?;
+                let shape: Vector2 = lua.from_value(shape_a)?;
+                let point_a = Isometry::new(vector![point.x, point.y], angle_a);
+                let shape_a = Cuboid::new(vector![shape.x, shape.y]);
+
+                let point: Vector2 = lua.from_value(point_b)?;
+                let shape: Vector2 = lua.from_value(shape_b)?;
+                let point_b = Isometry::new(vector![point.x, point.y], angle_b);
+                let shape_b = Cuboid::new(vector![shape.x, shape.y]);
+
+                Ok(
+                    parry::query::intersection_test(&point_a, &shape_a, &point_b, &shape_b)
+                        .unwrap(),
+                )
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:test_intersect_cuboid",
+            "info": "Check if a cuboid is intersecting against any solid body in the simulation.",
+            "member": [
+                { "name": "point",              "info": "Point of cuboid.",                                                                                                                                                                           "kind": "vector_2"      },
+                { "name": "angle",              "info": "Angle of cuboid.",                                                                                                                                                                           "kind": "number"        },
+                { "name": "shape",              "info": "Shape of cuboid.",                                                                                                                                                                           "kind": "vector_2"      },
+                { "name": "filter",             "info": "OPTIONAL: Scene query filter. If nil, every sensor is excluded. A rigid body handle to exclude is also accepted in its place, as in previous versions, and will also exclude every sensor.", "kind": "query_filter?" },
+                { "name": "exclude_solid_body", "info": "OPTIONAL: Solid body handle to exclude, if a rigid body handle was given in place of the scene query filter.",                                                                               "kind": "table?"        }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle, if any.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "test_intersect_cuboid",
+            |lua,
+             this,
+             (point, angle, shape, filter, exclude_solid_body): (
+                LuaValue,
+                f32,
+                LuaValue,
+                Option<LuaValue>,
+                Option<LuaValue>,
+            )| {
+                let point: Vector2 = lua.from_value(point)?;
+                let shape: Vector2 = lua.from_value(shape)?;
+                let point = Isometry::new(vector![point.x, point.y], angle);
+                let shape = Cuboid::new(vector![shape.x, shape.y]);
+
+                let query_filter = AliciaFilter::new_legacy(
+                    lua,
+                    filter,
+                    exclude_solid_body,
+                    QueryFilterFlags::EXCLUDE_SENSORS,
+                )?;
+                let predicate = query_filter.predicate(lua);
+                let filter = query_filter.query(&predicate);
+
+                let mut hit: Option<ColliderHandle> = None;
+
+                this.query_pipeline.intersections_with_shape(
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &point,
+                    &shape,
+                    filter,
+                    |handle| {
+                        hit = Some(handle);
+                        true
+                    },
+                );
+
+                query_filter.error()?;
+
+                if let Some(hit) = hit {
+                    lua.to_value(&hit)
+                } else {
+                    Ok(mlua::Nil)
+                }
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_solid_body_shape",
+            "info": "Get the shape of a solid body (ball or cuboid).",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "shape", "info": "Radius of the ball, or half-shape of the cuboid.", "kind": "number | table" }
+            ]
+        }
+        */
+        method.add_method_mut("get_solid_body_shape", |lua, this, collider: LuaValue| {
+            let collider: ColliderHandle = lua.from_value(collider)?;
+
+            if let Some(collider) = this.collider_set.get(collider) {
+                if let Some(shape) = collider.shape().as_ball() {
+                    return Ok(lua.to_value(&shape.radius));
+                }
+
+                if let Some(shape) = collider.shape().as_cuboid() {
+                    return Ok(lua.to_value(&(shape.half_extents.x, shape.half_extents.y)));
+                }
+            }
+
+            Err(mlua::Error::runtime(
+                "rapier_2d:get_solid_body_shape(): Invalid solid body handle.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_solid_body_shape",
+            "info": "Set the shape of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",                                      "kind": "table"              },
+                { "name": "...",        "info": "Radius of the ball, or half-shape of the cuboid.",       "kind": "number | vector_2" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_shape",
+            |lua, this, (collider, data): (LuaValue, mlua::Variadic<LuaValue>)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    if let Some(shape) = collider.shape_mut().as_ball_mut() {
+                        if let Some(radius) = data.first() {
+                            let radius: f32 = lua.from_value(radius.clone())?;
+
+                            shape.radius = radius;
+                        }
+                    }
+
+                    if let Some(shape) = collider.shape_mut().as_cuboid_mut() {
+                        if let Some(half_shape) = data.first() {
+                            let half_shape: Vector2 = lua.from_value(half_shape.clone())?;
+
+                            shape.half_extents.x = half_shape.x;
+                            shape.half_extents.y = half_shape.y;
+                        } else {
+                            return Err(mlua::Error::runtime(
+                                "rapier_2d:set_solid_body_shape(): Missing half-shape argument (vector_2).",
+                            ));
+                        }
+                    }
+
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_solid_body_shape(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_solid_body_parent",
+            "info": "Get the parent of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("get_solid_body_parent", |lua, this, collider: LuaValue| {
+            let collider: ColliderHandle = lua.from_value(collider)?;
+
+            if let Some(collider) = this.collider_set.get(collider) {
+                if let Some(parent) = collider.parent() {
+                    return lua.to_value(&parent);
+                } else {
+                    return Ok(mlua::Nil);
+                }
+            }
+
+            Err(mlua::Error::runtime(
+                "rapier_2d:get_solid_body_parent(): Invalid solid body handle.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_solid_body_position",
+            "info": "Get the position of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "position_x", "info": "Solid body position (X).", "kind": "number" },
+                { "name": "position_y", "info": "Solid body position (Y).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_position",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    return Ok((collider.translation().x, collider.translation().y));
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:get_solid_body_position(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_solid_body_rotation",
+            "info": "Get the rotation of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "rotation", "info": "Solid body rotation, in radians.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_rotation",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    return Ok(collider.rotation().angle());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:get_solid_body_rotation(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_solid_body_position",
+            "info": "Set the position of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",   "kind": "table"    },
+                { "name": "position",   "info": "Solid body position.", "kind": "vector_2" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_position",
+            |lua, this, (collider, position): (LuaValue, LuaValue)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+                let position: Vector2 = lua.from_value(position)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_translation(vector![position.x, position.y]);
+                    collider.set_translation_wrt_parent(vector![position.x, position.y]);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_solid_body_position(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_solid_body_rotation",
+            "info": "Set the rotation of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",               "kind": "table"  },
+                { "name": "rotation",   "info": "Solid body rotation, in radians.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_rotation",
+            |lua, this, (collider, rotation): (LuaValue, f32)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_rotation(Rotation::new(rotation));
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_solid_body_rotation(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_solid_body_sensor",
+            "info": "Set the sensor state of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",       "kind": "table"   },
+                { "name": "sensor",     "info": "Solid body sensor state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_sensor",
+            |lua, this, (collider, sensor): (LuaValue, bool)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.set_sensor(sensor);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_solid_body_sensor(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:solid_body_remove",
+            "info": "Remove a solid body.",
+            "member": [
+                { "name": "solid_body",  "info": "Solid body handle.",                                                             "kind": "table"   },
+                { "name": "wake_parent", "info": "Whether or not to wake up the rigid body parent this solid body is bound to.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "solid_body_remove",
+            |lua, this, (collider, wake_parent): (LuaValue, bool)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                this.collider_set.remove(
+                    collider,
+                    &mut this.island_manager,
+                    &mut this.rigid_body_set,
+                    wake_parent,
+                );
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:rigid_body_remove",
+            "info": "Remove a rigid body.",
+            "member": [
+                { "name": "rigid_body",        "info": "Rigid body handle.",                                                     "kind": "table"   },
+                { "name": "remove_solid_body", "info": "Whether or not to remove every solid body this rigid body is bound to.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "rigid_body_remove",
+            |lua, this, (rigid_body, remove_collider): (LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                this.rigid_body_set.remove(
+                    rigid_body,
+                    &mut this.island_manager,
+                    &mut this.collider_set,
+                    &mut this.impulse_joint_set,
+                    &mut this.multibody_joint_set,
+                    remove_collider,
+                );
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:character_controller",
+            "info": "Create a character controller.",
+            "result": [
+                { "name": "character_controller", "info": "Character controller.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("character_controller", |lua, _, _: ()| {
+            let character = KinematicCharacterController {
+                // raylib's screen-space is Y-down, so "up" is negative Y.
+                up: UnitVector::new_normalize(vector![0.0, -1.0]),
+                ..Default::default()
+            };
+
+            lua.to_value(&character)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_character_controller_up_vector",
+            "info": "Set the up vector of a character controller.",
+            "member": [
+                { "name": "character", "info": "Character controller.", "kind": "table"    },
+                { "name": "up",        "info": "Up vector.",            "kind": "vector_2" }
+            ],
+            "result": [
+                { "name": "character", "info": "Character controller.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_character_controller_up_vector",
+            |lua, _, (character, up): (LuaValue, LuaValue)| {
+                let mut character: KinematicCharacterController = lua.from_value(character)?;
+                let up: Vector2 = lua.from_value(up)?;
+                character.up = UnitVector::new_normalize(vector![up.x, up.y]);
+                lua.to_value(&character)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_character_controller_slope",
+            "info": "Set the slope angle limits of a character controller.",
+            "member": [
+                { "name": "character", "info": "Character controller.",                 "kind": "table"  },
+                { "name": "slope_min", "info": "Minimum slope angle to slide down on.", "kind": "number" },
+                { "name": "slope_max", "info": "Maximum slope angle to climb up on.",   "kind": "number" }
+            ],
+            "result": [
+                { "name": "character", "info": "Character controller.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_character_controller_slope",
+            |lua, _, (character, slope_min, slope_max): (LuaValue, f32, f32)| {
+                let mut character: KinematicCharacterController = lua.from_value(character)?;
+                character.min_slope_slide_angle = slope_min;
+                character.max_slope_climb_angle = slope_max;
+                lua.to_value(&character)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_character_auto_step",
+            "info": "Set the auto-step data of a character controller. Pass 0 as either kind to disable auto-step.",
+            "member": [
+                { "name": "character", "info": "Character controller.",                                "kind": "table"   },
+                { "name": "kind_a",    "info": "Maximum height kind. 1: absolute, 2: relative.",       "kind": "number"  },
+                { "name": "kind_b",    "info": "Minimum width kind. 1: absolute, 2: relative.",        "kind": "number"  },
+                { "name": "value_a",   "info": "Maximum height.",                                      "kind": "number"  },
+                { "name": "value_b",   "info": "Minimum width.",                                       "kind": "number"  },
+                { "name": "dynamic",   "info": "Whether or not to step on dynamic rigid bodies too.", "kind": "boolean" }
+            ],
+            "result": [
+                { "name": "character", "info": "Character controller.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_character_auto_step",
+            |lua,
+             _,
+             (character, kind_a, kind_b, value_a, value_b, dynamic): (
+                LuaValue,
+                i32,
+                i32,
+                f32,
+                f32,
+                bool,
+            )| {
+                let mut character: KinematicCharacterController = lua.from_value(character)?;
+                if kind_a == 0 || kind_b == 0 {
+                    character.autostep = None;
+                } else {
+                    character.autostep = Some(CharacterAutostep {
+                        max_height: match kind_a {
+                            1 => CharacterLength::Absolute(value_a),
+                            _ => CharacterLength::Relative(value_a),
+                        },
+                        min_width: match kind_b {
+                            1 => CharacterLength::Absolute(value_b),
+                            _ => CharacterLength::Relative(value_b),
+                        },
+                        include_dynamic_bodies: dynamic,
+                    });
+                }
+                lua.to_value(&character)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_character_snap_ground",
+            "info": "Set the snap-to-ground data of a character controller. Pass 0 as the kind to disable snap-to-ground.",
+            "member": [
+                { "name": "character", "info": "Character controller.",                  "kind": "table"  },
+                { "name": "kind",      "info": "Distance kind. 1: absolute, 2: relative.", "kind": "number" },
+                { "name": "value",     "info": "Distance.",                              "kind": "number" }
+            ],
+            "result": [
+                { "name": "character", "info": "Character controller.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_character_snap_ground",
+            |lua, _, (character, kind, value): (LuaValue, i32, f32)| {
+                let mut character: KinematicCharacterController = lua.from_value(character)?;
+                let snap = match kind {
+                    1 => Some(CharacterLength::Absolute(value)),
+                    2 => Some(CharacterLength::Relative(value)),
+                    _ => None,
+                };
+
+                character.snap_to_ground = snap;
+
+                lua.to_value(&character)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:character_controller_move",
+            "info": "Move a character controller.",
+            "member": [
+                { "name": "step",        "info": "Time step.",                                                      "kind": "number"        },
+                { "name": "character",   "info": "Character controller.",                                           "kind": "table"         },
+                { "name": "solid_body",  "info": "Solid body handle of the character.",                             "kind": "table"         },
+                { "name": "translation", "info": "Desired translation, per second.",                                "kind": "vector_2"      },
+                { "name": "filter",      "info": "OPTIONAL: Scene query filter. If nil, every sensor is excluded.", "kind": "query_filter?" }
+            ],
+            "result": [
+                { "name": "movement_x", "info": "Translation point (X).", "kind": "number"  },
+                { "name": "movement_y", "info": "Translation point (Y).", "kind": "number"  },
+                { "name": "floor",      "info": "Currently on floor.",    "kind": "boolean" },
+                { "name": "slope",      "info": "Currently on slope.",    "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "character_controller_move",
+            |lua,
+             this,
+             (step, character, collider, translation, filter): (
+                f32,
+                LuaValue,
+                LuaValue,
+                LuaValue,
+                Option<LuaTable>,
+            )| {
+                let character: KinematicCharacterController = lua.from_value(character)?;
+                let collider_h: ColliderHandle = lua.from_value(collider)?;
+                let collider_r = this.collider_set.get(collider_h).ok_or(mlua::Error::runtime(
+                    "rapier_2d:character_controller_move(): Invalid solid body handle.",
+                ))?;
+                let translation: Vector2 = lua.from_value(translation)?;
+
+                let query_filter = if filter.is_some() {
+                    AliciaFilter::new(lua, filter)?
+                } else {
+                    AliciaFilter {
+                        flag: QueryFilterFlags::EXCLUDE_SENSORS,
+                        ..Default::default()
+                    }
+                };
+                let predicate = query_filter.predicate(lua);
+
+                let movement = character.move_shape(
+                    step,
+                    &this.rigid_body_set,
+                    &this.collider_set,
+                    &this.query_pipeline,
+                    collider_r.shape(),
+                    collider_r.position(),
+                    vector![translation.x * step, translation.y * step],
+                    query_filter.query(&predicate).exclude_collider(collider_h),
+                    |_| {}
+                );
+
+                query_filter.error()?;
+
+                Ok((
+                    movement.translation.x,
+                    movement.translation.y,
+                    movement.grounded,
+                    movement.is_sliding_down_slope
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:rigid_body",
+            "info": "Create a rigid body.",
+            "member": [
+                { "name": "kind", "info": "Rigid body kind.", "kind": "rigid_body_kind" }
+            ],
+            "result": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("rigid_body", |lua, this, kind: i32| {
+            let rigid = match kind {
+                1 => RigidBodyBuilder::dynamic(),
+                2 => RigidBodyBuilder::kinematic_position_based(),
+                3 => RigidBodyBuilder::kinematic_velocity_based(),
+                _ => RigidBodyBuilder::fixed(),
+            };
+
+            lua.to_value(&this.rigid_body_set.insert(rigid))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_rigid_body_user_data",
+            "info": "Get the user data of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "user_data", "info": "Rigid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_rigid_body_user_data",
+            |lua, this, rigid_body: LuaValue| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get(rigid_body) {
+                    return Ok(rigid_body.user_data);
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:get_rigid_body_user_data(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_rigid_body_user_data",
+            "info": "Set the user data of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",    "kind": "table"  },
+                { "name": "user_data",  "info": "Rigid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_user_data",
+            |lua, this, (rigid_body, user_data): (LuaValue, u128)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.user_data = user_data;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_rigid_body_user_data(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_rigid_body_position",
+            "info": "Set the position of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                         "kind": "table"    },
+                { "name": "position",   "info": "Rigid body position.",                       "kind": "vector_2" },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_position",
+            |lua, this, (rigid_body, position, wake_up): (LuaValue, LuaValue, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+                let position: Vector2 = lua.from_value(position)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_translation(vector![position.x, position.y], wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_rigid_body_position(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_rigid_body_rotation",
+            "info": "Set the rotation of a rigid body.",
+            "member": [
+                { "name": "rigid_body", "info": "Rigid body handle.",                         "kind": "table"   },
+                { "name": "rotation",   "info": "Rigid body rotation, in radians.",           "kind": "number"  },
+                { "name": "wake_up",    "info": "Whether or not to wake up the rigid body.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_rigid_body_rotation",
+            |lua, this, (rigid_body, rotation, wake_up): (LuaValue, f32, bool)| {
+                let rigid_body: RigidBodyHandle = lua.from_value(rigid_body)?;
+
+                if let Some(rigid_body) = this.rigid_body_set.get_mut(rigid_body) {
+                    rigid_body.set_rotation(Rotation::new(rotation), wake_up);
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_rigid_body_rotation(): Invalid rigid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:get_solid_body_user_data",
+            "info": "Get the user data of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "user_data", "info": "Solid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_solid_body_user_data",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    return Ok(collider.user_data);
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:get_solid_body_user_data(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:set_solid_body_user_data",
+            "info": "Set the user data of a solid body.",
+            "member": [
+                { "name": "solid_body", "info": "Solid body handle.",    "kind": "table"  },
+                { "name": "user_data",  "info": "Solid body user data.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_solid_body_user_data",
+            |lua, this, (collider, user_data): (LuaValue, u128)| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get_mut(collider) {
+                    collider.user_data = user_data;
+                    return Ok(());
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier_2d:set_solid_body_user_data(): Invalid solid body handle.",
+                ))
+            },
+        );
+
+        //================================================================
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:solid_body",
+            "info": "Create a solid body. Kind 0 is a cuboid (vector_2 half-shape), kind 1 is a ball (number radius), kind 2 is a poly-line (vector_2 point table), any other kind is a convex hull (vector_2 point table).",
+            "member": [
+                { "name": "rigid_body", "info": "OPTIONAL: Rigid body handle to bind the solid body to.", "kind": "table?" },
+                { "name": "kind",       "info": "Solid body kind.",                                       "kind": "number" },
+                { "name": "...",        "info": "Solid body shape data.",                                 "kind": "any"    }
+            ],
+            "result": [
+                { "name": "solid_body", "info": "Solid body handle.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "solid_body",
+            |lua, this, (rigid_body, kind, data): (Option<LuaValue>, i32, mlua::Variadic<LuaValue>)| {
+                match kind {
+                    0 => {
+                        if let Some(half_shape) = data.first() {
+                            let half_shape: Vector2 = lua.from_value(half_shape.clone())?;
+
+                            this.insert_collider(
+                                lua,
+                                ColliderBuilder::cuboid(half_shape.x, half_shape.y),
+                                rigid_body,
+                            )
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier_2d:solid_body(): Missing half-shape (vector_2) argument.",
+                            ))
+                        }
+                    }
+                    1 => {
+                        if let Some(radius) = data.first() {
+                            let radius: f32 = lua.from_value(radius.clone())?;
+
+                            this.insert_collider(lua, ColliderBuilder::ball(radius), rigid_body)
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier_2d:solid_body(): Missing radius (number) argument.",
+                            ))
+                        }
+                    }
+                    2 => {
+                        if let Some(point_table) = data.first() {
+                            let point_table: Vec<Vector2> = lua.from_value(point_table.clone())?;
+                            let p_table: Vec<Point<f32>> =
+                                point_table.iter().map(|x| point![x.x, x.y]).collect();
+
+                            this.insert_collider(
+                                lua,
+                                ColliderBuilder::polyline(p_table, None),
+                                rigid_body,
+                            )
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier_2d:solid_body(): Missing point table ({ vector_2 }) argument.",
+                            ))
+                        }
+                    }
+                    _ => {
+                        if let Some(point_table) = data.first() {
+                            let point_table: Vec<Vector2> = lua.from_value(point_table.clone())?;
+                            let p_table: Vec<Point<f32>> =
+                                point_table.iter().map(|x| point![x.x, x.y]).collect();
+
+                            if let Some(collider) = ColliderBuilder::convex_hull(&p_table) {
+                                this.insert_collider(lua, collider, rigid_body)
+                            } else {
+                                Ok(mlua::Nil)
+                            }
+                        } else {
+                            Err(mlua::Error::runtime(
+                                "rapier_2d:solid_body(): Missing point table ({ vector_2 }) argument.",
+                            ))
+                        }
+                    }
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:step",
+            "info": "Step the Rapier (2D) simulation.",
+            "result": [
+                { "name": "event_list", "info": "Collision event list, or nil if there were none.", "kind": "table?" }
+            ],
+            "test": "rapier_2d/step.lua"
+        }
+        */
+        method.add_method_mut("step", |lua, this, _: ()| {
+            let _scope = ProfileScope::new("rapier_2d.step", "physics");
+
+            {
+                let mut list = this.event_handler.event_list.lock().unwrap();
+                list.clear();
+            }
+
+            // raylib's screen-space is Y-down, so gravity points towards positive Y.
+            this.simulation_pipeline.step(
+                &vector![0.0, 9.81],
+                &this.integration_parameter,
+                &mut this.island_manager,
+                &mut this.broad_phase,
+                &mut this.narrow_phase,
+                &mut this.rigid_body_set,
+                &mut this.collider_set,
+                &mut this.impulse_joint_set,
+                &mut this.multibody_joint_set,
+                &mut this.ccd_solver,
+                Some(&mut this.query_pipeline),
+                &(),
+                &this.event_handler,
+            );
+
+            let list = this.event_handler.event_list.lock().unwrap();
+
+            if !list.is_empty() {
+                lua.to_value(&*list)
+            } else {
+                Ok(mlua::Nil)
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier_2d:debug_render",
+            "info": "Render the Rapier (2D) simulation. Must be called within a 2D draw call.",
+            "head": true
+        }
+        */
+        method.add_method_mut("debug_render", |_, this, _: ()| {
+            this.debug_render.render(
+                &mut DebugRender,
+                &this.rigid_body_set,
+                &this.collider_set,
+                &this.impulse_joint_set,
+                &this.multibody_joint_set,
+                &this.narrow_phase,
+            );
+
+            Ok(())
+        });
+    }
+}
+
+#[derive(Default)]
+struct AliciaHandler {
+    event_list: Arc<Mutex<Vec<AliciaEvent>>>,
+}
+
+#[derive(Serialize)]
+struct AliciaEvent {
+    handle_a: ColliderHandle,
+    handle_b: ColliderHandle,
+    flag: CollisionEventFlags,
+    start: bool,
+}
+
+impl EventHandler for AliciaHandler {
+    fn handle_collision_event(
+        &self,
+        _: &RigidBodySet,
+        _: &ColliderSet,
+        event: CollisionEvent,
+        _: Option<&ContactPair>,
+    ) {
+        let mut lock = self.event_list.lock().unwrap();
+        match event {
+            CollisionEvent::Started(
+                collider_handle_a,
+                collider_handle_b,
+                collision_event_flags,
+            ) => {
+                lock.push(AliciaEvent {
+                    handle_a: collider_handle_a,
+                    handle_b: collider_handle_b,
+                    flag: collision_event_flags,
+                    start: true,
+                });
+            }
+            CollisionEvent::Stopped(
+                collider_handle_a,
+                collider_handle_b,
+                collision_event_flags,
+            ) => {
+                lock.push(AliciaEvent {
+                    handle_a: collider_handle_a,
+                    handle_b: collider_handle_b,
+                    flag: collision_event_flags,
+                    start: false,
+                });
+            }
+        }
+    }
+
+    fn handle_contact_force_event(
+        &self,
+        _: f32,
+        _: &RigidBodySet,
+        _: &ColliderSet,
+        _: &ContactPair,
+        _: f32,
+    ) {
+    }
+}
+
+// scene query filter. see query_filter, in the Rapier (3D) API.
+alicia_filter!(rapier2d);
+
+struct DebugRender;
+
+impl DebugRenderBackend for DebugRender {
+    fn draw_line(
+        &mut self,
+        _object: DebugRenderObject<'_>,
+        a: Point<f32>,
+        b: Point<f32>,
+        color: [f32; 4],
+    ) {
+        unsafe {
+            DrawLineV(
+                Vector2 { x: a.x, y: a.y },
+                Vector2 { x: b.x, y: b.y },
+                Color {
+                    r: (255.0 * color[0]) as u8,
+                    g: (255.0 * color[1]) as u8,
+                    b: (255.0 * color[2]) as u8,
+                    a: (255.0 * color[3]) as u8,
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,206 @@
+/*
+* Copyright (c) 2025 luxreduxdelux
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+// the scene query filter of the Rapier (3D) and Rapier (2D) API. only the Rapier crate (and so
+// every type in it) differs between both, so the filter is made by a macro, given the crate name.
+// see query_filter, in the Rapier (3D) API.
+macro_rules! alicia_filter {
+    ($rapier:ident) => {
+        mod filter {
+            use mlua::prelude::*;
+            use std::cell::RefCell;
+            use $rapier::prelude::*;
+
+            #[derive(Default)]
+            pub struct AliciaFilter {
+                pub flag: QueryFilterFlags,
+                pub group: Option<InteractionGroups>,
+                pub exclude_rigid_body: Option<RigidBodyHandle>,
+                pub exclude_solid_body: Option<ColliderHandle>,
+                pub predicate: Option<LuaFunction>,
+                // the first error raised by the predicate, to be returned by the query.
+                pub error: RefCell<Option<mlua::Error>>,
+            }
+
+            impl AliciaFilter {
+                pub fn new(lua: &Lua, table: Option<LuaTable>) -> mlua::Result<Self> {
+                    let mut filter = Self::default();
+
+                    let Some(table) = table else {
+                        return Ok(filter);
+                    };
+
+                    let group: Option<u32> = table.get("group")?;
+                    let mask: Option<u32> = table.get("mask")?;
+
+                    if group.is_some() || mask.is_some() {
+                        filter.group = Some(InteractionGroups::new(
+                            Group::from_bits_truncate(group.unwrap_or(u32::MAX)),
+                            Group::from_bits_truncate(mask.unwrap_or(u32::MAX)),
+                        ));
+                    }
+
+                    if let Some(rigid_body) = table.get::<Option<LuaValue>>("exclude_rigid_body")? {
+                        filter.exclude_rigid_body = Some(lua.from_value(rigid_body)?);
+                    }
+
+                    if let Some(solid_body) = table.get::<Option<LuaValue>>("exclude_solid_body")? {
+                        filter.exclude_solid_body = Some(lua.from_value(solid_body)?);
+                    }
+
+                    for (name, flag) in [
+                        ("exclude_sensor", QueryFilterFlags::EXCLUDE_SENSORS),
+                        ("exclude_solid", QueryFilterFlags::EXCLUDE_SOLIDS),
+                        ("exclude_fixed", QueryFilterFlags::EXCLUDE_FIXED),
+                        ("exclude_kinematic", QueryFilterFlags::EXCLUDE_KINEMATIC),
+                        ("exclude_dynamic", QueryFilterFlags::EXCLUDE_DYNAMIC),
+                    ] {
+                        if table.get::<Option<bool>>(name)?.unwrap_or_default() {
+                            filter.flag.insert(flag);
+                        }
+                    }
+
+                    filter.predicate = table.get("predicate")?;
+
+                    Ok(filter)
+                }
+
+                // a scene query filter, or the older argument pair of a rigid body and a solid body
+                // handle to exclude, in which case the given flag is also set.
+                pub fn new_legacy(
+                    lua: &Lua,
+                    filter: Option<LuaValue>,
+                    exclude_solid_body: Option<LuaValue>,
+                    flag: QueryFilterFlags,
+                ) -> mlua::Result<Self> {
+                    if exclude_solid_body.is_none()
+                        && let Some(LuaValue::Table(table)) = &filter
+                        && lua
+                            .from_value::<RigidBodyHandle>(LuaValue::Table(table.clone()))
+                            .is_err()
+                    {
+                        return Self::new(lua, Some(table.clone()));
+                    }
+
+                    let mut result = Self {
+                        flag,
+                        ..Default::default()
+                    };
+
+                    if let Some(rigid_body) = filter {
+                        result.exclude_rigid_body = Some(lua.from_value(rigid_body)?);
+                    }
+
+                    if let Some(solid_body) = exclude_solid_body {
+                        result.exclude_solid_body = Some(lua.from_value(solid_body)?);
+                    }
+
+                    Ok(result)
+                }
+
+                // the predicate has to outlive the query filter, so it is built separately by the
+                // caller.
+                pub fn predicate<'a>(
+                    &'a self,
+                    lua: &'a Lua,
+                ) -> impl Fn(ColliderHandle, &Collider) -> bool + 'a {
+                    move |handle, _| {
+                        let Some(predicate) = &self.predicate else {
+                            return true;
+                        };
+
+                        // once the predicate has failed, every other solid body is excluded.
+                        if self.error.borrow().is_some() {
+                            return false;
+                        }
+
+                        match lua.to_value(&handle).and_then(|x| predicate.call::<bool>(x)) {
+                            Ok(value) => value,
+                            Err(error) => {
+                                *self.error.borrow_mut() = Some(error);
+                                false
+                            }
+                        }
+                    }
+                }
+
+                // get the first error raised by the predicate, if any.
+                pub fn error(&self) -> mlua::Result<()> {
+                    match self.error.take() {
+                        Some(error) => Err(error),
+                        None => Ok(()),
+                    }
+                }
+
+                pub fn query<'a>(
+                    &self,
+                    predicate: &'a impl Fn(ColliderHandle, &Collider) -> bool,
+                ) -> QueryFilter<'a> {
+                    let mut filter = QueryFilter::new();
+
+                    filter.flags = self.flag;
+                    filter.groups = self.group;
+                    filter.exclude_rigid_body = self.exclude_rigid_body;
+                    filter.exclude_collider = self.exclude_solid_body;
+
+                    if self.predicate.is_some() {
+                        filter = filter.predicate(predicate);
+                    }
+
+                    filter
+                }
+            }
+        }
+
+        use filter::AliciaFilter;
+    };
+}
+
+pub(crate) use alicia_filter;
@@ -48,12 +48,29 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::data::Data;
 use crate::script::*;
 use crate::status::*;
 
 //================================================================
 
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
 use mlua::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::{Component, Path};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
 
 //================================================================
 
@@ -64,8 +81,12 @@ use mlua::prelude::*;
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
     let request = lua.create_table()?;
 
-    request.set("get",  lua.create_async_function(self::get)?)?;
-    request.set("post", lua.create_async_function(self::post)?)?;
+    request.set("get",        lua.create_async_function(self::get)?)?;
+    request.set("post",       lua.create_async_function(self::post)?)?;
+    request.set("send",       lua.create_async_function(self::send)?)?;
+    request.set("download",   lua.create_async_function(self::download)?)?;
+    request.set("new_client", lua.create_function(self::RequestClient::new)?)?;
+    request.set("new_server", lua.create_async_function(self::RequestServer::new)?)?;
 
     table.set("request", request)?;
 
@@ -95,20 +116,20 @@ async fn get(lua: Lua, (link, binary): (String, bool)) -> mlua::Result<LuaValue>
         lua.to_value(
             &reqwest::get(link)
                 .await
-                .map_err(|e| mlua::Error::runtime(e.to_string()))?
+                .map_err(|error| request_error("alicia.request.get()", error))?
                 .bytes()
                 .await
-                .map_err(|e| mlua::Error::runtime(e.to_string()))?
+                .map_err(|error| request_error("alicia.request.get()", error))?
                 .to_vec(),
         )
     } else {
         lua.to_value(
             &reqwest::get(link)
                 .await
-                .map_err(|e| mlua::Error::runtime(e.to_string()))?
+                .map_err(|error| request_error("alicia.request.get()", error))?
                 .text()
                 .await
-                .map_err(|e| mlua::Error::runtime(e.to_string()))?,
+                .map_err(|error| request_error("alicia.request.get()", error))?,
         )
     }
 }
@@ -176,13 +197,13 @@ async fn post(
     let result = result
         .send()
         .await
-        .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+        .map_err(|error| request_error("alicia.request.post()", error))?;
 
     if binary {
         let data = result
             .bytes()
             .await
-            .map_err(|e| mlua::Error::runtime(e.to_string()))?
+            .map_err(|error| request_error("alicia.request.post()", error))?
             .to_vec();
 
         let data = crate::base::data::Data::new(&lua, data)?;
@@ -194,7 +215,1044 @@ async fn post(
             &result
                 .text()
                 .await
-                .map_err(|e| mlua::Error::runtime(e.to_string()))?,
+                .map_err(|error| request_error("alicia.request.post()", error))?,
         )
     }
 }
+
+//================================================================
+
+// shared client for send and download, so that every call will re-use the same connection pool.
+static CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "request_info",
+    "info": "A table describing a request. Every field is optional. Only one of data, form, or json should be given.",
+    "member": [
+        { "name": "method",  "info": "Method, such as \"GET\", \"POST\", \"PUT\", \"DELETE\", \"PATCH\" or \"HEAD\". Default: \"GET\".",           "kind": "string?"          },
+        { "name": "header",  "info": "Header table, as name and value pairs. Default: nil.",                                                       "kind": "table?"           },
+        { "name": "query",   "info": "Query table, as name and value pairs, to append to the link. Default: nil.",                                 "kind": "table?"           },
+        { "name": "data",    "info": "Body payload. Default: nil.",                                                                                "kind": "(string | data)?" },
+        { "name": "form",    "info": "Form payload. Default: nil.",                                                                                "kind": "table?"           },
+        { "name": "json",    "info": "JSON payload. Default: nil.",                                                                                "kind": "table?"           },
+        { "name": "timeout", "info": "Time (in seconds) to wait for the whole request, overriding the client time-out. Default: nil.",             "kind": "number?"          },
+        { "name": "binary",  "info": "Receive the response body as binary (data), rather than as a string. Not used by download. Default: false.", "kind": "boolean?"         }
+    ]
+}
+*/
+struct RequestInfo {
+    method: reqwest::Method,
+    header: Option<HashMap<String, String>>,
+    query: Option<HashMap<String, String>>,
+    data: Option<Vec<u8>>,
+    form: Option<mlua::Table>,
+    json: Option<mlua::Table>,
+    timeout: Option<Duration>,
+    binary: bool,
+}
+
+impl RequestInfo {
+    // read the table by hand, as the data payload can be a user-data.
+    fn new(info: Option<mlua::Table>, call: &str) -> mlua::Result<Self> {
+        let Some(info) = info else {
+            return Ok(Self {
+                method: reqwest::Method::GET,
+                header: None,
+                query: None,
+                data: None,
+                form: None,
+                json: None,
+                timeout: None,
+                binary: false,
+            });
+        };
+
+        let method = info.get::<Option<String>>("method")?.unwrap_or("GET".to_string());
+        let method = reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+            .map_err(|_| mlua::Error::runtime(format!("{call}: Invalid method \"{method}\".")))?;
+
+        let data = match info.get::<LuaValue>("data")? {
+            LuaValue::Nil => None,
+            data => Some(Data::get_byte(data)?),
+        };
+
+        Ok(Self {
+            method,
+            header: info.get("header")?,
+            query: info.get("query")?,
+            data,
+            form: info.get("form")?,
+            json: info.get("json")?,
+            timeout: info
+                .get::<Option<f32>>("timeout")?
+                .map(|x| request_time(call, x))
+                .transpose()?,
+            binary: info.get::<Option<bool>>("binary")?.unwrap_or_default(),
+        })
+    }
+
+    // build the request out of a client.
+    fn build(self, client: &reqwest::Client, link: &str) -> reqwest::RequestBuilder {
+        let mut request = client.request(self.method, link);
+
+        if let Some(header) = self.header {
+            for (name, value) in header {
+                request = request.header(name, value);
+            }
+        }
+
+        if let Some(query) = self.query {
+            request = request.query(&query);
+        }
+
+        if let Some(data) = self.data {
+            request = request.body(data);
+        }
+
+        if let Some(form) = self.form {
+            request = request.form(&form);
+        }
+
+        if let Some(json) = self.json {
+            request = request.json(&json);
+        }
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        request
+    }
+}
+
+// convert a time-out in seconds, which may be negative, infinite or NaN.
+fn request_time(call: &str, time: f32) -> mlua::Result<Duration> {
+    Duration::try_from_secs_f32(time)
+        .map_err(|_| mlua::Error::runtime(format!("{call}: Invalid time-out \"{time}\".")))
+}
+
+// describe a request failure, so that a script can tell a time-out apart from a bad link.
+fn request_error(call: &str, error: reqwest::Error) -> mlua::Error {
+    let kind = if error.is_timeout() {
+        "Request timed out"
+    } else if error.is_connect() {
+        "Could not connect"
+    } else if error.is_redirect() {
+        "Too many redirects"
+    } else if error.is_builder() {
+        "Invalid request"
+    } else if error.is_body() || error.is_decode() {
+        "Could not read the response body"
+    } else {
+        "Request failure"
+    };
+
+    // the top-level error is usually vague, so append every underlying error too.
+    let mut info = error.to_string();
+    let mut source = std::error::Error::source(&error);
+
+    while let Some(error) = source {
+        info.push_str(&format!(": {error}"));
+        source = error.source();
+    }
+
+    mlua::Error::runtime(format!("{call}: {kind}. {info}"))
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "request_response",
+    "info": "A table describing a response. A response with an error status (such as 404) is still a response, and will not raise an error.",
+    "member": [
+        { "name": "status", "info": "Status code.",                                                                              "kind": "number"           },
+        { "name": "link",   "info": "Final URL link, after every redirect.",                                                     "kind": "string"           },
+        { "name": "header", "info": "Header table, as name and value pairs. A repeated header has its values joined by \", \".", "kind": "table"            },
+        { "name": "data",   "info": "Response body. Not set by download.",                                                       "kind": "(string | data)?" }
+    ]
+}
+*/
+fn response_table(lua: &Lua, response: &reqwest::Response) -> mlua::Result<mlua::Table> {
+    let table = lua.create_table()?;
+    let header = lua.create_table()?;
+
+    for (name, value) in response.headers() {
+        let value = String::from_utf8_lossy(value.as_bytes());
+
+        match header.get::<Option<String>>(name.as_str())? {
+            Some(last) => header.set(name.as_str(), format!("{last}, {value}"))?,
+            None => header.set(name.as_str(), value)?,
+        }
+    }
+
+    table.set("status", response.status().as_u16())?;
+    table.set("link", response.url().as_str())?;
+    table.set("header", header)?;
+
+    Ok(table)
+}
+
+async fn send_client(
+    lua: &Lua,
+    client: &reqwest::Client,
+    link: &str,
+    info: Option<mlua::Table>,
+    call: &str,
+) -> mlua::Result<mlua::Table> {
+    let info = RequestInfo::new(info, call)?;
+    let binary = info.binary;
+
+    let response = info
+        .build(client, link)
+        .send()
+        .await
+        .map_err(|error| request_error(call, error))?;
+
+    let table = response_table(lua, &response)?;
+
+    let data = response
+        .bytes()
+        .await
+        .map_err(|error| request_error(call, error))?
+        .to_vec();
+
+    if binary {
+        table.set("data", Data::new(lua, data)?)?;
+    } else {
+        table.set("data", lua.create_string(data)?)?;
+    }
+
+    Ok(table)
+}
+
+async fn download_client(
+    lua: &Lua,
+    client: &reqwest::Client,
+    (link, path, info, call): (String, String, Option<mlua::Table>, Option<mlua::Function>),
+    name: &str,
+) -> mlua::Result<mlua::Table> {
+    let path = ScriptData::get_path(lua, &path)?;
+    let info = RequestInfo::new(info, name)?;
+
+    let mut response = info
+        .build(client, &link)
+        .send()
+        .await
+        .map_err(|error| request_error(name, error))?;
+
+    let table = response_table(lua, &response)?;
+
+    if !response.status().is_success() {
+        return Ok(table);
+    }
+
+    // write to a partial file first, so that a failed download will never leave a broken file.
+    let part = format!("{path}.part");
+    let mut file = tokio::fs::File::create(&part).await.map_err(|error| {
+        mlua::Error::runtime(format!("{name}: Could not create file \"{part}\": {error}"))
+    })?;
+
+    let total = response.content_length();
+    let mut receive: u64 = 0;
+
+    let result: mlua::Result<()> = async {
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|error| request_error(name, error))?
+        {
+            file.write_all(&chunk).await?;
+            receive += chunk.len() as u64;
+
+            if let Some(call) = &call {
+                call.call::<()>((receive, total))?;
+            }
+        }
+
+        file.flush().await?;
+
+        Ok(())
+    }
+    .await;
+
+    drop(file);
+
+    // remove the partial file on a chunk, write or call-back error.
+    if let Err(error) = result {
+        let _ = tokio::fs::remove_file(&part).await;
+        return Err(error);
+    }
+
+    tokio::fs::rename(&part, &path).await.map_err(|error| {
+        mlua::Error::runtime(format!("{name}: Could not create file \"{path}\": {error}"))
+    })?;
+
+    Ok(table)
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.request.send",
+    "info": "Perform a request, with a shared client. Use a request client instead for cookies or a default header table.",
+    "member": [
+        { "name": "link", "info": "The target URL link.",                                 "kind": "string"        },
+        { "name": "info", "info": "OPTIONAL: Request configuration. Default: nil (GET).", "kind": "request_info?" }
+    ],
+    "result": [
+        { "name": "response", "info": "Response.", "kind": "request_response" }
+    ],
+    "test": "request/client.lua",
+    "routine": true
+}
+*/
+async fn send(lua: Lua, (link, info): (String, Option<mlua::Table>)) -> mlua::Result<mlua::Table> {
+    send_client(&lua, &CLIENT, &link, info, "alicia.request.send()").await
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "alicia.request.download",
+    "info": "Download a file, with a shared client. The body is written to the file as it is received. Nothing is written if the response has an error status.",
+    "member": [
+        { "name": "link", "info": "The target URL link.",                                                                                         "kind": "string"        },
+        { "name": "path", "info": "Path to the file to write to.",                                                                                "kind": "string"        },
+        { "name": "info", "info": "OPTIONAL: Request configuration. Default: nil (GET).",                                                         "kind": "request_info?" },
+        { "name": "call", "info": "OPTIONAL: Progress call-back, called with the byte count received and the byte count total (nil if unknown).", "kind": "function?"     }
+    ],
+    "result": [
+        { "name": "response", "info": "Response, with no body.", "kind": "request_response" }
+    ],
+    "test": "request/client.lua",
+    "routine": true
+}
+*/
+async fn download(
+    lua: Lua,
+    value: (String, String, Option<mlua::Table>, Option<mlua::Function>),
+) -> mlua::Result<mlua::Table> {
+    download_client(&lua, &CLIENT, value, "alicia.request.download()").await
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "request_client_info",
+    "info": "A table describing the configuration of a request client. Every field is optional.",
+    "member": [
+        { "name": "header",          "info": "Header table, sent with every request, as name and value pairs. Default: nil.",        "kind": "table?"   },
+        { "name": "user_agent",      "info": "User agent. Default: nil.",                                                            "kind": "string?"  },
+        { "name": "cookie",          "info": "Keep every cookie received, and send it back on every later request. Default: false.", "kind": "boolean?" },
+        { "name": "timeout",         "info": "Time (in seconds) to wait for a whole request. Default: nil (no time-out).",           "kind": "number?"  },
+        { "name": "timeout_connect", "info": "Time (in seconds) to wait for a connection. Default: nil (no time-out).",              "kind": "number?"  },
+        { "name": "redirect",        "info": "Largest number of redirects to follow. Default: 10.",                                  "kind": "number?"  }
+    ]
+}
+*/
+#[derive(Deserialize)]
+#[serde(default)]
+struct RequestClientInfo {
+    header: HashMap<String, String>,
+    user_agent: Option<String>,
+    cookie: bool,
+    timeout: Option<f32>,
+    timeout_connect: Option<f32>,
+    redirect: usize,
+}
+
+impl Default for RequestClientInfo {
+    fn default() -> Self {
+        Self {
+            header: HashMap::new(),
+            user_agent: None,
+            cookie: false,
+            timeout: None,
+            timeout_connect: None,
+            redirect: 10,
+        }
+    }
+}
+
+/* class
+{ "version": "1.0.0", "name": "request_client", "info": "An unique handle to a request client in memory. Every request made with the same client will re-use the same connection pool, and cookie store." }
+*/
+struct RequestClient(reqwest::Client);
+
+impl mlua::UserData for RequestClient {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_client:send",
+            "info": "Perform a request.",
+            "member": [
+                { "name": "link", "info": "The target URL link.",                                 "kind": "string"        },
+                { "name": "info", "info": "OPTIONAL: Request configuration. Default: nil (GET).", "kind": "request_info?" }
+            ],
+            "result": [
+                { "name": "response", "info": "Response.", "kind": "request_response" }
+            ],
+            "test": "request/client.lua",
+            "routine": true
+        }
+        */
+        method.add_async_method(
+            "send",
+            |lua: Lua, this, (link, info): (String, Option<mlua::Table>)| async move {
+                send_client(&lua, &this.0, &link, info, "request_client:send()").await
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_client:download",
+            "info": "Download a file. The body is written to the file as it is received. Nothing is written if the response has an error status.",
+            "member": [
+                { "name": "link", "info": "The target URL link.",                                                                                         "kind": "string"        },
+                { "name": "path", "info": "Path to the file to write to.",                                                                                "kind": "string"        },
+                { "name": "info", "info": "OPTIONAL: Request configuration. Default: nil (GET).",                                                         "kind": "request_info?" },
+                { "name": "call", "info": "OPTIONAL: Progress call-back, called with the byte count received and the byte count total (nil if unknown).", "kind": "function?"     }
+            ],
+            "result": [
+                { "name": "response", "info": "Response, with no body.", "kind": "request_response" }
+            ],
+            "test": "request/client.lua",
+            "routine": true
+        }
+        */
+        method.add_async_method(
+            "download",
+            |lua: Lua,
+             this,
+             value: (String, String, Option<mlua::Table>, Option<mlua::Function>)| async move {
+                download_client(&lua, &this.0, value, "request_client:download()").await
+            },
+        );
+    }
+}
+
+impl RequestClient {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.request.new_client",
+        "info": "Create a request client.",
+        "member": [
+            { "name": "info", "info": "OPTIONAL: Client configuration. Any missing field will use its default.", "kind": "request_client_info?" }
+        ],
+        "result": [
+            { "name": "client", "info": "Request client.", "kind": "request_client" }
+        ],
+        "test": "request/client.lua"
+    }
+    */
+    fn new(lua: &Lua, info: Option<LuaValue>) -> mlua::Result<Self> {
+        let info: RequestClientInfo = if let Some(info) = info {
+            lua.from_value(info)?
+        } else {
+            RequestClientInfo::default()
+        };
+
+        let mut header = reqwest::header::HeaderMap::new();
+
+        for (name, value) in info.header {
+            let name = reqwest::header::HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                mlua::Error::runtime(format!(
+                    "alicia.request.new_client(): Invalid header name \"{name}\"."
+                ))
+            })?;
+            let value = reqwest::header::HeaderValue::from_str(&value).map_err(|_| {
+                mlua::Error::runtime(format!(
+                    "alicia.request.new_client(): Invalid header value \"{value}\"."
+                ))
+            })?;
+
+            header.insert(name, value);
+        }
+
+        let mut client = reqwest::Client::builder()
+            .default_headers(header)
+            .cookie_store(info.cookie)
+            .redirect(reqwest::redirect::Policy::limited(info.redirect));
+
+        if let Some(user_agent) = info.user_agent {
+            client = client.user_agent(user_agent);
+        }
+
+        if let Some(timeout) = info.timeout {
+            client = client.timeout(request_time("alicia.request.new_client()", timeout)?);
+        }
+
+        if let Some(timeout) = info.timeout_connect {
+            client = client
+                .connect_timeout(request_time("alicia.request.new_client()", timeout)?);
+        }
+
+        let client = client
+            .build()
+            .map_err(|error| request_error("alicia.request.new_client()", error))?;
+
+        Ok(Self(client))
+    }
+}
+
+//================================================================
+
+// largest request body a server will accept.
+const SERVER_BODY: usize = 16777216;
+// time to wait before accepting again, after a failed accept (i.e. out of file descriptors).
+const SERVER_BACK_OFF: Duration = Duration::from_millis(100);
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "request_server_request",
+    "info": "A table describing a request, as given to a route call-back.",
+    "member": [
+        { "name": "method",  "info": "Method, such as \"GET\".",                                            "kind": "string" },
+        { "name": "path",    "info": "Path, without the query.",                                            "kind": "string" },
+        { "name": "query",   "info": "Query table, as name and value pairs.",                               "kind": "table"  },
+        { "name": "header",  "info": "Header table, as name and value pairs. Every name is in lower-case.", "kind": "table"  },
+        { "name": "data",    "info": "Request body.",                                                       "kind": "string" },
+        { "name": "address", "info": "Address of the remote socket.",                                       "kind": "string" }
+    ]
+}
+*/
+// a request received by the background server task, waiting for the Lua side to reply to it.
+struct ServerRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    header: Vec<(String, String)>,
+    data: Vec<u8>,
+    address: SocketAddr,
+    reply: oneshot::Sender<ServerReply>,
+}
+
+// the body of a reply. a file is read in the background task, rather than in the main thread.
+enum ServerBody {
+    Data(Vec<u8>),
+    File(String),
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "request_server_reply",
+    "info": "A table describing a reply, as returned by a route call-back. Every field is optional. Only one of data, json, or file should be given.",
+    "member": [
+        { "name": "status", "info": "Status code. Default: 200.",                                                "kind": "number?"          },
+        { "name": "header", "info": "Header table, as name and value pairs. Default: nil.",                      "kind": "table?"           },
+        { "name": "data",   "info": "Body. Default: nil.",                                                       "kind": "(string | data)?" },
+        { "name": "json",   "info": "Table to send as a JSON body. Default: nil.",                               "kind": "table?"           },
+        { "name": "file",   "info": "Path to a file to send as the body, read in the background. Default: nil.", "kind": "string?"          }
+    ]
+}
+*/
+struct ServerReply {
+    status: u16,
+    header: Vec<(String, String)>,
+    body: ServerBody,
+}
+
+impl ServerReply {
+    fn new(status: u16, data: &str) -> Self {
+        Self {
+            status,
+            header: vec![("content-type".to_string(), "text/plain; charset=utf-8".to_string())],
+            body: ServerBody::Data(data.as_bytes().to_vec()),
+        }
+    }
+
+    // convert the value returned by a route call-back into a reply.
+    fn from_value(lua: &Lua, value: LuaValue) -> mlua::Result<Self> {
+        let table = match value {
+            LuaValue::Nil => return Ok(Self::new(200, "")),
+            LuaValue::String(_) => return Ok(Self::new(200, &String::from_lua(value, lua)?)),
+            LuaValue::UserData(_) => {
+                return Ok(Self {
+                    status: 200,
+                    header: vec![(
+                        "content-type".to_string(),
+                        "application/octet-stream".to_string(),
+                    )],
+                    body: ServerBody::Data(Data::get_byte(value)?),
+                });
+            }
+            LuaValue::Table(table) => table,
+            _ => {
+                return Err(mlua::Error::runtime(
+                    "request_server:update(): Route result must be a string, data, table or nil.",
+                ));
+            }
+        };
+
+        let mut reply = Self::new(table.get::<Option<u16>>("status")?.unwrap_or(200), "");
+
+        if let Some(json) = table.get::<Option<LuaValue>>("json")? {
+            let json = serde_json::to_vec(&json).map_err(|error| {
+                mlua::Error::runtime(format!("request_server:update(): {error}"))
+            })?;
+
+            reply.header = vec![("content-type".to_string(), "application/json".to_string())];
+            reply.body = ServerBody::Data(json);
+        } else if let Some(path) = table.get::<Option<String>>("file")? {
+            let path = ScriptData::get_path(lua, &path)?;
+
+            reply.header = vec![("content-type".to_string(), server_mime(&path).to_string())];
+            reply.body = ServerBody::File(path);
+        } else if let Some(data) = table.get::<Option<LuaValue>>("data")? {
+            reply.body = ServerBody::Data(Data::get_byte(data)?);
+        }
+
+        // a custom header will override any default header set above.
+        if let Some(header) = table.get::<Option<HashMap<String, String>>>("header")? {
+            for (name, value) in header {
+                let name = name.to_lowercase();
+                reply.header.retain(|(other, _)| *other != name);
+                reply.header.push((name, value));
+            }
+        }
+
+        Ok(reply)
+    }
+
+    async fn to_response(self) -> Response<Full<Bytes>> {
+        let (status, data) = match self.body {
+            ServerBody::Data(data) => (self.status, data),
+            ServerBody::File(path) => match tokio::fs::read(&path).await {
+                Ok(data) => (self.status, data),
+                Err(_) => (404, b"Not found.".to_vec()),
+            },
+        };
+
+        let mut response = Response::builder().status(status);
+
+        for (name, value) in self.header {
+            response = response.header(name, value);
+        }
+
+        response
+            .body(Full::new(Bytes::from(data)))
+            .unwrap_or_else(|_| {
+                let mut response = Response::new(Full::new(Bytes::from("Invalid response.")));
+                *response.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+                response
+            })
+    }
+}
+
+// guess the MIME type of a file out of its extension.
+fn server_mime(path: &str) -> &'static str {
+    let extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "txt" | "lua" => "text/plain; charset=utf-8",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "ico" => "image/x-icon",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+// every static directory of a server, as a path prefix and a directory pair.
+type ServerStatic = Arc<RwLock<Vec<(String, String)>>>;
+
+// serve a static file, if the path is within any static directory.
+// decode every percent-encoded byte of a path. none on an invalid escape, or on a path that is not
+// valid UTF-8 once decoded.
+fn server_decode(path: &str) -> Option<String> {
+    let byte = path.as_bytes();
+    let mut data = Vec::with_capacity(byte.len());
+    let mut index = 0;
+
+    while index < byte.len() {
+        if byte[index] == b'%' {
+            let high = (*byte.get(index + 1)? as char).to_digit(16)?;
+            let low = (*byte.get(index + 2)? as char).to_digit(16)?;
+
+            data.push((high * 16 + low) as u8);
+            index += 3;
+        } else {
+            data.push(byte[index]);
+            index += 1;
+        }
+    }
+
+    String::from_utf8(data).ok()
+}
+
+fn server_static(list: &ServerStatic, path: &str) -> Option<ServerReply> {
+    let list = list.read().unwrap();
+
+    for (prefix, directory) in list.iter() {
+        let Some(file) = path.strip_prefix(prefix.as_str()) else {
+            continue;
+        };
+
+        // the path is still percent-encoded (i.e. "my%20file.png"), so it is decoded before any
+        // check is made on it.
+        let Some(file) = server_decode(file) else {
+            return Some(ServerReply::new(404, "Not found."));
+        };
+
+        // never leave the static directory. every component must be a plain name, so that a
+        // parent directory, a root or a drive prefix (i.e. "C:" on Windows) is rejected.
+        if !Path::new(&file)
+            .components()
+            .all(|part| matches!(part, Component::Normal(_)))
+        {
+            return Some(ServerReply::new(404, "Not found."));
+        }
+
+        let file = if file.is_empty() || file.ends_with('/') {
+            format!("{directory}/{file}index.html")
+        } else {
+            format!("{directory}/{file}")
+        };
+
+        return Some(ServerReply {
+            status: 200,
+            header: vec![("content-type".to_string(), server_mime(&file).to_string())],
+            body: ServerBody::File(file),
+        });
+    }
+
+    None
+}
+
+// handle a single request, either out of a static directory, or by handing it to the Lua side.
+async fn server_serve(
+    request: Request<Incoming>,
+    address: SocketAddr,
+    list: ServerStatic,
+    event: UnboundedSender<ServerRequest>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let path = request.uri().path().to_string();
+
+    if let Some(reply) = server_static(&list, &path) {
+        return Ok(reply.to_response().await);
+    }
+
+    let method = request.method().to_string();
+
+    let query = request
+        .uri()
+        .query()
+        .map(|query| {
+            reqwest::Url::parse(&format!("http://localhost/?{query}"))
+                .map(|link| link.query_pairs().into_owned().collect())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+
+    let header = request
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).to_string(),
+            )
+        })
+        .collect();
+
+    let data = match http_body_util::Limited::new(request.into_body(), SERVER_BODY)
+        .collect()
+        .await
+    {
+        Ok(data) => data.to_bytes().to_vec(),
+        Err(_) => {
+            let reply = ServerReply::new(413, "Request body is too large.");
+            return Ok(reply.to_response().await);
+        }
+    };
+
+    let (reply, receive) = oneshot::channel();
+
+    let _ = event.send(ServerRequest {
+        method,
+        path,
+        query,
+        header,
+        data,
+        address,
+        reply,
+    });
+
+    // the reply is dropped if the server itself is dropped before replying.
+    match receive.await {
+        Ok(reply) => Ok(reply.to_response().await),
+        Err(_) => Ok(ServerReply::new(503, "Server unavailable.").to_response().await),
+    }
+}
+
+/* class
+{ "version": "1.0.0", "name": "request_server", "info": "An unique handle to an HTTP server in memory. Incoming connections are accepted in the background, while every request is handed to a route call-back, in the main thread, on update. Call update once per frame." }
+*/
+struct RequestServer {
+    address: String,
+    event: UnboundedReceiver<ServerRequest>,
+    // every accept error raised in the background, drained on update.
+    failure: UnboundedReceiver<String>,
+    route: Vec<(Option<String>, String, mlua::Function)>,
+    list: ServerStatic,
+    task: JoinHandle<()>,
+}
+
+impl Drop for RequestServer {
+    fn drop(&mut self) {
+        // every pending request will be given a 503 reply, as the event receiver is dropped.
+        self.task.abort();
+    }
+}
+
+impl mlua::UserData for RequestServer {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_server:set_route",
+            "info": "Set a route call-back. A path ending in \"*\" will match any path with the same prefix, with the longest prefix taking priority. The call-back is given a request_server_request table, and must return either a string, a data buffer, a request_server_reply table, or nil.",
+            "member": [
+                { "name": "method", "info": "Method to match, such as \"GET\" or \"POST\". Use nil to match any method.", "kind": "string?"  },
+                { "name": "path",   "info": "Path to match, such as \"/status\".",                                        "kind": "string"   },
+                { "name": "call",   "info": "Route call-back.",                                                           "kind": "function" }
+            ],
+            "test": "request/server.lua"
+        }
+        */
+        method.add_method_mut(
+            "set_route",
+            |_: &Lua, this, (method, path, call): (Option<String>, String, mlua::Function)| {
+                let method = method.map(|method| method.to_uppercase());
+
+                this.route.retain(|(other_method, other_path, _)| {
+                    *other_method != method || *other_path != path
+                });
+                this.route.push((method, path, call));
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_server:set_static",
+            "info": "Serve every file in a directory, for every path with the given prefix. A path ending in \"/\" will serve the index.html file in it. This is done entirely in the background.",
+            "member": [
+                { "name": "prefix", "info": "Path prefix, such as \"/static/\".", "kind": "string" },
+                { "name": "path",   "info": "Path to the directory.",             "kind": "string" }
+            ],
+            "test": "request/server.lua"
+        }
+        */
+        method.add_method_mut(
+            "set_static",
+            |lua: &Lua, this, (prefix, path): (String, String)| {
+                let path = ScriptData::get_path(lua, &path)?;
+                let mut list = this.list.write().unwrap();
+
+                list.retain(|(other, _)| *other != prefix);
+                list.push((prefix, path.trim_end_matches('/').to_string()));
+
+                // match the longest prefix first.
+                list.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_server:update",
+            "info": "Reply to every request received since the last call, with its route call-back. A request with no route is given a 404 reply. This call does not block.",
+            "result": [
+                { "name": "count",   "info": "Request count handled.",                                                                                  "kind": "number" },
+                { "name": "failure", "info": "List of every error raised while accepting a connection since the last call, or nil if there were none.", "kind": "table?" }
+            ],
+            "test": "request/server.lua"
+        }
+        */
+        method.add_method_mut("update", |lua, this, _: ()| {
+            let mut count = 0;
+            let mut failure = Vec::new();
+
+            while let Ok(error) = this.failure.try_recv() {
+                failure.push(error);
+            }
+
+            while let Ok(request) = this.event.try_recv() {
+                count += 1;
+
+                let Some(call) = this.get_route(&request.method, &request.path) else {
+                    let _ = request.reply.send(ServerReply::new(404, "Not found."));
+                    continue;
+                };
+
+                let table = lua.create_table()?;
+                let query = lua.create_table()?;
+                let header = lua.create_table()?;
+
+                for (name, value) in request.query {
+                    query.set(name, value)?;
+                }
+
+                for (name, value) in request.header {
+                    match header.get::<Option<String>>(name.as_str())? {
+                        Some(last) => header.set(name, format!("{last}, {value}"))?,
+                        None => header.set(name, value)?,
+                    }
+                }
+
+                table.set("method", request.method)?;
+                table.set("path", request.path)?;
+                table.set("query", query)?;
+                table.set("header", header)?;
+                table.set("data", lua.create_string(request.data)?)?;
+                table.set("address", request.address.to_string())?;
+
+                // always reply, even on an error, so that the remote side is never left waiting.
+                match call
+                    .call::<LuaValue>(table)
+                    .and_then(|value| ServerReply::from_value(lua, value))
+                {
+                    Ok(reply) => {
+                        let _ = request.reply.send(reply);
+                    }
+                    Err(error) => {
+                        let _ = request.reply.send(ServerReply::new(500, "Internal error."));
+                        return Err(error);
+                    }
+                }
+            }
+
+            Ok((count, (!failure.is_empty()).then_some(failure)))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "request_server:get_address",
+            "info": "Get the local address the server is bound to.",
+            "result": [
+                { "name": "address", "info": "Local address.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method("get_address", |_, this, _: ()| Ok(this.address.clone()));
+    }
+}
+
+impl RequestServer {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.request.new_server",
+        "info": "Create an HTTP server, and listen for incoming connections in the background.",
+        "member": [
+            { "name": "address", "info": "Address to bind to, such as \"127.0.0.1:8080\". Use port 0 for any free port.", "kind": "string" }
+        ],
+        "result": [
+            { "name": "server", "info": "HTTP server.", "kind": "request_server" }
+        ],
+        "test": "request/server.lua",
+        "routine": true
+    }
+    */
+    async fn new(_: Lua, address: String) -> mlua::Result<Self> {
+        let socket = TcpListener::bind(&address).await?;
+        let address = socket.local_addr()?.to_string();
+
+        let (event_send, event) = unbounded_channel();
+        let (failure_send, failure) = unbounded_channel();
+        let list: ServerStatic = Arc::new(RwLock::new(Vec::new()));
+        let task_list = list.clone();
+
+        let task = tokio::spawn(async move {
+            loop {
+                let (stream, address) = match socket.accept().await {
+                    Ok(value) => value,
+                    Err(error) => {
+                        // a failed accept is not fatal to the server. report it on the next
+                        // update, and keep on accepting after a short back-off.
+                        let _ = failure_send.send(format!("Accept failed: {error}"));
+                        tokio::time::sleep(SERVER_BACK_OFF).await;
+                        continue;
+                    }
+                };
+
+                let list = task_list.clone();
+                let event = event_send.clone();
+
+                tokio::spawn(async move {
+                    let service = hyper::service::service_fn(move |request| {
+                        server_serve(request, address, list.clone(), event.clone())
+                    });
+
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+
+        Ok(Self {
+            address,
+            event,
+            failure,
+            route: Vec::new(),
+            list,
+            task,
+        })
+    }
+
+    // find the route for a request. an exact path will match first, then the longest prefix.
+    fn get_route(&self, method: &str, path: &str) -> Option<mlua::Function> {
+        let mut best: Option<(usize, &mlua::Function)> = None;
+
+        for (route_method, route_path, call) in &self.route {
+            if let Some(route_method) = route_method
+                && route_method != method
+            {
+                continue;
+            }
+
+            if route_path == path {
+                return Some(call.clone());
+            }
+
+            if let Some(prefix) = route_path.strip_suffix('*')
+                && path.starts_with(prefix)
+                && best.is_none_or(|(length, _)| prefix.len() > length)
+            {
+                best = Some((prefix.len(), call));
+            }
+        }
+
+        best.map(|(_, call)| call.clone())
+    }
+}
@@ -48,39 +48,606 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::data::Data;
 use crate::script::*;
 use crate::status::*;
 
 //================================================================
 
 use mlua::prelude::*;
-use tokio::io::AsyncWriteExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
 use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::task::JoinHandle;
+
+#[cfg(feature = "tls")]
+use std::task::Waker;
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature};
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::{
+    ClientConfig, DigitallySignedStruct, RootCertStore, ServerConfig, SignatureScheme,
+};
+#[cfg(feature = "tls")]
+use tokio_rustls::{TlsAcceptor, TlsConnector, TlsStream};
+
+#[cfg(feature = "web_socket")]
+use futures_util::{SinkExt, StreamExt};
+#[cfg(feature = "web_socket")]
+use tokio_tungstenite::WebSocketStream;
+#[cfg(feature = "web_socket")]
+use tokio_tungstenite::tungstenite::Message;
+#[cfg(feature = "web_socket")]
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
 
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "alicia.socket", "info": "The socket API.", "head": true }
+{ "version": "1.0.0", "name": "alicia.socket", "info": "The socket API." }
 */
 #[rustfmt::skip]
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
     let socket = lua.create_table()?;
 
-    socket.set("new_TCP_listen", lua.create_async_function(self::SocketTCPListen::new)?)?;
-    socket.set("new_TCP_stream", lua.create_async_function(self::SocketTCPStream::new)?)?;
-    socket.set("new_UDP",        lua.create_async_function(self::SocketUDP::new)?)?;
+    socket.set("new_TCP_listen",       lua.create_async_function(self::SocketTCPListen::new)?)?;
+    socket.set("new_TCP_stream",       lua.create_async_function(self::SocketTCPStream::new)?)?;
+    socket.set("new_UDP",              lua.create_async_function(self::SocketUDP::new)?)?;
+    socket.set("new_TCP_client",       lua.create_function(self::SocketTCPClient::new)?)?;
+    socket.set("new_TCP_server",       lua.create_function(self::SocketTCPServer::new)?)?;
+    socket.set("new_discovery_server", lua.create_async_function(self::SocketDiscoveryServer::new)?)?;
+    socket.set("new_discovery_client", lua.create_async_function(self::SocketDiscoveryClient::new)?)?;
+
+    #[cfg(feature = "web_socket")]
+    socket.set("new_web_socket",        lua.create_function(self::SocketWebSocket::new)?)?;
+    #[cfg(feature = "web_socket")]
+    socket.set("new_web_socket_server", lua.create_function(self::SocketWebSocketServer::new)?)?;
 
     table.set("socket", socket)?;
 
     Ok(())
 }
 
+// default TCP buffer size, which is also the largest message size allowed.
+const TCP_BUFFER: usize = 65536;
+// default UDP buffer size, which is the largest UDP payload size over IPv4.
+const UDP_BUFFER: usize = 65507;
+// size of the length prefix of every TCP message.
+const TCP_PREFIX: usize = 4;
+// time to wait before accepting again, after a failed accept (i.e. out of file descriptors).
+const ACCEPT_BACK_OFF: Duration = Duration::from_millis(100);
+// time to wait for a TLS handshake, so that a stalled peer can not hold up a connection forever.
+#[cfg(feature = "tls")]
+const TLS_HANDSHAKE: Duration = Duration::from_secs(10);
+
+// prefix of a discovery query, and of a discovery reply.
+const DISCOVERY_QUERY: [u8; 4] = *b"ALDQ";
+const DISCOVERY_REPLY: [u8; 4] = *b"ALDR";
+// time between each discovery query.
+const DISCOVERY_INTERVAL: Duration = Duration::from_secs(1);
+// time without a discovery reply before a server is dropped from the list.
+const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(3);
+
+// socket state, matching SOCKET_STATE in constant.lua.
+const STATE_CONNECTING: i32 = 0;
+const STATE_CONNECT: i32 = 1;
+const STATE_DISCONNECT: i32 = 2;
+
+//================================================================
+
+// an event sent from a background socket task to the socket user-data. the writer kind is the
+// message kind sent to the task, which is a raw buffer for every socket other than a web socket.
+enum SocketEvent<W = Vec<u8>> {
+    Listen,
+    Connect(usize, String, Option<UnboundedSender<W>>),
+    Message(usize, Vec<u8>),
+    #[cfg(feature = "web_socket")]
+    Text(usize, String),
+    #[cfg(feature = "web_socket")]
+    Pong(usize, Vec<u8>),
+    Disconnect(usize),
+    #[cfg(feature = "web_socket")]
+    Close(usize, u16, String),
+    Failure(Option<usize>, String),
+}
+
+impl<W> SocketEvent<W> {
+    // the kind of each event, matching SOCKET_EVENT in constant.lua.
+    fn to_table(self, lua: &Lua, server: bool) -> mlua::Result<LuaTable> {
+        let table = lua.create_table()?;
+
+        let client = match self {
+            Self::Listen => None,
+            Self::Connect(client, address, _) => {
+                table.set("kind", 0)?;
+                table.set("address", address)?;
+                Some(client)
+            }
+            Self::Message(client, data) => {
+                table.set("kind", 1)?;
+                table.set("data", Data::new(lua, data)?)?;
+                Some(client)
+            }
+            #[cfg(feature = "web_socket")]
+            Self::Text(client, text) => {
+                table.set("kind", 1)?;
+                table.set("text", text)?;
+                Some(client)
+            }
+            #[cfg(feature = "web_socket")]
+            Self::Pong(client, data) => {
+                table.set("kind", 4)?;
+                table.set("data", Data::new(lua, data)?)?;
+                Some(client)
+            }
+            Self::Disconnect(client) => {
+                table.set("kind", 2)?;
+                Some(client)
+            }
+            #[cfg(feature = "web_socket")]
+            Self::Close(client, code, info) => {
+                table.set("kind", 2)?;
+                table.set("code", code)?;
+                table.set("info", info)?;
+                Some(client)
+            }
+            Self::Failure(client, info) => {
+                table.set("kind", 3)?;
+                table.set("info", info)?;
+                client
+            }
+        };
+
+        if server && let Some(client) = client {
+            table.set("client", client)?;
+        }
+
+        Ok(table)
+    }
+}
+
+fn frame_encode(data: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(TCP_PREFIX + data.len());
+    frame.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    frame.extend_from_slice(data);
+    frame
+}
+
+async fn frame_decode<R: AsyncRead + Unpin>(
+    read: &mut R,
+    length: usize,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut prefix = [0; TCP_PREFIX];
+
+    match read.read_exact(&mut prefix).await {
+        Ok(_) => {}
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error),
+    }
+
+    let size = u32::from_be_bytes(prefix) as usize;
+
+    if size > length {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Message is larger than the buffer size.",
+        ));
+    }
+
+    let mut data = vec![0; size];
+    read.read_exact(&mut data).await?;
+
+    Ok(Some(data))
+}
+
+// run a connection until either side closes it, reporting every message and error.
+async fn connection(
+    client: usize,
+    socket: SocketStream,
+    length: usize,
+    event: UnboundedSender<SocketEvent>,
+    mut writer: UnboundedReceiver<Vec<u8>>,
+) {
+    let (mut read, mut write) = tokio::io::split(socket);
+
+    let read_loop = async {
+        loop {
+            match frame_decode(&mut read, length).await {
+                Ok(Some(data)) => {
+                    let _ = event.send(SocketEvent::Message(client, data));
+                }
+                Ok(None) => {
+                    let _ = event.send(SocketEvent::Disconnect(client));
+                    break;
+                }
+                Err(error) => {
+                    let _ = event.send(SocketEvent::Failure(Some(client), error.to_string()));
+                    break;
+                }
+            }
+        }
+    };
+
+    let write_loop = async {
+        // the loop will end once the user-data drops the sender, which closes the connection.
+        while let Some(data) = writer.recv().await {
+            let result = async {
+                write.write_all(&frame_encode(&data)).await?;
+                write.flush().await
+            };
+
+            if let Err(error) = result.await {
+                let _ = event.send(SocketEvent::Failure(Some(client), error.to_string()));
+                break;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = read_loop => {}
+        _ = write_loop => {}
+    }
+}
+
+//================================================================
+
+// a TCP stream, which may be wrapped in a TLS session.
+enum SocketStream {
+    Plain(TcpStream),
+    #[cfg(feature = "tls")]
+    Secure(Box<TlsStream<TcpStream>>),
+}
+
+impl SocketStream {
+    // read every byte available into the buffer, without blocking.
+    fn try_read(&mut self, data: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(socket) => socket.try_read(data),
+            #[cfg(feature = "tls")]
+            Self::Secure(socket) => {
+                // a TLS stream has no try_read, so poll it once with a waker that does nothing.
+                let mut context = Context::from_waker(Waker::noop());
+                let mut buffer = ReadBuf::new(data);
+
+                match Pin::new(socket.as_mut()).poll_read(&mut context, &mut buffer) {
+                    Poll::Ready(Ok(())) => Ok(buffer.filled().len()),
+                    // a peer closing the connection without a close notification is still a close.
+                    Poll::Ready(Err(error))
+                        if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+                    {
+                        Ok(0)
+                    }
+                    Poll::Ready(Err(error)) => Err(error),
+                    Poll::Pending => Err(std::io::ErrorKind::WouldBlock.into()),
+                }
+            }
+        }
+    }
+}
+
+impl AsyncRead for SocketStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+        buffer: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(socket) => Pin::new(socket).poll_read(context, buffer),
+            #[cfg(feature = "tls")]
+            Self::Secure(socket) => Pin::new(socket.as_mut()).poll_read(context, buffer),
+        }
+    }
+}
+
+impl AsyncWrite for SocketStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+        data: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            Self::Plain(socket) => Pin::new(socket).poll_write(context, data),
+            #[cfg(feature = "tls")]
+            Self::Secure(socket) => Pin::new(socket.as_mut()).poll_write(context, data),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(socket) => Pin::new(socket).poll_flush(context),
+            #[cfg(feature = "tls")]
+            Self::Secure(socket) => Pin::new(socket.as_mut()).poll_flush(context),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Self::Plain(socket) => Pin::new(socket).poll_shutdown(context),
+            #[cfg(feature = "tls")]
+            Self::Secure(socket) => Pin::new(socket.as_mut()).poll_shutdown(context),
+        }
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "feature": "tls",
+    "name": "TLS_info",
+    "info": "A table describing the TLS configuration of a TCP socket. Every field is optional. A client will verify the server certificate against the web PKI root list, and the given authority, if any. A server must give both a certificate and a key.",
+    "member": [
+        { "name": "domain",      "info": "Client: Name to verify the server certificate with. Default: host part of the address.",                                       "kind": "string?"  },
+        { "name": "authority",   "info": "Client: Path to a PEM certificate authority to trust, such as the one a self-signed certificate was made with. Default: nil.", "kind": "string?"  },
+        { "name": "insecure",    "info": "Client: Accept any server certificate. Only use this for development, never for a release. Default: false.",                   "kind": "boolean?" },
+        { "name": "certificate", "info": "Server: Path to a PEM certificate chain. Default: nil.",                                                                       "kind": "string?"  },
+        { "name": "key",         "info": "Server: Path to a PEM private key. Default: nil.",                                                                             "kind": "string?"  }
+    ]
+}
+*/
+#[cfg(feature = "tls")]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct TLSInfo {
+    domain: Option<String>,
+    authority: Option<String>,
+    insecure: bool,
+    certificate: Option<String>,
+    key: Option<String>,
+}
+
+#[cfg(feature = "tls")]
+impl TLSInfo {
+    // read a PEM file from the game path.
+    fn read(lua: &Lua, path: &str, call: &str) -> mlua::Result<Vec<u8>> {
+        let path = ScriptData::get_path(lua, path)?;
+
+        std::fs::read(&path).map_err(|error| {
+            mlua::Error::runtime(format!("{call}: Could not read file \"{path}\": {error}"))
+        })
+    }
+
+    // read every certificate in a PEM file.
+    fn certificate(
+        lua: &Lua,
+        path: &str,
+        call: &str,
+    ) -> mlua::Result<Vec<CertificateDer<'static>>> {
+        let data = Self::read(lua, path, call)?;
+
+        let list = CertificateDer::pem_slice_iter(&data)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                mlua::Error::runtime(format!("{call}: Invalid certificate: {error}"))
+            })?;
+
+        if list.is_empty() {
+            return Err(mlua::Error::runtime(format!("{call}: No certificate in file \"{path}\".")));
+        }
+
+        Ok(list)
+    }
+
+    // build the TLS client configuration.
+    fn client(&self, lua: &Lua, address: &str, call: &str) -> mlua::Result<SocketTLS> {
+        let provider = Arc::new(tokio_rustls::rustls::crypto::ring::default_provider());
+        let builder = ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|error| mlua::Error::runtime(format!("{call}: {error}")))?;
+
+        let config = if self.insecure {
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(TLSInsecure(provider)))
+                .with_no_client_auth()
+        } else {
+            let mut root = RootCertStore::empty();
+            root.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+            if let Some(authority) = &self.authority {
+                for certificate in Self::certificate(lua, authority, call)? {
+                    root.add(certificate)
+                        .map_err(|error| mlua::Error::runtime(format!("{call}: {error}")))?;
+                }
+            }
+
+            builder.with_root_certificates(root).with_no_client_auth()
+        };
+
+        // use the host part of the address by default, without the port or any IPv6 bracket.
+        let domain = match &self.domain {
+            Some(domain) => domain.clone(),
+            None => address
+                .rsplit_once(':')
+                .map_or(address, |(host, _)| host)
+                .trim_matches(['[', ']'])
+                .to_string(),
+        };
+
+        let domain = ServerName::try_from(domain)
+            .map_err(|_| mlua::Error::runtime(format!("{call}: Invalid domain name.")))?;
+
+        Ok(SocketTLS::Client(TlsConnector::from(Arc::new(config)), domain))
+    }
+
+    // build the TLS server configuration.
+    fn server(&self, lua: &Lua, call: &str) -> mlua::Result<SocketTLS> {
+        let (Some(certificate), Some(key)) = (&self.certificate, &self.key) else {
+            return Err(mlua::Error::runtime(format!(
+                "{call}: A TLS server needs both a certificate and a key."
+            )));
+        };
+
+        let certificate = Self::certificate(lua, certificate, call)?;
+        let key = PrivateKeyDer::from_pem_slice(&Self::read(lua, key, call)?)
+            .map_err(|error| mlua::Error::runtime(format!("{call}: Invalid key: {error}")))?;
+
+        let provider = Arc::new(tokio_rustls::rustls::crypto::ring::default_provider());
+        let config = ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|error| mlua::Error::runtime(format!("{call}: {error}")))?
+            .with_no_client_auth()
+            .with_single_cert(certificate, key)
+            .map_err(|error| mlua::Error::runtime(format!("{call}: {error}")))?;
+
+        Ok(SocketTLS::Server(TlsAcceptor::from(Arc::new(config))))
+    }
+}
+
+// a certificate verifier that accepts any server certificate. only the handshake signatures are
+// still checked, so that the session itself is sound.
+#[cfg(feature = "tls")]
+#[derive(Debug)]
+struct TLSInsecure(Arc<CryptoProvider>);
+
+#[cfg(feature = "tls")]
+impl ServerCertVerifier for TLSInsecure {
+    fn verify_server_cert(
+        &self,
+        _: &CertificateDer<'_>,
+        _: &[CertificateDer<'_>],
+        _: &ServerName<'_>,
+        _: &[u8],
+        _: UnixTime,
+    ) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        certificate: &CertificateDer<'_>,
+        signature: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls12_signature(
+            message,
+            certificate,
+            signature,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        certificate: &CertificateDer<'_>,
+        signature: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls13_signature(
+            message,
+            certificate,
+            signature,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+// the TLS side of a TCP socket. a client will connect with a connector, and a server will accept
+// with an acceptor. a plain socket will skip the TLS handshake entirely.
+#[derive(Clone, Default)]
+enum SocketTLS {
+    #[default]
+    Plain,
+    #[cfg(feature = "tls")]
+    Client(TlsConnector, ServerName<'static>),
+    #[cfg(feature = "tls")]
+    Server(TlsAcceptor),
+}
+
+impl SocketTLS {
+    // build the TLS side out of an optional TLS_info table.
+    fn new(
+        lua: &Lua,
+        info: Option<LuaValue>,
+        address: &str,
+        server: bool,
+        call: &str,
+    ) -> mlua::Result<Self> {
+        let Some(info) = info else {
+            return Ok(Self::Plain);
+        };
+
+        #[cfg(feature = "tls")]
+        {
+            let info: TLSInfo = lua.from_value(info)?;
+
+            return if server {
+                info.server(lua, call)
+            } else {
+                info.client(lua, address, call)
+            };
+        }
+
+        #[cfg(not(feature = "tls"))]
+        {
+            let _ = (lua, info, address, server);
+
+            return Err(mlua::Error::runtime(format!(
+                "{call}: TLS is not available. Build with the \"tls\" feature."
+            )));
+        }
+    }
+
+    // make the TLS handshake over a plain TCP stream, if any. the handshake will time out.
+    async fn upgrade(&self, socket: TcpStream) -> std::io::Result<SocketStream> {
+        match self {
+            Self::Plain => Ok(SocketStream::Plain(socket)),
+            #[cfg(feature = "tls")]
+            Self::Client(connector, domain) => {
+                let socket = Self::handshake(connector.connect(domain.clone(), socket)).await?;
+                Ok(SocketStream::Secure(Box::new(socket.into())))
+            }
+            #[cfg(feature = "tls")]
+            Self::Server(acceptor) => {
+                let socket = Self::handshake(acceptor.accept(socket)).await?;
+                Ok(SocketStream::Secure(Box::new(socket.into())))
+            }
+        }
+    }
+
+    #[cfg(feature = "tls")]
+    async fn handshake<T>(
+        future: impl Future<Output = std::io::Result<T>>,
+    ) -> std::io::Result<T> {
+        tokio::time::timeout(TLS_HANDSHAKE, future)
+            .await
+            .map_err(|_| {
+                std::io::Error::new(std::io::ErrorKind::TimedOut, "TLS handshake timed out.")
+            })?
+    }
+}
+
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "socket_TCP_stream", "info": "An unique handle to a TCP (stream) socket in memory." }
+{ "version": "1.0.0", "name": "socket_TCP_stream", "info": "An unique handle to a TCP (stream) socket in memory. Every message sent with set is prefixed with its length (as a big-endian, 32-bit integer), so that get will always return a complete message." }
 */
-struct SocketTCPStream(TcpStream);
+struct SocketTCPStream {
+    socket: SocketStream,
+    buffer: Vec<u8>,
+    // scratch buffer to read into, kept so that every get call will not allocate anew.
+    scratch: Vec<u8>,
+    length: usize,
+}
 
 unsafe impl Send for SocketTCPStream {}
 
@@ -92,35 +659,108 @@ impl mlua::UserData for SocketTCPStream {
         {
             "version": "1.0.0",
             "name": "socket_TCP_stream:get",
-            "info": "TO-DO",
-            "routine": true
+            "info": "Get the next message from the socket, if any has been fully received. This call does not block.",
+            "result": [
+                { "name": "data", "info": "Message, or nil if no message is available.", "kind": "data?" }
+            ]
+        }
+        */
+        method.add_method_mut("get", |lua, this, _: ()| {
+            let close = this.receive()?;
+
+            if let Some(data) = this.frame()? {
+                return Ok(Some(Data::new(lua, data)?));
+            }
+
+            if close {
+                return Err(mlua::Error::runtime(
+                    "socket_TCP_stream:get(): Connection closed.",
+                ));
+            }
+
+            Ok(None)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_stream:get_raw",
+            "info": "Get every byte received from the socket, with no framing. This call does not block.",
+            "result": [
+                { "name": "data", "info": "Received bytes, or nil if no byte is available.", "kind": "data?" }
+            ]
         }
         */
-        method.add_async_method("get", |_: Lua, this, _: ()| async move {
-            let mut data = [0; 32];
-            let mut length = 0;
+        method.add_method_mut("get_raw", |lua, this, _: ()| {
+            let close = this.receive()?;
+
+            if this.buffer.is_empty() {
+                if close {
+                    return Err(mlua::Error::runtime(
+                        "socket_TCP_stream:get_raw(): Connection closed.",
+                    ));
+                }
 
-            while let Ok(read) = this.0.try_read(&mut data) {
-                println!("{:?} bytes received", read);
-                length += read;
+                return Ok(None);
             }
 
-            Ok((data, length))
+            let data = std::mem::take(&mut this.buffer);
+
+            Ok(Some(Data::new(lua, data)?))
         });
 
         /* entry
         {
             "version": "1.0.0",
             "name": "socket_TCP_stream:set",
-            "info": "TO-DO",
+            "info": "Send a message through the socket.",
+            "member": [
+                { "name": "data", "info": "Message to send.", "kind": "string | data" }
+            ],
+            "result": [
+                { "name": "length", "info": "Length of the message sent, including the length prefix.", "kind": "number" }
+            ],
+            "routine": true
+        }
+        */
+        method.add_async_method_mut("set", |_: Lua, mut this, data: LuaValue| async move {
+            let data = Data::get_byte(data)?;
+
+            if data.len() > this.length {
+                return Err(mlua::Error::runtime(
+                    "socket_TCP_stream:set(): Message is larger than the buffer size.",
+                ));
+            }
+
+            let frame = frame_encode(&data);
+
+            this.socket.write_all(&frame).await?;
+            this.socket.flush().await?;
+
+            Ok(frame.len())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_stream:set_raw",
+            "info": "Send bytes through the socket, with no framing.",
+            "member": [
+                { "name": "data", "info": "Bytes to send.", "kind": "string | data" }
+            ],
+            "result": [
+                { "name": "length", "info": "Length of the bytes sent.", "kind": "number" }
+            ],
             "routine": true
         }
         */
-        method.add_async_method_mut("set", |_: Lua, mut this, data: Vec<u8>| async move {
-            let length = this.0.write(&data).await?;
-            println!("{:?} bytes sent", length);
+        method.add_async_method_mut("set_raw", |_: Lua, mut this, data: LuaValue| async move {
+            let data = Data::get_byte(data)?;
 
-            Ok(length)
+            this.socket.write_all(&data).await?;
+            this.socket.flush().await?;
+
+            Ok(data.len())
         });
     }
 }
@@ -130,16 +770,85 @@ impl SocketTCPStream {
     {
         "version": "1.0.0",
         "name": "alicia.socket.new_TCP_stream",
-        "info": "TO-DO",
-        "routine": true
+        "info": "Connect to a TCP socket.",
+        "member": [
+            { "name": "address", "info": "Address to connect to.",                                                                    "kind": "string"    },
+            { "name": "length",  "info": "OPTIONAL: Buffer size, which is also the largest message size. Default: 65536.",            "kind": "number?"   },
+            { "name": "tls",     "info": "OPTIONAL: TLS configuration. The TLS handshake is made before returning. Default: nil (plain).", "kind": "TLS_info?" }
+        ],
+        "result": [
+            { "name": "socket", "info": "TCP (stream) socket.", "kind": "socket_TCP_stream" }
+        ],
+        "routine": true,
+        "test": "socket/TLS.lua"
     }
     */
-    async fn new(_: Lua, address: String) -> mlua::Result<Self> {
-        let socket = TcpStream::connect(address).await?;
+    async fn new(
+        lua: Lua,
+        (address, length, tls): (String, Option<usize>, Option<LuaValue>),
+    ) -> mlua::Result<Self> {
+        let tls = SocketTLS::new(&lua, tls, &address, false, "alicia.socket.new_TCP_stream()")?;
+        let socket = TcpStream::connect(&address).await?;
+        let socket = tls.upgrade(socket).await?;
+
+        Ok(Self::from_socket(socket, length))
+    }
+
+    fn from_socket(socket: SocketStream, length: Option<usize>) -> Self {
+        let length = length.unwrap_or(TCP_BUFFER);
+
+        Self {
+            socket,
+            buffer: Vec::new(),
+            scratch: vec![0; length],
+            length,
+        }
+    }
+
+    // read every byte available into the buffer, without blocking. true on a closed connection.
+    // the buffer will never hold more than a single message of the largest size, so any byte past
+    // that is left in the socket until the buffer is drained.
+    fn receive(&mut self) -> mlua::Result<bool> {
+        loop {
+            let space = (TCP_PREFIX + self.length).saturating_sub(self.buffer.len());
+
+            if space == 0 {
+                return Ok(false);
+            }
+
+            let space = space.min(self.scratch.len());
+
+            match self.socket.try_read(&mut self.scratch[..space]) {
+                Ok(0) => return Ok(true),
+                Ok(read) => self.buffer.extend_from_slice(&self.scratch[..read]),
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => return Ok(false),
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    // pop the next complete message out of the buffer, if there is one.
+    fn frame(&mut self) -> mlua::Result<Option<Vec<u8>>> {
+        if self.buffer.len() < TCP_PREFIX {
+            return Ok(None);
+        }
+
+        let length = u32::from_be_bytes(self.buffer[..TCP_PREFIX].try_into().unwrap()) as usize;
+
+        if length > self.length {
+            return Err(mlua::Error::runtime(
+                "socket_TCP_stream:get(): Message is larger than the buffer size.",
+            ));
+        }
+
+        if self.buffer.len() < TCP_PREFIX + length {
+            return Ok(None);
+        }
 
-        println!("done connect!");
+        let data = self.buffer[TCP_PREFIX..TCP_PREFIX + length].to_vec();
+        self.buffer.drain(..TCP_PREFIX + length);
 
-        Ok(Self(socket))
+        Ok(Some(data))
     }
 }
 
@@ -148,7 +857,11 @@ impl SocketTCPStream {
 /* class
 { "version": "1.0.0", "name": "socket_TCP_listen", "info": "An unique handle to a TCP (listen) socket in memory." }
 */
-struct SocketTCPListen(TcpListener);
+struct SocketTCPListen {
+    socket: TcpListener,
+    length: Option<usize>,
+    tls: SocketTLS,
+}
 
 unsafe impl Send for SocketTCPListen {}
 
@@ -159,17 +872,23 @@ impl mlua::UserData for SocketTCPListen {
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_TCP:accept",
-            "info": "TO-DO",
+            "name": "socket_TCP_listen:accept",
+            "info": "Accept an incoming connection. The new socket will use the same buffer size and TLS configuration as the listen socket. The TLS handshake, if any, is made before returning, and will time out after 10 seconds.",
+            "result": [
+                { "name": "socket",  "info": "TCP (stream) socket.",          "kind": "socket_TCP_stream" },
+                { "name": "address", "info": "Address of the remote socket.", "kind": "string"            }
+            ],
             "routine": true
         }
         */
         method.add_async_method("accept", |_: Lua, this, _: ()| async move {
-            let (socket, address) = this.0.accept().await?;
-
-            println!("done accept!");
+            let (socket, address) = this.socket.accept().await?;
+            let socket = this.tls.upgrade(socket).await?;
 
-            Ok((SocketTCPStream(socket), address.to_string()))
+            Ok((
+                SocketTCPStream::from_socket(socket, this.length),
+                address.to_string(),
+            ))
         });
     }
 }
@@ -179,129 +898,1854 @@ impl SocketTCPListen {
     {
         "version": "1.0.0",
         "name": "alicia.socket.new_TCP_listen",
-        "info": "TO-DO",
-        "routine": true
+        "info": "Create a TCP socket, listening for incoming connections.",
+        "member": [
+            { "name": "address", "info": "Address to bind to.",                                                                                      "kind": "string"    },
+            { "name": "length",  "info": "OPTIONAL: Buffer size for every accepted socket, which is also the largest message size. Default: 65536.", "kind": "number?"   },
+            { "name": "tls",     "info": "OPTIONAL: TLS configuration, with a certificate and key. Default: nil (plain).",                              "kind": "TLS_info?" }
+        ],
+        "result": [
+            { "name": "socket", "info": "TCP (listen) socket.", "kind": "socket_TCP_listen" }
+        ],
+        "routine": true,
+        "test": "socket/TLS.lua"
     }
     */
-    async fn new(_: Lua, address: String) -> mlua::Result<Self> {
+    async fn new(
+        lua: Lua,
+        (address, length, tls): (String, Option<usize>, Option<LuaValue>),
+    ) -> mlua::Result<Self> {
+        let tls = SocketTLS::new(&lua, tls, &address, true, "alicia.socket.new_TCP_listen()")?;
         let socket = TcpListener::bind(address).await?;
 
-        Ok(Self(socket))
+        Ok(Self { socket, length, tls })
     }
 }
 
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "socket_UDP", "info": "An unique handle to a UDP socket in memory." }
+{ "version": "1.0.0", "name": "socket_TCP_client", "info": "An unique handle to a TCP (client) socket in memory. The connection is made in the background, and every event must be drained with poll, once per frame. Messages use the same framing as socket_TCP_stream." }
 */
-struct SocketUDP(UdpSocket);
+struct SocketTCPClient {
+    state: i32,
+    event: UnboundedReceiver<SocketEvent>,
+    writer: UnboundedSender<Vec<u8>>,
+    task: JoinHandle<()>,
+    simulate: Option<Simulate>,
+}
 
-unsafe impl Send for SocketUDP {}
+impl Drop for SocketTCPClient {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
 
-impl mlua::UserData for SocketUDP {
+impl mlua::UserData for SocketTCPClient {
     fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
 
     fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_UDP:connect",
-            "info": "TO-DO",
-            "routine": true
+            "name": "socket_TCP_client:poll",
+            "info": "Drain every event received since the last call. Each event has a kind (socket_event), and depending on the kind, an address (connect), a data buffer (message), or an error message (failure). This call does not block.",
+            "result": [
+                { "name": "event_list", "info": "Event list.", "kind": "table" }
+            ]
         }
         */
-        method.add_async_method("connect", |_: Lua, this, address: String| async move {
-            this.0.connect(address).await?;
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            let list = lua.create_table()?;
 
-            Ok(())
+            while let Ok(event) = this.event.try_recv() {
+                match event {
+                    SocketEvent::Connect(..) => this.state = STATE_CONNECT,
+                    SocketEvent::Disconnect(_) | SocketEvent::Failure(..) => {
+                        this.state = STATE_DISCONNECT
+                    }
+                    _ => {}
+                }
+
+                list.push(event.to_table(lua, false)?)?;
+            }
+
+            Ok(list)
         });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_UDP:get",
-            "info": "TO-DO"
+            "name": "socket_TCP_client:get_state",
+            "info": "Get the connection state, as of the last poll call.",
+            "result": [
+                { "name": "state", "info": "Connection state.", "kind": "socket_state" }
+            ]
         }
         */
-        method.add_method("get", |_: &Lua, this, _: ()| {
-            let mut data = [0; 32];
-            let mut length = 0;
-
-            while let Ok(read) = this.0.try_recv(&mut data) {
-                println!("{:?} bytes received", read);
-                length += read;
-            }
-
-            Ok((data, length))
-        });
+        method.add_method("get_state", |_, this, _: ()| Ok(this.state));
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_UDP:set",
-            "info": "TO-DO",
-            "routine": true
+            "name": "socket_TCP_client:set",
+            "info": "Queue a message to send. Messages queued while the socket is still connecting will be sent once connected. This call does not block.",
+            "member": [
+                { "name": "data", "info": "Message to send.", "kind": "string | data" }
+            ]
         }
         */
-        method.add_async_method("set", |_: Lua, this, data: Vec<u8>| async move {
-            let length = this.0.send(&data).await?;
-            println!("{:?} bytes sent", length);
+        method.add_method_mut("set", |_, this, data: LuaValue| {
+            let data = Data::get_byte(data)?;
+            let send = match &mut this.simulate {
+                Some(simulate) => simulate.send_stream(0, &this.writer, data),
+                None => this.writer.send(data).is_ok(),
+            };
 
-            Ok(length)
+            if this.state == STATE_DISCONNECT || !send {
+                return Err(mlua::Error::runtime(
+                    "socket_TCP_client:set(): Socket is disconnected.",
+                ));
+            }
+
+            Ok(())
         });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_UDP:get_at",
-            "info": "TO-DO"
+            "name": "socket_TCP_client:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "socket/simulate.lua"
         }
         */
-        method.add_method("get_at", |_: &Lua, this, _: ()| {
-            let mut data = [0; 32];
-            let mut length = 0;
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
 
-            while let Ok((read, address)) = this.0.try_recv_from(&mut data) {
-                println!("{:?} bytes received from {address}", read);
-                length += read;
-            }
-
-            Ok((data, length))
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
         });
 
         /* entry
         {
             "version": "1.0.0",
-            "name": "socket_UDP:set_at",
-            "info": "TO-DO",
-            "routine": true
+            "name": "socket_TCP_client:close",
+            "info": "Close the connection. Any queued message that has not been sent yet is dropped."
         }
         */
-        method.add_async_method(
-            "set_at",
-            |_: Lua, this, (data, address): (Vec<u8>, String)| async move {
-                let length = this.0.send_to(&data, address).await?;
-                println!("{:?} bytes sent", length);
+        method.add_method_mut("close", |_, this, _: ()| {
+            this.task.abort();
+            this.state = STATE_DISCONNECT;
 
-                Ok(length)
-            },
-        );
+            Ok(())
+        });
     }
 }
 
-impl SocketUDP {
+impl SocketTCPClient {
     /* entry
     {
         "version": "1.0.0",
-        "name": "alicia.socket.new_UDP",
-        "info": "TO-DO",
-        "routine": true
+        "name": "alicia.socket.new_TCP_client",
+        "info": "Create a TCP (client) socket, and connect to a TCP socket in the background. This call does not block.",
+        "member": [
+            { "name": "address", "info": "Address to connect to.",                                                                             "kind": "string"    },
+            { "name": "length",  "info": "OPTIONAL: Buffer size, which is also the largest message size. Default: 65536.",                     "kind": "number?"   },
+            { "name": "tls",     "info": "OPTIONAL: TLS configuration. The TLS handshake is made before the connect event. Default: nil (plain).", "kind": "TLS_info?" }
+        ],
+        "result": [
+            { "name": "socket", "info": "TCP (client) socket.", "kind": "socket_TCP_client" }
+        ],
+        "test": "socket/TCP.lua"
     }
     */
-    async fn new(_: Lua, address: String) -> mlua::Result<Self> {
-        let sock = UdpSocket::bind(address).await?;
+    fn new(
+        lua: &Lua,
+        (address, length, tls): (String, Option<usize>, Option<LuaValue>),
+    ) -> mlua::Result<Self> {
+        let tls = SocketTLS::new(lua, tls, &address, false, "alicia.socket.new_TCP_client()")?;
+        let length = length.unwrap_or(TCP_BUFFER);
+        let (event_send, event) = unbounded_channel();
+        let (writer, writer_receive) = unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            let socket = match TcpStream::connect(&address).await {
+                Ok(socket) => tls.upgrade(socket).await,
+                Err(error) => Err(error),
+            };
+
+            match socket {
+                Ok(socket) => {
+                    let _ = event_send.send(SocketEvent::Connect(0, address, None));
+                    connection(0, socket, length, event_send, writer_receive).await;
+                }
+                Err(error) => {
+                    let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                }
+            }
+        });
+
+        Ok(Self {
+            state: STATE_CONNECTING,
+            event,
+            writer,
+            task,
+            simulate: None,
+        })
+    }
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "socket_TCP_server", "info": "An unique handle to a TCP (server) socket in memory. Incoming connections are accepted in the background, and every event must be drained with poll, once per frame. Every connection is given a client number. Messages use the same framing as socket_TCP_stream." }
+*/
+struct SocketTCPServer {
+    state: i32,
+    event: UnboundedReceiver<SocketEvent>,
+    client: HashMap<usize, UnboundedSender<Vec<u8>>>,
+    task: JoinHandle<()>,
+    simulate: Option<Simulate>,
+}
+
+impl Drop for SocketTCPServer {
+    fn drop(&mut self) {
+        // every connection will also close, as the client map is dropped.
+        self.task.abort();
+    }
+}
+
+impl mlua::UserData for SocketTCPServer {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_server:poll",
+            "info": "Drain every event received since the last call. Each event has a kind (socket_event), a client number, and depending on the kind, an address (connect), a data buffer (message), or an error message (failure). A failure event with no client number means the server socket has failed to bind, or to accept a connection, in which case it will keep on listening. This call does not block.",
+            "result": [
+                { "name": "event_list", "info": "Event list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            let list = lua.create_table()?;
+
+            while let Ok(mut event) = this.event.try_recv() {
+                match &mut event {
+                    SocketEvent::Listen => {
+                        this.state = STATE_CONNECT;
+                        continue;
+                    }
+                    SocketEvent::Connect(client, _, sender) => {
+                        if let Some(sender) = sender.take() {
+                            this.client.insert(*client, sender);
+                        }
+                    }
+                    SocketEvent::Disconnect(client) | SocketEvent::Failure(Some(client), _) => {
+                        this.client.remove(&*client);
+
+                        if let Some(simulate) = &mut this.simulate {
+                            simulate.stream.remove(&*client);
+                        }
+                    }
+                    // only a failure to bind is fatal. a failure to accept is just reported.
+                    SocketEvent::Failure(None, _) if this.state == STATE_CONNECTING => {
+                        this.state = STATE_DISCONNECT
+                    }
+                    _ => {}
+                }
+
+                list.push(event.to_table(lua, true)?)?;
+            }
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_server:get_state",
+            "info": "Get the server state, as of the last poll call. The server is in the connect state once it is listening for incoming connections.",
+            "result": [
+                { "name": "state", "info": "Server state.", "kind": "socket_state" }
+            ]
+        }
+        */
+        method.add_method("get_state", |_, this, _: ()| Ok(this.state));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_server:set",
+            "info": "Queue a message to send to a client. This call does not block.",
+            "member": [
+                { "name": "client", "info": "Client number.",   "kind": "number"        },
+                { "name": "data",   "info": "Message to send.", "kind": "string | data" }
+            ]
+        }
+        */
+        method.add_method_mut("set", |_, this, (client, data): (usize, LuaValue)| {
+            if let Some(writer) = this.client.get(&client) {
+                let data = Data::get_byte(data)?;
+                let send = match &mut this.simulate {
+                    Some(simulate) => simulate.send_stream(client, writer, data),
+                    None => writer.send(data).is_ok(),
+                };
+
+                if send {
+                    return Ok(());
+                }
+            }
+
+            Err(mlua::Error::runtime(
+                "socket_TCP_server:set(): Invalid or disconnected client.",
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_server:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "socket/simulate.lua"
+        }
+        */
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
+
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_TCP_server:close",
+            "info": "Close the connection to a client. If no client is given, close the server socket, and every connection.",
+            "member": [
+                { "name": "client", "info": "OPTIONAL: Client number.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut("close", |_, this, client: Option<usize>| {
+            // dropping the sender of a connection will close it.
+            if let Some(client) = client {
+                this.client.remove(&client);
+            } else {
+                this.task.abort();
+                this.state = STATE_DISCONNECT;
+                this.client.clear();
+            }
+
+            Ok(())
+        });
+    }
+}
+
+impl SocketTCPServer {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.socket.new_TCP_server",
+        "info": "Create a TCP (server) socket, and listen for incoming connections in the background. This call does not block.",
+        "member": [
+            { "name": "address", "info": "Address to bind to.",                                                                                 "kind": "string"    },
+            { "name": "length",  "info": "OPTIONAL: Buffer size for every connection, which is also the largest message size. Default: 65536.", "kind": "number?"   },
+            { "name": "tls",     "info": "OPTIONAL: TLS configuration, with a certificate and key. Default: nil (plain).",                         "kind": "TLS_info?" }
+        ],
+        "result": [
+            { "name": "socket", "info": "TCP (server) socket.", "kind": "socket_TCP_server" }
+        ],
+        "test": "socket/TCP.lua"
+    }
+    */
+    fn new(
+        lua: &Lua,
+        (address, length, tls): (String, Option<usize>, Option<LuaValue>),
+    ) -> mlua::Result<Self> {
+        let tls = SocketTLS::new(lua, tls, &address, true, "alicia.socket.new_TCP_server()")?;
+        let length = length.unwrap_or(TCP_BUFFER);
+        let (event_send, event) = unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            let socket = match TcpListener::bind(&address).await {
+                Ok(socket) => socket,
+                Err(error) => {
+                    let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                    return;
+                }
+            };
+
+            let _ = event_send.send(SocketEvent::Listen);
+            let mut count = 0;
+
+            loop {
+                match socket.accept().await {
+                    Ok((stream, address)) => {
+                        count += 1;
+
+                        let client = count;
+                        let tls = tls.clone();
+                        let event_send = event_send.clone();
+
+                        // make the TLS handshake in the connection task, so that a slow client
+                        // will not hold up every other incoming connection.
+                        tokio::spawn(async move {
+                            let stream = match tls.upgrade(stream).await {
+                                Ok(stream) => stream,
+                                Err(error) => {
+                                    let _ = event_send.send(SocketEvent::Failure(
+                                        Some(client),
+                                        error.to_string(),
+                                    ));
+                                    return;
+                                }
+                            };
+
+                            let (sender, writer_receive) = unbounded_channel();
+
+                            // send the connect event first, so that it always comes before a
+                            // message.
+                            let _ = event_send.send(SocketEvent::Connect(
+                                client,
+                                address.to_string(),
+                                Some(sender),
+                            ));
+
+                            connection(client, stream, length, event_send, writer_receive).await;
+                        });
+                    }
+                    Err(error) => {
+                        // a failed accept is not fatal to the listener. report it, and keep on
+                        // accepting after a short back-off.
+                        let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                        tokio::time::sleep(ACCEPT_BACK_OFF).await;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            state: STATE_CONNECTING,
+            event,
+            client: HashMap::new(),
+            task,
+            simulate: None,
+        })
+    }
+}
+
+//================================================================
+
+// largest payload of a ping frame.
+#[cfg(feature = "web_socket")]
+const PING_BUFFER: usize = 125;
+
+// build a web socket message out of a Lua value.
+#[cfg(feature = "web_socket")]
+fn web_socket_message(data: LuaValue, text: Option<bool>, call: &str) -> mlua::Result<Message> {
+    let data = Data::get_byte(data)?;
+
+    if text.unwrap_or_default() {
+        let text = String::from_utf8(data)
+            .map_err(|_| mlua::Error::runtime(format!("{call}: Text is not valid UTF-8.")))?;
+
+        Ok(Message::text(text))
+    } else {
+        Ok(Message::binary(data))
+    }
+}
+
+// build a web socket ping message out of a Lua value.
+#[cfg(feature = "web_socket")]
+fn web_socket_ping(data: Option<LuaValue>, call: &str) -> mlua::Result<Message> {
+    let data = match data {
+        Some(data) => Data::get_byte(data)?,
+        None => Vec::new(),
+    };
+
+    if data.len() > PING_BUFFER {
+        return Err(mlua::Error::runtime(format!("{call}: Ping data is larger than 125 bytes.")));
+    }
+
+    Ok(Message::Ping(data.into()))
+}
+
+// build a web socket close message. the default close code is 1000 (normal closure).
+#[cfg(feature = "web_socket")]
+fn web_socket_close(code: Option<u16>, reason: Option<String>) -> Message {
+    Message::Close(Some(CloseFrame {
+        code: code.unwrap_or(1000).into(),
+        reason: reason.unwrap_or_default().into(),
+    }))
+}
+
+// run a web socket connection until either side closes it, reporting every message and error.
+#[cfg(feature = "web_socket")]
+async fn web_socket_connection<S: AsyncRead + AsyncWrite + Unpin>(
+    client: usize,
+    socket: WebSocketStream<S>,
+    event: UnboundedSender<SocketEvent<Message>>,
+    mut writer: UnboundedReceiver<Message>,
+) {
+    let (mut write, mut read) = socket.split();
+
+    let read_loop = async {
+        let mut close = false;
+
+        // a ping is answered automatically. after a close frame, keep reading until the close
+        // hand-shake is done.
+        while let Some(message) = read.next().await {
+            match message {
+                Ok(Message::Text(text)) => {
+                    let _ = event.send(SocketEvent::Text(client, text.to_string()));
+                }
+                Ok(Message::Binary(data)) => {
+                    let _ = event.send(SocketEvent::Message(client, data.to_vec()));
+                }
+                Ok(Message::Pong(data)) => {
+                    let _ = event.send(SocketEvent::Pong(client, data.to_vec()));
+                }
+                Ok(Message::Close(frame)) => {
+                    // 1005 is the close code for a close frame with no code.
+                    let (code, reason) = frame
+                        .map(|frame| (u16::from(frame.code), frame.reason.to_string()))
+                        .unwrap_or((1005, String::new()));
+
+                    let _ = event.send(SocketEvent::Close(client, code, reason));
+                    close = true;
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    let _ = event.send(SocketEvent::Failure(Some(client), error.to_string()));
+                    return;
+                }
+            }
+        }
+
+        if !close {
+            let _ = event.send(SocketEvent::Disconnect(client));
+        }
+    };
+
+    let write_loop = async {
+        // the loop will end once the user-data drops the sender, which closes the connection.
+        while let Some(message) = writer.recv().await {
+            if let Err(error) = write.send(message).await {
+                let _ = event.send(SocketEvent::Failure(Some(client), error.to_string()));
+                break;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = read_loop => {}
+        _ = write_loop => {}
+    }
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "feature": "web_socket", "name": "socket_web_socket", "info": "An unique handle to a web socket (client) in memory. The connection is made in the background, and every event must be drained with poll, once per frame." }
+*/
+#[cfg(feature = "web_socket")]
+struct SocketWebSocket {
+    state: i32,
+    event: UnboundedReceiver<SocketEvent<Message>>,
+    writer: UnboundedSender<Message>,
+    task: JoinHandle<()>,
+    simulate: Option<Simulate>,
+}
+
+#[cfg(feature = "web_socket")]
+impl Drop for SocketWebSocket {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl SocketWebSocket {
+    fn send(&mut self, message: Message, call: &str) -> mlua::Result<()> {
+        let send = match &mut self.simulate {
+            Some(simulate) => simulate.send_stream(0, &self.writer, message),
+            None => self.writer.send(message).is_ok(),
+        };
+
+        if self.state == STATE_DISCONNECT || !send {
+            return Err(mlua::Error::runtime(format!("{call}: Socket is disconnected.")));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl mlua::UserData for SocketWebSocket {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:poll",
+            "info": "Drain every event received since the last call. Each event has a kind (socket_event), and depending on the kind, an address (connect), a data buffer (binary message, or pong) or a text string (text message), a close code and reason (disconnect, if the other end sent a close frame), or an error message (failure). This call does not block.",
+            "result": [
+                { "name": "event_list", "info": "Event list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            let list = lua.create_table()?;
+
+            while let Ok(event) = this.event.try_recv() {
+                match event {
+                    SocketEvent::Connect(..) => this.state = STATE_CONNECT,
+                    SocketEvent::Disconnect(_)
+                    | SocketEvent::Close(..)
+                    | SocketEvent::Failure(..) => this.state = STATE_DISCONNECT,
+                    _ => {}
+                }
+
+                list.push(event.to_table(lua, false)?)?;
+            }
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:get_state",
+            "info": "Get the connection state, as of the last poll call.",
+            "result": [
+                { "name": "state", "info": "Connection state.", "kind": "socket_state" }
+            ]
+        }
+        */
+        method.add_method("get_state", |_, this, _: ()| Ok(this.state));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:set",
+            "info": "Queue a message to send. Messages queued while the socket is still connecting will be sent once connected. This call does not block.",
+            "member": [
+                { "name": "data", "info": "Message to send.",                                                                "kind": "string | data" },
+                { "name": "text", "info": "OPTIONAL: Send as a text message, rather than a binary message. Default: false.", "kind": "boolean?"      }
+            ]
+        }
+        */
+        method.add_method_mut("set", |_, this, (data, text): (LuaValue, Option<bool>)| {
+            let call = "socket_web_socket:set()";
+
+            this.send(web_socket_message(data, text, call)?, call)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:ping",
+            "info": "Queue a ping to send. The other end will answer with a pong event, with the same data. This call does not block.",
+            "member": [
+                { "name": "data", "info": "OPTIONAL: Ping data, up to 125 bytes.", "kind": "string | data | nil" }
+            ]
+        }
+        */
+        method.add_method_mut("ping", |_, this, data: Option<LuaValue>| {
+            let call = "socket_web_socket:ping()";
+
+            this.send(web_socket_ping(data, call)?, call)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:close",
+            "info": "Close the connection, by sending a close frame. A disconnect event will be raised once the other end has answered.",
+            "member": [
+                { "name": "code",   "info": "OPTIONAL: Close code. Default: 1000.",    "kind": "number?" },
+                { "name": "reason", "info": "OPTIONAL: Close reason. Default: empty.", "kind": "string?" }
+            ]
+        }
+        */
+        method.add_method_mut("close", |_, this, (code, reason): (Option<u16>, Option<String>)| {
+            this.send(web_socket_close(code, reason), "socket_web_socket:close()")
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped. A close frame is delayed, but never dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "socket/simulate.lua"
+        }
+        */
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
+
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
+        });
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl SocketWebSocket {
+    /* entry
+    {
+        "version": "1.0.0",
+        "feature": "web_socket",
+        "name": "alicia.socket.new_web_socket",
+        "info": "Create a web socket (client), and connect to a web socket server in the background. This call does not block.",
+        "member": [
+            { "name": "URL", "info": "URL to connect to, such as \"ws://127.0.0.1:8080\" or \"wss://example.com/path\".", "kind": "string" }
+        ],
+        "result": [
+            { "name": "socket", "info": "Web socket (client).", "kind": "socket_web_socket" }
+        ],
+        "test": "socket/web_socket.lua"
+    }
+    */
+    fn new(_: &Lua, url: String) -> mlua::Result<Self> {
+        let (event_send, event) = unbounded_channel();
+        let (writer, writer_receive) = unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            match tokio_tungstenite::connect_async(&url).await {
+                Ok((socket, _)) => {
+                    let _ = event_send.send(SocketEvent::Connect(0, url, None));
+                    web_socket_connection(0, socket, event_send, writer_receive).await;
+                }
+                Err(error) => {
+                    let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                }
+            }
+        });
+
+        Ok(Self {
+            state: STATE_CONNECTING,
+            event,
+            writer,
+            task,
+            simulate: None,
+        })
+    }
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "feature": "web_socket", "name": "socket_web_socket_server", "info": "An unique handle to a web socket (server) in memory. Incoming connections are accepted in the background, and every event must be drained with poll, once per frame. Every connection is given a client number." }
+*/
+#[cfg(feature = "web_socket")]
+struct SocketWebSocketServer {
+    state: i32,
+    event: UnboundedReceiver<SocketEvent<Message>>,
+    client: HashMap<usize, UnboundedSender<Message>>,
+    task: JoinHandle<()>,
+    simulate: Option<Simulate>,
+}
+
+#[cfg(feature = "web_socket")]
+impl Drop for SocketWebSocketServer {
+    fn drop(&mut self) {
+        // every connection will also close, as the client map is dropped.
+        self.task.abort();
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl SocketWebSocketServer {
+    fn send(&mut self, client: usize, message: Message, call: &str) -> mlua::Result<()> {
+        if let Some(writer) = self.client.get(&client) {
+            let send = match &mut self.simulate {
+                Some(simulate) => simulate.send_stream(client, writer, message),
+                None => writer.send(message).is_ok(),
+            };
+
+            if send {
+                return Ok(());
+            }
+        }
+
+        Err(mlua::Error::runtime(format!("{call}: Invalid or disconnected client.")))
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl mlua::UserData for SocketWebSocketServer {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:poll",
+            "info": "Drain every event received since the last call. Each event has a kind (socket_event), a client number, and depending on the kind, an address (connect), a data buffer (binary message, or pong) or a text string (text message), a close code and reason (disconnect, if the client sent a close frame), or an error message (failure). A failure event with no client number means the server socket has failed to bind, or to accept a connection, in which case it will keep on listening. This call does not block.",
+            "result": [
+                { "name": "event_list", "info": "Event list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            let list = lua.create_table()?;
+
+            while let Ok(mut event) = this.event.try_recv() {
+                match &mut event {
+                    SocketEvent::Listen => {
+                        this.state = STATE_CONNECT;
+                        continue;
+                    }
+                    SocketEvent::Connect(client, _, sender) => {
+                        if let Some(sender) = sender.take() {
+                            this.client.insert(*client, sender);
+                        }
+                    }
+                    SocketEvent::Disconnect(client)
+                    | SocketEvent::Close(client, ..)
+                    | SocketEvent::Failure(Some(client), _) => {
+                        this.client.remove(&*client);
+
+                        if let Some(simulate) = &mut this.simulate {
+                            simulate.stream.remove(&*client);
+                        }
+                    }
+                    // only a failure to bind is fatal. a failure to accept is just reported.
+                    SocketEvent::Failure(None, _) if this.state == STATE_CONNECTING => {
+                        this.state = STATE_DISCONNECT
+                    }
+                    _ => {}
+                }
+
+                list.push(event.to_table(lua, true)?)?;
+            }
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:get_state",
+            "info": "Get the server state, as of the last poll call. The server is in the connect state once it is listening for incoming connections.",
+            "result": [
+                { "name": "state", "info": "Server state.", "kind": "socket_state" }
+            ]
+        }
+        */
+        method.add_method("get_state", |_, this, _: ()| Ok(this.state));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:set",
+            "info": "Queue a message to send to a client. This call does not block.",
+            "member": [
+                { "name": "client", "info": "Client number.",                                                                  "kind": "number"        },
+                { "name": "data",   "info": "Message to send.",                                                                "kind": "string | data" },
+                { "name": "text",   "info": "OPTIONAL: Send as a text message, rather than a binary message. Default: false.", "kind": "boolean?"      }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set",
+            |_, this, (client, data, text): (usize, LuaValue, Option<bool>)| {
+                let call = "socket_web_socket_server:set()";
+
+                this.send(client, web_socket_message(data, text, call)?, call)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:ping",
+            "info": "Queue a ping to send to a client. The client will answer with a pong event, with the same data. This call does not block.",
+            "member": [
+                { "name": "client", "info": "Client number.",                        "kind": "number"              },
+                { "name": "data",   "info": "OPTIONAL: Ping data, up to 125 bytes.", "kind": "string | data | nil" }
+            ]
+        }
+        */
+        method.add_method_mut("ping", |_, this, (client, data): (usize, Option<LuaValue>)| {
+            let call = "socket_web_socket_server:ping()";
+
+            this.send(client, web_socket_ping(data, call)?, call)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:close",
+            "info": "Close the connection to a client, by sending a close frame. If no client is given, close the server socket, and drop every connection.",
+            "member": [
+                { "name": "client", "info": "OPTIONAL: Client number.",                "kind": "number?" },
+                { "name": "code",   "info": "OPTIONAL: Close code. Default: 1000.",    "kind": "number?" },
+                { "name": "reason", "info": "OPTIONAL: Close reason. Default: empty.", "kind": "string?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "close",
+            |_, this, (client, code, reason): (Option<usize>, Option<u16>, Option<String>)| {
+                // dropping the sender of a connection will close it, once the close frame is sent.
+                if let Some(client) = client {
+                    let call = "socket_web_socket_server:close()";
+                    let _ = this.send(client, web_socket_close(code, reason), call);
+                    this.client.remove(&client);
+                } else {
+                    this.task.abort();
+                    this.state = STATE_DISCONNECT;
+                    this.client.clear();
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "web_socket",
+            "name": "socket_web_socket_server:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every delayed message is sent in the background once due. Any message still delayed by the last simulation is dropped. A close frame is delayed, but never dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "socket/simulate.lua"
+        }
+        */
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
+
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
+        });
+    }
+}
+
+#[cfg(feature = "web_socket")]
+impl SocketWebSocketServer {
+    /* entry
+    {
+        "version": "1.0.0",
+        "feature": "web_socket",
+        "name": "alicia.socket.new_web_socket_server",
+        "info": "Create a web socket (server), and listen for incoming connections in the background. This call does not block.",
+        "member": [
+            { "name": "address", "info": "Address to bind to.", "kind": "string" }
+        ],
+        "result": [
+            { "name": "socket", "info": "Web socket (server).", "kind": "socket_web_socket_server" }
+        ],
+        "test": "socket/web_socket.lua"
+    }
+    */
+    fn new(_: &Lua, address: String) -> mlua::Result<Self> {
+        let (event_send, event) = unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            let socket = match TcpListener::bind(&address).await {
+                Ok(socket) => socket,
+                Err(error) => {
+                    let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                    return;
+                }
+            };
+
+            let _ = event_send.send(SocketEvent::Listen);
+            let mut count = 0;
+
+            loop {
+                match socket.accept().await {
+                    Ok((stream, address)) => {
+                        count += 1;
+
+                        let client = count;
+                        let event_send = event_send.clone();
+
+                        // a connection that fails the hand-shake is dropped without an event.
+                        tokio::spawn(async move {
+                            if let Ok(socket) = tokio_tungstenite::accept_async(stream).await {
+                                let (sender, writer_receive) = unbounded_channel();
+
+                                let _ = event_send.send(SocketEvent::Connect(
+                                    client,
+                                    address.to_string(),
+                                    Some(sender),
+                                ));
+
+                                web_socket_connection(client, socket, event_send, writer_receive)
+                                    .await;
+                            }
+                        });
+                    }
+                    Err(error) => {
+                        // a failed accept is not fatal to the listener. report it, and keep on
+                        // accepting after a short back-off.
+                        let _ = event_send.send(SocketEvent::Failure(None, error.to_string()));
+                        tokio::time::sleep(ACCEPT_BACK_OFF).await;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            state: STATE_CONNECTING,
+            event,
+            client: HashMap::new(),
+            task,
+            simulate: None,
+        })
+    }
+}
+
+//================================================================
+
+// a multicast group address, and the interface to use it on.
+enum Multicast {
+    V4(Ipv4Addr, Ipv4Addr),
+    V6(Ipv6Addr, u32),
+}
+
+impl Multicast {
+    fn new(group: &str, interface: Option<String>, call: &str) -> mlua::Result<Self> {
+        let group: IpAddr = group
+            .parse()
+            .map_err(|_| mlua::Error::runtime(format!("{call}: Invalid group address.")))?;
+
+        if !group.is_multicast() {
+            return Err(mlua::Error::runtime(format!(
+                "{call}: Group address is not a multicast address."
+            )));
+        }
+
+        match group {
+            IpAddr::V4(group) => {
+                let interface = match interface {
+                    Some(interface) => interface.parse().map_err(|_| {
+                        mlua::Error::runtime(format!("{call}: Invalid interface address."))
+                    })?,
+                    None => Ipv4Addr::UNSPECIFIED,
+                };
+
+                Ok(Self::V4(group, interface))
+            }
+            IpAddr::V6(group) => {
+                let interface = match interface {
+                    Some(interface) => interface.parse().map_err(|_| {
+                        mlua::Error::runtime(format!("{call}: Invalid interface index."))
+                    })?,
+                    None => 0,
+                };
+
+                Ok(Self::V6(group, interface))
+            }
+        }
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "simulate_info",
+    "info": "A table describing a network condition simulation, applied to every datagram or message sent. Every field is optional. Only outgoing datagrams or messages are affected, so both ends should set a simulation to affect both directions. The same seed, with the same datagrams sent in the same order, will always drop, duplicate and re-order the same datagrams. A stream (TCP or web socket) is reliable and always in order, so that a message is never dropped, duplicated or re-ordered. A lost message is instead re-sent, stalling it and every later message by a round trip.",
+    "member": [
+        { "name": "latency",       "info": "Delay (in seconds) of every datagram. Default: 0.0.",                                                                 "kind": "number?" },
+        { "name": "jitter",        "info": "Largest random delay (in seconds) added on top of the latency. Default: 0.0.",                                        "kind": "number?" },
+        { "name": "loss",          "info": "Chance of a datagram being dropped (or a stream message being stalled), from 0.0 to 1.0. Default: 0.0.",              "kind": "number?" },
+        { "name": "duplicate",     "info": "Chance of a datagram being sent twice, from 0.0 to 1.0. Default: 0.0.",                                               "kind": "number?" },
+        { "name": "reorder",       "info": "Chance of a datagram being held back, so that later datagrams will arrive before it, from 0.0 to 1.0. Default: 0.0.", "kind": "number?" },
+        { "name": "reorder_delay", "info": "Extra delay (in seconds) of a held back datagram. Default: 0.1.",                                                     "kind": "number?" },
+        { "name": "seed",          "info": "Random seed. Default: nil (random).",                                                                                 "kind": "number?" }
+    ]
+}
+*/
+#[derive(Deserialize)]
+#[serde(default)]
+struct SimulateInfo {
+    latency: f32,
+    jitter: f32,
+    loss: f32,
+    duplicate: f32,
+    reorder: f32,
+    reorder_delay: f32,
+    seed: Option<u64>,
+}
+
+impl Default for SimulateInfo {
+    fn default() -> Self {
+        Self {
+            latency: 0.0,
+            jitter: 0.0,
+            loss: 0.0,
+            duplicate: 0.0,
+            reorder: 0.0,
+            reorder_delay: 0.1,
+            seed: None,
+        }
+    }
+}
+
+// a delayed send, made by the timer task once due.
+type SimulateSend = Box<dyn FnOnce() + Send>;
+
+// a network condition simulation. every datagram or message sent is either dropped, or queued
+// with a delay, and only truly sent once due, by a timer task in the background.
+pub struct Simulate {
+    info: SimulateInfo,
+    seed: u64,
+    state: u64,
+    // due time of the last message of every stream, by client number, to keep a stream in order.
+    stream: HashMap<usize, Instant>,
+    queue: UnboundedSender<(Instant, SimulateSend)>,
+    task: JoinHandle<()>,
+}
+
+impl Drop for Simulate {
+    fn drop(&mut self) {
+        // every datagram or message still delayed is dropped.
+        self.task.abort();
+    }
+}
+
+impl Simulate {
+    // build a simulation out of a simulate_info table, or nil to turn the simulation off.
+    pub fn new(lua: &Lua, info: Option<LuaValue>) -> mlua::Result<Option<Self>> {
+        let Some(info) = info else {
+            return Ok(None);
+        };
+
+        let info: SimulateInfo = lua.from_value(info)?;
+
+        // keep a random seed within the range of a Lua number, so that it can be given back.
+        let seed = info.seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|time| time.as_nanos() as u64 & ((1 << 53) - 1))
+                .unwrap_or_default()
+        });
+
+        let (queue, receive) = unbounded_channel();
+
+        Ok(Some(Self {
+            info,
+            seed,
+            state: seed,
+            stream: HashMap::new(),
+            queue,
+            task: tokio::spawn(Self::timer(receive)),
+        }))
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // make every delayed send once due, in order of due time.
+    async fn timer(mut receive: UnboundedReceiver<(Instant, SimulateSend)>) {
+        let mut queue: Vec<(Instant, SimulateSend)> = Vec::new();
+
+        loop {
+            let due = queue.first().map(|(due, _)| *due).unwrap_or_else(Instant::now);
+
+            tokio::select! {
+                entry = receive.recv() => {
+                    let Some((due, call)) = entry else {
+                        break;
+                    };
+
+                    // a send with the same due time as another will keep its order.
+                    let index = queue.partition_point(|(x, _)| *x <= due);
+                    queue.insert(index, (due, call));
+                }
+                _ = tokio::time::sleep_until(due.into()), if !queue.is_empty() => {
+                    let time = Instant::now();
+                    let count = queue.iter().take_while(|(due, _)| *due <= time).count();
+
+                    for (_, call) in queue.drain(..count) {
+                        call();
+                    }
+                }
+            }
+        }
+    }
+
+    // random number from 0.0 to 1.0, with split-mix 64.
+    fn random(&mut self) -> f32 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+        value ^= value >> 31;
+
+        (value >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn delay(&mut self, reorder: bool) -> Duration {
+        let mut delay = self.info.latency + self.info.jitter * self.random();
+
+        if reorder && self.random() < self.info.reorder {
+            delay += self.info.reorder_delay;
+        }
+
+        Duration::try_from_secs_f32(delay.max(0.0)).unwrap_or_default()
+    }
+
+    // send a datagram through the simulation, to an address, or to the connected address if none.
+    pub fn send(
+        &mut self,
+        socket: &Arc<UdpSocket>,
+        data: &[u8],
+        address: Option<SocketAddr>,
+    ) -> std::io::Result<usize> {
+        // every roll is always made, so that the outcome of a datagram will never change the
+        // outcome of any later datagram.
+        let loss = self.random() < self.info.loss;
+        let duplicate = self.random() < self.info.duplicate;
+        let delay = self.delay(true);
+        let delay_duplicate = self.delay(true);
+
+        if !loss {
+            let time = Instant::now();
+
+            self.send_datagram(time + delay, socket, data, address);
+
+            if duplicate {
+                self.send_datagram(time + delay_duplicate, socket, data, address);
+            }
+        }
+
+        // a dropped datagram is still reported as sent, as a real network would.
+        Ok(data.len())
+    }
+
+    fn send_datagram(
+        &self,
+        due: Instant,
+        socket: &Arc<UdpSocket>,
+        data: &[u8],
+        address: Option<SocketAddr>,
+    ) {
+        let socket = socket.clone();
+        let data = data.to_vec();
+
+        let _ = self.queue.send((
+            due,
+            Box::new(move || {
+                // a failed send is the same as a lost datagram.
+                let _ = match address {
+                    Some(address) => socket.try_send_to(&data, address),
+                    None => socket.try_send(&data),
+                };
+            }),
+        ));
+    }
+
+    // send a message of a stream through the simulation. a stream is reliable and always in
+    // order, so that a message is never dropped, duplicated or re-ordered. a lost message is
+    // instead re-sent, which will stall it and every later message. will return false if the
+    // stream is closed.
+    pub fn send_stream<W: Send + 'static>(
+        &mut self,
+        client: usize,
+        writer: &UnboundedSender<W>,
+        data: W,
+    ) -> bool {
+        if writer.is_closed() {
+            return false;
+        }
+
+        // every roll is always made, as in send.
+        let loss = self.random() < self.info.loss;
+        let mut delay = self.delay(false);
+        let delay_resend = self.delay(false);
+
+        if loss {
+            // the re-send time-out of a round trip, and the trip of the re-sent message itself.
+            delay += delay_resend * 3;
+        }
+
+        let time = Instant::now();
+        let last = self.stream.entry(client).or_insert(time);
+        let due = (time + delay).max(*last);
+        let writer = writer.clone();
+
+        *last = due;
+
+        let _ = self.queue.send((
+            due,
+            Box::new(move || {
+                let _ = writer.send(data);
+            }),
+        ));
+
+        true
+    }
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "socket_UDP", "info": "An unique handle to a UDP socket in memory." }
+*/
+struct SocketUDP {
+    socket: Arc<UdpSocket>,
+    buffer: Vec<u8>,
+    simulate: Option<Simulate>,
+}
+
+unsafe impl Send for SocketUDP {}
+
+impl mlua::UserData for SocketUDP {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:connect",
+            "info": "Connect the socket to a remote address, for use with get and set.",
+            "member": [
+                { "name": "address", "info": "Address to connect to.", "kind": "string" }
+            ],
+            "routine": true
+        }
+        */
+        method.add_async_method("connect", |_: Lua, this, address: String| async move {
+            this.socket.connect(address).await?;
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:get",
+            "info": "Get the next datagram from the connected address, if any. This call does not block.",
+            "result": [
+                { "name": "data", "info": "Datagram, or nil if no datagram is available.", "kind": "data?" }
+            ]
+        }
+        */
+        method.add_method_mut("get", |lua, this, _: ()| {
+            match this.socket.try_recv(&mut this.buffer) {
+                Ok(read) => Ok(Some(Data::new(lua, this.buffer[..read].to_vec())?)),
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+                Err(error) => Err(error.into()),
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set",
+            "info": "Send a datagram to the connected address.",
+            "member": [
+                { "name": "data", "info": "Datagram to send.", "kind": "string | data" }
+            ],
+            "result": [
+                { "name": "length", "info": "Length of the datagram sent.", "kind": "number" }
+            ],
+            "routine": true
+        }
+        */
+        method.add_async_method_mut("set", |_: Lua, mut this, data: LuaValue| async move {
+            let data = Data::get_byte(data)?;
+            let this = &mut *this;
+
+            if let Some(simulate) = &mut this.simulate {
+                return Ok(simulate.send(&this.socket, &data, None)?);
+            }
+
+            Ok(this.socket.send(&data).await?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:get_at",
+            "info": "Get the next datagram from any address, if any. This call does not block.",
+            "result": [
+                { "name": "data",    "info": "Datagram, or nil if no datagram is available.", "kind": "data?"   },
+                { "name": "address", "info": "Address of the sender.",                        "kind": "string?" }
+            ]
+        }
+        */
+        method.add_method_mut("get_at", |lua, this, _: ()| {
+            match this.socket.try_recv_from(&mut this.buffer) {
+                Ok((read, address)) => Ok((
+                    Some(Data::new(lua, this.buffer[..read].to_vec())?),
+                    Some(address.to_string()),
+                )),
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => Ok((None, None)),
+                Err(error) => Err(error.into()),
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set_at",
+            "info": "Send a datagram to an address.",
+            "member": [
+                { "name": "data",    "info": "Datagram to send.",   "kind": "string | data" },
+                { "name": "address", "info": "Address to send to.", "kind": "string"        }
+            ],
+            "result": [
+                { "name": "length", "info": "Length of the datagram sent.", "kind": "number" }
+            ],
+            "routine": true
+        }
+        */
+        method.add_async_method_mut(
+            "set_at",
+            |_: Lua, mut this, (data, address): (LuaValue, String)| async move {
+                let data = Data::get_byte(data)?;
+                let this = &mut *this;
+
+                if let Some(simulate) = &mut this.simulate {
+                    let address = tokio::net::lookup_host(address).await?.next().ok_or_else(|| {
+                        mlua::Error::runtime("socket_UDP:set_at(): Could not resolve address.")
+                    })?;
+
+                    return Ok(simulate.send(&this.socket, &data, Some(address))?);
+                }
+
+                Ok(this.socket.send_to(&data, address).await?)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set_simulate",
+            "info": "Set a network condition simulation, for testing. Every delayed datagram is sent in the background once due. Any datagram still delayed by the last simulation is dropped.",
+            "member": [
+                { "name": "info", "info": "OPTIONAL: Simulation configuration. Use nil to turn the simulation off.", "kind": "simulate_info?" }
+            ],
+            "result": [
+                { "name": "seed", "info": "Random seed in use, or nil if the simulation is off. Use it to reproduce a run.", "kind": "number?" }
+            ],
+            "test": "socket/simulate.lua"
+        }
+        */
+        method.add_method_mut("set_simulate", |lua, this, info: Option<LuaValue>| {
+            this.simulate = Simulate::new(lua, info)?;
+
+            Ok(this.simulate.as_ref().map(|simulate| simulate.get_seed()))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set_broadcast",
+            "info": "Set whether the socket may send datagrams to a broadcast address.",
+            "member": [
+                { "name": "broadcast", "info": "Broadcast state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("set_broadcast", |_, this, broadcast: bool| {
+            Ok(this.socket.set_broadcast(broadcast)?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:join_multicast",
+            "info": "Join a multicast group. Any datagram sent to the group address will be received by this socket.",
+            "member": [
+                { "name": "group",     "info": "Group address (IPv4 or IPv6), without a port.",                               "kind": "string"  },
+                { "name": "interface", "info": "OPTIONAL: Interface address (IPv4) or index (IPv6). Default: any interface.", "kind": "string?" }
+            ]
+        }
+        */
+        method.add_method(
+            "join_multicast",
+            |_, this, (group, interface): (String, Option<String>)| {
+                match Multicast::new(&group, interface, "socket_UDP:join_multicast()")? {
+                    Multicast::V4(group, interface) => {
+                        Ok(this.socket.join_multicast_v4(group, interface)?)
+                    }
+                    Multicast::V6(group, interface) => {
+                        Ok(this.socket.join_multicast_v6(&group, interface)?)
+                    }
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:leave_multicast",
+            "info": "Leave a multicast group.",
+            "member": [
+                { "name": "group",     "info": "Group address (IPv4 or IPv6), without a port.",                               "kind": "string"  },
+                { "name": "interface", "info": "OPTIONAL: Interface address (IPv4) or index (IPv6). Default: any interface.", "kind": "string?" }
+            ]
+        }
+        */
+        method.add_method(
+            "leave_multicast",
+            |_, this, (group, interface): (String, Option<String>)| {
+                match Multicast::new(&group, interface, "socket_UDP:leave_multicast()")? {
+                    Multicast::V4(group, interface) => {
+                        Ok(this.socket.leave_multicast_v4(group, interface)?)
+                    }
+                    Multicast::V6(group, interface) => {
+                        Ok(this.socket.leave_multicast_v6(&group, interface)?)
+                    }
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set_multicast_loop",
+            "info": "Set whether datagrams sent to a multicast group are looped back to this machine.",
+            "member": [
+                { "name": "loop", "info": "Loop state.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("set_multicast_loop", |_, this, state: bool| {
+            if this.socket.local_addr()?.is_ipv6() {
+                Ok(this.socket.set_multicast_loop_v6(state)?)
+            } else {
+                Ok(this.socket.set_multicast_loop_v4(state)?)
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_UDP:set_multicast_TTL",
+            "info": "Set the time-to-live of datagrams sent to a multicast group (IPv4 only), which is the largest number of hops a datagram can take. Default: 1, for the local network only.",
+            "member": [
+                { "name": "TTL", "info": "Time-to-live.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method("set_multicast_TTL", |_, this, value: u32| {
+            Ok(this.socket.set_multicast_ttl_v4(value)?)
+        });
+    }
+}
+
+impl SocketUDP {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.socket.new_UDP",
+        "info": "Create a UDP socket.",
+        "member": [
+            { "name": "address", "info": "Address to bind to.",                                                                     "kind": "string"  },
+            { "name": "length",  "info": "OPTIONAL: Buffer size. Any datagram larger than this will be truncated. Default: 65507.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "socket", "info": "UDP socket.", "kind": "socket_UDP" }
+        ],
+        "routine": true
+    }
+    */
+    async fn new(_: Lua, (address, length): (String, Option<usize>)) -> mlua::Result<Self> {
+        let socket = UdpSocket::bind(address).await?;
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            buffer: vec![0; length.unwrap_or(UDP_BUFFER)],
+            simulate: None,
+        })
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "discovery_info",
+    "info": "A table describing a server, as advertised to every discovery client on the local network. Every field is optional.",
+    "member": [
+        { "name": "name",           "info": "Server name.",                                                                     "kind": "string?" },
+        { "name": "port",           "info": "Port to connect to the server with, which is not the discovery port. Default: 0.", "kind": "number?" },
+        { "name": "player",         "info": "Player count. Default: 0.",                                                        "kind": "number?" },
+        { "name": "player_maximum", "info": "Player maximum. Default: 0.",                                                      "kind": "number?" },
+        { "name": "meta",           "info": "Custom metadata. Default: nil.",                                                   "kind": "table?"  }
+    ]
+}
+*/
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct DiscoveryInfo {
+    name: String,
+    port: u16,
+    player: usize,
+    player_maximum: usize,
+    meta: serde_json::Value,
+}
+
+impl DiscoveryInfo {
+    // serialize the info as a discovery reply.
+    fn to_reply(&self) -> mlua::Result<Vec<u8>> {
+        let mut reply = DISCOVERY_REPLY.to_vec();
+        reply.extend(serde_json::to_vec(self).map_err(|e| mlua::Error::runtime(e.to_string()))?);
+
+        Ok(reply)
+    }
+}
+
+/* class
+{ "version": "1.0.0", "name": "socket_discovery_server", "info": "An unique handle to a discovery server in memory. Every discovery query on the local network will be answered with the server info. update must be called once per frame." }
+*/
+struct SocketDiscoveryServer {
+    socket: UdpSocket,
+    reply: Vec<u8>,
+    buffer: Vec<u8>,
+}
+
+impl mlua::UserData for SocketDiscoveryServer {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_discovery_server:update",
+            "info": "Answer every pending discovery query. This call does not block."
+        }
+        */
+        method.add_method_mut("update", |_, this, _: ()| {
+            while let Ok((read, address)) = this.socket.try_recv_from(&mut this.buffer) {
+                if this.buffer[..read] == DISCOVERY_QUERY {
+                    let _ = this.socket.try_send_to(&this.reply, address);
+                }
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_discovery_server:set_info",
+            "info": "Set the server info, such as when the player count has changed.",
+            "member": [
+                { "name": "info", "info": "Server info.", "kind": "discovery_info" }
+            ]
+        }
+        */
+        method.add_method_mut("set_info", |lua, this, info: LuaValue| {
+            let info: DiscoveryInfo = lua.from_value(info)?;
+            this.reply = info.to_reply()?;
+
+            Ok(())
+        });
+    }
+}
+
+impl SocketDiscoveryServer {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.socket.new_discovery_server",
+        "info": "Create a discovery server, which will answer every discovery query sent to the discovery port, by broadcast or multicast.",
+        "member": [
+            { "name": "port",  "info": "Discovery port.",                                                                                                    "kind": "number"         },
+            { "name": "info",  "info": "Server info.",                                                                                                       "kind": "discovery_info" },
+            { "name": "group", "info": "OPTIONAL: Multicast group address (IPv4 or IPv6) to join, for use with a multicast discovery client. Default: nil.", "kind": "string?"        }
+        ],
+        "result": [
+            { "name": "server", "info": "Discovery server.", "kind": "socket_discovery_server" }
+        ],
+        "routine": true,
+        "test": "socket/discovery.lua"
+    }
+    */
+    async fn new(
+        lua: Lua,
+        (port, info, group): (u16, LuaValue, Option<String>),
+    ) -> mlua::Result<Self> {
+        let info: DiscoveryInfo = lua.from_value(info)?;
+        let call = "alicia.socket.new_discovery_server()";
+
+        let socket = match group {
+            Some(group) => match Multicast::new(&group, None, call)? {
+                Multicast::V4(group, interface) => {
+                    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port)).await?;
+                    socket.join_multicast_v4(group, interface)?;
+                    socket
+                }
+                Multicast::V6(group, interface) => {
+                    let socket = UdpSocket::bind((Ipv6Addr::UNSPECIFIED, port)).await?;
+                    socket.join_multicast_v6(&group, interface)?;
+                    socket
+                }
+            },
+            None => UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port)).await?,
+        };
+
+        Ok(Self {
+            socket,
+            reply: info.to_reply()?,
+            buffer: vec![0; UDP_BUFFER],
+        })
+    }
+}
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "socket_discovery_client", "info": "An unique handle to a discovery client in memory. A discovery query is sent every second, and any server that has not answered in the last three seconds is dropped from the server list." }
+*/
+struct SocketDiscoveryClient {
+    socket: UdpSocket,
+    address: SocketAddr,
+    list: HashMap<SocketAddr, (DiscoveryInfo, Instant)>,
+    query: Option<Instant>,
+    buffer: Vec<u8>,
+}
+
+impl mlua::UserData for SocketDiscoveryClient {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "socket_discovery_client:poll",
+            "info": "Send a discovery query if one is due, receive every pending reply, and get the refreshed server list. Each server has every field of its discovery_info, and the address to connect to the server with. This call does not block.",
+            "result": [
+                { "name": "server_list", "info": "Server list, sorted by address.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("poll", |lua, this, _: ()| {
+            let time = Instant::now();
+
+            if this
+                .query
+                .is_none_or(|query| time.duration_since(query) >= DISCOVERY_INTERVAL)
+            {
+                let _ = this.socket.try_send_to(&DISCOVERY_QUERY, this.address);
+                this.query = Some(time);
+            }
+
+            while let Ok((read, address)) = this.socket.try_recv_from(&mut this.buffer) {
+                let data = &this.buffer[..read];
+
+                if data.starts_with(&DISCOVERY_REPLY)
+                    && let Ok(info) = serde_json::from_slice(&data[DISCOVERY_REPLY.len()..])
+                {
+                    this.list.insert(address, (info, time));
+                }
+            }
+
+            this.list
+                .retain(|_, (_, receive)| time.duration_since(*receive) < DISCOVERY_TIMEOUT);
+
+            let mut work: Vec<(SocketAddr, &DiscoveryInfo)> = this
+                .list
+                .iter()
+                .map(|(address, (info, _))| (SocketAddr::new(address.ip(), info.port), info))
+                .collect();
+            work.sort_by_key(|(address, _)| *address);
+
+            let list = lua.create_table()?;
+
+            for (address, info) in work {
+                let table = lua.create_table()?;
+
+                table.set("address", address.to_string())?;
+                table.set("name", info.name.clone())?;
+                table.set("port", info.port)?;
+                table.set("player", info.player)?;
+                table.set("player_maximum", info.player_maximum)?;
+
+                if !info.meta.is_null() {
+                    table.set("meta", lua.to_value(&info.meta)?)?;
+                }
+
+                list.push(table)?;
+            }
+
+            Ok(list)
+        });
+    }
+}
+
+impl SocketDiscoveryClient {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "alicia.socket.new_discovery_client",
+        "info": "Create a discovery client.",
+        "member": [
+            { "name": "address", "info": "Address to send every discovery query to. Either a broadcast address (such as \"255.255.255.255:7000\") or a multicast group address (such as \"239.255.0.1:7000\"), with the discovery port.", "kind": "string" }
+        ],
+        "result": [
+            { "name": "client", "info": "Discovery client.", "kind": "socket_discovery_client" }
+        ],
+        "routine": true,
+        "test": "socket/discovery.lua"
+    }
+    */
+    async fn new(_: Lua, address: String) -> mlua::Result<Self> {
+        let address = tokio::net::lookup_host(address).await?.next().ok_or_else(|| {
+            mlua::Error::runtime("alicia.socket.new_discovery_client(): Invalid address.")
+        })?;
+
+        let socket = if address.is_ipv6() {
+            UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0)).await?
+        } else {
+            let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+            socket.set_broadcast(true)?;
+            socket
+        };
 
-        Ok(Self(sock))
+        Ok(Self {
+            socket,
+            address,
+            list: HashMap::new(),
+            query: None,
+            buffer: vec![0; UDP_BUFFER],
+        })
     }
 }
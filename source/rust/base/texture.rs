@@ -54,6 +54,7 @@ use crate::status::*;
 //================================================================
 
 use crate::base::helper::*;
+use crate::base::profiler::*;
 use mlua::prelude::*;
 
 //================================================================
@@ -62,7 +63,16 @@ use mlua::prelude::*;
 { "version": "1.0.0", "name": "alicia.texture", "info": "The texture API.", "head": true }
 */
 #[rustfmt::skip]
-pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, script_info: Option<&ScriptInfo>) -> mlua::Result<()> {
+    // part of head API. only run on head API pass, if we are running in head mode.
+    if let Some(info) = script_info {
+        if !info.head {
+            return Ok(())
+        }
+    } else {
+        return Ok(())
+    }
+
     let texture = lua.create_table()?;
 
     texture.set("new",             lua.create_function(self::LuaTexture::new)?)?;
@@ -452,6 +462,7 @@ impl LuaTexture {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Self> {
+        let _scope = ProfileScope::new("texture.new", "asset");
         let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
 
         unsafe {
@@ -476,6 +487,7 @@ impl LuaTexture {
     }
     */
     fn new_from_memory(lua: &Lua, (data, kind): (LuaValue, String)) -> mlua::Result<Self> {
+        let _scope = ProfileScope::new("texture.new_from_memory", "asset");
         let image = crate::base::image::LuaImage::new_from_memory(lua, (data, kind))?;
 
         unsafe {
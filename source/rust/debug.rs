@@ -0,0 +1,1071 @@
+/*
+* Copyright (c) 2025 luxreduxdelux
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use mlua::prelude::*;
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+
+//================================================================
+
+// the debug adapter, only made once per process so that a client will stay attached on reload.
+static DEBUGGER: OnceLock<Option<Debugger>> = OnceLock::new();
+
+// step mode, as requested by the client.
+#[derive(Default, Clone, Copy)]
+enum DebugStep {
+    #[default]
+    None,
+    In,
+    Over(usize),
+    Out(usize),
+}
+
+// a command from the server thread, for the Lua thread to run while paused.
+enum DebugCommand {
+    Request(Value),
+    Resume(&'static str),
+}
+
+// a handle to a variable list, as given to the client by variable reference.
+enum DebugHandle {
+    Scope(usize, &'static str),
+    Table(mlua::Table),
+}
+
+// debug state, shared between the server thread and the Lua thread.
+#[derive(Default)]
+struct DebugState {
+    // every breakpoint line, by canonical file path.
+    breakpoint: HashMap<PathBuf, HashSet<i64>>,
+    step: DebugStep,
+    // pause on the next line, as requested by the client.
+    pause: bool,
+    // the Lua thread is currently paused.
+    paused: bool,
+    // pause on a script error.
+    error: bool,
+    failure: Option<String>,
+    stream: Option<TcpStream>,
+    sequence: i64,
+}
+
+impl DebugState {
+    // check if the hook will need to do any work at all.
+    fn active(&self) -> bool {
+        self.stream.is_some()
+            && (self.pause || !self.breakpoint.is_empty() || !matches!(self.step, DebugStep::None))
+    }
+
+    fn send(&mut self, mut message: Value) {
+        self.sequence += 1;
+        message["seq"] = json!(self.sequence);
+
+        if let Some(stream) = &mut self.stream {
+            let message = message.to_string();
+            let _ = write!(stream, "Content-Length: {}\r\n\r\n{}", message.len(), message);
+        }
+    }
+
+    fn event(&mut self, event: &str, body: Value) {
+        self.send(json!({ "type": "event", "event": event, "body": body }));
+    }
+
+    fn respond(&mut self, request: &Value, result: Result<Value, String>) {
+        let mut message = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": result.is_ok(),
+        });
+
+        match result {
+            Ok(body) => message["body"] = body,
+            Err(error) => message["message"] = json!(error),
+        }
+
+        self.send(message);
+    }
+}
+
+//================================================================
+
+// debug adapter protocol server, to step through game Lua code from an editor. enabled with the
+// --debug [address] command-line argument, or with --debug-wait [address] to also hold up start-up
+// until a client has attached.
+pub struct Debugger {
+    state: Arc<Mutex<DebugState>>,
+    active: Arc<AtomicBool>,
+    receive: Mutex<Receiver<DebugCommand>>,
+    source: Mutex<HashMap<String, Option<PathBuf>>>,
+}
+
+impl Debugger {
+    const ARGUMENT: &'static str = "--debug";
+    const ARGUMENT_WAIT: &'static str = "--debug-wait";
+    const ADDRESS: &'static str = "127.0.0.1:4711";
+    const THREAD: i64 = 1;
+    // inspection helper. every function will look for the first Lua function above itself, which
+    // will be the function that set off the hook.
+    const INSPECT: &'static str = r#"
+local inspect = {}
+
+local function base(level)
+    while true do
+        local info = debug.getinfo(level, "S")
+
+        if not info then
+            return nil
+        end
+
+        if not (info.what == "C") then
+            return level
+        end
+
+        level = level + 1
+    end
+end
+
+function inspect.stack()
+    local list = {}
+    local level = base(3)
+
+    while level do
+        local info = debug.getinfo(level, "nSl")
+
+        if not info then
+            break
+        end
+
+        table.insert(list, {
+            name   = info.name or info.what,
+            source = info.source,
+            line   = info.currentline,
+        })
+
+        level = level + 1
+    end
+
+    return list
+end
+
+function inspect.variable(frame, kind)
+    local list = {}
+    local level = base(3)
+
+    if not level then
+        return list
+    end
+
+    level = level + frame
+
+    if kind == "local" then
+        local i = 1
+
+        while true do
+            local name, value = debug.getlocal(level, i)
+
+            if not name then
+                break
+            end
+
+            -- skip every temporary value.
+            if not (string.sub(name, 1, 1) == "(") then
+                table.insert(list, { name = name, value = value })
+            end
+
+            i = i + 1
+        end
+    else
+        local info = debug.getinfo(level, "f")
+        local i = 1
+
+        while info and info.func do
+            local name, value = debug.getupvalue(info.func, i)
+
+            if not name then
+                break
+            end
+
+            table.insert(list, { name = name, value = value })
+
+            i = i + 1
+        end
+    end
+
+    return list
+end
+
+function inspect.evaluate(frame, code)
+    local scope = {}
+    local level = base(3)
+
+    -- with no frame, only the global table is in scope.
+    if frame and level then
+        level = level + frame
+
+        local info = debug.getinfo(level, "f")
+        local i = 1
+
+        while info and info.func do
+            local name, value = debug.getupvalue(info.func, i)
+
+            if not name then
+                break
+            end
+
+            scope[name] = value
+            i = i + 1
+        end
+
+        -- every local will shadow an up-value of the same name.
+        i = 1
+
+        while true do
+            local name, value = debug.getlocal(level, i)
+
+            if not name then
+                break
+            end
+
+            scope[name] = value
+            i = i + 1
+        end
+    end
+
+    setmetatable(scope, { __index = _G })
+
+    local call, error = loadstring("return " .. code, "=watch")
+
+    if not call then
+        call, error = loadstring(code, "=watch")
+    end
+
+    if not call then
+        return false, error
+    end
+
+    setfenv(call, scope)
+
+    return pcall(call)
+end
+
+return inspect
+"#;
+    // wrap an entry-point in a message handler, which will run before the stack unwinds.
+    const WRAP: &'static str = r#"
+local call, handler = ...
+
+return function(...)
+    local result = { xpcall(call, handler, ...) }
+
+    if not result[1] then
+        error(result[2], 0)
+    end
+
+    return unpack(result, 2, table.maxn(result))
+end
+"#;
+
+    // attach the debug hook to a Lua state. the server is only made on the first call, and will
+    // only wait for a client to attach before returning with --debug-wait.
+    pub fn attach(lua: &Lua) -> mlua::Result<()> {
+        if DEBUGGER.get_or_init(Self::new).is_some() {
+            crate::script::ScriptHook::set(lua, |x| x.debug = true);
+        }
+
+        Ok(())
+    }
+
+    // line hook, called by the Lua hook dispatcher.
+    pub fn line(lua: &Lua, debug: mlua::Debug) -> mlua::Result<()> {
+        if let Some(Some(debugger)) = DEBUGGER.get() {
+            debugger.hook(lua, debug)?;
+        }
+
+        Ok(())
+    }
+
+    // wrap an entry-point, so that a script error will pause with the Lua stack still in place,
+    // if a client is attached. the entry-point is given back as-is with no debug adapter.
+    pub fn wrap(lua: &Lua, call: mlua::Function) -> mlua::Result<mlua::Function> {
+        let Some(Some(debugger)) = DEBUGGER.get() else {
+            return Ok(call);
+        };
+
+        let handler = lua.create_function(move |lua, error: LuaValue| {
+            // an error in the message handler would replace the actual script error.
+            let _ = debugger.failure(lua, &error);
+            Ok(error)
+        })?;
+
+        lua.load(Self::WRAP).set_name("=debug").call((call, handler))
+    }
+
+    // pause on a script error, if a client is attached.
+    fn failure(&self, lua: &Lua, error: &LuaValue) -> mlua::Result<()> {
+        let text = Self::format(lua, error);
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            if state.stream.is_none() || !state.error {
+                return Ok(());
+            }
+
+            state.failure = Some(text.clone());
+        }
+
+        self.pause(lua, "exception", Some(&text))
+    }
+
+    fn new() -> Option<Self> {
+        let mut argument_list = std::env::args()
+            .skip_while(|x| !(x == Self::ARGUMENT || x == Self::ARGUMENT_WAIT));
+
+        let wait = argument_list.next()? == Self::ARGUMENT_WAIT;
+
+        let address = argument_list
+            .next()
+            .filter(|x| !x.starts_with("--"))
+            .unwrap_or_else(|| Self::ADDRESS.to_string());
+
+        let listener = match TcpListener::bind(&address) {
+            Ok(listener) => listener,
+            Err(error) => {
+                eprintln!("ERROR: Could not start debug adapter on \"{address}\": {error}");
+                return None;
+            }
+        };
+
+        Some(Self::bind(listener, wait))
+    }
+
+    // start the server on a listener. if waiting, block until a client has sent every breakpoint.
+    fn bind(listener: TcpListener, wait: bool) -> Self {
+        let address = listener
+            .local_addr()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+
+        let state = Arc::new(Mutex::new(DebugState {
+            error: true,
+            ..Default::default()
+        }));
+        let active = Arc::new(AtomicBool::new(false));
+        let (send, receive) = std::sync::mpsc::channel();
+        let (ready_send, ready_receive) = std::sync::mpsc::channel();
+
+        {
+            let state = state.clone();
+            let active = active.clone();
+
+            std::thread::spawn(move || Self::server(listener, state, active, send, ready_send));
+        }
+
+        if wait {
+            println!("Debugger::new(): Waiting for a debug adapter client on \"{address}\"...");
+
+            // wait until the client has sent every breakpoint.
+            let _ = ready_receive.recv();
+        } else {
+            println!("Debugger::new(): Listening for a debug adapter client on \"{address}\".");
+        }
+
+        Self {
+            state,
+            active,
+            receive: Mutex::new(receive),
+            source: Mutex::new(HashMap::new()),
+        }
+    }
+
+    //================================================================
+    // server thread.
+
+    fn server(
+        listener: TcpListener,
+        state: Arc<Mutex<DebugState>>,
+        active: Arc<AtomicBool>,
+        send: Sender<DebugCommand>,
+        ready: Sender<()>,
+    ) {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+
+            state.lock().unwrap().stream = stream.try_clone().ok();
+
+            let mut reader = BufReader::new(stream);
+
+            while let Some(request) = Self::read(&mut reader) {
+                if !Self::request(&state, &active, &send, &ready, &request) {
+                    break;
+                }
+            }
+
+            // the client is gone. clear every breakpoint, and resume if paused.
+            let paused = {
+                let mut state = state.lock().unwrap();
+
+                state.stream = None;
+                state.breakpoint.clear();
+                state.step = DebugStep::None;
+                state.pause = false;
+                active.store(false, Ordering::Relaxed);
+
+                state.paused
+            };
+
+            if paused {
+                let _ = send.send(DebugCommand::Resume("continue"));
+            }
+        }
+    }
+
+    // read a single message, framed by a content length header.
+    fn read(reader: &mut BufReader<TcpStream>) -> Option<Value> {
+        let mut length = None;
+
+        loop {
+            let mut line = String::new();
+
+            if reader.read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+
+            let line = line.trim();
+
+            if line.is_empty() {
+                break;
+            }
+
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+
+        let mut data = vec![0; length?];
+        reader.read_exact(&mut data).ok()?;
+
+        serde_json::from_slice(&data).ok()
+    }
+
+    // handle a request from the client. return false to drop the client.
+    fn request(
+        state: &Mutex<DebugState>,
+        active: &AtomicBool,
+        send: &Sender<DebugCommand>,
+        ready: &Sender<()>,
+        request: &Value,
+    ) -> bool {
+        let mut state = state.lock().unwrap();
+        let argument = &request["arguments"];
+
+        match request["command"].as_str().unwrap_or_default() {
+            "initialize" => {
+                state.respond(
+                    request,
+                    Ok(json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsEvaluateForHovers": true,
+                        "supportsExceptionInfoRequest": true,
+                        "exceptionBreakpointFilters": [
+                            { "filter": "error", "label": "Script error", "default": true }
+                        ],
+                    })),
+                );
+                state.event("initialized", json!({}));
+            }
+            "launch" | "attach" => state.respond(request, Ok(json!({}))),
+            "setBreakpoints" => {
+                let path = argument["source"]["path"].as_str().unwrap_or_default();
+                let path = std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+                let line: HashSet<i64> = argument["breakpoints"]
+                    .as_array()
+                    .map(|x| x.iter().filter_map(|x| x["line"].as_i64()).collect())
+                    .unwrap_or_default();
+                let list: Vec<Value> = line
+                    .iter()
+                    .map(|x| json!({ "verified": true, "line": x }))
+                    .collect();
+
+                if line.is_empty() {
+                    state.breakpoint.remove(&path);
+                } else {
+                    state.breakpoint.insert(path, line);
+                }
+
+                state.respond(request, Ok(json!({ "breakpoints": list })));
+            }
+            "setExceptionBreakpoints" => {
+                state.error = argument["filters"]
+                    .as_array()
+                    .is_some_and(|x| x.iter().any(|x| x == "error"));
+                state.respond(request, Ok(json!({})));
+            }
+            "configurationDone" => {
+                state.respond(request, Ok(json!({})));
+                let _ = ready.send(());
+            }
+            "threads" => {
+                state.respond(
+                    request,
+                    Ok(json!({ "threads": [ { "id": Self::THREAD, "name": "main" } ] })),
+                );
+            }
+            "pause" => {
+                state.pause = true;
+                state.respond(request, Ok(json!({})));
+            }
+            command @ ("continue" | "next" | "stepIn" | "stepOut") => {
+                if state.paused {
+                    let command = match command {
+                        "next" => "next",
+                        "stepIn" => "stepIn",
+                        "stepOut" => "stepOut",
+                        _ => "continue",
+                    };
+
+                    let _ = send.send(DebugCommand::Resume(command));
+                }
+
+                state.respond(request, Ok(json!({ "allThreadsContinued": true })));
+            }
+            "stackTrace" | "scopes" | "variables" | "evaluate" => {
+                if state.paused {
+                    let _ = send.send(DebugCommand::Request(request.clone()));
+                } else {
+                    state.respond(request, Err("Script is not paused.".to_string()));
+                }
+            }
+            "exceptionInfo" => {
+                let failure = state.failure.clone().unwrap_or_default();
+
+                state.respond(
+                    request,
+                    Ok(json!({
+                        "exceptionId": "error",
+                        "description": failure,
+                        "breakMode": "always",
+                    })),
+                );
+            }
+            "disconnect" => {
+                state.respond(request, Ok(json!({})));
+                return false;
+            }
+            _ => state.respond(request, Err("Unsupported request.".to_string())),
+        }
+
+        active.store(state.active(), Ordering::Relaxed);
+
+        true
+    }
+
+    //================================================================
+    // Lua thread.
+
+    fn hook(&self, lua: &Lua, debug: mlua::Debug) -> mlua::Result<()> {
+        // fast path: no client, or nothing to pause on.
+        if !self.active.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let line = debug.curr_line() as i64;
+        let source = debug.source().source.map(|x| x.to_string());
+
+        let reason = {
+            let mut state = self.state.lock().unwrap();
+
+            let step = match state.step {
+                DebugStep::None => false,
+                DebugStep::In => true,
+                DebugStep::Over(depth) => Self::depth(lua) <= depth,
+                DebugStep::Out(depth) => Self::depth(lua) < depth,
+            };
+
+            if state.pause {
+                state.pause = false;
+                Some("pause")
+            } else if step {
+                Some("step")
+            } else if !state.breakpoint.is_empty()
+                && let Some(path) = source.and_then(|x| self.source_path(&x))
+                && state.breakpoint.get(&path).is_some_and(|x| x.contains(&line))
+            {
+                Some("breakpoint")
+            } else {
+                None
+            }
+        };
+
+        if let Some(reason) = reason {
+            self.pause(lua, reason, None)?;
+        }
+
+        Ok(())
+    }
+
+    // block the Lua thread, and answer every request from the client until resumed.
+    fn pause(&self, lua: &Lua, reason: &str, failure: Option<&str>) -> mlua::Result<()> {
+        let inspect: mlua::Table = lua.load(Self::INSPECT).set_name("=debug").eval()?;
+        let mut handle = Vec::new();
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            state.paused = true;
+            state.step = DebugStep::None;
+            state.event(
+                "stopped",
+                json!({
+                    "reason": reason,
+                    "threadId": Self::THREAD,
+                    "allThreadsStopped": true,
+                    "text": failure,
+                }),
+            );
+        }
+
+        let receive = self.receive.lock().unwrap();
+
+        while let Ok(command) = receive.recv() {
+            match command {
+                DebugCommand::Request(request) => {
+                    let result = self.inspect(lua, &inspect, &mut handle, &request);
+                    self.state.lock().unwrap().respond(&request, result);
+                }
+                DebugCommand::Resume(command) => {
+                    let mut state = self.state.lock().unwrap();
+
+                    // the step depth is relative to the paused function.
+                    state.step = match (command, failure) {
+                        (_, Some(_)) => DebugStep::None,
+                        ("next", _) => DebugStep::Over(Self::depth(lua)),
+                        ("stepIn", _) => DebugStep::In,
+                        ("stepOut", _) => DebugStep::Out(Self::depth(lua)),
+                        _ => DebugStep::None,
+                    };
+
+                    break;
+                }
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+
+        state.paused = false;
+        self.active.store(state.active(), Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    // answer a request that will need the Lua state.
+    fn inspect(
+        &self,
+        lua: &Lua,
+        inspect: &mlua::Table,
+        handle: &mut Vec<DebugHandle>,
+        request: &Value,
+    ) -> Result<Value, String> {
+        let argument = &request["arguments"];
+        let frame = argument["frameId"].as_u64().map(|x| x as usize);
+
+        match request["command"].as_str().unwrap_or_default() {
+            "stackTrace" => {
+                let call: mlua::Function = inspect.get("stack").map_err(|e| e.to_string())?;
+                let list: Vec<mlua::Table> = call.call(()).map_err(|e| e.to_string())?;
+
+                let list: Vec<Value> = list
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        let source: String = x.get("source").unwrap_or_default();
+                        let line: i64 = x.get("line").unwrap_or_default();
+                        let name: String = x.get("name").unwrap_or_default();
+
+                        json!({
+                            "id": i,
+                            "name": name,
+                            "source": self.source(&source),
+                            "line": line.max(0),
+                            "column": 0,
+                        })
+                    })
+                    .collect();
+
+                Ok(json!({ "stackFrames": list, "totalFrames": list.len() }))
+            }
+            "scopes" => {
+                let frame = frame.unwrap_or_default();
+
+                handle.push(DebugHandle::Scope(frame, "local"));
+                let scope_local = handle.len();
+                handle.push(DebugHandle::Scope(frame, "upvalue"));
+                let scope_upvalue = handle.len();
+
+                Ok(json!({
+                    "scopes": [
+                        { "name": "Locals", "variablesReference": scope_local },
+                        { "name": "Upvalues", "variablesReference": scope_upvalue },
+                    ]
+                }))
+            }
+            "variables" => {
+                let index = argument["variablesReference"].as_u64().unwrap_or_default() as usize;
+                let mut pair = Vec::new();
+
+                match handle.get(index.wrapping_sub(1)) {
+                    Some(DebugHandle::Scope(frame, kind)) => {
+                        let call: mlua::Function =
+                            inspect.get("variable").map_err(|e| e.to_string())?;
+                        let list: Vec<mlua::Table> =
+                            call.call((*frame, *kind)).map_err(|e| e.to_string())?;
+
+                        for entry in list {
+                            let name: String = entry.get("name").unwrap_or_default();
+                            let value: LuaValue = entry.get("value").unwrap_or(LuaValue::Nil);
+
+                            pair.push((name, value));
+                        }
+                    }
+                    Some(DebugHandle::Table(table)) => {
+                        for entry in table.clone().pairs::<LuaValue, LuaValue>() {
+                            let (k, v) = entry.map_err(|e| e.to_string())?;
+
+                            pair.push((Self::format(lua, &k), v));
+                        }
+                    }
+                    None => return Err("Invalid variable reference.".to_string()),
+                }
+
+                let list: Vec<Value> = pair
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let (text, index) = Self::variable(lua, handle, value.clone());
+
+                        json!({
+                            "name": name,
+                            "value": text,
+                            "type": value.type_name(),
+                            "variablesReference": index,
+                        })
+                    })
+                    .collect();
+
+                Ok(json!({ "variables": list }))
+            }
+            "evaluate" => {
+                let code = argument["expression"].as_str().unwrap_or_default();
+                let frame = frame.unwrap_or_default();
+                let call: mlua::Function = inspect.get("evaluate").map_err(|e| e.to_string())?;
+                let result: mlua::MultiValue =
+                    call.call((frame, code)).map_err(|e| e.to_string())?;
+                let mut result = result.into_iter();
+
+                let success = matches!(result.next(), Some(LuaValue::Boolean(true)));
+                let value = result.next().unwrap_or(LuaValue::Nil);
+
+                if success {
+                    let (text, index) = Self::variable(lua, handle, value);
+
+                    Ok(json!({ "result": text, "variablesReference": index }))
+                } else {
+                    Err(Self::format(lua, &value))
+                }
+            }
+            _ => Err("Unsupported request.".to_string()),
+        }
+    }
+
+    // get the text of a value, and a variable reference if the value can be expanded.
+    fn variable(lua: &Lua, handle: &mut Vec<DebugHandle>, value: LuaValue) -> (String, usize) {
+        let text = match &value {
+            LuaValue::String(value) => format!("{:?}", value.to_string_lossy()),
+            value => Self::format(lua, value),
+        };
+
+        if let LuaValue::Table(table) = value {
+            handle.push(DebugHandle::Table(table));
+            (text, handle.len())
+        } else {
+            (text, 0)
+        }
+    }
+
+    fn format(lua: &Lua, value: &LuaValue) -> String {
+        lua.globals()
+            .get::<mlua::Function>("tostring")
+            .and_then(|x| x.call::<String>(value))
+            .unwrap_or_else(|_| value.type_name().to_string())
+    }
+
+    // get the stack depth, from the function that set off the hook.
+    fn depth(lua: &Lua) -> usize {
+        let mut depth = 0;
+
+        while lua.inspect_stack(depth).is_some() {
+            depth += 1;
+        }
+
+        depth
+    }
+
+    // get the source of a stack frame, as sent to the client.
+    fn source(&self, source: &str) -> Value {
+        if let Some(path) = self.source_path(source) {
+            let name = path.file_name().map(|x| x.to_string_lossy().to_string());
+
+            json!({ "name": name, "path": path })
+        } else {
+            json!({ "name": source })
+        }
+    }
+
+    // get the canonical path of a chunk source. only a chunk loaded from a file will have one.
+    fn source_path(&self, source: &str) -> Option<PathBuf> {
+        let mut cache = self.source.lock().unwrap();
+
+        cache
+            .entry(source.to_string())
+            .or_insert_with(|| {
+                source
+                    .strip_prefix('@')
+                    .and_then(|x| std::fs::canonicalize(x).ok())
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod test_debug {
+    use super::*;
+
+    fn write(stream: &mut TcpStream, message: Value) {
+        let message = message.to_string();
+        write!(stream, "Content-Length: {}\r\n\r\n{}", message.len(), message).unwrap();
+    }
+
+    // read every message until one will match the given type and name.
+    fn wait(reader: &mut BufReader<TcpStream>, kind: &str, name: &str) -> Value {
+        loop {
+            let message = Debugger::read(reader).unwrap();
+
+            if message["type"] == kind && (message["command"] == name || message["event"] == name)
+            {
+                return message;
+            }
+        }
+    }
+
+    #[test]
+    fn framing() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(client.try_clone().unwrap());
+
+        let mut state = DebugState {
+            stream: Some(server.try_clone().unwrap()),
+            ..Default::default()
+        };
+
+        // every message is given a new sequence number.
+        state.event("initialized", json!({}));
+        state.respond(&json!({ "seq": 4, "command": "threads" }), Err("Fail.".to_string()));
+
+        let message = Debugger::read(&mut reader).unwrap();
+        assert_eq!(message["seq"], 1);
+        assert_eq!(message["event"], "initialized");
+
+        let message = Debugger::read(&mut reader).unwrap();
+        assert_eq!(message["seq"], 2);
+        assert_eq!(message["request_seq"], 4);
+        assert_eq!(message["command"], "threads");
+        assert_eq!(message["success"], false);
+        assert_eq!(message["message"], "Fail.");
+
+        // any other header is skipped, and the length is in bytes rather than characters.
+        let body = json!({ "seq": 1, "command": "evaluate", "arguments": { "expression": "é" } });
+        let body = body.to_string();
+        write!(
+            server,
+            "Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let message = Debugger::read(&mut reader).unwrap();
+        assert_eq!(message["arguments"]["expression"], "é");
+
+        // a closed stream will end the message list.
+        drop(state);
+        drop(server);
+        assert!(Debugger::read(&mut reader).is_none());
+    }
+
+    #[test]
+    fn breakpoint_step() {
+        let path = std::env::temp_dir().join("alicia_debug.lua");
+        std::fs::write(&path, "local a = 1\nlocal b = 2\nlocal c = a + b\nresult = c\n").unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let debugger: &'static Debugger = Box::leak(Box::new(Debugger::bind(listener, false)));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        write(&mut stream, json!({ "seq": 1, "type": "request", "command": "initialize" }));
+        write(
+            &mut stream,
+            json!({
+                "seq": 2,
+                "type": "request",
+                "command": "setBreakpoints",
+                "arguments": {
+                    "source": { "path": path },
+                    "breakpoints": [ { "line": 3 } ],
+                },
+            }),
+        );
+        write(&mut stream, json!({ "seq": 3, "type": "request", "command": "configurationDone" }));
+
+        // every breakpoint is in place once the configuration is done.
+        wait(&mut reader, "response", "configurationDone");
+
+        let client = std::thread::spawn(move || {
+            let line = |stream: &mut TcpStream, reader: &mut BufReader<TcpStream>, seq: i64| {
+                write(
+                    stream,
+                    json!({
+                        "seq": seq,
+                        "type": "request",
+                        "command": "stackTrace",
+                        "arguments": { "threadId": Debugger::THREAD },
+                    }),
+                );
+                wait(reader, "response", "stackTrace")["body"]["stackFrames"][0]["line"].clone()
+            };
+
+            let stopped = wait(&mut reader, "event", "stopped");
+            assert_eq!(stopped["body"]["reason"], "breakpoint");
+            assert_eq!(line(&mut stream, &mut reader, 4), 3);
+
+            write(
+                &mut stream,
+                json!({
+                    "seq": 5,
+                    "type": "request",
+                    "command": "scopes",
+                    "arguments": { "frameId": 0 },
+                }),
+            );
+            let scope = wait(&mut reader, "response", "scopes");
+            let scope = scope["body"]["scopes"][0]["variablesReference"].clone();
+
+            write(
+                &mut stream,
+                json!({
+                    "seq": 6,
+                    "type": "request",
+                    "command": "variables",
+                    "arguments": { "variablesReference": scope },
+                }),
+            );
+            let variable = wait(&mut reader, "response", "variables");
+            let variable: Vec<(String, String)> = variable["body"]["variables"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|x| {
+                    let name = x["name"].as_str().unwrap_or_default().to_string();
+                    let value = x["value"].as_str().unwrap_or_default().to_string();
+                    (name, value)
+                })
+                .collect();
+            assert!(variable.contains(&("a".to_string(), "1".to_string())));
+            assert!(variable.contains(&("b".to_string(), "2".to_string())));
+
+            write(&mut stream, json!({ "seq": 7, "type": "request", "command": "next" }));
+
+            let stopped = wait(&mut reader, "event", "stopped");
+            assert_eq!(stopped["body"]["reason"], "step");
+            assert_eq!(line(&mut stream, &mut reader, 8), 4);
+
+            write(&mut stream, json!({ "seq": 9, "type": "request", "command": "continue" }));
+            wait(&mut reader, "response", "continue");
+        });
+
+        let lua = unsafe { Lua::unsafe_new_with(LuaStdLib::ALL, LuaOptions::new()) };
+
+        lua.set_hook(mlua::HookTriggers::EVERY_LINE, move |lua, debug| {
+            debugger.hook(lua, debug)?;
+            Ok(mlua::VmState::Continue)
+        })
+        .unwrap();
+
+        lua.load(path.as_path()).exec().unwrap();
+
+        client.join().unwrap();
+
+        assert_eq!(lua.globals().get::<i64>("result").unwrap(), 3);
+
+        let _ = std::fs::remove_file(path);
+    }
+}
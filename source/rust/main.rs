@@ -49,6 +49,8 @@
 */
 
 mod base;
+#[cfg(feature = "debug_adapter")]
+mod debug;
 mod script;
 mod status;
 mod test;
@@ -67,8 +69,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // create the Alicia state.
     let mut status = Status::new().await;
 
-    // create the RL context.
-    status.window().await;
+    // create the RL context, unless running in head-less mode.
+    if status.head() {
+        status.window().await;
+    }
 
     loop {
         match status {
@@ -81,9 +85,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         status = state;
                     }
                 } else {
-                    panic!(
+                    return Err(
                         "main(): Missing info manifest data. Refer to the wiki on how to launch Alicia in head-less mode."
-                    )
+                            .into(),
+                    );
                 }
             },
             // success status: standard state.
@@ -101,7 +106,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         status = state;
                     }
                 } else {
-                    panic!("{error:?}")
+                    // no window to show the crash-handler in, close with the error instead.
+                    match Status::failure_head_less(script, error).await {
+                        Ok(state) => status = state,
+                        Err(error) => return Err(error.into()),
+                    }
                 }
             },
             // closure status: break the infinite loop and close.
@@ -60,6 +60,9 @@ use serde::Deserialize;
 #[cfg(feature = "zip")]
 use std::io::Read;
 
+#[cfg(feature = "file_notify")]
+use notify::{Config, PollWatcher, RecursiveMode, Watcher};
+
 use mlua::prelude::*;
 use serde::Serialize;
 use std::ffi::{CStr, CString};
@@ -94,13 +97,15 @@ pub struct Script {
 impl Script {
     const FILE_MAIN: &'static str = include_str!("../lua/main.lua");
     #[rustfmt::skip]
-    pub const FILE_BASE: [BaseFile; 9] = [
+    pub const FILE_BASE: [BaseFile; 11] = [
         BaseFile::new("base/constant.lua",    include_str!(concat!(env!("OUT_DIR"), "/constant.lua"))),
         BaseFile::new("base/extension.lua",   include_str!(concat!(env!("OUT_DIR"), "/extension.lua"))),
         BaseFile::new("base/primitive.lua",   include_str!(concat!(env!("OUT_DIR"), "/primitive.lua"))),
         BaseFile::new("base/scheduler.lua",   include_str!(concat!(env!("OUT_DIR"), "/scheduler.lua"))),
+        BaseFile::new("base/replication.lua", include_str!(concat!(env!("OUT_DIR"), "/replication.lua"))),
         BaseFile::new("base/action.lua",      include_str!(concat!(env!("OUT_DIR"), "/action.lua"))),
         BaseFile::new("base/logger.lua",      include_str!(concat!(env!("OUT_DIR"), "/logger.lua"))),
+        BaseFile::new("base/profiler.lua",    include_str!(concat!(env!("OUT_DIR"), "/profiler.lua"))),
         BaseFile::new("base/window.lua",      include_str!(concat!(env!("OUT_DIR"), "/window.lua"))),
         BaseFile::new("base/system.lua",      include_str!(concat!(env!("OUT_DIR"), "/system.lua"))),
         BaseFile::new("base/scene.lua",       include_str!(concat!(env!("OUT_DIR"), "/scene.lua"))),
@@ -115,6 +120,8 @@ impl Script {
     const CALL_MAIN: &'static str = "main";
     const CALL_INFO: &'static str = "info";
     const CALL_FAIL: &'static str = "fail";
+    #[cfg(feature = "file_notify")]
+    const CALL_RELOAD: &'static str = "reload";
 
     //================================================================
 
@@ -126,6 +133,7 @@ impl Script {
         let status_info = StatusInfo {
             safe: true,
             path: "test/asset".to_string(),
+            head_less: false,
         };
 
         let alicia = Self::set_environment(&lua, &status_info)?;
@@ -162,19 +170,41 @@ impl Script {
             }
         };
 
+        // resolve the game path before moving into it, for the hot-reload watcher.
+        #[cfg(feature = "file_notify")]
+        let game_path = std::fs::canonicalize(&status_info.path)?;
+
         std::env::set_current_dir(&status_info.path).unwrap();
 
         let alicia = Self::set_environment(&lua, status_info)?;
 
+        // attach the debug adapter before any game code is run, so that every breakpoint is hit.
+        #[cfg(feature = "debug_adapter")]
+        crate::debug::Debugger::attach(&lua)?;
+
         // set the standard Alicia library.
         Self::system(&lua, &alicia, status_info, None)?;
 
         lua.load(Self::get_main_data(status_info)?).exec()?;
 
-        let script_info = Self::get_script_info(&lua, &alicia).await?;
+        let mut script_info = Self::get_script_info(&lua, &alicia).await?;
+
+        // the head-less flag will always override the info manifest.
+        if status_info.head_less {
+            script_info.head = false;
+        }
 
         // set script data.
-        lua.set_app_data(ScriptData::new(status_info.clone(), script_info.clone()));
+        #[allow(unused_mut)]
+        let mut script_data = ScriptData::new(status_info.clone(), script_info.clone());
+
+        // watch the game path for any change to a Lua module, in hot-reload mode.
+        #[cfg(feature = "file_notify")]
+        if script_info.reload {
+            script_data.reload = Some(ScriptReload::new(&game_path)?);
+        }
+
+        lua.set_app_data(script_data);
 
         // set the standard Alicia library.
         Self::system(&lua, &alicia, status_info, Some(&script_info))?;
@@ -185,6 +215,13 @@ impl Script {
         // get the fail function.
         let fail: Option<mlua::Function> = alicia.get(Self::CALL_FAIL).unwrap_or(None);
 
+        // pause on a script error before the stack unwinds, if a debug adapter client is attached.
+        #[cfg(feature = "debug_adapter")]
+        let (main, fail) = (
+            crate::debug::Debugger::wrap(&lua, main)?,
+            fail.map(|x| crate::debug::Debugger::wrap(&lua, x)).transpose()?,
+        );
+
         Ok(Self {
             lua,
             main,
@@ -201,6 +238,15 @@ impl Script {
             .map_err(|e| e.to_string())
     }
 
+    // main Lua entry-point, in head-less mode. called once per tick, with the tick's frame time.
+    // nil will keep running, true will reload Alicia, and false will close Alicia.
+    pub async fn main_head_less(&self, step: f32) -> Result<Option<bool>, String> {
+        self.main
+            .call_async::<Option<bool>>(step)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     // fail Lua entry-point.
     pub async fn fail(&self, message: &str) -> Result<bool, String> {
         if let Some(fail) = &self.fail {
@@ -221,6 +267,7 @@ impl Script {
         StatusInfo {
             safe: true,
             path: path.to_string(),
+            head_less: false,
         }
         .dump();
     }
@@ -231,6 +278,7 @@ impl Script {
         StatusInfo {
             safe: true,
             path: path.to_string(),
+            head_less: false,
         }
         .dump();
     }
@@ -273,14 +321,109 @@ impl Script {
         file       ::set_global(lua, alicia, status_info, script_info)?;
         data       ::set_global(lua, alicia, status_info, script_info)?;
         socket     ::set_global(lua, alicia, status_info, script_info)?;
+        net        ::set_global(lua, alicia, status_info, script_info)?;
         automation ::set_global(lua, alicia, status_info, script_info)?;
         collision  ::set_global(lua, alicia, status_info, script_info)?;
+        profiler   ::set_global(lua, alicia, status_info, script_info)?;
+
+        #[cfg(feature = "rapier3d")] rapier    ::set_global(lua, alicia, status_info, script_info)?;
+        #[cfg(feature = "rapier2d")] rapier_2d ::set_global(lua, alicia, status_info, script_info)?;
+        #[cfg(feature = "zip")]      zip       ::set_global(lua, alicia, status_info, script_info)?;
+        #[cfg(feature = "request")]  request   ::set_global(lua, alicia, status_info, script_info)?;
+        #[cfg(feature = "steam")]    steam     ::set_global(lua, alicia, status_info, script_info)?;
+        #[cfg(feature = "discord")]  discord   ::set_global(lua, alicia, status_info, script_info)?;
+
+        Ok(())
+    }
+
+    // re-execute every Lua module that has changed since the last call, in hot-reload mode.
+    #[cfg(feature = "file_notify")]
+    pub fn reload(lua: &Lua) -> mlua::Result<()> {
+        // get every changed file first, as re-executing a module will need the script data too.
+        let path_list = {
+            let mut script_data = lua.app_data_mut::<ScriptData>().unwrap();
+
+            if let Some(reload) = &mut script_data.reload {
+                reload.poll()
+            } else {
+                return Ok(());
+            }
+        };
+
+        if path_list.is_empty() {
+            return Ok(());
+        }
+
+        let package: mlua::Table = lua.globals().get("package")?;
+        let loaded: mlua::Table = package.get("loaded")?;
+        let search: mlua::Function = package.get("searchpath")?;
+        let search_path: mlua::String = package.get("path")?;
+
+        // find the name of every loaded module that was read from a changed file.
+        let mut module_list = Vec::new();
 
-        #[cfg(feature = "rapier3d")] rapier  ::set_global(lua, alicia, status_info, script_info)?;
-        #[cfg(feature = "zip")]      zip     ::set_global(lua, alicia, status_info, script_info)?;
-        #[cfg(feature = "request")]  request ::set_global(lua, alicia, status_info, script_info)?;
-        #[cfg(feature = "steam")]    steam   ::set_global(lua, alicia, status_info, script_info)?;
-        #[cfg(feature = "discord")]  discord ::set_global(lua, alicia, status_info, script_info)?;
+        for pair in loaded.pairs::<LuaValue, LuaValue>() {
+            let (name, _) = pair?;
+
+            if let LuaValue::String(name) = name
+                && let Ok(Some(file)) = search.call::<Option<String>>((&name, &search_path))
+                && let Ok(file) = std::fs::canonicalize(file)
+                && path_list.contains(&file)
+            {
+                module_list.push((name.to_str()?.to_string(), file));
+            }
+        }
+
+        let alicia: mlua::Table = lua.globals().get("alicia")?;
+        let hook = alicia.get::<Option<mlua::Function>>(Self::CALL_RELOAD)?;
+
+        for (name, file) in module_list {
+            // a module with an error in it will not be reloaded, rather than crash the game.
+            if let Err(error) = Self::reload_module(lua, &loaded, &name, &file) {
+                eprintln!("ERROR: Could not reload module \"{name}\": {error}");
+                continue;
+            }
+
+            println!("Script::reload(): Reloaded module \"{name}\".");
+
+            // call the optional reload hook, with the name of the reloaded module. the module
+            // itself has been reloaded by now, so an error in the hook is reported on its own.
+            if let Some(hook) = &hook
+                && let Err(error) = hook.call::<()>(name.as_str())
+            {
+                eprintln!("ERROR: Reload hook failed for module \"{name}\": {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "file_notify")]
+    fn reload_module(
+        lua: &Lua,
+        loaded: &mlua::Table,
+        name: &str,
+        file: &std::path::Path,
+    ) -> mlua::Result<()> {
+        let data = std::fs::read_to_string(file)?;
+        let call = lua
+            .load(data)
+            .set_name(format!("@{}", file.display()))
+            .into_function()?;
+        let value: LuaValue = call.call(name)?;
+
+        match (loaded.get::<LuaValue>(name)?, value) {
+            // patch the old module table in place, so that any reference to it will stay valid.
+            (LuaValue::Table(old), LuaValue::Table(new)) => {
+                for pair in new.pairs::<LuaValue, LuaValue>() {
+                    let (k, v) = pair?;
+                    old.raw_set(k, v)?;
+                }
+            }
+            // the module did not return anything, and instead only set global state.
+            (_, LuaValue::Nil) => {}
+            (_, value) => loaded.set(name, value)?,
+        }
 
         Ok(())
     }
@@ -442,11 +585,14 @@ pub struct Feature {
     file_notify: bool,
     rapier3d: bool,
     rapier2d: bool,
+    web_socket: bool,
+    tls: bool,
     zip: bool,
     request: bool,
     steam: bool,
     discord: bool,
     embed: bool,
+    debug_adapter: bool,
 }
 
 impl Feature {
@@ -457,11 +603,14 @@ impl Feature {
             file_notify: cfg!(feature = "file_notify"),
             rapier3d: cfg!(feature = "rapier3d"),
             rapier2d: cfg!(feature = "rapier2d"),
+            web_socket: cfg!(feature = "web_socket"),
+            tls: cfg!(feature = "tls"),
             zip: cfg!(feature = "zip"),
             request: cfg!(feature = "request"),
             steam: cfg!(feature = "steam"),
             discord: cfg!(feature = "discord"),
             embed: cfg!(feature = "embed"),
+            debug_adapter: cfg!(feature = "debug_adapter"),
         }
     }
 }
@@ -473,16 +622,28 @@ pub struct ScriptData {
     pub version: String,
     pub feature: Feature,
     pub path_escape: bool,
+    // frame time of the last tick, and target frame rate, in head-less mode only.
+    #[serde(skip)]
+    pub frame_time: f32,
+    #[serde(skip)]
+    pub frame_rate: u32,
+    #[cfg(feature = "file_notify")]
+    #[serde(skip)]
+    pub reload: Option<ScriptReload>,
 }
 
 impl ScriptData {
     pub fn new(status_info: StatusInfo, script_info: ScriptInfo) -> Self {
         Self {
+            frame_rate: script_info.rate,
             status_info,
             script_info,
             version: Status::VERSION.to_string(),
             feature: Feature::new(),
             path_escape: false,
+            frame_time: 0.0,
+            #[cfg(feature = "file_notify")]
+            reload: None,
         }
     }
 
@@ -512,6 +673,119 @@ impl ScriptData {
 
 //================================================================
 
+// file watcher for the game path, in hot-reload mode.
+#[cfg(feature = "file_notify")]
+pub struct ScriptReload {
+    _watcher: PollWatcher,
+    receive: std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+}
+
+#[cfg(feature = "file_notify")]
+impl ScriptReload {
+    const POLL_RATE: std::time::Duration = std::time::Duration::from_millis(250);
+
+    fn new(path: &std::path::Path) -> mlua::Result<Self> {
+        let (send, receive) = std::sync::mpsc::channel();
+
+        // the watcher will scan the game path in its own thread.
+        let config = Config::default().with_poll_interval(Self::POLL_RATE);
+        let mut watcher =
+            PollWatcher::new(send, config).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+        watcher
+            .watch(path, RecursiveMode::Recursive)
+            .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+        Ok(Self {
+            _watcher: watcher,
+            receive,
+        })
+    }
+
+    // get every Lua file that has been changed since the last call.
+    fn poll(&mut self) -> Vec<std::path::PathBuf> {
+        let mut result = Vec::new();
+
+        while let Ok(event) = self.receive.try_recv() {
+            let Ok(event) = event else {
+                continue;
+            };
+
+            if !(event.kind.is_modify() || event.kind.is_create()) {
+                continue;
+            }
+
+            for path in event.paths {
+                if path.extension().is_some_and(|x| x == "lua")
+                    && let Ok(path) = std::fs::canonicalize(path)
+                    && !result.contains(&path)
+                {
+                    result.push(path);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+//================================================================
+
+// every user of the Lua hook. only one hook can be set at a time, so the debug adapter and the
+// profiler share a single dispatcher, set with the union of every trigger.
+#[derive(Default, Clone, Copy)]
+pub struct ScriptHook {
+    pub debug: bool,
+    pub profile: bool,
+}
+
+impl ScriptHook {
+    // get the current hook state.
+    pub fn get(lua: &Lua) -> Self {
+        lua.app_data_ref::<Self>().map(|x| *x).unwrap_or_default()
+    }
+
+    // change the hook state, and re-set (or remove) the Lua hook to match.
+    #[allow(unused_variables)]
+    pub fn set(lua: &Lua, call: impl FnOnce(&mut Self)) {
+        let mut hook = Self::get(lua);
+        call(&mut hook);
+        lua.set_app_data(hook);
+
+        if !hook.debug && !hook.profile {
+            lua.remove_hook();
+            return;
+        }
+
+        let mut trigger = mlua::HookTriggers::new();
+
+        if hook.debug {
+            trigger = trigger | mlua::HookTriggers::EVERY_LINE;
+        }
+
+        if hook.profile {
+            trigger = trigger | mlua::HookTriggers::ON_CALLS | mlua::HookTriggers::ON_RETURNS;
+        }
+
+        lua.set_hook(trigger, move |lua, debug| {
+            match debug.event() {
+                #[cfg(feature = "debug_adapter")]
+                mlua::DebugEvent::Line if hook.debug => crate::debug::Debugger::line(lua, debug)?,
+                mlua::DebugEvent::Call | mlua::DebugEvent::TailCall | mlua::DebugEvent::Ret
+                    if hook.profile =>
+                {
+                    crate::base::profiler::Profiler::hook(debug)?
+                }
+                _ => {}
+            }
+
+            Ok(mlua::VmState::Continue)
+        });
+    }
+}
+
+//================================================================
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ScriptInfo {
     #[serde(default = "ScriptInfo::name")]
@@ -524,6 +798,8 @@ pub struct ScriptInfo {
     pub rate: u32,
     #[serde(default = "ScriptInfo::head")]
     pub head: bool,
+    #[serde(default = "ScriptInfo::reload")]
+    pub reload: bool,
     #[serde(default = "ScriptInfo::sync")]
     pub sync: bool,
     #[serde(default = "ScriptInfo::full")]
@@ -567,6 +843,7 @@ impl ScriptInfo {
     fn size()       -> (i32, i32)     { (1024, 768) }
     fn rate()       -> u32            { 60    }
     fn head()       -> bool           { true  }
+    fn reload()     -> bool           { false }
     fn sync()       -> bool           { false }
     fn full()       -> bool           { false }
     fn no_border()  -> bool           { false }
@@ -595,6 +872,7 @@ impl Default for ScriptInfo {
             size:       Self::size(),
             rate:       Self::rate(),
             head:       Self::head(),
+            reload:     Self::reload(),
             sync:       Self::sync(),
             full:       Self::full(),
             no_border:  Self::no_border(),
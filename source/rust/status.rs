@@ -57,6 +57,7 @@ use crate::window::*;
 #[cfg(feature = "embed")]
 use rust_embed::Embed;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 
 //================================================================
 
@@ -95,6 +96,7 @@ impl Status {
                     println!("// -> StatusInfo manifest:");
                     println!("//   * Safe: {}", info.safe);
                     println!("//   * Path: {}", info.path);
+                    println!("//   * Head: {}", script.info.head);
                     println!("//");
                     println!("// -> Feature list:");
 
@@ -128,6 +130,9 @@ impl Status {
                     #[cfg(feature = "embed")]
                     println!("//   * File embed");
 
+                    #[cfg(feature = "debug_adapter")]
+                    println!("//   * Debug adapter");
+
                     println!("//================================================================");
 
                     Self::Success(script)
@@ -213,6 +218,15 @@ impl Status {
         }
     }
 
+    // check if a RL context should be made. with no script instance, only the command-line
+    // argument list can tell.
+    pub fn head(&self) -> bool {
+        match self {
+            Self::Success(script) => script.info.head,
+            _ => !StatusInfo::get_head_less(),
+        }
+    }
+
     // missing state, info.json does not exist.
     pub async fn missing(window: &mut Window) -> Option<Status> {
         window.missing().await
@@ -220,6 +234,10 @@ impl Status {
 
     // success state.
     pub async fn success(script: &Script) -> Option<Status> {
+        if !script.info.head {
+            return Self::success_head_less(script).await;
+        }
+
         match script.main().await {
             Ok(result) => {
                 if result {
@@ -252,6 +270,51 @@ impl Status {
         }
     }
 
+    // success state, in head-less mode. there is no window to wait on, so the main entry-point is
+    // instead called once per tick, at the target frame rate.
+    async fn success_head_less(script: &Script) -> Option<Status> {
+        let mut time = Instant::now();
+        let mut step = 0.0;
+
+        loop {
+            // hot-reload any changed Lua module, once per tick.
+            #[cfg(feature = "file_notify")]
+            if let Err(result) = Script::reload(&script.lua) {
+                return Some(Status::Failure(Some(script.clone()), result.to_string()));
+            }
+
+            match script.main_head_less(step).await {
+                // return true, reload Alicia.
+                Ok(Some(true)) => return Some(Status::new().await),
+                // return false, close Alicia.
+                Ok(Some(false)) => return Some(Status::Closure),
+                // return nil, keep running.
+                Ok(None) => {}
+                // error, go to failure state.
+                Err(result) => return Some(Status::Failure(Some(script.clone()), result)),
+            }
+
+            let rate = if let Some(script_data) = script.lua.app_data_ref::<ScriptData>() {
+                script_data.frame_rate.max(1)
+            } else {
+                60
+            };
+
+            // sleep for whatever is left of the tick.
+            let tick = time + Duration::from_secs_f32(1.0 / rate as f32);
+            tokio::time::sleep_until(tick.into()).await;
+
+            step = time.elapsed().as_secs_f32();
+            time = Instant::now();
+
+            crate::base::profiler::Profiler::frame();
+
+            if let Some(mut script_data) = script.lua.app_data_mut::<ScriptData>() {
+                script_data.frame_time = step;
+            }
+        }
+    }
+
     // failure state.
     pub async fn failure(
         window: &mut Window,
@@ -289,6 +352,26 @@ impl Status {
         window.failure(text).await
     }
 
+    // failure state, in head-less mode. there is no window to show the crash-handler in, so only a
+    // crash-handler set in Lua can recover. otherwise, return the error to close Alicia with.
+    pub async fn failure_head_less(script: &Option<Script>, text: &str) -> Result<Status, String> {
+        // a script instance is available, and a crash-handler was set in Lua.
+        if let Some(script) = script {
+            if script.fail.is_some() {
+                return match script.fail(text).await {
+                    // return true, reload Alicia.
+                    Ok(true) => Ok(Status::new().await),
+                    // return false, close Alicia.
+                    Ok(false) => Ok(Status::Closure),
+                    // an error in the crash-handler.
+                    Err(result) => Err(result),
+                };
+            }
+        }
+
+        Err(text.to_string())
+    }
+
     // panic window, useful for when no RL context is available to display an error.
     pub fn panic(text: &str) {
         rfd::MessageDialog::new()
@@ -313,12 +396,20 @@ pub enum InfoResult {
 pub struct StatusInfo {
     pub safe: bool,
     pub path: String,
+    #[serde(default)]
+    pub head_less: bool,
 }
 
 impl StatusInfo {
     pub const FILE_: &'static str = "info.json";
     pub const MAIN_PATH: &'static str = "main";
     pub const MAIN_FILE: &'static str = "main.lua";
+    pub const HEAD_LESS: &'static str = "--headless";
+
+    // check the command-line argument list for the head-less flag.
+    pub fn get_head_less() -> bool {
+        std::env::args().any(|x| x == Self::HEAD_LESS)
+    }
 
     pub fn new() -> Result<Self, InfoResult> {
         let mut result: Option<StatusInfo> = None;
@@ -332,6 +423,7 @@ impl StatusInfo {
             result = Some(Self {
                 safe: true,
                 path: ".".to_string(),
+                head_less: false,
             });
         }
 
@@ -343,6 +435,7 @@ impl StatusInfo {
             result = Some(Self {
                 safe: true,
                 path: Self::MAIN_PATH.to_string(),
+                head_less: false,
             });
         }
 
@@ -356,6 +449,7 @@ impl StatusInfo {
                 result = Some(Self {
                     safe: true,
                     path: ".".to_string(),
+                    head_less: false,
                 });
             }
         }
@@ -387,6 +481,7 @@ impl StatusInfo {
         let mut argument = StatusInfo {
             safe: true,
             path: ".".to_string(),
+            head_less: false,
         };
         let mut argument_list = std::env::args();
 
@@ -405,6 +500,10 @@ impl StatusInfo {
 
                     argument_pick = true;
                 }
+                // not a path argument, so it will apply to every other info source too.
+                Self::HEAD_LESS => {
+                    argument.head_less = true;
+                }
                 _ => {}
             }
         }
@@ -416,7 +515,11 @@ impl StatusInfo {
         //================================================================
 
         // file does not exist, return missing.
-        if let Some(result) = result {
+        if let Some(mut result) = result {
+            if argument.head_less {
+                result.head_less = true;
+            }
+
             Ok(result)
         } else {
             Err(InfoResult::Missing)
@@ -0,0 +1,47 @@
+-- Create a logger, with the logger console toggled by the F3 key. Every print call is now captured by the logger.
+local lua_print = print
+local logger = logger:new(INPUT_BOARD.F3)
+
+assert(not (print == lua_print))
+
+-- Evaluate an expression. The result is printed to the logger.
+assert(logger:evaluate("1 + 1"))
+assert(logger.current[#logger.current].label == "2")
+
+-- Evaluate a statement. The code is evaluated in the running Lua state.
+assert(logger:evaluate("value = { foo = \"bar\" }"))
+assert(value.foo == "bar")
+
+-- Tables are pretty-printed.
+assert(logger:evaluate("value"))
+assert(string.find(logger.current[#logger.current].label, "foo = bar"))
+
+-- Print calls are captured.
+assert(logger:evaluate("print(\"hello\", \"world\")"))
+assert(logger.current[#logger.current].label == "hello\tworld")
+
+-- An error will not be raised, but printed to the logger instead.
+assert(not logger:evaluate("error(\"failure\")"))
+assert(string.find(logger.current[#logger.current].label, "failure"))
+
+-- Complete the last identifier, walking down from the global table.
+local list = logger:complete("alicia.gen")
+
+assert(#list == 1)
+assert(list[1] == "alicia.general")
+
+local list = logger:complete("print(alicia.general.get_time")
+
+assert(list[1] == "print(alicia.general.get_time")
+assert(list[2] == "print(alicia.general.get_time_unix")
+
+-- Evaluate every line sent through the standard input. This would typically be done in your main game loop.
+logger:update()
+
+-- Draw the logger. This would typically be done in your main game loop.
+--logger:draw()
+
+-- Close the logger. The print function it replaced is restored.
+logger:close()
+
+assert(print == lua_print)
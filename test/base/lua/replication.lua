@@ -0,0 +1,54 @@
+-- Launch the replication server in a second, head-less Alicia process (see test/data/replication/main.lua).
+local path = alicia.general.get_argument()[1]
+
+if not (path:sub(1, 1) == "/") then
+    path = os.getenv("PWD") .. "/" .. path
+end
+
+local process = io.popen(string.format("cd test/data/replication && \"%s\" --headless", path))
+
+-- Create a client transport, and a replication client at 60 ticks per second.
+local client_net = alicia.net.new("0.0.0.0:0")
+local client     = replication_client:new(60.0)
+
+local peer = client_net:connect("127.0.0.1:7778")
+
+local entity = nil
+local time   = os.time()
+
+-- Wait for the server to start, and for the entity to travel for a second.
+while os.time() - time < 10 do
+    -- Client: apply every snapshot, and acknowledge the newest one.
+    for _, event in ipairs(client_net:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            client:receive(event.data)
+        end
+    end
+
+    local acknowledge = client:get_acknowledge()
+
+    if acknowledge then
+        client_net:set(peer, NET_CHANNEL.UNRELIABLE, acknowledge)
+    end
+
+    client:update(1.0 / 60.0)
+
+    entity = client:get_entity(1)
+
+    if entity and entity.x >= 60.0 then
+        break
+    end
+end
+
+-- A malformed packet (not valid UTF-8, or not a snapshot) is dropped, instead of taking the client down.
+assert(client:receive(alicia.data.to_data(-1, 0)) == false)
+assert(client:receive(alicia.data.to_data("snapshot", 2)) == false)
+
+-- Disconnect, which will also close the server process.
+client_net:disconnect(peer)
+client_net:poll()
+process:close()
+
+assert(entity)
+assert(entity.name == "entity")
+assert(entity.x >= 60.0)
@@ -0,0 +1,64 @@
+-- Create a server transport, accepting incoming connections, and a client transport.
+local server = alicia.net.new("127.0.0.1:7777", { listen = true })
+local client = alicia.net.new("0.0.0.0:0")
+
+-- Connect to the server. The handshake is made in the background.
+local peer = client:connect("127.0.0.1:7777")
+
+assert(client:get_state(peer) == SOCKET_STATE.CONNECTING)
+
+-- A message large enough to be split into fragments.
+local large = string.rep("alicia", 1024)
+
+local message = {}
+
+for x = 1, 1000 do
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "first")
+            client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, "second")
+            client:set(peer, NET_CHANNEL.RELIABLE_UNORDERED, large)
+        end
+    end
+
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            table.insert(message, event)
+        end
+    end
+
+    if #message == 3 then
+        break
+    end
+end
+
+assert(client:get_state(peer) == SOCKET_STATE.CONNECT)
+assert(#message == 3)
+
+-- Messages in an ordered channel are received in the order they were sent.
+-- Data kind 2 converts a data buffer to a string.
+local ordered = {}
+
+for _, event in ipairs(message) do
+    if event.channel == NET_CHANNEL.RELIABLE_ORDERED then
+        table.insert(ordered, alicia.data.from_data(event.data, 2))
+    else
+        assert(alicia.data.from_data(event.data, 2) == large)
+    end
+end
+
+assert(ordered[1] == "first")
+assert(ordered[2] == "second")
+
+-- Disconnect. The server will be notified on its next poll.
+client:disconnect(peer)
+
+assert(client:get_state(peer) == SOCKET_STATE.DISCONNECT)
+
+-- An infinite, negative or NaN time-out is rejected.
+for _, timeout in ipairs({ math.huge, -1.0, 0.0 / 0.0 }) do
+    local success, error = pcall(alicia.net.new, "0.0.0.0:0", { timeout = timeout })
+
+    assert(not success)
+    assert(string.find(tostring(error), "Invalid time-out"))
+end
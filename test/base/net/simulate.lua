@@ -0,0 +1,46 @@
+-- Create a server and client transport, on a bad network: every packet is delayed, some are
+-- dropped, duplicated or re-ordered.
+local server = alicia.net.new("127.0.0.1:7787", { listen = true })
+local client = alicia.net.new("0.0.0.0:0")
+
+local simulate = {
+    latency   = 0.02,
+    jitter    = 0.02,
+    loss      = 0.1,
+    duplicate = 0.1,
+    reorder   = 0.1,
+    seed      = 1,
+}
+
+server:set_simulate(simulate)
+client:set_simulate(simulate)
+
+local peer = client:connect("127.0.0.1:7787")
+
+-- Send 20 messages on the ordered channel, once connected.
+local message = {}
+local clock = os.clock()
+
+while #message < 20 and os.clock() - clock < 10.0 do
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            for x = 1, 20 do
+                client:set(peer, NET_CHANNEL.RELIABLE_ORDERED, tostring(x))
+            end
+        end
+    end
+
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            -- Data kind 2 converts a data buffer to a string.
+            table.insert(message, alicia.data.from_data(event.data, 2))
+        end
+    end
+end
+
+-- Every message on a reliable, ordered channel still arrives, once, and in order.
+assert(#message == 20)
+
+for x = 1, 20 do
+    assert(message[x] == tostring(x))
+end
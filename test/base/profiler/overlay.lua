@@ -0,0 +1,5 @@
+-- Create a profiler overlay, toggled with the F4 key.
+local overlay = profiler:new(INPUT_BOARD.F4)
+
+-- Draw the overlay. This would typically be done in your main game loop, inside alicia.draw.begin.
+overlay:draw()
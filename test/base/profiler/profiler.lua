@@ -0,0 +1,84 @@
+-- Begin recording. Every Lua function call is also recorded.
+alicia.profiler.begin(true)
+
+local function work()
+    local value = 0.0
+
+    for x = 1, 100000 do
+        value = value + math.sqrt(x)
+    end
+
+    return value
+end
+
+-- Time a call, as a named scope. Every value returned by the call is passed through.
+local value = alicia.profiler.scope("work", work)
+
+assert(value > 0.0)
+
+-- Mark the end of a frame. This is done automatically by alicia.draw.begin.
+alicia.profiler.new_frame()
+
+-- Get every scope in the last frame, from slowest to fastest.
+local frame_time, list = alicia.profiler.get_frame()
+local entry = nil
+
+for _, scope in ipairs(list) do
+    if scope.name == "work" then
+        entry = scope
+    end
+end
+
+assert(frame_time > 0.0)
+assert(entry)
+assert(entry.count == 1)
+assert(entry.time > 0.0 and entry.time <= frame_time)
+
+-- Close recording, and write every record out as a Chrome trace-event file.
+alicia.profiler.close()
+alicia.profiler.dump("work/trace.json")
+
+local trace = alicia.data.deserialize(alicia.file.get_file("work/trace.json", false, false))
+
+assert(#trace.traceEvents > 0)
+assert(not alicia.profiler.get_state())
+
+-- Record a call stack deeper than the profiler will track. Every call past the limit is skipped,
+-- and will not cut short a function further up the stack.
+alicia.profiler.begin(true)
+
+local function deep(depth)
+    if depth > 0 then
+        return 1 + deep(depth - 1)
+    end
+
+    return 0
+end
+
+local function outer()
+    local value = alicia.profiler.scope("deep", deep, 512)
+
+    return value
+end
+
+assert(outer() == 512)
+
+alicia.profiler.new_frame()
+
+local _, list = alicia.profiler.get_frame()
+local scope_deep  = nil
+local scope_outer = nil
+
+for _, scope in ipairs(list) do
+    if scope.name == "deep" then
+        scope_deep = scope
+    elseif scope.name:find("^outer") then
+        scope_outer = scope
+    end
+end
+
+assert(scope_deep)
+assert(scope_outer)
+assert(scope_outer.time >= scope_deep.time)
+
+alicia.profiler.close()
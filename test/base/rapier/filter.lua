@@ -0,0 +1,51 @@
+-- Create a new simulation, with a fixed floor, and a sensor above it.
+local rapier = alicia.rapier.new()
+
+local floor  = rapier:solid_body(nil, 0, vector_3:new(8.0, 0.5, 8.0))
+local sensor = rapier:solid_body(nil, 0, vector_3:new(1.0, 1.0, 1.0))
+
+rapier:set_solid_body_position(floor, vector_3:new(0.0, -4.0, 0.0))
+rapier:set_solid_body_position(sensor, vector_3:new(0.0, 4.0, 0.0))
+rapier:set_solid_body_sensor(sensor, true)
+
+-- Step once, to update the scene query pipeline.
+rapier:step()
+
+-- Cast a ray downward, which will hit the sensor first.
+local ray = { position = vector_3:new(0.0, 10.0, 0.0), direction = vector_3:new(0.0, -1.0, 0.0) }
+
+local hit = rapier:cast_ray(ray, 20.0, true)
+
+assert(hit.index == sensor.index)
+
+-- Exclude every sensor, which will hit the floor instead.
+local hit = rapier:cast_ray(ray, 20.0, true, { exclude_sensor = true })
+
+assert(hit.index == floor.index)
+
+-- A rigid body and a solid body handle to exclude are still accepted in place of a filter.
+local hit = rapier:cast_ray(ray, 20.0, true, nil, sensor)
+
+assert(hit.index == floor.index)
+
+-- An error in the predicate is returned by the query, rather than silently excluding the solid body.
+local success, message = pcall(rapier.cast_ray, rapier, ray, 20.0, true, {
+    predicate = function(solid_body)
+        error("predicate failure")
+    end,
+})
+
+assert(not success)
+assert(tostring(message):find("predicate failure"))
+
+-- Without a filter, a cuboid intersection test will exclude every sensor, as in previous versions.
+local point = vector_3:new(0.0, 4.0, 0.0)
+local angle = vector_3:new(0.0, 0.0, 0.0)
+local shape = vector_3:new(0.5, 0.5, 0.5)
+
+assert(rapier:test_intersect_cuboid(point, angle, shape) == nil)
+
+-- With a filter, every sensor is included unless excluded by the filter.
+local hit = rapier:test_intersect_cuboid(point, angle, shape, {})
+
+assert(hit.index == sensor.index)
@@ -0,0 +1,21 @@
+-- Create a new simulation, and load a model.
+local rapier = alicia.rapier.new()
+local model  = alicia.model.new("test/data/model/cube.obj")
+
+-- Create a solid body of every kind from the first mesh of the model.
+for _, kind in pairs(MODEL_SHAPE_KIND) do
+    local solid = rapier:solid_body_from_model(nil, model, 0, kind)
+
+    assert(solid)
+end
+
+-- An invalid mesh index or kind is rejected.
+local success, error = pcall(rapier.solid_body_from_model, rapier, nil, model, 1, MODEL_SHAPE_KIND.TRI_MESH)
+
+assert(not success)
+assert(string.find(tostring(error), "Invalid mesh index"))
+
+local success, error = pcall(rapier.solid_body_from_model, rapier, nil, model, 0, 3)
+
+assert(not success)
+assert(string.find(tostring(error), "Invalid kind"))
@@ -0,0 +1,75 @@
+-- Create a new simulation.
+local rapier = alicia.rapier.new()
+
+-- Create a fixed floor, with no rigid body parent.
+rapier:solid_body(nil, 0, vector_3:new(8.0, 0.5, 8.0))
+
+-- Create a dynamic rigid body, and bind a cuboid solid body to it.
+local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+local solid = rapier:solid_body(rigid, 0, vector_3:new(0.5, 0.5, 0.5))
+
+rapier:set_rigid_body_position(rigid, vector_3:new(0.0, 4.0, 0.0), true)
+
+-- Step the simulation for half a second, then save it.
+for x = 1, 30 do
+    rapier:step()
+end
+
+local data = rapier:save()
+
+-- Load the saved simulation. Handles are kept as-is.
+local other = alicia.rapier.load(data)
+
+-- Step both simulations for another second.
+for x = 1, 60 do
+    rapier:step()
+    other:step()
+end
+
+-- Both simulations should be in the exact same state.
+local a_x, a_y, a_z = rapier:get_solid_body_position(solid)
+local b_x, b_y, b_z = other:get_solid_body_position(solid)
+
+assert(a_x == b_x)
+assert(a_y == b_y)
+assert(a_z == b_z)
+
+-- Create a vehicle, with a chassis and four wheels, and drive it forward.
+local chassis = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+local body = rapier:solid_body(chassis, 0, vector_3:new(1.0, 0.25, 0.5))
+rapier:set_rigid_body_position(chassis, vector_3:new(0.0, 2.0, 4.0), true)
+
+local vehicle = rapier:vehicle(chassis)
+
+for _, point in ipairs({
+    vector_3:new(0.75, 0.0, 0.5),
+    vector_3:new(0.75, 0.0, -0.5),
+    vector_3:new(-0.75, 0.0, 0.5),
+    vector_3:new(-0.75, 0.0, -0.5),
+}) do
+    local wheel = rapier:vehicle_add_wheel(vehicle, point, vector_3:new(0.0, -1.0, 0.0),
+        vector_3:new(0.0, 0.0, 1.0), 0.5, 0.25)
+
+    rapier:set_vehicle_wheel_engine_force(vehicle, wheel, 4.0)
+end
+
+for x = 1, 30 do
+    rapier:step()
+end
+
+-- Load the saved simulation. The vehicle handle is kept as-is.
+other = alicia.rapier.load(rapier:save())
+
+for x = 1, 60 do
+    rapier:step()
+    other:step()
+end
+
+-- Both vehicles should be in the exact same state.
+local a_x, a_y, a_z = rapier:get_solid_body_position(body)
+local b_x, b_y, b_z = other:get_solid_body_position(body)
+
+assert(a_x == b_x)
+assert(a_y == b_y)
+assert(a_z == b_z)
+assert(rapier:get_vehicle_speed(vehicle) == other:get_vehicle_speed(vehicle))
@@ -0,0 +1,31 @@
+-- Create a new simulation, with a dynamic rigid body.
+local rapier = alicia.rapier.new()
+
+local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+rapier:solid_body(rigid, 0, vector_3:new(0.5, 0.5, 0.5))
+
+-- Step by a frame time. Half of a time step is kept in the accumulator.
+rapier:set_time_step(0.1)
+
+local _, _, alpha = rapier:step(0.25)
+
+assert(math.abs(alpha - 0.5) < 0.001)
+
+-- An infinite, negative or NaN frame time is rejected, and the accumulator is left as-is.
+for _, time in ipairs({ math.huge, -1.0, 0.0 / 0.0 }) do
+    local success, error = pcall(rapier.step, rapier, time)
+
+    assert(not success)
+    assert(string.find(tostring(error), "Invalid frame time"))
+end
+
+local _, _, alpha = rapier:step(0.0)
+
+assert(math.abs(alpha - 0.5) < 0.001)
+
+-- An infinite, zero or NaN time step is rejected too.
+for _, time_step in ipairs({ math.huge, 0.0, 0.0 / 0.0 }) do
+    assert(not pcall(rapier.set_time_step, rapier, time_step))
+end
+
+assert(math.abs(rapier:get_time_step() - 0.1) < 0.001)
@@ -0,0 +1,23 @@
+-- Create a new 2D simulation.
+local rapier = alicia.rapier_2d.new()
+
+-- Create a fixed floor, with no rigid body parent.
+rapier:solid_body(nil, 0, vector_2:new(8.0, 0.5))
+
+-- Create a dynamic rigid body, and bind a ball solid body to it.
+local rigid = rapier:rigid_body(RIGID_BODY_KIND.DYNAMIC)
+local solid = rapier:solid_body(rigid, 1, 0.5)
+
+-- Move the ball above the floor. Raylib's screen-space is Y-down, so "above" is negative Y.
+rapier:set_rigid_body_position(rigid, vector_2:new(0.0, -4.0), true)
+
+-- Step the simulation for two seconds.
+for x = 1, 120 do
+    rapier:step()
+end
+
+-- The ball should have fallen, and come to a rest on the floor.
+local _, y = rapier:get_solid_body_position(solid)
+
+assert(y > -4.0)
+assert(y < 0.0)
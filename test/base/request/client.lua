@@ -0,0 +1,76 @@
+-- Create a request client, with a header sent on every request, and a cookie store.
+local client = alicia.request.new_client({
+    header  = { ["X-Game"] = "alicia" },
+    cookie  = true,
+    timeout = 10.0,
+})
+
+-- PUT request with a JSON payload and a custom header.
+local response = client:send("http://httpbin.org/put", {
+    method = "PUT",
+    header = { ["X-Version"] = "1.0.0" },
+    json   = { foo = "bar" },
+})
+
+assert(response.status == 200)
+assert(string.find(response.header["content-type"], "application/json"))
+
+local body = alicia.data.deserialize(response.data)
+
+assert(body.json.foo == "bar")
+assert(body.headers["X-Game"] == "alicia")
+assert(body.headers["X-Version"] == "1.0.0")
+
+-- HEAD request. There is no body, but there is still a status and a header table.
+local response = client:send("http://httpbin.org/get", { method = "HEAD" })
+
+assert(response.status == 200)
+assert(response.data == "")
+
+-- An error status is still a response, rather than an error.
+local response = client:send("http://httpbin.org/status/404", { method = "DELETE" })
+
+assert(response.status == 404)
+
+-- Cookies are kept by the client, and sent back on every later request.
+local response = client:send("http://httpbin.org/cookies/set", { query = { session = "123" } })
+
+assert(response.link == "http://httpbin.org/cookies")
+assert(alicia.data.deserialize(response.data).cookies.session == "123")
+
+-- A request that takes longer than its time-out will fail with a time-out error.
+local success, error = pcall(client.send, client, "http://httpbin.org/delay/5", { timeout = 1.0 })
+
+assert(not success)
+assert(string.find(tostring(error), "Request timed out"))
+
+-- A time-out that is negative, infinite or NaN is an error, rather than a panic.
+local success, error = pcall(client.send, client, "http://httpbin.org/get", { timeout = -1.0 })
+
+assert(not success)
+assert(string.find(tostring(error), "Invalid time-out"))
+
+local success = pcall(alicia.request.new_client, { timeout = math.huge })
+
+assert(not success)
+
+-- Download a file, with a progress call-back.
+local progress = 0
+
+local response = alicia.request.download("http://httpbin.org/bytes/4096", "work/download.bin", nil, function(receive, total)
+    assert(total == 4096)
+    progress = receive
+end)
+
+assert(response.status == 200)
+assert(progress == 4096)
+assert(alicia.file.get_file_size("work/download.bin") == 4096)
+
+-- A failed download will never leave a partial file behind.
+local success = pcall(alicia.request.download, "http://httpbin.org/bytes/4096", "work/failure.bin", nil, function()
+    error("failure")
+end)
+
+assert(not success)
+assert(not alicia.file.get_file_exist("work/failure.bin"))
+assert(not alicia.file.get_file_exist("work/failure.bin.part"))
@@ -0,0 +1,85 @@
+-- Create an HTTP server, listening in the background.
+local server = alicia.request.new_server("127.0.0.1:7783")
+
+-- Reply to a GET request on "/status" with a JSON body.
+server:set_route("GET", "/status", function(request)
+    return { json = { name = "alicia", level = request.query.level } }
+end)
+
+-- Reply to any request on a path starting with "/echo/" with the request body.
+server:set_route(nil, "/echo/*", function(request)
+    return { status = 201, header = { ["X-Path"] = request.path }, data = request.data }
+end)
+
+-- Serve every file in "test/data" on "/file/".
+server:set_static("/file/", "test/data")
+
+-- Send a raw HTTP request, and update the server until the whole reply is received.
+local function send(request)
+    local stream = alicia.socket.new_TCP_stream("127.0.0.1:7783")
+
+    stream:set_raw(request)
+
+    local reply = ""
+    local clock = os.clock()
+
+    while os.clock() - clock < 5.0 do
+        -- Every accept error is reported on update, and the server keeps on listening.
+        local _, failure = server:update()
+
+        assert(failure == nil)
+
+        local success, data = pcall(stream.get_raw, stream)
+
+        -- The server closes the connection once it has replied.
+        if not success then
+            break
+        end
+
+        if data then
+            -- Data kind 2 converts a data buffer to a string.
+            reply = reply .. alicia.data.from_data(data, 2)
+        end
+    end
+
+    return reply
+end
+
+local reply = send("GET /status?level=2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 200"))
+assert(string.find(reply, "application/json"))
+assert(string.find(reply, "\"level\":\"2\""))
+
+local reply = send("POST /echo/test HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello")
+
+assert(string.find(reply, "HTTP/1.1 201"))
+assert(string.find(reply, "x-path: /echo/test"))
+assert(string.find(reply, "hello"))
+
+local reply = send("GET /file/sample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 200"))
+assert(string.find(reply, "text/plain"))
+
+-- A percent-encoded path is decoded first.
+local reply = send("GET /file/%73ample.txt HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 200"))
+
+-- A request with no route, or outside the static directory, is given a 404 reply.
+local reply = send("GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 404"))
+
+local reply = send("GET /file/../Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 404"))
+
+local reply = send("GET /file//etc/hosts HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 404"))
+
+local reply = send("GET /file/%2E%2E/Cargo.toml HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+
+assert(string.find(reply, "HTTP/1.1 404"))
@@ -0,0 +1,93 @@
+-- Create a TCP server, listening in the background.
+local server = alicia.socket.new_TCP_server("127.0.0.1:7783")
+
+local time = alicia.general.get_time()
+
+while server:get_state() ~= SOCKET_STATE.CONNECT and alicia.general.get_time() - time < 5.0 do
+    server:poll()
+    alicia.general.sleep(0.01)
+end
+
+assert(server:get_state() == SOCKET_STATE.CONNECT)
+
+-- Connect to the server in the background, and queue a message before the connection is made.
+local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+
+client:set("hello")
+
+-- Wait for the server to receive the message, and reply to it.
+local message = nil
+local peer    = nil
+local time    = alicia.general.get_time()
+
+while not message and alicia.general.get_time() - time < 5.0 do
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            peer = event.client
+        elseif event.kind == SOCKET_EVENT.MESSAGE then
+            -- Data kind 2 converts a data buffer to a string.
+            message = alicia.data.from_data(event.data, 2)
+            server:set(event.client, "world")
+        end
+    end
+
+    alicia.general.sleep(0.01)
+end
+
+assert(peer)
+assert(message == "hello")
+
+-- Wait for the client to receive the reply.
+local reply = nil
+local time  = alicia.general.get_time()
+
+while not reply and alicia.general.get_time() - time < 5.0 do
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            reply = alicia.data.from_data(event.data, 2)
+        end
+    end
+
+    alicia.general.sleep(0.01)
+end
+
+assert(reply == "world")
+assert(client:get_state() == SOCKET_STATE.CONNECT)
+
+-- Close the client. The server will raise a disconnect event, and keep on listening.
+client:close()
+
+local close = false
+local time  = alicia.general.get_time()
+
+while not close and alicia.general.get_time() - time < 5.0 do
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.DISCONNECT then
+            close = event.client == peer
+        end
+    end
+
+    alicia.general.sleep(0.01)
+end
+
+assert(close)
+
+-- A second client can still connect.
+local client = alicia.socket.new_TCP_client("127.0.0.1:7783")
+local join   = false
+local time   = alicia.general.get_time()
+
+while not join and alicia.general.get_time() - time < 5.0 do
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            join = true
+        end
+    end
+
+    alicia.general.sleep(0.01)
+end
+
+assert(join)
+assert(server:get_state() == SOCKET_STATE.CONNECT)
+
+server:close()
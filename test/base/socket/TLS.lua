@@ -0,0 +1,82 @@
+-- A certificate and key for "localhost", made with a local certificate authority.
+local server_info = {
+    certificate = "test/data/tls/certificate.pem",
+    key         = "test/data/tls/key.pem",
+}
+
+-- Trust the local certificate authority, on top of the web PKI root list.
+local client_info = {
+    authority = "test/data/tls/authority.pem",
+}
+
+-- Create a TLS server, listening in the background.
+local server = alicia.socket.new_TCP_server("127.0.0.1:7780", nil, server_info)
+
+local clock = os.clock()
+
+while server:get_state() ~= SOCKET_STATE.CONNECT and os.clock() - clock < 5.0 do
+    server:poll()
+end
+
+-- Connect to the server. The TLS handshake is made before returning.
+local stream = alicia.socket.new_TCP_stream("127.0.0.1:7780", nil, client_info)
+
+stream:set("hello")
+
+local message = nil
+local clock = os.clock()
+
+while not message and os.clock() - clock < 5.0 do
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            -- Data kind 2 converts a data buffer to a string.
+            message = alicia.data.from_data(event.data, 2)
+            server:set(event.client, "world")
+        end
+    end
+end
+
+assert(message == "hello")
+
+local reply = nil
+local clock = os.clock()
+
+while not reply and os.clock() - clock < 5.0 do
+    reply = stream:get()
+end
+
+assert(alicia.data.from_data(reply, 2) == "world")
+
+-- Without the local certificate authority, the server certificate can not be verified.
+assert(not pcall(alicia.socket.new_TCP_stream, "127.0.0.1:7780", nil, {}))
+
+-- Create a TLS listen socket, and connect to it in the background. A development build can
+-- skip verifying the server certificate altogether.
+local listen = alicia.socket.new_TCP_listen("127.0.0.1:7781", nil, server_info)
+local client = alicia.socket.new_TCP_client("127.0.0.1:7781", nil, { insecure = true })
+
+-- Accept the connection. The TLS handshake is made before returning.
+local accept = listen:accept()
+
+accept:set("hello")
+
+local message = nil
+local clock = os.clock()
+
+while not message and os.clock() - clock < 5.0 do
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            message = alicia.data.from_data(event.data, 2)
+        end
+    end
+end
+
+assert(message == "hello")
+assert(client:get_state() == SOCKET_STATE.CONNECT)
+
+-- A peer that never makes the TLS handshake will time out, rather than hold up accept forever.
+local plain = alicia.socket.new_TCP_client("127.0.0.1:7781")
+local success, error = pcall(listen.accept, listen)
+
+assert(not success)
+assert(string.find(tostring(error), "TLS handshake timed out"))
@@ -0,0 +1,45 @@
+-- Create a discovery server on port 7779. The game itself would be on port 7777.
+local server = alicia.socket.new_discovery_server(7779, {
+    name           = "Server",
+    port           = 7777,
+    player         = 2,
+    player_maximum = 8,
+    meta           = { map = "level_1" },
+})
+
+-- Create a discovery client. On a local network, this would be a broadcast address,
+-- such as "255.255.255.255:7779", or a multicast group address.
+local client = alicia.socket.new_discovery_client("127.0.0.1:7779")
+
+local list = {}
+local clock = alicia.general.get_time()
+
+while #list == 0 and alicia.general.get_time() - clock < 2.0 do
+    server:update()
+    list = client:poll()
+
+    alicia.general.sleep(0.01)
+end
+
+assert(#list == 1)
+assert(list[1].name == "Server")
+assert(list[1].address == "127.0.0.1:7777")
+assert(list[1].player == 2)
+assert(list[1].meta.map == "level_1")
+
+-- Update the player count, which will be sent on the next discovery reply.
+server:set_info({ name = "Server", port = 7777, player = 3, player_maximum = 8 })
+
+-- A discovery query is sent every second, so the update will reach the client within a second.
+local clock = alicia.general.get_time()
+
+while not (list[1] and list[1].player == 3) and alicia.general.get_time() - clock < 3.0 do
+    server:update()
+    list = client:poll()
+
+    alicia.general.sleep(0.01)
+end
+
+assert(#list == 1)
+assert(list[1].player == 3)
+assert(list[1].meta == nil)
@@ -0,0 +1,148 @@
+-- Send 100 datagrams through a simulation with a fixed seed, and get every index received.
+local function run(seed, address)
+    local send = alicia.socket.new_UDP("127.0.0.1:0")
+    local receive = alicia.socket.new_UDP(address)
+
+    -- Drop half of every datagram, and duplicate a tenth of them.
+    assert(send:set_simulate({ loss = 0.5, duplicate = 0.1, seed = seed }) == seed)
+
+    for x = 1, 100 do
+        send:set_at(tostring(x), address)
+    end
+
+    local list = {}
+    local clock = alicia.general.get_time()
+
+    while alicia.general.get_time() - clock < 0.5 do
+        local data = receive:get_at()
+
+        if data then
+            -- Data kind 2 converts a data buffer to a string.
+            table.insert(list, alicia.data.from_data(data, 2))
+        else
+            alicia.general.sleep(0.01)
+        end
+    end
+
+    return list
+end
+
+local list_a = run(1234, "127.0.0.1:7784")
+local list_b = run(1234, "127.0.0.1:7785")
+
+-- Some datagrams are lost, but not all of them.
+assert(#list_a > 0 and #list_a < 100)
+
+-- The same seed will always drop and duplicate the same datagrams.
+assert(#list_a == #list_b)
+
+for x = 1, #list_a do
+    assert(list_a[x] == list_b[x])
+end
+
+-- Delay every datagram by 0.2 seconds.
+local send = alicia.socket.new_UDP("127.0.0.1:0")
+local receive = alicia.socket.new_UDP("127.0.0.1:7786")
+
+send:set_simulate({ latency = 0.2 })
+send:set_at("late", "127.0.0.1:7786")
+
+assert(receive:get_at() == nil)
+
+local data = nil
+local clock = alicia.general.get_time()
+
+-- A delayed datagram is sent in the background once due, with no further call on the socket.
+while not data and alicia.general.get_time() - clock < 1.0 do
+    alicia.general.sleep(0.01)
+
+    data = receive:get_at()
+end
+
+assert(alicia.general.get_time() - clock >= 0.15)
+assert(alicia.data.from_data(data, 2) == "late")
+
+-- A stream is simulated too. Every message is delayed, but always kept in order.
+local server = alicia.socket.new_TCP_server("127.0.0.1:7788")
+local client = alicia.socket.new_TCP_client("127.0.0.1:7788")
+
+client:set_simulate({ latency = 0.2, jitter = 0.1, seed = 1 })
+
+local message = {}
+local number = nil
+local clock = alicia.general.get_time()
+
+for x = 1, 10 do
+    client:set(tostring(x))
+end
+
+while #message < 10 and alicia.general.get_time() - clock < 2.0 do
+    for _, event in ipairs(server:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            number = event.client
+        elseif event.kind == SOCKET_EVENT.MESSAGE then
+            table.insert(message, alicia.data.from_data(event.data, 2))
+        end
+    end
+
+    client:poll()
+    alicia.general.sleep(0.01)
+end
+
+assert(alicia.general.get_time() - clock >= 0.15)
+assert(#message == 10)
+
+for x = 1, 10 do
+    assert(message[x] == tostring(x))
+end
+
+-- A lost message is never dropped from a stream. It is re-sent instead, stalling it.
+server:set_simulate({ latency = 0.1, loss = 1.0 })
+server:set(number, "stall")
+
+local message = nil
+local clock = alicia.general.get_time()
+
+while not message and alicia.general.get_time() - clock < 2.0 do
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.MESSAGE then
+            message = alicia.data.from_data(event.data, 2)
+        end
+    end
+
+    alicia.general.sleep(0.01)
+end
+
+assert(alicia.general.get_time() - clock >= 0.35)
+assert(message == "stall")
+
+client:close()
+server:close()
+
+-- A web socket is simulated the same way, if available.
+if alicia.socket.new_web_socket_server then
+    local server = alicia.socket.new_web_socket_server("127.0.0.1:7789")
+    local client = alicia.socket.new_web_socket("ws://127.0.0.1:7789")
+
+    client:set_simulate({ latency = 0.2 })
+    client:set("late", true)
+
+    local message = nil
+    local clock = alicia.general.get_time()
+
+    while not message and alicia.general.get_time() - clock < 2.0 do
+        for _, event in ipairs(server:poll()) do
+            if event.kind == SOCKET_EVENT.MESSAGE then
+                message = event.data
+            end
+        end
+
+        client:poll()
+        alicia.general.sleep(0.01)
+    end
+
+    assert(alicia.general.get_time() - clock >= 0.15)
+    assert(message == "late")
+
+    server:close()
+end
@@ -0,0 +1,49 @@
+-- Create a web socket server, and a web socket client. Both connect in the background.
+local server = alicia.socket.new_web_socket_server("127.0.0.1:8080")
+local client = alicia.socket.new_web_socket("ws://127.0.0.1:8080")
+
+local text  = nil
+local pong  = false
+local close = false
+local code  = nil
+
+-- Both sockets work in the background, so poll them for up to five seconds.
+local time = alicia.general.get_time()
+
+while alicia.general.get_time() - time < 5.0 do
+    for _, event in ipairs(server:poll()) do
+        -- Send a text message to every new client.
+        if event.kind == SOCKET_EVENT.CONNECT then
+            server:set(event.client, "Hello, world!", true)
+        end
+    end
+
+    for _, event in ipairs(client:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            client:ping("ping")
+        elseif event.kind == SOCKET_EVENT.MESSAGE then
+            text = event.text
+        elseif event.kind == SOCKET_EVENT.PONG then
+            pong = true
+        elseif event.kind == SOCKET_EVENT.DISCONNECT then
+            code = event.code
+        end
+    end
+
+    -- Close the connection once every message has been received.
+    if text and pong and not close then
+        client:close(1000, "Goodbye.")
+        close = true
+    end
+
+    if code then
+        break
+    end
+
+    -- Yield to the background tasks between each poll.
+    alicia.general.sleep(0.01)
+end
+
+assert(text == "Hello, world!")
+assert(pong)
+assert(code == 1000)
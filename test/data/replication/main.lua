@@ -0,0 +1,42 @@
+-- Replication server, launched as a second, head-less Alicia process by test/base/lua/replication.lua.
+alicia.general.load_base()
+
+local time = 0.0
+local x    = 0.0
+local net  = alicia.net.new("127.0.0.1:7778", { listen = true })
+local server = replication_server:new()
+
+function alicia.info()
+    return {
+        name = "replication server",
+        rate = 60,
+        head = false,
+    }
+end
+
+function alicia.main(step)
+    time = time + step
+
+    -- give up if the client never shows up, or never leaves.
+    if time > 10.0 then
+        return false
+    end
+
+    for _, event in ipairs(net:poll()) do
+        if event.kind == SOCKET_EVENT.CONNECT then
+            server:insert_client(event.peer)
+        elseif event.kind == SOCKET_EVENT.MESSAGE then
+            server:receive(event.peer, event.data)
+        elseif event.kind == SOCKET_EVENT.DISCONNECT then
+            -- the client is done, close the server.
+            return false
+        end
+    end
+
+    -- move an entity along the X axis.
+    x = x + 1.0
+
+    server:set_entity(1, { x = x, name = "entity" })
+    server:update()
+    server:send(net)
+end